@@ -0,0 +1,59 @@
+use na::{Isometry2, Point2, Unit, Vector2};
+use ncollide2d::mass_properties::Volumetric;
+use ncollide2d::shape::{Ball, ConvexPolygon, Cuboid, Plane, Segment};
+use ncollide2d::transformation;
+
+#[test]
+fn intersection_of_convex_polygons() {
+    let square = ConvexPolygon::try_from_points(&[
+        Point2::new(-1.0, -1.0),
+        Point2::new(1.0, -1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(-1.0, 1.0),
+    ])
+    .unwrap();
+    let diamond = ConvexPolygon::try_from_points(&[
+        Point2::new(0.0, -1.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(-1.0, 0.0),
+    ])
+    .unwrap();
+    let m2 = Isometry2::translation(1.0, 0.0);
+    let res = transformation::convex_intersection(&Isometry2::identity(), &square, &m2, &diamond).unwrap();
+
+    // The left half of the translated diamond.
+    assert_eq!(res.points().len(), 3);
+    assert_relative_eq!(res.volume(), 1.0, epsilon = 1.0e-10);
+
+    let far = Isometry2::translation(5.0, 0.0);
+    assert!(transformation::convex_intersection(&Isometry2::identity(), &square, &far, &diamond).is_none());
+
+    // Curved shapes are approximated.
+    let res = transformation::convex_intersection(&Isometry2::identity(), &Ball::new(2.0), &Isometry2::identity(), &square).unwrap();
+    assert_relative_eq!(res.volume(), 4.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn intersection_with_flat_shapes() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let segment = Segment::new(Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0));
+    let m1 = Isometry2::identity();
+
+    for m2 in &[Isometry2::identity(), Isometry2::translation(10.0, 0.0)] {
+        assert!(transformation::convex_intersection(&m1, &cuboid, m2, &segment).is_none());
+        assert!(transformation::convex_intersection(m2, &segment, &m1, &cuboid).is_none());
+    }
+}
+
+#[test]
+fn clip_by_half_space() {
+    let cuboid = Cuboid::new(Vector2::new(2.0, 1.0));
+    let plane = Plane::new(Unit::new_normalize(Vector2::new(1.0, 0.0)));
+    let m = Isometry2::new(Vector2::new(3.0, 1.0), 0.5);
+    let res = transformation::clip_convex_by_half_space(&m, &cuboid, &(m * Isometry2::translation(1.0, 0.0)), &plane).unwrap();
+
+    assert_eq!(res.points().len(), 4);
+    assert_relative_eq!(res.volume(), 6.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.center_of_mass(), Point2::new(-0.5, 0.0), epsilon = 1.0e-10);
+}
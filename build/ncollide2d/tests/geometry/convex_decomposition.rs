@@ -0,0 +1,124 @@
+use na::Point2;
+use ncollide2d::shape::{ConvexPolygon, Polyline};
+use ncollide2d::transformation::{convex_decomposition, convex_decomposition_polyline};
+
+fn area(poly: &ConvexPolygon<f64>) -> f64 {
+    let pts = poly.points();
+    (0..pts.len())
+        .map(|i| {
+            let (a, b) = (pts[i], pts[(i + 1) % pts.len()]);
+            a.x * b.y - a.y * b.x
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+fn is_convex(poly: &ConvexPolygon<f64>) -> bool {
+    let pts = poly.points();
+    let n = pts.len();
+    (0..n).all(|i| {
+        let ab = pts[(i + 1) % n] - pts[i];
+        let bc = pts[(i + 2) % n] - pts[(i + 1) % n];
+        ab.x * bc.y - ab.y * bc.x > -1.0e-10
+    })
+}
+
+// An L-shaped polygon with a collinear vertex on its long side.
+fn l_shape() -> Vec<Point2<f64>> {
+    vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(4.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 3.0),
+        Point2::new(0.0, 3.0),
+    ]
+}
+
+#[test]
+fn l_shape_gives_two_pieces() {
+    let parts = convex_decomposition(&l_shape(), &[], usize::max_value()).unwrap();
+    let total: f64 = parts.iter().map(area).sum();
+
+    assert_eq!(parts.len(), 2);
+    assert!(parts.iter().all(is_convex));
+    assert_relative_eq!(total, 6.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn convex_polygon_is_kept_whole() {
+    let hexagon: Vec<_> = (0..6)
+        .map(|i| {
+            let angle = i as f64 * std::f64::consts::PI / 3.0;
+            Point2::new(angle.cos(), angle.sin())
+        })
+        .collect();
+
+    let parts = convex_decomposition(&hexagon, &[], 6).unwrap();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].points().len(), 6);
+
+    let parts = convex_decomposition(&hexagon, &[], 4).unwrap();
+    assert!(parts.len() == 2 || parts.len() == 3);
+    assert!(parts.iter().all(|p| p.points().len() <= 4));
+
+    let parts = convex_decomposition(&hexagon, &[], 3).unwrap();
+    assert_eq!(parts.len(), 4);
+}
+
+#[test]
+fn polygon_with_hole() {
+    let outer = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(4.0, 4.0),
+        Point2::new(0.0, 4.0),
+    ];
+    let hole = vec![
+        Point2::new(1.0, 1.0),
+        Point2::new(3.0, 1.0),
+        Point2::new(3.0, 3.0),
+        Point2::new(1.0, 3.0),
+    ];
+
+    let parts = convex_decomposition(&outer, &[hole], usize::max_value()).unwrap();
+    let total: f64 = parts.iter().map(area).sum();
+
+    assert!(parts.len() >= 4 && parts.len() <= 8);
+    assert!(parts.iter().all(is_convex));
+    assert_relative_eq!(total, 12.0, epsilon = 1.0e-10);
+
+    for part in &parts {
+        let c = part
+            .points()
+            .iter()
+            .fold(Point2::origin(), |c, p| c + p.coords / part.points().len() as f64);
+        assert!((c.x - 2.0).abs() > 1.0 || (c.y - 2.0).abs() > 1.0);
+    }
+}
+
+#[test]
+fn comb_from_polyline() {
+    // A comb with 5 teeth.
+    let mut points = vec![Point2::new(0.0, 0.0), Point2::new(10.0, 0.0)];
+
+    for i in (0..5).rev() {
+        let x = i as f64 * 2.0;
+        points.push(Point2::new(x + 2.0, 3.0));
+        points.push(Point2::new(x + 1.0, 3.0));
+        points.push(Point2::new(x + 1.0, 1.0));
+        points.push(Point2::new(x, 1.0));
+    }
+
+    let n = points.len();
+    let indices = (0..n).map(|i| Point2::new(i, (i + 1) % n)).collect();
+    let polyline = Polyline::new(points, Some(indices));
+    let parts = convex_decomposition_polyline(&polyline, usize::max_value()).unwrap();
+    let total: f64 = parts.iter().map(area).sum();
+
+    // The optimum is 6 pieces, and Hertel-Mehlhorn is at most 4 times worse.
+    assert!(parts.len() >= 6 && parts.len() <= 24);
+    assert!(parts.iter().all(is_convex));
+    assert_relative_eq!(total, 10.0 + 5.0 * 2.0, epsilon = 1.0e-10);
+}
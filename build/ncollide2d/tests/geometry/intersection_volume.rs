@@ -0,0 +1,60 @@
+use na::{self, Isometry2, Point2, Unit, Vector2};
+use ncollide2d::query;
+use ncollide2d::shape::{Ball, ConvexPolygon, Cuboid, Plane, Segment};
+use std::f64::consts::PI;
+
+#[test]
+fn overlapping_polygons() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let triangle = ConvexPolygon::try_from_points(&[
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(0.0, 4.0),
+    ])
+    .unwrap();
+    let res = query::intersection_volume(&Isometry2::identity(), &cuboid, &Isometry2::identity(), &triangle).unwrap();
+
+    assert_relative_eq!(res.volume, 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, Point2::new(0.5, 0.5), epsilon = 1.0e-10);
+
+    let rot = Isometry2::new(Vector2::new(1.0, 2.0), 0.4);
+    let res = query::intersection_volume(&rot, &triangle, &(rot * Isometry2::translation(-0.5, -0.5)), &cuboid).unwrap();
+
+    assert_relative_eq!(res.volume, 0.25, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, rot * Point2::new(0.25, 0.25), epsilon = 1.0e-10);
+
+    let far = Isometry2::translation(10.0, 0.0);
+    assert!(query::intersection_volume(&Isometry2::identity(), &cuboid, &far, &triangle).is_none());
+    assert!(query::intersection_volume(&far, &triangle, &Isometry2::identity(), &cuboid).is_none());
+}
+
+#[test]
+fn flat_shapes() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let segment = Segment::new(Point2::new(-1.0, 0.0), Point2::new(1.0, 0.0));
+    let m1 = Isometry2::identity();
+
+    for m2 in &[Isometry2::identity(), Isometry2::translation(10.0, 0.0)] {
+        assert!(query::intersection_volume(&m1, &cuboid, m2, &segment).is_none());
+        assert!(query::intersection_volume(m2, &segment, &m1, &cuboid).is_none());
+    }
+}
+
+#[test]
+fn submerged_area() {
+    let plane = Plane::new(Unit::new_normalize(Vector2::new(-1.0, 1.0)));
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let res = query::intersection_volume(&Isometry2::identity(), &cuboid, &Isometry2::identity(), &plane).unwrap();
+
+    assert_relative_eq!(res.volume, 2.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, Point2::new(1.0 / 3.0, -1.0 / 3.0), epsilon = 1.0e-10);
+
+    let ball = Ball::new(1.0);
+    let water = Isometry2::translation(0.0, 0.5);
+    let res = query::intersection_volume(&water, &Plane::new(Vector2::y_axis()), &Isometry2::identity(), &ball).unwrap();
+
+    // A disk minus a circular segment of height 0.5.
+    let segment = PI / 3.0 - 3.0f64.sqrt() / 4.0;
+    assert_relative_eq!(res.volume, PI - segment, max_relative = 0.01);
+    assert!(res.centroid.y < 0.0 && res.centroid.x.abs() < 1.0e-10);
+}
@@ -0,0 +1,72 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::mass_properties::{shape_mass_properties, MassProperties, Volumetric};
+use ncollide2d::shape::{Ball, Capsule, Compound, ConvexPolygon, Cuboid, ShapeHandle, Triangle};
+
+fn polygon(points: Vec<Point2<f64>>) -> MassProperties<f64> {
+    ConvexPolygon::try_from_points(&points).unwrap().mass_properties(2.0)
+}
+
+fn circle_points(center: Point2<f64>, radius: f64, n: usize, start: f64, end: f64) -> Vec<Point2<f64>> {
+    (0..=n)
+        .map(|i| {
+            let angle = start + (end - start) * i as f64 / n as f64;
+            center + Vector2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+#[test]
+fn exact_shapes_match_polygons() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0)).mass_properties(2.0);
+    assert_relative_eq!(cuboid.volume, 8.0);
+    assert_relative_eq!(cuboid.angular_inertia, 16.0 * 5.0 / 3.0);
+
+    let shifted = polygon(vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 4.0),
+        Point2::new(0.0, 4.0),
+    ]);
+    assert_relative_eq!(shifted.mass, cuboid.mass);
+    assert_relative_eq!(shifted.local_com, Point2::new(1.0, 2.0));
+    assert_relative_eq!(shifted.angular_inertia, cuboid.angular_inertia, epsilon = 1.0e-10);
+
+    let ball = Ball::new(1.5).mass_properties(2.0);
+    let disk = polygon(circle_points(Point2::origin(), 1.5, 1000, 0.0, 6.28));
+    assert_relative_eq!(ball.mass, disk.mass, max_relative = 1.0e-3);
+    assert_relative_eq!(ball.angular_inertia, disk.angular_inertia, max_relative = 1.0e-3);
+
+    let pi = std::f64::consts::PI;
+    let mut points = circle_points(Point2::new(0.0, 1.0), 0.5, 500, 0.0, pi);
+    points.extend(circle_points(Point2::new(0.0, -1.0), 0.5, 500, pi, 2.0 * pi));
+    let capsule = Capsule::new(1.0, 0.5).mass_properties(2.0);
+    let approx = polygon(points);
+    assert_relative_eq!(capsule.mass, approx.mass, max_relative = 1.0e-4);
+    assert_relative_eq!(capsule.angular_inertia, approx.angular_inertia, max_relative = 1.0e-4);
+
+    // The orientation of a triangle does not matter.
+    let (a, b, c) = (Point2::new(0.0, 0.0), Point2::new(3.0, 0.0), Point2::new(0.0, 3.0));
+    let t1 = Triangle::new(a, b, c).mass_properties(1.0);
+    let t2 = Triangle::new(a, c, b).mass_properties(1.0);
+    assert_relative_eq!(t1.volume, 4.5);
+    assert_relative_eq!(t1.local_com, Point2::new(1.0, 1.0));
+    assert_eq!(t1, t2);
+}
+
+#[test]
+fn compound_uses_the_parallel_axis_theorem() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 1.0));
+    let parts = vec![
+        (Isometry2::new(Vector2::new(1.0, 0.0), 0.3), ShapeHandle::new(cuboid.clone())),
+        (Isometry2::new(Vector2::new(3.0, 0.0), 0.0), ShapeHandle::new(cuboid.clone())),
+    ];
+    let props = shape_mass_properties(&Compound::new(parts), 1.0).unwrap();
+    let one = cuboid.mass_properties(1.0);
+
+    assert_relative_eq!(props.local_com, Point2::new(2.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(
+        props.angular_inertia,
+        (one.angular_inertia + one.mass) * 2.0,
+        epsilon = 1.0e-10
+    );
+}
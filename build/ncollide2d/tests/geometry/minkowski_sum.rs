@@ -0,0 +1,30 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::{self, PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Ball, ConvexPolygon, Cuboid, MinkowskiSum, SweptShape};
+use ncollide2d::transformation::ToPolyline;
+
+#[test]
+fn rounded_rectangle_queries() {
+    let shape = MinkowskiSum::new(Cuboid::new(Vector2::new(2.0, 1.0)), Ball::new(0.5));
+    let m = Isometry2::translation(0.0, 1.0);
+
+    assert!(shape.contains_point(&m, &Point2::new(2.4, 1.0)));
+    assert!(!shape.contains_point(&m, &Point2::new(2.45, 2.45)));
+
+    let ray = Ray::new(Point2::new(0.0, 10.0), -Vector2::y());
+    assert_relative_eq!(shape.toi_with_ray(&m, &ray, true).unwrap(), 7.5, epsilon = 1.0e-6);
+
+    let ball = Ball::new(1.0);
+    let dist = query::distance(&m, &shape, &Isometry2::translation(5.0, 1.0), &ball);
+    assert_relative_eq!(dist, 1.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn swept_rectangle_hull() {
+    let swept = SweptShape::new(Cuboid::new(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 2.0));
+    let polygon = ConvexPolygon::try_from_points(&swept.to_polyline(()).coords()).unwrap();
+
+    assert_eq!(polygon.points().len(), 6);
+    assert!(swept.contains_point(&Isometry2::identity(), &Point2::new(2.5, 2.5)));
+    assert!(!swept.contains_point(&Isometry2::identity(), &Point2::new(2.5, -0.5)));
+}
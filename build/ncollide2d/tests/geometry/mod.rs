@@ -3,3 +3,12 @@ mod compound_penetration;
 mod epa2;
 mod ray_cast;
 mod time_of_impact2;
+mod one_way_collisions;
+mod to_polyline;
+mod triangulation;
+mod convex_decomposition;
+mod mass_properties;
+mod intersection_volume;
+mod clipping;
+mod minkowski_sum;
+mod voxels;
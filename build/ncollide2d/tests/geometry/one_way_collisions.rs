@@ -0,0 +1,73 @@
+use na::{DVector, Isometry2, Point2, Vector2};
+use ncollide2d::query::{Proximity, Ray, RayCast};
+use ncollide2d::shape::{Ball, HeightField, Polyline, ShapeHandle};
+use ncollide2d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+fn world_with_ball(ground: ShapeHandle<f32>, ball_y: f32, query: GeometricQueryType<f32>) -> CollisionWorld<f32, ()> {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let _ = world.add(Isometry2::identity(), ground, groups, query, ());
+    let _ = world.add(
+        Isometry2::translation(0.1, ball_y),
+        ShapeHandle::new(Ball::new(0.5)),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+    world
+}
+
+fn num_contacts(ground: ShapeHandle<f32>, ball_y: f32) -> usize {
+    let world = world_with_ball(ground, ball_y, GeometricQueryType::Contacts(0.0, 0.0));
+    let n = world.contact_pairs(true).map(|p| p.3.len()).sum();
+    n
+}
+
+fn proximity(ground: ShapeHandle<f32>, ball_y: f32) -> Proximity {
+    let world = world_with_ball(ground, ball_y, GeometricQueryType::Proximity(0.0));
+    let prox = world
+        .proximity_pairs(false)
+        .next()
+        .map(|p| p.2.proximity())
+        .unwrap_or(Proximity::Disjoint);
+    prox
+}
+
+#[test]
+fn one_way_polyline_and_heightfield() {
+    // Counterclockwise normals of segments going to the left point upward.
+    let mut polyline = Polyline::new(vec![Point2::new(1.0, 0.0), Point2::new(-1.0, 0.0)], None);
+    let mut heightfield = HeightField::new(DVector::zeros(3), Vector2::new(2.0, 1.0));
+
+    assert!(num_contacts(ShapeHandle::new(polyline.clone()), -0.45) > 0);
+    assert!(num_contacts(ShapeHandle::new(heightfield.clone()), -0.45) > 0);
+
+    polyline.set_one_way(Some(0.5));
+    heightfield.set_one_way(Some(0.5));
+
+    assert!(num_contacts(ShapeHandle::new(polyline.clone()), 0.45) > 0);
+    assert!(num_contacts(ShapeHandle::new(heightfield.clone()), 0.45) > 0);
+    assert_eq!(num_contacts(ShapeHandle::new(polyline.clone()), -0.45), 0);
+    assert_eq!(num_contacts(ShapeHandle::new(heightfield.clone()), -0.45), 0);
+
+    let down = Ray::new(Point2::new(0.1, 1.0), -Vector2::y());
+    let up = Ray::new(Point2::new(0.1, -1.0), Vector2::y());
+    let id = Isometry2::identity();
+
+    for shape in [&polyline as &dyn RayCast<f32>, &heightfield as &dyn RayCast<f32>].iter() {
+        assert!(shape.toi_with_ray(&id, &down, true).is_some());
+        assert!(shape.toi_with_ray(&id, &up, true).is_none());
+    }
+}
+
+#[test]
+fn one_way_polyline_proximity() {
+    let mut polyline = Polyline::new(vec![Point2::new(1.0, 0.0), Point2::new(-1.0, 0.0)], None);
+    assert_eq!(proximity(ShapeHandle::new(polyline.clone()), -0.45), Proximity::Intersecting);
+
+    polyline.set_one_way(Some(0.5));
+    assert_eq!(proximity(ShapeHandle::new(polyline.clone()), 0.45), Proximity::Intersecting);
+    // The ball overlaps the polyline but its center is behind it.
+    assert_eq!(proximity(ShapeHandle::new(polyline.clone()), -0.45), Proximity::Disjoint);
+}
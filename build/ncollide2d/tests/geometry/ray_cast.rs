@@ -1,6 +1,6 @@
-use na::{self, Isometry2, Point2, Vector2};
+use na::{self, DVector, Isometry2, Point2, Vector2};
 use ncollide2d::query::{Ray, RayCast};
-use ncollide2d::shape::{ConvexPolygon, Segment, Shape};
+use ncollide2d::shape::{ConvexPolygon, HeightField, Segment, Shape};
 
 #[test]
 fn issue_178_parallel_raycast() {
@@ -134,3 +134,34 @@ fn convexpoly_raycast_fuzz() {
         }
     }
 }
+
+#[test]
+fn transformed_heightfield_raycast() {
+    let heights = DVector::from_fn(17, |i, _| ((i * 7) % 5) as f64 * 0.3);
+    let heightfield = HeightField::new(heights, Vector2::new(8.0, 1.0));
+    let m = Isometry2::new(Vector2::new(1.0, -2.0), 0.2);
+    let mut nhits = 0;
+
+    for i in 0..40 {
+        let t = i as f64 * 0.37;
+        let origin = m * Point2::new(-3.9 + (t * 1.3) % 7.8, 3.0);
+        let ray = Ray::new(origin, m * Vector2::new(t.cos() * 0.4, -1.0));
+
+        let expected = heightfield
+            .segments()
+            .filter_map(|seg| seg.toi_with_ray(&m, &ray, true))
+            .fold(None, |best: Option<f64>, toi| Some(best.map_or(toi, |b| b.min(toi))));
+        let found = heightfield.toi_with_ray(&m, &ray, true);
+
+        match (expected, found) {
+            (Some(toi1), Some(toi2)) => {
+                assert_relative_eq!(toi1, toi2, epsilon = 1.0e-6);
+                nhits += 1;
+            }
+            (None, None) => {}
+            _ => panic!("Expected {:?}, found {:?}.", expected, found),
+        }
+    }
+
+    assert!(nhits > 30);
+}
@@ -0,0 +1,73 @@
+use na::{Isometry2, Point2, Unit, Vector2};
+use ncollide2d::shape::{Compound, ConvexPolygon, Cuboid, Plane, Polyline, ShapeHandle, Voxels};
+use ncollide2d::transformation::{shape_to_polyline, ToPolyline};
+
+#[test]
+fn convex_polygon_to_polyline() {
+    let points = [
+        Point2::new(0.0f32, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.5, 0.2),
+    ];
+    let polygon = ConvexPolygon::try_from_points(&points).unwrap();
+    let polyline = polygon.to_polyline(());
+
+    assert_eq!(polyline.coords(), polygon.points());
+    assert_eq!(polyline.segment_indices().len(), 3);
+}
+
+#[test]
+fn polyline_to_polyline_keeps_segments() {
+    let points = vec![Point2::new(0.0f32, 0.0), Point2::new(1.0, 0.0), Point2::new(1.0, 1.0)];
+    let polyline = Polyline::new(points.clone(), None);
+    let procedural = polyline.to_polyline(());
+
+    assert_eq!(procedural.coords(), &points[..]);
+    // The shape polyline is open so it must not be closed back.
+    assert_eq!(procedural.segment_indices(), vec![Point2::new(0, 1), Point2::new(1, 2)]);
+}
+
+#[test]
+fn plane_to_polyline() {
+    let normal = Unit::new_normalize(Vector2::new(1.0f32, 1.0));
+    let polyline = Plane::new(normal).to_polyline(2.0);
+    let coords = polyline.coords();
+
+    assert_eq!(coords.len(), 2);
+    assert_relative_eq!((coords[1] - coords[0]).dot(&normal), 0.0, epsilon = 1.0e-6);
+    assert_relative_eq!(na::distance(&coords[0], &coords[1]), 4.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn voxels_to_polyline_skips_internal_sides() {
+    let cells = vec![Point2::new(0, 0), Point2::new(1, 0)];
+    let voxels = Voxels::from_cells(Vector2::repeat(1.0f32), Vector2::new(2, 1), cells);
+    let polyline = voxels.to_polyline(());
+
+    assert_eq!(polyline.segment_indices().len(), 6);
+    assert!(polyline.coords().iter().all(|pt| pt.x != 1.0 || pt.y == 0.0 || pt.y == 1.0));
+}
+
+#[test]
+fn compound_to_polyline_merges_transformed_parts() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector2::repeat(0.5f32)));
+    let compound = Compound::new(vec![
+        (Isometry2::translation(2.0, 0.0), cuboid.clone()),
+        (Isometry2::translation(-2.0, 0.0), cuboid),
+    ]);
+
+    let polyline = compound.to_polyline((10, 1.0));
+    let indices = polyline.segment_indices();
+
+    assert_eq!(polyline.coords().len(), 8);
+    assert_eq!(indices.len(), 8);
+    assert!(polyline.coords()[..4].iter().all(|pt| pt.x >= 1.5 && pt.x <= 2.5));
+    assert!(polyline.coords()[4..].iter().all(|pt| pt.x >= -2.5 && pt.x <= -1.5));
+    // The two squares are not connected.
+    assert!(indices[..4].iter().all(|i| i.x < 4 && i.y < 4));
+    assert!(indices[4..].iter().all(|i| i.x >= 4 && i.y >= 4));
+
+    let dynamic = shape_to_polyline(&compound, 10, 1.0).unwrap();
+    assert_eq!(dynamic.coords(), polyline.coords());
+}
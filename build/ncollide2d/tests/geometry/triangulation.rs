@@ -0,0 +1,251 @@
+use na::Point2;
+use ncollide2d::procedural::TriMesh;
+use ncollide2d::shape::Polyline;
+use ncollide2d::transformation::{
+    triangulate, triangulate_polygon, triangulate_polyline, TriangulationError,
+};
+
+fn triangles(mesh: &TriMesh<f64>) -> Vec<[Point2<f64>; 3]> {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|idx| {
+            [
+                mesh.coords[idx[0] as usize],
+                mesh.coords[idx[1] as usize],
+                mesh.coords[idx[2] as usize],
+            ]
+        })
+        .collect()
+}
+
+fn signed_area(tri: &[Point2<f64>; 3]) -> f64 {
+    let ab = tri[1] - tri[0];
+    let ac = tri[2] - tri[0];
+    (ab.x * ac.y - ab.y * ac.x) / 2.0
+}
+
+fn has_edge(mesh: &TriMesh<f64>, a: &Point2<f64>, b: &Point2<f64>) -> bool {
+    triangles(mesh).iter().any(|tri| {
+        (0..3).any(|k| {
+            let (u, v) = (tri[k], tri[(k + 1) % 3]);
+            (u == *a && v == *b) || (u == *b && v == *a)
+        })
+    })
+}
+
+fn square(center: Point2<f64>, half: f64) -> Vec<Point2<f64>> {
+    vec![
+        center + na::Vector2::new(-half, -half),
+        center + na::Vector2::new(half, -half),
+        center + na::Vector2::new(half, half),
+        center + na::Vector2::new(-half, half),
+    ]
+}
+
+#[test]
+fn delaunay_triangulation_of_point_set() {
+    let pts: Vec<_> = (0..50)
+        .map(|i| {
+            let i = i as f64;
+            Point2::new((i * 0.618).fract() * 10.0, (i * 0.414).fract() * 7.0)
+        })
+        .collect();
+    let mesh = triangulate(&pts).unwrap();
+
+    for tri in triangles(&mesh) {
+        assert!(signed_area(&tri) > 0.0);
+        let (center, radius) = ncollide2d::utils::circumcircle(&tri[0], &tri[1], &tri[2]);
+
+        for pt in &pts {
+            assert!(na::distance(pt, &center) >= radius - 1.0e-7);
+        }
+    }
+}
+
+#[test]
+fn triangulation_of_degenerate_point_sets() {
+    let collinear: Vec<_> = (0..5).map(|i| Point2::new(i as f64, 2.0 * i as f64)).collect();
+    let point_sets = vec![Vec::new(), vec![Point2::new(1.0, 2.0)], collinear];
+
+    for pts in point_sets {
+        let mesh = triangulate(&pts).unwrap();
+        assert_eq!(mesh.coords, pts);
+        assert!(triangles(&mesh).is_empty());
+    }
+}
+
+#[test]
+fn polygon_with_hole_and_collinear_vertices() {
+    // A square with collinear vertices on its sides, and a square hole.
+    let outer = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(4.0, 4.0),
+        Point2::new(2.0, 4.0),
+        Point2::new(0.0, 4.0),
+        Point2::new(0.0, 2.0),
+        Point2::new(0.0, 0.0),
+    ];
+    let mut hole = square(Point2::new(2.0, 2.0), 0.5);
+    hole.reverse();
+
+    let mesh = triangulate_polygon(&outer, &[hole.clone()]).unwrap();
+    let tris = triangles(&mesh);
+    let area: f64 = tris.iter().map(signed_area).sum();
+
+    // The closing duplicate vertex is removed.
+    assert_eq!(mesh.coords.len(), 11);
+    // n + 2 * holes - 2 triangles.
+    assert_eq!(tris.len(), 11);
+    assert_relative_eq!(area, 15.0, epsilon = 1.0e-10);
+
+    for tri in &tris {
+        assert!(signed_area(tri) > 0.0);
+        let center = na::center(&na::center(&tri[0], &tri[1]), &tri[2]);
+        assert!((center.x - 2.0).abs() > 0.5 || (center.y - 2.0).abs() > 0.5);
+    }
+
+    for l in [&outer[..7], &hole[..]].iter() {
+        for i in 0..l.len() {
+            assert!(has_edge(&mesh, &l[i], &l[(i + 1) % l.len()]));
+        }
+    }
+}
+
+#[test]
+fn constrained_edges_are_kept() {
+    // A comb-shaped polygon whose edges are not all Delaunay.
+    let outer = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(10.0, 0.0),
+        Point2::new(10.0, 3.0),
+        Point2::new(9.0, 3.0),
+        Point2::new(8.0, 0.5),
+        Point2::new(7.0, 3.0),
+        Point2::new(3.0, 3.0),
+        Point2::new(2.0, 0.5),
+        Point2::new(1.0, 3.0),
+        Point2::new(0.0, 3.0),
+    ];
+    let mesh = triangulate_polygon(&outer, &[]).unwrap();
+    let tris = triangles(&mesh);
+    let area: f64 = tris.iter().map(signed_area).sum();
+    let expected: f64 = {
+        let n = outer.len();
+        (0..n)
+            .map(|i| {
+                let (a, b) = (outer[i], outer[(i + 1) % n]);
+                a.x * b.y - a.y * b.x
+            })
+            .sum::<f64>()
+            / 2.0
+    };
+
+    assert_eq!(tris.len(), outer.len() - 2);
+    assert_relative_eq!(area, expected, epsilon = 1.0e-10);
+
+    for i in 0..outer.len() {
+        assert!(has_edge(&mesh, &outer[i], &outer[(i + 1) % outer.len()]));
+    }
+}
+
+#[test]
+fn large_polygon_with_long_constrained_edges() {
+    // A disk sampled with many vertices, with a thin slit hole crossing most of it: the edges
+    // of the slit cross many edges of the unconstrained triangulation.
+    let n = 2000;
+    let outer: Vec<_> = (0..n)
+        .map(|i| {
+            let angle = i as f64 * std::f64::consts::PI * 2.0 / n as f64;
+            Point2::new(angle.cos() * 10.0, angle.sin() * 10.0)
+        })
+        .collect();
+    let hole = vec![
+        Point2::new(-9.0, -0.01),
+        Point2::new(9.0, -0.01),
+        Point2::new(9.0, 0.01),
+        Point2::new(-9.0, 0.01),
+    ];
+    let mesh = triangulate_polygon(&outer, &[hole.clone()]).unwrap();
+    let tris = triangles(&mesh);
+
+    assert_eq!(tris.len(), n + 4);
+    assert!(tris.iter().all(|tri| signed_area(tri) > 0.0));
+
+    for i in 0..4 {
+        assert!(has_edge(&mesh, &hole[i], &hole[(i + 1) % 4]));
+    }
+}
+
+#[test]
+fn polyline_with_two_loops() {
+    let mut points = square(Point2::origin(), 2.0);
+    points.extend(square(Point2::origin(), 1.0));
+    let indices = vec![
+        Point2::new(0, 1),
+        Point2::new(1, 2),
+        Point2::new(2, 3),
+        Point2::new(3, 0),
+        Point2::new(4, 5),
+        Point2::new(5, 6),
+        Point2::new(6, 7),
+        Point2::new(7, 4),
+    ];
+    let polyline = Polyline::new(points, Some(indices));
+    let mesh = triangulate_polyline(&polyline).unwrap();
+    let area: f64 = triangles(&mesh).iter().map(signed_area).sum();
+
+    assert_relative_eq!(area, 12.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn invalid_boundaries() {
+    assert_eq!(
+        triangulate_polygon::<f64>(&[], &[]).err(),
+        Some(TriangulationError::DegenerateLoop(0))
+    );
+
+    // Two squares touching at a corner.
+    let mut figure_eight = square(Point2::new(1.0, 1.0), 1.0);
+    figure_eight.insert(3, Point2::new(3.0, 2.0));
+    figure_eight.insert(3, Point2::new(3.0, 3.0));
+    figure_eight.insert(3, Point2::new(2.0, 3.0));
+    assert_eq!(
+        triangulate_polygon(&figure_eight, &[]).err(),
+        Some(TriangulationError::SelfTouching(Point2::new(2.0, 2.0)))
+    );
+
+    // A bow tie.
+    let bow_tie = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(0.0, 2.0),
+    ];
+    assert_eq!(
+        triangulate_polygon(&bow_tie, &[]).err(),
+        Some(TriangulationError::SelfIntersecting(Point2::new(1.0, 1.0)))
+    );
+
+    // A hole touching the outer boundary.
+    let hole = vec![Point2::new(0.0, 1.0), Point2::new(1.0, 0.5), Point2::new(1.0, 1.5)];
+    assert_eq!(
+        triangulate_polygon(&square(Point2::new(1.0, 1.0), 1.0), &[hole]).err(),
+        Some(TriangulationError::SelfTouching(Point2::new(0.0, 1.0)))
+    );
+
+    // Collinear vertices only.
+    let line = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(3.0, 0.0)];
+    assert_eq!(
+        triangulate_polygon(&line, &[]).err(),
+        Some(TriangulationError::DegenerateLoop(0))
+    );
+
+    // An open polyline.
+    let polyline = Polyline::new(square(Point2::origin(), 1.0), None);
+    assert_eq!(
+        triangulate_polyline(&polyline).err(),
+        Some(TriangulationError::OpenBoundary(Point2::new(-1.0, -1.0)))
+    );
+}
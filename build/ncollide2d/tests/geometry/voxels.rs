@@ -0,0 +1,101 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::query::{PointQuery, Ray, RayCast};
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle, Voxels};
+use ncollide2d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+// A row of 4 unit voxels with a single voxel on top of the voxel (3, 0).
+fn floor() -> Voxels<f32> {
+    let mut cells: Vec<_> = (0..4).map(|i| Point2::new(i, 0)).collect();
+    cells.push(Point2::new(3, 1));
+    Voxels::from_cells(Vector2::repeat(1.0), Vector2::new(4, 2), cells)
+}
+
+#[test]
+fn voxels_dense_construction() {
+    let occupied = [true, true, true, true, false, false, false, true];
+    let dense = Voxels::new(Vector2::repeat(1.0f32), Vector2::new(4, 2), &occupied);
+    let sparse = floor();
+
+    assert_eq!(dense.cells().len(), 5);
+    assert_eq!(*dense.aabb(), *sparse.aabb());
+    assert!(dense.is_voxel_occupied(&Point2::new(3, 1)));
+    assert!(!dense.is_voxel_occupied(&Point2::new(2, 1)));
+}
+
+#[test]
+fn voxels_point_queries() {
+    let voxels = floor();
+    let m = Isometry2::translation(0.0, -1.0);
+
+    assert!(voxels.contains_point(&m, &Point2::new(0.5, -0.5)));
+    assert!(!voxels.contains_point(&m, &Point2::new(0.5, 0.5)));
+
+    // Inside: the closest point is on the top face, not on the internal faces.
+    let proj = voxels.project_point(&m, &Point2::new(1.0, -0.2), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point2::new(1.0, 0.0), epsilon = 1.0e-5);
+}
+
+#[test]
+fn voxels_ray_casts() {
+    let voxels = floor();
+    let m = Isometry2::identity();
+
+    let ray = Ray::new(Point2::new(1.5, 5.0), -Vector2::y());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-5);
+    assert_relative_eq!(inter.normal, Vector2::y(), epsilon = 1.0e-5);
+
+    // Crosses empty cells before hitting the voxel on top.
+    let ray = Ray::new(Point2::new(-1.0, 1.5), Vector2::x());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-5);
+    assert_relative_eq!(inter.normal, -Vector2::x(), epsilon = 1.0e-5);
+
+    // From the inside, the internal faces are ignored if the ray is not solid.
+    let ray = Ray::new(Point2::new(0.5, 0.5), Vector2::x());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert_relative_eq!(inter.toi, 3.5, epsilon = 1.0e-5);
+}
+
+#[test]
+fn empty_voxels_ray_casts() {
+    let ray = Ray::new(Point2::new(-1.0, 0.5), Vector2::x());
+    let empty = Voxels::from_cells(Vector2::repeat(1.0f32), Vector2::new(0, 3), Vec::new());
+    assert!(empty.toi_with_ray(&Isometry2::identity(), &ray, true).is_none());
+
+    let empty = Voxels::new(Vector2::repeat(1.0f32), Vector2::new(2, 2), &[false; 4]);
+    assert!(empty.toi_with_ray(&Isometry2::identity(), &ray, true).is_none());
+}
+
+#[test]
+fn voxels_internal_edges_are_ignored() {
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(Isometry2::identity(), ShapeHandle::new(floor()), groups, query, ());
+    // A cuboid deep enough across the internal face at x = 1 to have the smallest
+    // penetration along the x axis with the voxel at x = 1.
+    let _ = world.add(
+        Isometry2::translation(0.6, 1.2),
+        ShapeHandle::new(Cuboid::new(Vector2::repeat(0.5))),
+        groups,
+        query,
+        (),
+    );
+    // A ball right above the internal vertex at x = 2.
+    let _ = world.add(Isometry2::translation(2.0, 1.2), ShapeHandle::new(Ball::new(0.5)), groups, query, ());
+    world.update();
+
+    let mut ncontacts = 0;
+
+    for (_, _, _, manifold) in world.contact_pairs(true) {
+        for c in manifold.contacts() {
+            assert_relative_eq!(*c.contact.normal, Vector2::y(), epsilon = 1.0e-5);
+            assert_relative_eq!(c.contact.depth, 0.3, epsilon = 1.0e-5);
+            ncontacts += 1;
+        }
+    }
+
+    assert!(ncontacts > 0);
+}
@@ -0,0 +1,54 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::shape::{Ball, Cuboid, ShapeHandle};
+use ncollide2d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+#[test]
+fn ccd_prevents_tunnelling() {
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let wall = world
+        .add(Isometry2::identity(), ShapeHandle::new(Cuboid::new(Vector2::new(0.1, 5.0))), groups, query, ())
+        .handle();
+    let bullet = world
+        .add(Isometry2::translation(-5.0, 0.0), ShapeHandle::new(Ball::new(0.1)), groups, query, ())
+        .handle();
+    world.update();
+
+    // Without CCD, the bullet goes through the wall.
+    world.set_position(bullet, Isometry2::translation(5.0, 0.0));
+    world.update();
+    assert_eq!(world.contact_events().len(), 0);
+    assert_eq!(world.toi_events().len(), 0);
+
+    world.set_ccd_enabled(bullet, true);
+    world.update();
+    world.set_position(bullet, Isometry2::translation(-5.0, 0.0));
+    world.update();
+    assert_eq!(world.contact_events().len(), 0);
+
+    let event = world.toi_events().iter().next().expect("No TOI event.");
+    let (bullet_pt, wall_pt, normal) = if event.collider1 == bullet {
+        (event.world1, event.world2, *event.normal)
+    } else {
+        (event.world2, event.world1, -*event.normal)
+    };
+    assert!(event.collider1 == wall || event.collider2 == wall);
+    assert!((event.toi - 0.48).abs() < 1.0e-3);
+    assert!((bullet_pt.x - 0.1).abs() < 1.0e-3);
+    assert!((wall_pt.x - 0.1).abs() < 1.0e-3);
+    assert!((normal.x + 1.0).abs() < 1.0e-3);
+
+    // The object did not move since the last update, so its broad phase proxy no longer
+    // encloses its motion through the wall.
+    world.update();
+    assert_eq!(world.toi_events().len(), 0);
+    assert_eq!(world.contact_pairs(false).count(), 0);
+
+    // The motion can also be given as a velocity.
+    world.set_position(bullet, Isometry2::translation(5.0, 0.0));
+    world.set_ccd_velocity(bullet, &Vector2::new(100.0, 0.0), 0.1);
+    world.update();
+    assert_eq!(world.toi_events().len(), 1);
+    assert!((world.toi_events().iter().next().unwrap().toi - 0.48).abs() < 1.0e-3);
+}
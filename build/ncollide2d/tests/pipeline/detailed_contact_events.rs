@@ -0,0 +1,68 @@
+use na::Isometry2;
+use ncollide2d::events::DetailedContactEvent;
+use ncollide2d::shape::{Ball, ShapeHandle};
+use ncollide2d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+#[test]
+fn detailed_contact_events() {
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let h1 = world.add(Isometry2::identity(), shape.clone(), groups, query, ()).handle();
+    let h2 = world.add(Isometry2::translation(1.5, 0.0), shape, groups, query, ()).handle();
+
+    // Disabled by default.
+    world.update();
+    assert_eq!(world.contact_events().len(), 1);
+    assert_eq!(world.detailed_contact_events().len(), 0);
+
+    world.set_detailed_contact_events_enabled(true);
+    world.set_position(h2, Isometry2::translation(10.0, 0.0));
+    world.update();
+    world.set_position(h2, Isometry2::translation(1.5, 0.0));
+    world.update();
+
+    match world.detailed_contact_events().iter().next() {
+        Some(DetailedContactEvent::Started(co1, co2, summary)) => {
+            assert_eq!((*co1, *co2), (h1, h2));
+            assert_eq!(summary.num_contacts, 1);
+            assert!((summary.deepest.depth - 0.5).abs() < 1.0e-5);
+            assert!((summary.deepest.normal.x - 1.0).abs() < 1.0e-5);
+        }
+        e => panic!("Unexpected event: {:?}", e),
+    }
+
+    // Persisted events are generated even if the objects did not move.
+    for nframes in 2..4 {
+        world.update();
+        assert_eq!(world.contact_events().len(), 0);
+        match world.detailed_contact_events().iter().next() {
+            Some(DetailedContactEvent::Persisted(_, _, _, n)) => assert_eq!(*n, nframes),
+            e => panic!("Unexpected event: {:?}", e),
+        }
+    }
+
+    // Separated but still close enough to be reported by the broad phase.
+    world.set_position(h2, Isometry2::translation(2.001, 0.0));
+    world.update();
+    match world.detailed_contact_events().iter().next() {
+        Some(DetailedContactEvent::Stopped(_, _, summary)) => {
+            assert!((summary.deepest.depth - 0.5).abs() < 1.0e-5)
+        }
+        e => panic!("Unexpected event: {:?}", e),
+    }
+
+    // Separated by the broad phase.
+    world.set_position(h2, Isometry2::translation(1.8, 0.0));
+    world.update();
+    world.set_position(h2, Isometry2::translation(10.0, 0.0));
+    world.update();
+    assert_eq!(world.detailed_contact_events().len(), 1);
+    match world.detailed_contact_events().iter().next() {
+        Some(DetailedContactEvent::Stopped(_, _, summary)) => {
+            assert!((summary.deepest.depth - 0.2).abs() < 1.0e-5)
+        }
+        e => panic!("Unexpected event: {:?}", e),
+    }
+}
@@ -0,0 +1,68 @@
+use na::{Isometry2, Vector2};
+use ncollide2d::shape::{Ball, ShapeHandle};
+use ncollide2d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+#[test]
+fn collision_groups_max_group_id() {
+    assert_eq!(CollisionGroups::max_group_id(), 29);
+    assert_eq!(CollisionGroups::<u32>::max_group_id_for_bit_set(), 29);
+    assert_eq!(CollisionGroups::<u64>::max_group_id_for_bit_set(), 63);
+    assert_eq!(CollisionGroups::<u128>::max_group_id_for_bit_set(), 127);
+    assert_eq!(CollisionGroups::<[u64; 4]>::max_group_id_for_bit_set(), 255);
+    assert_eq!(CollisionGroups::<[u64; 16]>::max_group_id_for_bit_set(), 1023);
+}
+
+#[test]
+fn large_collision_groups_membership() {
+    let groups = CollisionGroups::<[u64; 4]>::default()
+        .with_membership(&[3, 64, 200])
+        .with_blacklist(&[255]);
+
+    assert!(groups.is_member_of(3));
+    assert!(groups.is_member_of(64));
+    assert!(groups.is_member_of(200));
+    assert!(!groups.is_member_of(63));
+    assert!(!groups.is_member_of(201));
+    assert!(groups.can_interact_with(254));
+    assert!(!groups.can_interact_with(255));
+    assert!(!groups.can_interact_with_self());
+}
+
+#[test]
+#[should_panic]
+fn large_collision_groups_out_of_range() {
+    let _ = CollisionGroups::<u128>::default().with_membership(&[128]);
+}
+
+#[test]
+fn large_collision_groups_world() {
+    let mut world = CollisionWorld::<f64, (), [u64; 4]>::new(0.1);
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let groups_a = CollisionGroups::default()
+        .with_membership(&[150])
+        .with_whitelist(&[150, 250]);
+    let groups_b = CollisionGroups::default()
+        .with_membership(&[250])
+        .with_whitelist(&[150]);
+    let groups_c = CollisionGroups::default()
+        .with_membership(&[150])
+        .with_blacklist(&[250]);
+
+    let a = world
+        .add(Isometry2::new(Vector2::new(0.0, 0.0), 0.0), shape.clone(), groups_a, contact_query, ())
+        .handle();
+    let b = world
+        .add(Isometry2::new(Vector2::new(1.0, 0.0), 0.0), shape.clone(), groups_b, contact_query, ())
+        .handle();
+    let c = world
+        .add(Isometry2::new(Vector2::new(0.5, 0.5), 0.0), shape.clone(), groups_c, contact_query, ())
+        .handle();
+    world.update();
+
+    assert!(world.contact_pair(a, b, true).is_some());
+    assert!(world.contact_pair(a, c, true).is_some());
+    // `c` blacklists the group of `b`.
+    assert!(world.contact_pair(b, c, true).is_none());
+}
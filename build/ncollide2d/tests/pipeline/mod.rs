@@ -1,2 +1,5 @@
+mod ccd;
+mod detailed_contact_events;
 mod is_send_sync;
+mod large_collision_groups;
 mod world_remove;
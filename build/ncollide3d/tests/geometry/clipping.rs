@@ -0,0 +1,123 @@
+use na::{self, Isometry3, Point3, Unit, Vector3};
+use ncollide3d::mass_properties::Volumetric;
+use ncollide3d::math::Point;
+use ncollide3d::procedural;
+use ncollide3d::shape::{ConvexHull, Cuboid, Plane, TriMesh, Triangle};
+use ncollide3d::transformation;
+
+// Twice the signed area of a polygon projected on the `xy` plane.
+fn signed_area_xy(poly: &[Point<f64>]) -> f64 {
+    (0..poly.len())
+        .map(|i| {
+            let (a, b) = (poly[i], poly[(i + 1) % poly.len()]);
+            a.x * b.y - a.y * b.x
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+#[test]
+fn intersection_of_convex_hulls() {
+    let hull = ConvexHull::try_from_points(&procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).coords).unwrap();
+    let m2 = Isometry3::translation(1.0, 0.5, 0.0);
+    let res = transformation::convex_intersection(&Isometry3::identity(), &hull, &m2, &hull).unwrap();
+
+    assert_eq!(res.points().len(), 8);
+    assert_relative_eq!(res.volume(), 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.center_of_mass(), Point3::new(0.5, 0.25, 0.0), epsilon = 1.0e-10);
+
+    // The result is expressed in the local frame of the first shape.
+    let m1 = Isometry3::new(Vector3::new(5.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    let res = transformation::convex_intersection(&m1, &hull, &(m1 * m2), &hull).unwrap();
+    assert_relative_eq!(res.center_of_mass(), Point3::new(0.5, 0.25, 0.0), epsilon = 1.0e-10);
+
+    let far = Isometry3::translation(3.0, 0.0, 0.0);
+    assert!(transformation::convex_intersection(&Isometry3::identity(), &hull, &far, &hull).is_none());
+}
+
+#[test]
+fn intersection_with_flat_shapes() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let triangle = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let m1 = Isometry3::identity();
+
+    for m2 in &[Isometry3::identity(), Isometry3::translation(10.0, 0.0, 0.0)] {
+        assert!(transformation::convex_intersection(&m1, &cuboid, m2, &triangle).is_none());
+        assert!(transformation::convex_intersection(m2, &triangle, &m1, &cuboid).is_none());
+    }
+}
+
+#[test]
+fn clip_by_half_space() {
+    let cube = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let plane = Plane::new(Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0)));
+    let res = transformation::clip_convex_by_half_space(&Isometry3::identity(), &cube, &Isometry3::identity(), &plane).unwrap();
+
+    // A triangular prism.
+    assert_eq!(res.points().len(), 6);
+    assert_relative_eq!(res.volume(), 4.0, epsilon = 1.0e-10);
+
+    // Plane entirely below, or entirely above the shape.
+    let below = Isometry3::translation(-3.0, 0.0, 0.0);
+    assert!(transformation::clip_convex_by_half_space(&Isometry3::identity(), &cube, &below, &plane).is_none());
+
+    let above = Isometry3::translation(3.0, 0.0, 0.0);
+    let res = transformation::clip_convex_by_half_space(&Isometry3::identity(), &cube, &above, &plane).unwrap();
+    assert_relative_eq!(res.volume(), 8.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn slice_closed_trimesh() {
+    // A hollow box: the inner cuboid is oriented inward.
+    let mut mesh = procedural::cuboid(&Vector3::new(4.0, 4.0, 4.0));
+    let mut inner = procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0));
+    inner.flip_triangles();
+    mesh.append(&inner);
+    let mesh = TriMesh::from(mesh);
+
+    let mplane = Isometry3::translation(0.0, 0.0, 0.5);
+    let plane = Plane::new(Vector3::z_axis());
+    let polylines = transformation::slice_trimesh(&mesh, &mplane, &plane);
+    assert_eq!(polylines.len(), 2);
+
+    for polyline in &polylines {
+        // Closed loops.
+        assert_eq!(polyline.edges().len(), polyline.points().len());
+
+        for pt in polyline.points() {
+            assert_relative_eq!(pt.z, 0.5, epsilon = 1.0e-10);
+        }
+    }
+
+    let mut areas: Vec<_> = transformation::trimesh_cross_section(&mesh, &mplane, &plane)
+        .iter()
+        .map(|poly| signed_area_xy(poly))
+        .collect();
+    areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    // The outer boundary is counter-clockwise, the hole is clockwise.
+    assert_eq!(areas.len(), 2);
+    assert_relative_eq!(areas[0], -4.0, epsilon = 1.0e-10);
+    assert_relative_eq!(areas[1], 16.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn slice_open_trimesh() {
+    let mesh = TriMesh::from(procedural::quad(2.0, 2.0, 4, 4));
+    let plane = Plane::new(Vector3::x_axis());
+    let mplane = Isometry3::translation(0.3, 0.0, 0.0);
+    let polylines = transformation::slice_trimesh(&mesh, &mplane, &plane);
+
+    assert_eq!(polylines.len(), 1);
+    assert_eq!(polylines[0].edges().len(), polylines[0].points().len() - 1);
+    assert!(transformation::trimesh_cross_section(&mesh, &mplane, &plane).is_empty());
+
+    let length: f64 = (0..polylines[0].edges().len())
+        .map(|i| polylines[0].edge_segment(i).length())
+        .sum();
+    assert_relative_eq!(length, 2.0, epsilon = 1.0e-10);
+}
@@ -0,0 +1,104 @@
+use na::{self, Isometry3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::{self, ClosestPoints};
+use ncollide3d::shape::{CompositeShape, Compound, Cuboid, Shape, ShapeHandle, TriMesh};
+
+fn sphere_mesh() -> TriMesh<f32> {
+    TriMesh::from(procedural::sphere(2.0, 12, 12, false))
+}
+
+fn cuboid_compound() -> Compound<f32> {
+    let mut shapes = Vec::new();
+
+    for i in 0..4 {
+        for j in 0..4 {
+            let pos = Isometry3::translation(i as f32 * 1.5, 0.0, j as f32 * 1.5);
+            shapes.push((pos, ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5f32)))));
+        }
+    }
+
+    Compound::new(shapes)
+}
+
+// Brute-force distance between every pair of parts.
+fn brute_force_distance(
+    m1: &Isometry3<f32>,
+    g1: &dyn CompositeShape<f32>,
+    m2: &Isometry3<f32>,
+    g2: &dyn CompositeShape<f32>,
+) -> f32
+{
+    let mut res = std::f32::MAX;
+
+    for i in 0..g1.nparts() {
+        g1.map_part_at(i, m1, &mut |m1, part1| {
+            for j in 0..g2.nparts() {
+                g2.map_part_at(j, m2, &mut |m2, part2| {
+                    res = res.min(query::distance(m1, part1, m2, part2))
+                })
+            }
+        })
+    }
+
+    res
+}
+
+#[test]
+fn trimesh_compound_distance_matches_brute_force() {
+    let mesh = sphere_mesh();
+    let compound = cuboid_compound();
+    let m2 = Isometry3::identity();
+
+    for k in 0..5 {
+        let m1 = Isometry3::new(
+            Vector3::new(2.0 + k as f32 * 0.6, 4.0 - k as f32 * 0.4, 1.0),
+            Vector3::new(0.1 * k as f32, 0.2, 0.0),
+        );
+
+        let expected = brute_force_distance(
+            &m1,
+            mesh.as_composite_shape().unwrap(),
+            &m2,
+            compound.as_composite_shape().unwrap(),
+        );
+        let distance = query::distance(&m1, &mesh, &m2, &compound);
+        assert_relative_eq!(distance, expected, epsilon = 1.0e-4);
+
+        match query::closest_points(&m1, &mesh, &m2, &compound, 10.0) {
+            ClosestPoints::WithinMargin(p1, p2) => {
+                assert_relative_eq!(na::distance(&p1, &p2), expected, epsilon = 1.0e-4)
+            }
+            ClosestPoints::Intersecting => assert_eq!(expected, 0.0),
+            ClosestPoints::Disjoint => panic!("The shapes should be within the margin."),
+        }
+    }
+}
+
+#[test]
+fn trimesh_compound_closest_points_outside_of_margin() {
+    let mesh = sphere_mesh();
+    let compound = cuboid_compound();
+    let m1 = Isometry3::translation(0.0, 10.0, 0.0);
+
+    match query::closest_points(&m1, &mesh, &Isometry3::identity(), &compound, 1.0) {
+        ClosestPoints::Disjoint => {}
+        _ => panic!("The shapes should be further than the margin."),
+    }
+}
+
+#[test]
+fn trimesh_compound_deepest_contact() {
+    let mesh = sphere_mesh();
+    let compound = cuboid_compound();
+    let m1 = Isometry3::translation(1.5, 1.3, 1.5);
+
+    let contact = query::contact(&m1, &mesh, &Isometry3::identity(), &compound, 0.0)
+        .expect("The shapes should be in contact.");
+
+    assert!(contact.depth > 0.0);
+    assert!(contact.depth < 0.5);
+    assert!(contact.normal.y < 0.0);
+
+    let m1 = Isometry3::translation(2.25, 10.0, 2.25);
+    assert!(query::contact(&m1, &mesh, &Isometry3::identity(), &compound, 1.0).is_none());
+}
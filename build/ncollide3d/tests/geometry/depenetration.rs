@@ -0,0 +1,90 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, Compound, Cuboid, HeightField, ShapeHandle, TriMesh};
+
+// The floor y = 0 and the wall x = 0 of a corner, both of side 4.
+fn corner() -> TriMesh<f32> {
+    let points = vec![
+        Point3::new(0.0, 0.0, -2.0),
+        Point3::new(4.0, 0.0, -2.0),
+        Point3::new(4.0, 0.0, 2.0),
+        Point3::new(0.0, 0.0, 2.0),
+        Point3::new(0.0, 4.0, -2.0),
+        Point3::new(0.0, 4.0, 2.0),
+    ];
+    let indices = vec![
+        Point3::new(0, 2, 1),
+        Point3::new(0, 3, 2),
+        Point3::new(0, 4, 5),
+        Point3::new(0, 5, 3),
+    ];
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn ball_pushed_out_of_trimesh_corner() {
+    let ball = Ball::new(0.5);
+    let m2 = Isometry3::translation(0.3, 0.2, 0.0);
+    let res = query::depenetration(&Isometry3::identity(), &corner(), &m2, &ball, 20);
+
+    assert!(res.converged);
+    let center = m2.translation.vector + res.translation;
+    assert!(center.x >= 0.5 - 1.0e-3, "{:?}", center);
+    assert!(center.y >= 0.5 - 1.0e-3, "{:?}", center);
+
+    // The deepest contact alone only resolves the penetration with one of the walls.
+    let contact = query::contact(&Isometry3::identity(), &corner(), &m2, &ball, 0.0).unwrap();
+    let center = m2.translation.vector + *contact.normal * contact.depth;
+    assert!(center.x < 0.5 - 1.0e-3 || center.y < 0.5 - 1.0e-3);
+}
+
+#[test]
+fn composite_shape_given_second() {
+    let ball = Ball::new(0.5);
+    let m1 = Isometry3::translation(0.3, 0.2, 0.0);
+    let res = query::depenetration(&m1, &ball, &Isometry3::identity(), &corner(), 20);
+
+    assert!(res.converged);
+    // The corner moves away from the ball instead.
+    assert!(res.translation.x <= -0.2 + 1.0e-3, "{:?}", res.translation);
+    assert!(res.translation.y <= -0.3 + 1.0e-3, "{:?}", res.translation);
+}
+
+#[test]
+fn cuboid_pushed_out_of_heightfield() {
+    let hf = HeightField::new(DMatrix::zeros(5, 5), Vector3::new(4.0, 1.0, 4.0));
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let m2 = Isometry3::translation(0.1, 0.2, 0.3);
+    let res = query::depenetration(&Isometry3::identity(), &hf, &m2, &cuboid, 20);
+
+    assert!(res.converged);
+    assert_relative_eq!(res.translation, Vector3::new(0.0, 0.3, 0.0), epsilon = 1.0e-3);
+}
+
+#[test]
+fn ball_pushed_out_of_compound() {
+    let floor = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let wall = Cuboid::new(Vector3::new(0.5, 2.0, 2.0));
+    let compound = Compound::new(vec![
+        (Isometry3::translation(2.0, -0.5, 0.0), ShapeHandle::new(floor)),
+        (Isometry3::translation(-0.5, 2.0, 0.0), ShapeHandle::new(wall)),
+    ]);
+    let ball = Ball::new(0.5);
+    let m2 = Isometry3::translation(0.4, 0.1, 0.0);
+    let res = query::depenetration(&Isometry3::identity(), &compound, &m2, &ball, 20);
+
+    assert!(res.converged);
+    let center = m2.translation.vector + res.translation;
+    assert!(center.x >= 0.5 - 1.0e-3, "{:?}", center);
+    assert!(center.y >= 0.5 - 1.0e-3, "{:?}", center);
+}
+
+#[test]
+fn separated_shapes_are_left_untouched() {
+    let ball = Ball::new(0.5);
+    let m2 = Isometry3::translation(2.0, 2.0, 0.0);
+    let res = query::depenetration(&Isometry3::identity(), &corner(), &m2, &ball, 20);
+
+    assert!(res.converged);
+    assert_eq!(res.translation, Vector3::zeros());
+}
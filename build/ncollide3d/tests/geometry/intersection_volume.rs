@@ -0,0 +1,115 @@
+use na::{self, Isometry3, Point3, Unit, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query;
+use ncollide3d::shape::{Ball, ConvexHull, Cuboid, Plane, Segment, Triangle};
+use std::f64::consts::PI;
+
+#[test]
+fn overlapping_cuboids() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.0, 0.5, 0.0);
+    let res = query::intersection_volume(&m1, &cuboid, &m2, &cuboid).unwrap();
+
+    assert_relative_eq!(res.volume, 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, Point3::new(0.5, 0.25, 0.0), epsilon = 1.0e-10);
+
+    // Same thing, with the whole scene rotated and one cuboid given as a convex hull.
+    let hull = ConvexHull::try_from_points(&procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)).coords).unwrap();
+    let rot = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 0.7));
+    let res = query::intersection_volume(&(rot * m1), &hull, &(rot * m2), &cuboid).unwrap();
+
+    assert_relative_eq!(res.volume, 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, rot * Point3::new(0.5, 0.25, 0.0), epsilon = 1.0e-10);
+}
+
+#[test]
+fn rotated_cuboids() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(na::zero(), Vector3::z() * PI / 4.0);
+    let res = query::intersection_volume(&m1, &cuboid, &m2, &cuboid).unwrap();
+
+    // A regular octagon extruded along z.
+    assert_relative_eq!(res.volume, 8.0 * (2.0f64.sqrt() - 1.0) * 2.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, Point3::origin(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn disjoint_or_touching_shapes() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let ball = Ball::new(1.0);
+    let m1 = Isometry3::identity();
+
+    let far = Isometry3::translation(3.0, 0.0, 0.0);
+
+    assert!(query::intersection_volume(&m1, &cuboid, &far, &ball).is_none());
+    assert!(query::intersection_volume(&far, &ball, &m1, &cuboid).is_none());
+    assert!(query::intersection_volume(&m1, &cuboid, &Isometry3::translation(0.0, 2.0, 0.0), &cuboid).is_none());
+}
+
+#[test]
+fn flat_shapes() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let triangle = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let segment = Segment::new(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    let m1 = Isometry3::identity();
+
+    for m2 in &[Isometry3::identity(), Isometry3::translation(10.0, 0.0, 0.0)] {
+        assert!(query::intersection_volume(&m1, &cuboid, m2, &triangle).is_none());
+        assert!(query::intersection_volume(m2, &triangle, &m1, &cuboid).is_none());
+        assert!(query::intersection_volume(&m1, &cuboid, m2, &segment).is_none());
+        assert!(query::intersection_volume(m2, &segment, &m1, &cuboid).is_none());
+    }
+}
+
+#[test]
+fn overlapping_balls() {
+    let ball = Ball::new(1.0);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.0, 0.0, 0.0);
+    let res = query::intersection_volume(&m1, &ball, &m2, &ball).unwrap();
+
+    // Two spherical caps of height 0.5.
+    let expected = 2.0 * PI * 0.25 * (3.0 - 0.5) / 3.0;
+    assert_relative_eq!(res.volume, expected, max_relative = 0.05);
+    assert_relative_eq!(res.centroid, Point3::new(0.5, 0.0, 0.0), epsilon = 1.0e-2);
+}
+
+#[test]
+fn submerged_volume() {
+    let plane = Plane::new(Vector3::y_axis());
+    let water = Isometry3::translation(0.0, 1.0, 0.0);
+
+    // A cuboid half below the water surface.
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let res = query::intersection_volume(&water, &plane, &Isometry3::translation(0.0, 1.0, 0.0), &cuboid).unwrap();
+
+    assert_relative_eq!(res.volume, 24.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, Point3::new(0.0, 0.0, 0.0), epsilon = 1.0e-10);
+
+    // A ball entirely under water, or half-submerged.
+    let ball = Ball::new(2.0);
+    let res = query::intersection_volume(&Isometry3::translation(0.0, -2.0, 0.0), &ball, &water, &plane).unwrap();
+    assert_relative_eq!(res.volume, 4.0 / 3.0 * PI * 8.0, max_relative = 0.02);
+    assert_relative_eq!(res.centroid, Point3::new(0.0, -2.0, 0.0), epsilon = 1.0e-10);
+
+    let res = query::intersection_volume(&Isometry3::translation(0.0, 1.0, 0.0), &ball, &water, &plane).unwrap();
+    assert_relative_eq!(res.volume, 2.0 / 3.0 * PI * 8.0, max_relative = 0.02);
+    assert_relative_eq!(res.centroid, Point3::new(0.0, 1.0 - 3.0 / 4.0, 0.0), epsilon = 2.0e-2);
+
+    // A ball entirely above water.
+    assert!(query::intersection_volume(&water, &plane, &Isometry3::translation(0.0, 3.5, 0.0), &ball).is_none());
+
+    // A cube on its edge, with a tilted water surface.
+    let tilted = Plane::new(Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0)));
+    let cube = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let res = query::intersection_volume(&Isometry3::identity(), &tilted, &Isometry3::identity(), &cube).unwrap();
+
+    assert_relative_eq!(res.volume, 4.0, epsilon = 1.0e-10);
+    assert_relative_eq!(res.centroid, Point3::new(-1.0 / 3.0, -1.0 / 3.0, 0.0), epsilon = 1.0e-10);
+}
@@ -0,0 +1,89 @@
+use na::{Isometry3, Matrix3, Point3, Vector3};
+use ncollide3d::mass_properties::{shape_mass_properties, MassProperties, Volumetric};
+use ncollide3d::procedural;
+use ncollide3d::shape::{
+    Ball, Capsule, Compound, Cone, ConvexHull, Cuboid, Cylinder, ShapeHandle, TriMesh,
+};
+
+// The mass properties of the convex hull of a fine mesh of the same shape.
+fn hull_properties(mesh: procedural::TriMesh<f64>) -> MassProperties<f64> {
+    ConvexHull::try_from_points(&mesh.coords).unwrap().mass_properties(2.0)
+}
+
+fn assert_close(p1: &MassProperties<f64>, p2: &MassProperties<f64>, rel: f64) {
+    assert_relative_eq!(p1.volume, p2.volume, max_relative = rel);
+    assert_relative_eq!(p1.mass, p2.mass, max_relative = rel);
+    assert_relative_eq!(p1.local_com, p2.local_com, epsilon = rel * p1.volume.cbrt());
+    assert_relative_eq!(p1.angular_inertia, p2.angular_inertia, epsilon = rel * p1.angular_inertia.norm());
+}
+
+#[test]
+fn polyhedral_shapes_match_exact_values() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let exact = cuboid.mass_properties(2.0);
+
+    assert_relative_eq!(exact.volume, 48.0);
+    assert_relative_eq!(exact.mass, 96.0);
+    assert_relative_eq!(
+        exact.angular_inertia,
+        Matrix3::from_diagonal(&Vector3::new(13.0, 10.0, 5.0)) * 96.0 / 3.0
+    );
+
+    // The convex hull and the triangle mesh of the same cuboid, moved away from the origin.
+    let mut mesh = procedural::cuboid(&Vector3::new(2.0, 4.0, 6.0));
+    mesh.translate_by(&na::Translation3::new(1.0, 2.0, 3.0));
+    let hull = hull_properties(mesh.clone());
+    let trimesh = TriMesh::from(mesh).mass_properties(2.0);
+    let mut moved = exact;
+    moved.local_com = Point3::new(1.0, 2.0, 3.0);
+
+    assert_close(&hull, &moved, 1.0e-10);
+    assert_close(&trimesh, &moved, 1.0e-10);
+}
+
+#[test]
+fn curved_shapes_match_fine_meshes() {
+    let ball = Ball::new(1.5).mass_properties(2.0);
+    assert_close(&ball, &hull_properties(procedural::sphere(3.0, 50, 50, false)), 5.0e-3);
+
+    let cylinder = Cylinder::new(1.0, 0.5).mass_properties(2.0);
+    assert_close(&cylinder, &hull_properties(procedural::cylinder(1.0, 2.0, 200)), 1.0e-3);
+
+    let cone = Cone::new(1.0, 0.5).mass_properties(2.0);
+    assert_close(&cone, &hull_properties(procedural::cone(1.0, 2.0, 200)), 1.0e-3);
+
+    let capsule = Capsule::new(1.0, 0.5).mass_properties(2.0);
+    assert_close(&capsule, &hull_properties(procedural::capsule(&1.0, &2.0, 50, 50)), 5.0e-3);
+}
+
+#[test]
+fn compound_uses_the_parallel_axis_theorem() {
+    let ball = Ball::new(1.0);
+    let parts = vec![
+        (Isometry3::translation(2.0, 0.0, 0.0), ShapeHandle::new(ball.clone())),
+        (Isometry3::translation(-2.0, 0.0, 0.0), ShapeHandle::new(ball.clone())),
+    ];
+    let compound = Compound::new(parts);
+    let props = shape_mass_properties(&compound, 1.0).unwrap();
+    let one = ball.mass_properties(1.0);
+    let i0 = one.angular_inertia[(0, 0)];
+
+    assert_relative_eq!(props.mass, one.mass * 2.0);
+    assert_relative_eq!(props.local_com, Point3::origin());
+    assert_relative_eq!(
+        props.angular_inertia,
+        Matrix3::from_diagonal(&Vector3::new(i0, i0 + one.mass * 4.0, i0 + one.mass * 4.0)) * 2.0,
+        epsilon = 1.0e-10
+    );
+
+    // A rotated cuboid.
+    let rot = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), Vector3::z() * std::f64::consts::FRAC_PI_2);
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let compound = Compound::new(vec![(rot, ShapeHandle::new(cuboid.clone()))]);
+    let props = compound.mass_properties(1.0);
+    let expected = cuboid.mass_properties(1.0).angular_inertia;
+
+    assert_relative_eq!(props.local_com, Point3::new(0.0, 0.0, 1.0), epsilon = 1.0e-10);
+    assert_relative_eq!(props.angular_inertia[(0, 0)], expected[(1, 1)], epsilon = 1.0e-10);
+    assert_relative_eq!(props.angular_inertia[(1, 1)], expected[(0, 0)], epsilon = 1.0e-10);
+}
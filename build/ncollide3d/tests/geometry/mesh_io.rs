@@ -0,0 +1,173 @@
+use na::{Point3, Vector3};
+use ncollide3d::io::{self, MeshIoError};
+use ncollide3d::procedural::{self, IndexBuffer, TriMesh};
+
+fn same_triangles(m1: &TriMesh<f64>, m2: &TriMesh<f64>) -> bool {
+    let tris = |m: &TriMesh<f64>| -> Vec<[Point3<f64>; 3]> {
+        m.flat_indices()
+            .chunks(3)
+            .map(|t| [m.coords[t[0] as usize], m.coords[t[1] as usize], m.coords[t[2] as usize]])
+            .collect()
+    };
+    let (t1, t2) = (tris(m1), tris(m2));
+
+    t1.len() == t2.len()
+        && t1.iter().zip(t2.iter()).all(|(a, b)| {
+            a.iter().zip(b.iter()).all(|(p, q)| na::distance(p, q) < 1.0e-6)
+        })
+}
+
+#[test]
+fn obj_with_split_attributes() {
+    let obj = "\
+# A unit quad.
+o quad
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+vn 0 0 1
+s off
+f 1/1/1 2/2/1 3/3/1 -1/-1/-1
+";
+    let mesh: TriMesh<f64> = io::read_obj(obj.as_bytes()).unwrap();
+
+    assert_eq!(mesh.coords.len(), 4);
+    assert_eq!(mesh.normals.as_ref().unwrap(), &vec![Vector3::z()]);
+    assert_eq!(mesh.uvs.as_ref().unwrap().len(), 4);
+    assert_eq!(mesh.num_triangles(), 2);
+
+    let faces = mesh.indices.clone().unwrap_split();
+    assert_eq!(faces[1], Point3::new(Point3::new(0, 0, 0), Point3::new(2, 0, 2), Point3::new(3, 0, 3)));
+
+    let mut out = Vec::new();
+    io::write_obj(&mesh, &mut out).unwrap();
+    let mesh2: TriMesh<f64> = io::read_obj(&out[..]).unwrap();
+
+    assert!(same_triangles(&mesh, &mesh2));
+    assert_eq!(mesh2.uvs, mesh.uvs);
+    assert_eq!(mesh2.normals, mesh.normals);
+
+    let shape = io::to_shape(mesh2);
+    assert_eq!(shape.faces().len(), 2);
+}
+
+#[test]
+fn obj_errors_have_line_numbers() {
+    let err = io::read_obj::<f64, _>("v 0 0 0\nv 1 0 0\n\nf 1 2 3\n".as_bytes()).unwrap_err();
+    match err {
+        MeshIoError::Parse { line, .. } => assert_eq!(line, 4),
+        e => panic!("unexpected error: {}", e),
+    }
+
+    let err = io::read_obj::<f64, _>("v 0 0 zero\n".as_bytes()).unwrap_err();
+    assert_eq!(format!("{}", err), "line 1: invalid number `zero`");
+
+    let err = io::read_obj::<f64, _>("v 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2 3\n".as_bytes())
+        .unwrap_err();
+    match err {
+        MeshIoError::Parse { line, .. } => assert_eq!(line, 5),
+        e => panic!("unexpected error: {}", e),
+    }
+}
+
+#[test]
+fn stl_round_trips() {
+    let mesh = procedural::cuboid(&Vector3::new(1.0, 2.0, 3.0));
+
+    let mut binary = Vec::new();
+    io::write_stl_binary(&mesh, &mut binary).unwrap();
+    assert_eq!(binary.len(), 84 + 12 * 50);
+
+    let mut ascii = Vec::new();
+    io::write_stl_ascii(&mesh, &mut ascii).unwrap();
+
+    for bytes in [binary, ascii].iter() {
+        let read: TriMesh<f64> = io::read_stl(&bytes[..]).unwrap();
+
+        // The duplicate vertices are merged.
+        assert_eq!(read.coords.len(), 8);
+        assert_eq!(read.normals.as_ref().unwrap().len(), 12);
+        assert!(same_triangles(&mesh, &read));
+    }
+
+    let err = io::read_stl::<f64, _>("solid x\n facet normal 0 0 1\n  vertex 0 0 0\n endfacet\n".as_bytes())
+        .unwrap_err();
+    match err {
+        MeshIoError::Parse { line, .. } => assert_eq!(line, 4),
+        e => panic!("unexpected error: {}", e),
+    }
+}
+
+#[test]
+fn ply_ascii_and_binary() {
+    let ascii = "\
+ply
+format ascii 1.0
+comment a quad with a quality property
+element vertex 4
+property float x
+property float y
+property float z
+property float quality
+property float u
+property float v
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0 1 0 0
+1 0 0 1 1 0
+1 1 0 1 1 1
+0 1 0 1 0 1
+4 0 1 2 3
+";
+    let mesh: TriMesh<f64> = io::read_ply(ascii.as_bytes()).unwrap();
+
+    assert_eq!(mesh.coords[2], Point3::new(1.0, 1.0, 0.0));
+    assert!(mesh.normals.is_none());
+    assert_eq!(mesh.uvs.as_ref().unwrap()[3], na::Point2::new(0.0, 1.0));
+    match &mesh.indices {
+        IndexBuffer::Unified(idx) => assert_eq!(idx, &vec![Point3::new(0, 1, 2), Point3::new(0, 2, 3)]),
+        _ => panic!("expected an unified index buffer"),
+    }
+
+    let mut binary = b"ply\nformat binary_big_endian 1.0\nelement vertex 3\nproperty double x\n\
+property double y\nproperty double z\nelement face 1\nproperty list uchar ushort vertex_index\nend_header\n"
+        .to_vec();
+    for v in [0.0f64, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0].iter() {
+        binary.extend_from_slice(&v.to_be_bytes());
+    }
+    binary.push(3);
+    for i in [0u16, 1, 2].iter() {
+        binary.extend_from_slice(&i.to_be_bytes());
+    }
+
+    let mesh: TriMesh<f64> = io::read_ply(&binary[..]).unwrap();
+    assert_eq!(mesh.coords[1], Point3::new(1.0, 0.0, 0.0));
+    assert_eq!(mesh.num_triangles(), 1);
+
+    let mesh = procedural::sphere(1.0, 8, 8, true);
+    let mut out = Vec::new();
+    io::write_ply(&mesh, &mut out).unwrap();
+    let read: TriMesh<f64> = io::read_ply(&out[..]).unwrap();
+    assert!(same_triangles(&mesh, &read));
+    assert!(read.has_normals() && read.has_uvs());
+
+    let err = io::read_ply::<f64, _>(ascii.replace("4 0 1 2 3", "3 0 1 7").as_bytes()).unwrap_err();
+    assert_eq!(format!("{}", err), "line 18: the vertex index 7 is out of range");
+}
+
+#[test]
+fn ply_with_huge_list_count() {
+    let mut binary = b"ply\nformat binary_little_endian 1.0\nelement vertex 0\nelement face 1\n\
+property list uint uint vertex_index\nend_header\n"
+        .to_vec();
+    binary.extend_from_slice(&u32::max_value().to_le_bytes());
+    binary.extend_from_slice(&0u32.to_le_bytes());
+
+    assert!(io::read_ply::<f64, _>(&binary[..]).is_err());
+}
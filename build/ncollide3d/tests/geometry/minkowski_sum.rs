@@ -0,0 +1,93 @@
+use na::{self, Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::BoundingVolume;
+use ncollide3d::query::{self, PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Ball, ConvexHull, Cuboid, MinkowskiSum, Plane, Shape, ShapeHandle, SweptShape};
+use ncollide3d::transformation::ToTriMesh;
+use ncollide3d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+#[test]
+fn rounded_cuboid_queries() {
+    let shape = MinkowskiSum::new(Cuboid::new(Vector3::new(1.0, 2.0, 3.0)), Ball::new(0.5));
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+
+    let aabb = shape.aabb(&m);
+    assert_relative_eq!(aabb.mins(), &Point3::new(-0.5, -2.5, -3.5), epsilon = 1.0e-6);
+    assert_relative_eq!(aabb.maxs(), &Point3::new(2.5, 2.5, 3.5), epsilon = 1.0e-6);
+
+    assert_relative_eq!(shape.distance_to_point(&m, &Point3::new(5.0, 0.0, 0.0), true), 2.5, epsilon = 1.0e-6);
+    assert!(shape.contains_point(&m, &Point3::new(2.4, 2.0, 3.0)));
+    // Outside of the rounded corner, but inside of the bounding box.
+    assert!(!shape.contains_point(&m, &Point3::new(2.45, 2.45, 3.45)));
+
+    let ray = Ray::new(Point3::new(10.0, 0.0, 0.0), -Vector3::x());
+    assert_relative_eq!(shape.toi_with_ray(&m, &ray, true).unwrap(), 7.5, epsilon = 1.0e-6);
+
+    let ball = Ball::new(1.0);
+    let contact = query::contact(&m, &shape, &Isometry3::translation(3.0, 0.0, 0.0), &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal, Vector3::x(), epsilon = 1.0e-6);
+
+    let dist = query::distance(&m, &shape, &Isometry3::translation(0.0, 10.0, 0.0), &ball);
+    assert_relative_eq!(dist, 6.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn swept_shape_queries() {
+    let swept = SweptShape::new(Ball::new(1.0), Vector3::new(4.0, 0.0, 0.0));
+    let m = Isometry3::identity();
+
+    assert!(swept.contains_point(&m, &Point3::new(2.0, 0.9, 0.0)));
+    assert!(swept.contains_point(&m, &Point3::new(4.9, 0.0, 0.0)));
+    assert!(!swept.contains_point(&m, &Point3::new(-1.1, 0.0, 0.0)));
+
+    // The swept volume contains the shape at all its intermediate positions.
+    let aabb = swept.aabb(&m);
+    let ball = Ball::new(1.0);
+    for i in 0..=4 {
+        assert!(aabb.contains(&ball.aabb(&Isometry3::translation(i as f64, 0.0, 0.0))));
+    }
+
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let prox = query::proximity(&m, &swept, &Isometry3::translation(3.0, 1.2, 0.0), &cuboid, 0.0);
+    assert_eq!(prox, query::Proximity::Intersecting);
+
+    let res = query::intersection_volume(&m, &swept, &Isometry3::translation(2.0, 0.0, 0.0), &cuboid).unwrap();
+    assert_relative_eq!(res.volume, 1.0, max_relative = 0.02);
+}
+
+#[test]
+fn explicit_convex_hulls() {
+    let shape = MinkowskiSum::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), Cuboid::new(Vector3::new(0.5, 1.0, 2.0)));
+    let hull = ConvexHull::try_from_points(&shape.to_trimesh(((), ())).coords).unwrap();
+    let aabb = hull.aabb(&Isometry3::identity());
+
+    assert_relative_eq!(aabb.maxs(), &Point3::new(1.5, 2.0, 3.0), epsilon = 1.0e-10);
+    assert_eq!(hull.points().len(), 8);
+
+    let swept = SweptShape::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), Vector3::new(2.0, 2.0, 0.0));
+    let hull = ConvexHull::try_from_points(&swept.to_trimesh(()).coords).unwrap();
+    assert_eq!(hull.points().len(), 12);
+}
+
+#[test]
+fn rounded_cuboid_in_collision_world() {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let shape = MinkowskiSum::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), Ball::new(0.5));
+
+    let _ = world.add(Isometry3::identity(), ShapeHandle::new(Plane::new(Vector3::y_axis())), groups, query, ());
+    let _ = world.add(Isometry3::translation(0.0, 1.4, 0.0), ShapeHandle::new(shape.clone()), groups, query, ());
+    let _ = world.add(Isometry3::translation(0.0, 4.3, 0.0), ShapeHandle::new(shape), groups, query, ());
+    world.update();
+
+    let depths: Vec<f64> = world
+        .contact_pairs(true)
+        .map(|(_, _, _, manifold)| manifold.deepest_contact().unwrap().contact.depth)
+        .collect();
+
+    assert_eq!(depths.len(), 2);
+    for depth in depths {
+        assert_relative_eq!(depth, 0.1, epsilon = 1.0e-6);
+    }
+}
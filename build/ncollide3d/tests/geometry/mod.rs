@@ -1,5 +1,6 @@
 mod ball_ball_toi;
 mod ball_triangle_toi;
+mod composite_composite_queries;
 mod contact;
 mod cylinder_cuboid_contact;
 mod epa3;
@@ -8,3 +9,18 @@ mod time_of_impact3;
 mod trimesh_trimesh_toi;
 mod cuboid_ray_cast;
 mod interferences_with_ray;
+mod qbvh;
+mod one_way_collisions;
+mod depenetration;
+mod trimesh_point_query;
+mod signed_distance_field;
+mod voxels;
+mod to_trimesh;
+#[cfg(feature = "io")]
+mod mesh_io;
+mod trimesh_repair;
+mod simplify;
+mod mass_properties;
+mod intersection_volume;
+mod clipping;
+mod minkowski_sum;
@@ -0,0 +1,106 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::query::{Proximity, Ray, RayCast};
+use ncollide3d::shape::{Ball, HeightField, ShapeHandle, TriMesh};
+use ncollide3d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+// A square of side 2 on the plane y = 0, facing upward.
+fn upward_quad() -> TriMesh<f32> {
+    let points = vec![
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(-1.0, 0.0, 1.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    TriMesh::new(points, indices, None)
+}
+
+fn flat_heightfield() -> HeightField<f32> {
+    HeightField::new(DMatrix::zeros(3, 3), Vector3::new(2.0, 1.0, 2.0))
+}
+
+fn world_with_ball(ground: ShapeHandle<f32>, ball_y: f32, query: GeometricQueryType<f32>) -> CollisionWorld<f32, ()> {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let _ = world.add(Isometry3::identity(), ground, groups, query, ());
+    let _ = world.add(
+        Isometry3::translation(0.0, ball_y, 0.0),
+        ShapeHandle::new(Ball::new(0.5)),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+    world
+}
+
+fn num_contacts(ground: ShapeHandle<f32>, ball_y: f32) -> usize {
+    let world = world_with_ball(ground, ball_y, GeometricQueryType::Contacts(0.0, 0.0));
+    world.contact_pairs(true).map(|p| p.3.len()).sum()
+}
+
+fn proximity(ground: ShapeHandle<f32>, ball_y: f32) -> Proximity {
+    let world = world_with_ball(ground, ball_y, GeometricQueryType::Proximity(0.0));
+    let prox = world
+        .proximity_pairs(false)
+        .next()
+        .map(|p| p.2.proximity())
+        .unwrap_or(Proximity::Disjoint);
+    prox
+}
+
+#[test]
+fn one_way_trimesh_contacts() {
+    let mut mesh = upward_quad();
+    assert!(num_contacts(ShapeHandle::new(mesh.clone()), 0.45) > 0);
+    assert!(num_contacts(ShapeHandle::new(mesh.clone()), -0.45) > 0);
+
+    mesh.set_one_way(Some(0.5));
+    assert!(num_contacts(ShapeHandle::new(mesh.clone()), 0.45) > 0);
+    assert_eq!(num_contacts(ShapeHandle::new(mesh.clone()), -0.45), 0);
+}
+
+#[test]
+fn one_way_heightfield_contacts() {
+    let mut heightfield = flat_heightfield();
+    assert!(num_contacts(ShapeHandle::new(heightfield.clone()), -0.45) > 0);
+
+    heightfield.set_one_way(Some(0.5));
+    assert!(num_contacts(ShapeHandle::new(heightfield.clone()), 0.45) > 0);
+    assert_eq!(num_contacts(ShapeHandle::new(heightfield.clone()), -0.45), 0);
+}
+
+#[test]
+fn one_way_trimesh_proximity() {
+    let mut mesh = upward_quad();
+    assert_eq!(proximity(ShapeHandle::new(mesh.clone()), -0.6), Proximity::Disjoint);
+    assert_eq!(proximity(ShapeHandle::new(mesh.clone()), -0.45), Proximity::Intersecting);
+
+    mesh.set_one_way(Some(0.5));
+    assert_eq!(proximity(ShapeHandle::new(mesh.clone()), 0.45), Proximity::Intersecting);
+    // The ball overlaps the mesh but its center is behind it.
+    assert_eq!(proximity(ShapeHandle::new(mesh.clone()), -0.45), Proximity::Disjoint);
+}
+
+#[test]
+fn one_way_ray_casts() {
+    let down = Ray::new(Point3::new(0.1, 1.0, 0.2), -Vector3::y());
+    let up = Ray::new(Point3::new(0.1, -1.0, 0.2), Vector3::y());
+    let id = Isometry3::identity();
+
+    let mut mesh = upward_quad();
+    let mut heightfield = flat_heightfield();
+    assert!(mesh.toi_with_ray(&id, &up, true).is_some());
+    assert!(heightfield.toi_with_ray(&id, &up, true).is_some());
+
+    mesh.set_one_way(Some(0.5));
+    heightfield.set_one_way(Some(0.5));
+
+    for shape in [&mesh as &dyn RayCast<f32>, &heightfield as &dyn RayCast<f32>].iter() {
+        let toi = shape.toi_with_ray(&id, &down, true).unwrap();
+        assert!((toi - 1.0).abs() < 1.0e-5);
+        assert!(shape.toi_and_normal_with_ray(&id, &down, true).is_some());
+        assert!(shape.toi_with_ray(&id, &up, true).is_none());
+        assert!(shape.toi_and_normal_with_ray(&id, &up, true).is_none());
+    }
+}
@@ -0,0 +1,148 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::partitioning::{BVH, QBVH};
+use ncollide3d::procedural;
+use ncollide3d::query::visitors::{BoundingVolumeInterferencesCollector, RayInterferencesCollector};
+use ncollide3d::query::{Ray, RayCast};
+use ncollide3d::shape::{DeformableShape, HeightField, HeightFieldCellStatus, TriMesh};
+
+fn tessellated_plane() -> TriMesh<f32> {
+    let mut mesh = procedural::quad(10.0, 10.0, 20, 20);
+    mesh.transform_by(&Isometry3::rotation(Vector3::x() * std::f32::consts::FRAC_PI_2));
+    TriMesh::from(mesh)
+}
+
+#[test]
+fn qbvh_interferences_match_bvt() {
+    let mesh = tessellated_plane();
+    let qbvh = QBVH::from_bvh(mesh.bvt());
+
+    for i in 0..20 {
+        let center = Point3::new(-4.87 + i as f32 * 0.49, 0.0, 2.13 - i as f32 * 0.21);
+        let aabb = AABB::from_half_extents(center, Vector3::repeat(0.3));
+
+        let mut expected = Vec::new();
+        let mut found = Vec::new();
+        mesh.bvt()
+            .visit(&mut BoundingVolumeInterferencesCollector::new(&aabb, &mut expected));
+        qbvh.visit(&mut BoundingVolumeInterferencesCollector::new(&aabb, &mut found));
+
+        expected.sort();
+        found.sort();
+        assert_eq!(expected, found);
+
+        // The QBVH is conservative so it may find a few more
+        // leaves when the ray is close to their boundaries.
+        let ray = Ray::new(center + Vector3::y(), -Vector3::y());
+        let mut expected = Vec::new();
+        let mut found = Vec::new();
+        mesh.bvt()
+            .visit(&mut RayInterferencesCollector::new(&ray, &mut expected));
+        qbvh.visit(&mut RayInterferencesCollector::new(&ray, &mut found));
+
+        assert!(!expected.is_empty());
+        assert!(expected.iter().all(|i| found.contains(i)));
+    }
+}
+
+#[test]
+fn qbvh_trimesh_ray_cast() {
+    let mesh = tessellated_plane();
+
+    for i in 0..20 {
+        let origin = Point3::new(-4.9 + i as f32 * 0.49, 3.0, 4.9 - i as f32 * 0.49);
+        let ray = Ray::new(origin, -Vector3::y());
+        let inter = mesh
+            .toi_and_normal_with_ray(&Isometry3::identity(), &ray, true)
+            .expect("The ray should hit the plane.");
+
+        assert_relative_eq!(inter.toi, 3.0, epsilon = 1.0e-5);
+        assert_relative_eq!(inter.normal.y.abs(), 1.0, epsilon = 1.0e-5);
+    }
+
+    let ray = Ray::new(Point3::new(6.0, 3.0, 0.0), -Vector3::y());
+    assert!(mesh.toi_with_ray(&Isometry3::identity(), &ray, true).is_none());
+}
+
+#[test]
+fn qbvh_refit_after_deformation() {
+    let mut mesh = tessellated_plane();
+    let shift = Vector3::new(0.0, 2.0, 0.0);
+    // Only lift the vertices with a positive x coordinate.
+    let coords: Vec<f32> = mesh
+        .points()
+        .iter()
+        .flat_map(|pt| {
+            let pt = if pt.x > 0.0 { pt + shift } else { *pt };
+            vec![pt.x, pt.y, pt.z]
+        })
+        .collect();
+    mesh.set_deformations(&coords);
+
+    let lifted = AABB::from_half_extents(Point3::new(2.5, 2.0, 0.0), Vector3::repeat(0.1));
+    let mut found = Vec::new();
+    mesh.qbvh()
+        .visit(&mut BoundingVolumeInterferencesCollector::new(&lifted, &mut found));
+    assert!(!found.is_empty());
+
+    let lifted_before = AABB::new(lifted.mins() - shift, lifted.maxs() - shift);
+    let mut found = Vec::new();
+    mesh.qbvh()
+        .visit(&mut BoundingVolumeInterferencesCollector::new(&lifted_before, &mut found));
+    assert!(found.is_empty());
+
+    // Both trees of the mesh must have been refit.
+    for i in 0..20 {
+        let center = Point3::new(-4.87 + i as f32 * 0.49, i as f32 * 0.1, 2.13 - i as f32 * 0.21);
+        let aabb = AABB::from_half_extents(center, Vector3::repeat(0.3));
+        let mut expected = Vec::new();
+        let mut found = Vec::new();
+        mesh.bvt()
+            .visit(&mut BoundingVolumeInterferencesCollector::new(&aabb, &mut expected));
+        mesh.qbvh()
+            .visit(&mut BoundingVolumeInterferencesCollector::new(&aabb, &mut found));
+
+        expected.sort();
+        found.sort();
+        assert_eq!(expected, found);
+    }
+
+    let id = Isometry3::identity();
+    let ray = Ray::new(Point3::new(2.5, 3.0, 0.1), -Vector3::y());
+    assert_relative_eq!(mesh.toi_with_ray(&id, &ray, true).unwrap(), 1.0, epsilon = 1.0e-5);
+    let ray = Ray::new(Point3::new(-2.5, 3.0, 0.1), -Vector3::y());
+    assert_relative_eq!(mesh.toi_with_ray(&id, &ray, true).unwrap(), 3.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn qbvh_heightfield_ray_cast() {
+    let heights = DMatrix::from_fn(9, 13, |i, j| ((i * 7 + j * 3) % 5) as f32 * 0.3);
+    let mut heightfield = HeightField::new(heights, Vector3::new(6.0, 1.0, 4.0));
+    heightfield.cells_statuses_mut()[(3, 4)] = HeightFieldCellStatus::CELL_REMOVED;
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::y() * 0.3);
+    let mut nhits = 0;
+
+    for i in 0..40 {
+        let t = i as f32 * 0.37;
+        let origin = m * Point3::new(-2.8 + (t * 1.3) % 5.6, 3.0, -1.9 + (t * 0.7) % 3.8);
+        let dir = m * Vector3::new(t.cos() * 0.4, -1.0, t.sin() * 0.3);
+        let ray = Ray::new(origin, dir);
+
+        let expected = heightfield
+            .triangles()
+            .filter_map(|tri| tri.toi_with_ray(&m, &ray, true))
+            .fold(None, |best: Option<f32>, toi| Some(best.map_or(toi, |b| b.min(toi))));
+        let found = heightfield.toi_and_normal_with_ray(&m, &ray, true);
+
+        match (expected, found) {
+            (Some(toi), Some(inter)) => {
+                assert_relative_eq!(inter.toi, toi, epsilon = 1.0e-4);
+                nhits += 1;
+            }
+            (None, None) => {}
+            (expected, found) => panic!("Expected {:?}, found {:?}.", expected, found.map(|i| i.toi)),
+        }
+    }
+
+    assert!(nhits > 30);
+}
@@ -0,0 +1,142 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+use ncollide3d::procedural;
+use ncollide3d::query::{self, PointQuery, Ray, RayCast};
+use ncollide3d::shape::{Ball, Cuboid, HeightField, Plane, ShapeHandle, SignedDistanceField, TriMesh, Triangle};
+use ncollide3d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+fn ball_sdf() -> SignedDistanceField<f32> {
+    SignedDistanceField::from_shape(&Ball::new(1.0), 0.1, 0.5)
+}
+
+#[test]
+fn sdf_distances_and_gradients() {
+    let sdf = ball_sdf();
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+
+    assert_relative_eq!(sdf.distance_at_local_point(&Point3::origin()), -1.0, epsilon = 1.0e-3);
+    assert_relative_eq!(sdf.distance_at_local_point(&Point3::new(0.0, 1.2, 0.0)), 0.2, epsilon = 1.0e-3);
+    // Outside of the grid.
+    assert_relative_eq!(sdf.distance_at_local_point(&Point3::new(0.0, 0.0, 3.0)), 2.0, epsilon = 1.0e-3);
+
+    let n = sdf.normal_at_local_point(&Point3::new(0.5, 0.5, 0.0)).unwrap();
+    assert_relative_eq!(*n, Vector3::new(1.0, 1.0, 0.0).normalize(), epsilon = 1.0e-2);
+
+    assert!(sdf.contains_point(&m, &Point3::new(1.5, 0.0, 0.0)));
+    assert!(!sdf.contains_point(&m, &Point3::new(2.5, 0.0, 0.0)));
+    assert_relative_eq!(sdf.distance_to_point(&m, &Point3::new(1.0, 0.0, -1.5), true), 0.5, epsilon = 1.0e-3);
+
+    let proj = sdf.project_point(&m, &Point3::new(1.0, 0.5, 0.0), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 1.0, 0.0), epsilon = 1.0e-2);
+}
+
+#[test]
+fn sdf_from_solid_trimesh() {
+    let mut mesh = TriMesh::from(procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)));
+    mesh.set_solid(true);
+    let sdf = SignedDistanceField::from_shape(&mesh, 0.25, 0.5);
+
+    assert_relative_eq!(sdf.distance_at_local_point(&Point3::origin()), -1.0, epsilon = 1.0e-3);
+    assert_relative_eq!(sdf.distance_at_local_point(&Point3::new(1.25, 0.0, 0.0)), 0.25, epsilon = 1.0e-3);
+}
+
+#[test]
+fn sdf_ray_marching() {
+    let sdf = ball_sdf();
+    let m = Isometry3::translation(0.0, 2.0, 0.0);
+
+    let ray = Ray::new(Point3::new(-5.0, 2.0, 0.0), Vector3::x());
+    let inter = sdf.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-2);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-2);
+
+    // From the inside.
+    let ray = Ray::new(Point3::new(0.0, 2.0, 0.0), Vector3::x());
+    assert_eq!(sdf.toi_with_ray(&m, &ray, true), Some(0.0));
+    let toi = sdf.toi_with_ray(&m, &ray, false).unwrap();
+    assert_relative_eq!(toi, 1.0, epsilon = 1.0e-2);
+
+    let ray = Ray::new(Point3::new(-5.0, 4.0, 0.0), Vector3::x());
+    assert!(sdf.toi_with_ray(&m, &ray, true).is_none());
+}
+
+#[test]
+fn sdf_contact_with_ball() {
+    let sdf = ball_sdf();
+    let ball = Ball::new(0.5);
+    let m2 = Isometry3::translation(0.0, 1.4, 0.0);
+
+    let c = query::contact(&Isometry3::identity(), &sdf, &m2, &ball, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 0.1, epsilon = 1.0e-3);
+    assert_relative_eq!(*c.normal, Vector3::y(), epsilon = 1.0e-2);
+
+    let c = query::contact(&m2, &ball, &Isometry3::identity(), &sdf, 0.0).unwrap();
+    assert_relative_eq!(*c.normal, -Vector3::y(), epsilon = 1.0e-2);
+
+    let far = Isometry3::translation(0.0, 2.0, 0.0);
+    assert!(query::contact(&Isometry3::identity(), &sdf, &far, &ball, 0.1).is_none());
+}
+
+#[test]
+fn sdf_contacts_in_collision_world() {
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let ground = SignedDistanceField::from_shape(&Cuboid::new(Vector3::new(2.0, 0.5, 2.0)), 0.25, 0.5);
+    let _ = world.add(Isometry3::identity(), ShapeHandle::new(ground), groups, query, ());
+    let _ = world.add(
+        Isometry3::translation(0.0, 0.9, 0.0),
+        ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5))),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    // The four bottom corners of the cuboid.
+    assert_eq!(manifold.len(), 4);
+
+    for c in manifold.contacts() {
+        assert_relative_eq!(c.contact.depth, 0.1, epsilon = 1.0e-3);
+    }
+}
+
+#[test]
+fn sdf_from_flat_shape() {
+    let tri = Triangle::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 0.0, 1.0));
+    let sdf = SignedDistanceField::from_shape(&tri, 0.1, 0.0);
+
+    assert!(sdf.nsamples().iter().all(|n| *n >= 2));
+    assert!(sdf.cell_size().iter().all(|s| *s > 0.0));
+    assert_relative_eq!(sdf.distance_at_local_point(&Point3::new(0.25, 0.05, 0.25)), 0.05, epsilon = 1.0e-3);
+}
+
+#[test]
+#[should_panic]
+fn sdf_from_plane() {
+    let _ = SignedDistanceField::from_shape(&Plane::new(Vector3::y_axis()), 0.1, 0.0);
+}
+
+#[test]
+fn sdf_contact_with_heightfield() {
+    let sdf = ball_sdf();
+    let heightfield = HeightField::new(DMatrix::zeros(5, 5), Vector3::new(4.0, 1.0, 4.0));
+    let m1 = Isometry3::translation(0.0, 0.9, 0.0);
+
+    let c = query::contact(&m1, &sdf, &Isometry3::identity(), &heightfield, 0.0).unwrap();
+    assert_relative_eq!(c.depth, 0.1, epsilon = 1.0e-3);
+}
+
+#[test]
+fn sdf_unsupported_contact_pairs() {
+    let dispatcher = DefaultContactDispatcher::new();
+    let sdf = ball_sdf();
+    let plane = Plane::new(Vector3::y_axis());
+
+    assert!(dispatcher.get_contact_algorithm(&sdf, &plane).is_none());
+    assert!(dispatcher.get_contact_algorithm(&plane, &sdf).is_none());
+    assert!(dispatcher.get_contact_algorithm(&sdf, &sdf.clone()).is_none());
+    assert!(dispatcher.get_contact_algorithm(&sdf, &Ball::new(1.0)).is_some());
+}
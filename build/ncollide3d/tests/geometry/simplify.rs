@@ -0,0 +1,111 @@
+use na::Point3;
+use ncollide3d::procedural::{self, IndexBuffer, TriMesh};
+use ncollide3d::transformation;
+
+fn area(mesh: &TriMesh<f64>) -> f64 {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            let (a, b, c) = (
+                mesh.coords[t[0] as usize],
+                mesh.coords[t[1] as usize],
+                mesh.coords[t[2] as usize],
+            );
+            (b - a).cross(&(c - a)).norm() / 2.0
+        })
+        .sum()
+}
+
+fn welded_sphere() -> TriMesh<f64> {
+    let mut mesh = procedural::sphere(2.0, 40, 40, false);
+    mesh.unify_index_buffer();
+    let _ = mesh.weld_vertices(1.0e-6);
+    let _ = mesh.remove_degenerate_triangles(1.0e-6);
+    assert!(mesh.validate(1.0e-6).is_valid());
+    mesh
+}
+
+#[test]
+fn simplify_sphere_to_target_count() {
+    let mesh = welded_sphere();
+    assert!(mesh.num_triangles() > 1000);
+
+    let simplified = transformation::simplify(&mesh, 200, std::f64::MAX, true);
+    let report = simplified.validate(1.0e-6);
+
+    assert!(simplified.num_triangles() <= 200);
+    assert!(simplified.num_triangles() >= 190);
+    assert!(report.is_closed() && report.is_manifold() && report.is_consistently_oriented());
+    assert_eq!(simplified.normals.as_ref().unwrap().len(), simplified.coords.len());
+
+    for pt in &simplified.coords {
+        let r = pt.coords.norm();
+        assert!(r > 0.9 && r < 1.05, "vertex too far from the sphere: {}", r);
+    }
+
+    let coarse = transformation::simplify(&mesh, 20, std::f64::MAX, false);
+    assert!(coarse.num_triangles() <= 20);
+}
+
+#[test]
+fn simplify_with_error_bound_keeps_flat_shape() {
+    let mesh = procedural::quad(4.0f64, 2.0, 20, 10);
+    let boundary: Vec<Point3<f64>> = mesh
+        .coords
+        .iter()
+        .filter(|p| (p.x.abs() - 2.0).abs() < 1.0e-9 || (p.y.abs() - 1.0).abs() < 1.0e-9)
+        .cloned()
+        .collect();
+
+    let simplified = transformation::simplify(&mesh, 0, 1.0e-10, true);
+
+    // Only the boundary vertices are left, with fewer triangles.
+    assert!(simplified.num_triangles() < mesh.num_triangles() / 4);
+    assert_relative_eq!(area(&simplified), 8.0, epsilon = 1.0e-8);
+    assert!(simplified.coords.iter().all(|p| p.z.abs() < 1.0e-9));
+
+    for pt in &boundary {
+        assert!(simplified.coords.iter().any(|p| na::distance(p, pt) < 1.0e-9));
+    }
+
+    // A tiny error bound does not allow collapses on a curved surface.
+    let sphere = welded_sphere();
+    let unchanged = transformation::simplify(&sphere, 0, 0.0, true);
+    assert_eq!(unchanged.num_triangles(), sphere.num_triangles());
+}
+
+#[test]
+fn simplify_keeps_bow_tie_vertices() {
+    // Two boxes touching at the origin, which is the center of the triangle fan of their bottom
+    // and top faces respectively. Moving this vertex to a corner of the smaller face costs
+    // nothing and flips no triangle.
+    let mut coords = vec![Point3::origin()];
+    let mut idx = Vec::new();
+    let small = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+    let large = [(0.0, -3.0), (3.0, 0.0), (0.0, 3.0), (-3.0, 0.0)];
+
+    for (z, square) in [(1.0, small), (-1.0, large)].iter() {
+        let c = coords.len() as u32;
+        let t = c + 4;
+        coords.extend(square.iter().map(|p| Point3::new(p.0, p.1, 0.0)));
+        coords.extend(square.iter().map(|p| Point3::new(p.0, p.1, *z)));
+        idx.push(Point3::new(t, t + 1, t + 2));
+        idx.push(Point3::new(t, t + 2, t + 3));
+
+        for i in 0..4 {
+            let j = (i + 1) % 4;
+            idx.push(Point3::new(0, c + j, c + i));
+            idx.push(Point3::new(c + i, c + j, t + j));
+            idx.push(Point3::new(c + i, t + j, t + i));
+        }
+    }
+
+    let mut mesh = TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(idx)));
+    let _ = mesh.orient_consistently();
+    assert_eq!(mesh.validate(1.0e-6).non_manifold_vertices, vec![0]);
+
+    let target = mesh.num_triangles() - 2;
+    let simplified = transformation::simplify(&mesh, target, std::f64::MAX, false);
+    assert!(simplified.num_triangles() < mesh.num_triangles());
+    assert!(simplified.coords.iter().any(|p| p.coords.norm() == 0.0));
+}
@@ -0,0 +1,127 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::procedural::TriMesh;
+use ncollide3d::shape::{
+    self, Ball, Compound, ConvexHull, Cuboid, Plane, Polyline, Segment, ShapeHandle, SignedDistanceField, Voxels,
+};
+use ncollide3d::transformation::{shape_to_trimesh, ToTriMesh};
+
+// Checks that `mesh` is closed and that its faces point outward, using `inside` as a point
+// enclosed by it.
+fn assert_closed_and_outward(mesh: TriMesh<f32>, inside: Point3<f32>) {
+    let mesh = shape::TriMesh::from(mesh);
+    assert!(mesh.is_closed());
+    assert_relative_eq!(mesh.winding_number(&inside), 1.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn convex_hull_to_trimesh() {
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ];
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+    let mesh = hull.to_trimesh(());
+
+    assert_eq!(mesh.coords.len(), hull.points().len());
+    assert_closed_and_outward(mesh, Point3::new(0.3, 0.3, 0.3));
+
+    // Coplanar faces are merged by the convex hull, and triangulated back here.
+    let cube = ConvexHull::try_from_points(&Cuboid::new(Vector3::repeat(1.0f32)).to_trimesh(()).coords).unwrap();
+    let mesh = cube.to_trimesh(());
+    assert_eq!(mesh.num_triangles(), 12);
+    assert_closed_and_outward(mesh, Point3::origin());
+}
+
+#[test]
+fn trimesh_to_trimesh_round_trip() {
+    let mesh = shape::TriMesh::from(Cuboid::new(Vector3::new(1.0f32, 2.0, 3.0)).to_trimesh(()));
+    let procedural = mesh.to_trimesh(());
+
+    assert_eq!(procedural.coords, mesh.points());
+    assert_eq!(procedural.num_triangles(), mesh.faces().len());
+
+    for (idx, face) in procedural.flat_indices().chunks(3).zip(mesh.faces()) {
+        assert_eq!(idx, &[face.indices.x as u32, face.indices.y as u32, face.indices.z as u32]);
+    }
+}
+
+#[test]
+fn plane_to_trimesh() {
+    let normal = Unit::new_normalize(Vector3::new(1.0f32, 2.0, -0.5));
+    let mesh = Plane::new(normal).to_trimesh(3.0);
+    let coords = &mesh.coords;
+
+    assert_eq!(mesh.num_triangles(), 2);
+
+    for idx in mesh.flat_indices().chunks(3) {
+        let (a, b, c) = (coords[idx[0] as usize], coords[idx[1] as usize], coords[idx[2] as usize]);
+        let n = (b - a).cross(&(c - a)).normalize();
+        assert_relative_eq!(n, *normal, epsilon = 1.0e-5);
+    }
+
+    for pt in coords {
+        assert_relative_eq!(pt.coords.dot(&normal), 0.0, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.coords.norm(), 3.0 * 2.0f32.sqrt(), epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn voxels_to_trimesh_skips_internal_faces() {
+    let cells = vec![Point3::new(0, 0, 0), Point3::new(1, 0, 0)];
+    let voxels = Voxels::from_cells(Vector3::repeat(1.0f32), Vector3::new(2, 1, 1), cells);
+    let mesh = voxels.to_trimesh(());
+
+    assert_eq!(mesh.num_triangles(), 20);
+    let mut mesh = shape::TriMesh::from(mesh);
+    mesh.set_solid(true);
+    assert_relative_eq!(mesh.winding_number(&Point3::new(1.0, 0.5, 0.5)), 1.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn compound_to_trimesh_merges_transformed_parts() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5f32)));
+    let ball = ShapeHandle::new(Ball::new(0.5f32));
+    let segment = ShapeHandle::new(Segment::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0)));
+    let compound = Compound::new(vec![
+        (Isometry3::translation(2.0, 0.0, 0.0), cuboid),
+        (Isometry3::translation(-2.0, 0.0, 0.0), ball.clone()),
+        (Isometry3::identity(), segment),
+    ]);
+
+    let mesh = compound.to_trimesh((10, 1.0));
+    let ball_mesh = ball.as_shape::<Ball<f32>>().unwrap().to_trimesh((10, 10));
+    let ncuboid_coords = mesh.coords.len() - ball_mesh.coords.len();
+
+    assert_eq!(mesh.num_triangles(), 12 + ball_mesh.num_triangles());
+    assert!(mesh.coords[..ncuboid_coords].iter().all(|pt| pt.x >= 1.5 && pt.x <= 2.5));
+    assert!(mesh.coords[ncuboid_coords..].iter().all(|pt| pt.x >= -2.5 && pt.x <= -1.5));
+
+    let dynamic = shape_to_trimesh(&compound, 10, 1.0).unwrap();
+    assert_eq!(dynamic.coords, mesh.coords);
+}
+
+#[test]
+fn signed_distance_field_to_trimesh() {
+    let sdf = SignedDistanceField::from_shape(&Ball::new(1.0f32), 0.1, 0.2);
+    let mesh = shape_to_trimesh(&sdf, 10, 1.0).unwrap();
+
+    // The vertices are interpolated on the edges of the grid.
+    for pt in &mesh.coords {
+        assert_relative_eq!(pt.coords.norm(), 1.0, epsilon = 1.0e-2);
+    }
+
+    assert_closed_and_outward(mesh, Point3::origin());
+}
+
+#[test]
+fn shapes_without_volume_to_trimesh() {
+    let segment = Segment::new(Point3::origin(), Point3::new(0.0f32, 1.0, 0.0));
+    let points = vec![Point3::origin(), Point3::new(1.0f32, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)];
+    let polyline = Polyline::new(points, None);
+
+    assert!(shape_to_trimesh(&segment, 10, 1.0).is_none());
+    assert!(shape_to_trimesh(&polyline, 10, 1.0).is_none());
+}
@@ -0,0 +1,118 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::procedural;
+use ncollide3d::query::PointQuery;
+use ncollide3d::shape::TriMesh;
+
+// A closed cube of side 2 centered at the origin.
+fn cube() -> TriMesh<f32> {
+    TriMesh::from(procedural::cuboid(&Vector3::new(2.0, 2.0, 2.0)))
+}
+
+#[test]
+fn winding_number_of_closed_trimesh() {
+    let mesh = cube();
+
+    assert!(mesh.is_closed());
+    assert_relative_eq!(mesh.winding_number(&Point3::origin()), 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(mesh.winding_number(&Point3::new(0.9, -0.5, 0.2)), 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(mesh.winding_number(&Point3::new(3.0, 0.0, 0.0)), 0.0, epsilon = 1.0e-5);
+    assert!(mesh.encloses_local_point(&Point3::new(0.5, 0.5, 0.5)));
+    assert!(!mesh.encloses_local_point(&Point3::new(1.5, 0.5, 0.5)));
+}
+
+#[test]
+fn signed_distance_to_closed_trimesh() {
+    let mesh = cube();
+    let m = Isometry3::translation(10.0, 0.0, 0.0);
+
+    assert_relative_eq!(mesh.signed_distance_to_point(&m, &Point3::new(10.0, 0.0, 0.0)), -1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(mesh.signed_distance_to_point(&m, &Point3::new(10.0, 0.75, 0.0)), -0.25, epsilon = 1.0e-5);
+    assert_relative_eq!(mesh.signed_distance_to_point(&m, &Point3::new(13.0, 0.0, 0.0)), 2.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn solid_trimesh_point_queries() {
+    let mut mesh = cube();
+    let m = Isometry3::identity();
+    let inside = Point3::new(0.0, 0.5, 0.0);
+    let outside = Point3::new(0.0, 1.5, 0.0);
+
+    // As a surface, only the points on the triangles are inside.
+    assert!(!mesh.contains_point(&m, &inside));
+    let proj = mesh.project_point(&m, &inside, true);
+    assert_relative_eq!(proj.point, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-5);
+
+    mesh.set_solid(true);
+    assert!(mesh.contains_point(&m, &inside));
+    assert!(!mesh.contains_point(&m, &outside));
+
+    let proj = mesh.project_point(&m, &inside, true);
+    assert!(proj.is_inside);
+    assert_eq!(proj.point, inside);
+
+    let proj = mesh.project_point(&m, &inside, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-5);
+    assert_relative_eq!(mesh.distance_to_point(&m, &inside, false), -0.5, epsilon = 1.0e-5);
+    assert_relative_eq!(mesh.distance_to_point(&m, &inside, true), 0.0, epsilon = 1.0e-5);
+
+    let proj = mesh.project_point(&m, &outside, true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-5);
+}
+
+#[test]
+fn open_trimesh_is_not_closed() {
+    let points = vec![Point3::origin(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)];
+    let mesh = TriMesh::new(points, vec![Point3::new(0, 1, 2)], None);
+    assert!(!mesh.is_closed());
+}
+
+#[test]
+fn trimesh_with_non_manifold_edge_is_not_closed() {
+    // Two closed tetrahedra sharing the edge (0, 1), which is thus adjacent to four faces.
+    let points = vec![
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 0.0, -1.0),
+    ];
+    let indices = vec![
+        Point3::new(0, 2, 1),
+        Point3::new(0, 1, 3),
+        Point3::new(0, 3, 2),
+        Point3::new(1, 2, 3),
+        Point3::new(0, 4, 1),
+        Point3::new(0, 1, 5),
+        Point3::new(0, 5, 4),
+        Point3::new(1, 4, 5),
+    ];
+    let mesh = TriMesh::new(points, indices, None);
+    assert!(!mesh.is_closed());
+}
+
+#[test]
+fn solid_trimesh_signed_distance() {
+    let mut mesh = cube();
+    mesh.set_solid(true);
+    let m = Isometry3::identity();
+
+    assert_relative_eq!(mesh.signed_distance_to_point(&m, &Point3::new(0.0, 0.75, 0.0)), -0.25, epsilon = 1.0e-5);
+    assert_relative_eq!(mesh.signed_distance_to_point(&m, &Point3::new(0.0, 3.0, 0.0)), 2.0, epsilon = 1.0e-5);
+    assert!(!mesh.contains_point(&m, &Point3::new(0.0, 30.0, 0.0)));
+}
+
+#[test]
+fn approximate_winding_number_classifies_points() {
+    let mesh = TriMesh::from(procedural::sphere(2.0, 32, 32, false));
+
+    for i in 0..13 {
+        for j in 0..13 {
+            let pt = Point3::new(-1.2 + i as f32 * 0.2, -1.2 + j as f32 * 0.2, 0.05);
+            let exact = mesh.winding_number(&pt) > 0.5;
+            assert_eq!(mesh.encloses_local_point(&pt), exact);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use na::{Point2, Point3, Vector3};
+use ncollide3d::procedural::{self, IndexBuffer, TriMesh};
+
+// The signed volume enclosed by a mesh, positive if its triangles are counter-clockwise
+// when seen from the outside.
+fn volume(mesh: &TriMesh<f64>) -> f64 {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            let (a, b, c) = (
+                mesh.coords[t[0] as usize].coords,
+                mesh.coords[t[1] as usize].coords,
+                mesh.coords[t[2] as usize].coords,
+            );
+            a.dot(&b.cross(&c)) / 6.0
+        })
+        .sum()
+}
+
+fn welded_cube() -> TriMesh<f64> {
+    let mut mesh = procedural::cuboid(&Vector3::new(1.0, 2.0, 3.0));
+    mesh.unify_index_buffer();
+    assert_eq!(mesh.weld_vertices(1.0e-6), 16);
+    mesh
+}
+
+#[test]
+fn weld_vertices_closes_the_mesh() {
+    let mut mesh = procedural::cuboid(&Vector3::new(1.0, 2.0, 3.0));
+    mesh.unify_index_buffer();
+
+    let report = mesh.validate(1.0e-6);
+    assert!(!report.is_closed());
+    assert!(report.is_manifold());
+
+    let mesh = welded_cube();
+    assert_eq!(mesh.coords.len(), 8);
+    assert_eq!(mesh.normals.as_ref().unwrap().len(), 8);
+    assert!(mesh.validate(1.0e-6).is_valid());
+    assert_relative_eq!(volume(&mesh), 6.0, epsilon = 1.0e-10);
+
+    // The split index buffer is kept.
+    let mut split = procedural::cuboid(&Vector3::new(1.0, 2.0, 3.0));
+    let _ = split.weld_vertices(1.0e-6);
+    assert!(split.validate(1.0e-6).is_valid());
+    match split.indices {
+        IndexBuffer::Split(_) => {}
+        _ => panic!("the index buffer should still be split"),
+    }
+}
+
+#[test]
+fn orient_consistently_fixes_flipped_triangles() {
+    let mut mesh = welded_cube();
+
+    if let IndexBuffer::Unified(ref mut idx) = mesh.indices {
+        idx[0].coords.swap((1, 0), (2, 0));
+        idx[7].coords.swap((1, 0), (2, 0));
+    }
+
+    let report = mesh.validate(1.0e-6);
+    assert!(report.is_closed());
+    assert_eq!(report.inconsistent_edges.len(), 6);
+
+    assert_eq!(mesh.orient_consistently(), 2);
+    assert!(mesh.validate(1.0e-6).is_valid());
+    assert_relative_eq!(volume(&mesh), 6.0, epsilon = 1.0e-10);
+
+    // A mesh turned inside out is turned back.
+    mesh.flip_triangles();
+    assert_eq!(mesh.orient_consistently(), 12);
+    assert_relative_eq!(volume(&mesh), 6.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn fill_holes_and_remove_degenerates() {
+    let mut mesh = welded_cube();
+    let mut idx = mesh.indices.clone().unwrap_unified();
+    let _ = idx.remove(3);
+    // A triangle with a repeated vertex and a flat one.
+    idx.push(Point3::new(0, 0, 1));
+    idx.push(Point3::new(8, 9, 10));
+    mesh.indices = IndexBuffer::Unified(idx);
+    mesh.normals = None;
+    mesh.coords.push(Point3::new(5.0, 0.0, 0.0));
+    mesh.coords.push(Point3::new(6.0, 0.0, 0.0));
+    mesh.coords.push(Point3::new(7.0, 1.0e-8, 0.0));
+
+    let report = mesh.validate(1.0e-6);
+    assert_eq!(report.degenerate_triangles, vec![11, 12]);
+    assert_eq!(report.boundary_edges.len(), 6);
+
+    assert_eq!(mesh.remove_degenerate_triangles(1.0e-6), 2);
+    assert_eq!(mesh.coords.len(), 8);
+    assert_eq!(mesh.fill_holes(2), 0);
+    assert_eq!(mesh.fill_holes(3), 1);
+    assert!(mesh.validate(1.0e-6).is_valid());
+    assert_relative_eq!(volume(&mesh), 6.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn repair_soup() {
+    // Each triangle with its own vertices, and some of them flipped.
+    let mut mesh = procedural::sphere(1.0, 10, 10, false);
+    mesh.replicate_vertices();
+
+    if let IndexBuffer::Unified(ref mut idx) = mesh.indices {
+        for t in idx.iter_mut().step_by(3) {
+            t.coords.swap((1, 0), (2, 0));
+        }
+    }
+
+    mesh.repair(1.0e-6, 8);
+    let report = mesh.validate(1.0e-6);
+    assert!(report.is_valid(), "{:?}", report);
+    assert!(volume(&mesh) > 0.0);
+}
+
+#[test]
+fn non_manifold_and_self_intersecting() {
+    // Two tetrahedra touching at their apex.
+    let coords = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(-1.0, 1.0, 1.0),
+        Point3::new(0.0, -1.0, 1.0),
+        Point3::new(1.0, 1.0, -1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(0.0, -1.0, -1.0),
+    ];
+    let idx = vec![
+        Point3::new(0, 1, 2),
+        Point3::new(0, 2, 3),
+        Point3::new(0, 3, 1),
+        Point3::new(1, 3, 2),
+        Point3::new(0, 5, 4),
+        Point3::new(0, 6, 5),
+        Point3::new(0, 4, 6),
+        Point3::new(4, 5, 6),
+    ];
+    let mesh = TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(idx)));
+    let report = mesh.validate(1.0e-6);
+
+    assert_eq!(report.non_manifold_vertices, vec![0]);
+    assert!(report.is_closed());
+    assert!(report.self_intersections.is_empty());
+
+    // A triangle crossing a cube.
+    let mut mesh = welded_cube();
+    let n = mesh.coords.len() as u32;
+    mesh.coords.push(Point3::new(0.0, 0.0, -5.0));
+    mesh.coords.push(Point3::new(0.0, 0.1, 5.0));
+    mesh.coords.push(Point3::new(0.0, 5.0, 0.0));
+    mesh.normals = None;
+    if let IndexBuffer::Unified(ref mut idx) = mesh.indices {
+        idx.push(Point3::new(n, n + 1, n + 2));
+    }
+
+    let report = mesh.validate(1.0e-6);
+    assert!(!report.self_intersections.is_empty());
+    assert!(report.self_intersections.iter().all(|p| p.1 == 12));
+    assert_eq!(report.boundary_edges.len(), 3);
+    assert!(report.boundary_edges.contains(&Point2::new(n, n + 1)));
+}
+
+#[test]
+fn fill_non_convex_hole() {
+    // A prism with a notched base, without its bottom cap. The fan around the first vertex of the
+    // base would cover the notch.
+    let base = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 1.0), (0.0, 4.0)];
+    let mut coords: Vec<_> = base.iter().map(|p| Point3::new(p.0, p.1, 0.0)).collect();
+    coords.extend(base.iter().map(|p| Point3::new(p.0, p.1, 1.0)));
+    let mut idx = vec![
+        Point3::new(5, 6, 8),
+        Point3::new(6, 7, 8),
+        Point3::new(5, 8, 9),
+    ];
+
+    for i in 0..5 {
+        let j = (i + 1) % 5;
+        idx.push(Point3::new(i, j, j + 5));
+        idx.push(Point3::new(i, j + 5, i + 5));
+    }
+
+    let mut mesh = TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(idx)));
+    assert_eq!(mesh.fill_holes(5), 1);
+    assert!(mesh.validate(1.0e-6).is_valid());
+    assert_relative_eq!(volume(&mesh), 10.0, epsilon = 1.0e-10);
+
+    // The bottom cap covers the base exactly once.
+    let bottom_area: f64 = mesh
+        .flat_indices()
+        .chunks(3)
+        .skip(13)
+        .map(|t| {
+            let a = mesh.coords[t[0] as usize];
+            let b = mesh.coords[t[1] as usize];
+            let c = mesh.coords[t[2] as usize];
+            let normal = (b - a).cross(&(c - a));
+            assert!(normal.z < 0.0);
+            normal.norm() / 2.0
+        })
+        .sum();
+    assert_relative_eq!(bottom_area, 10.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn split_t_junctions() {
+    // A unit square next to two rectangles, the middle vertex of which is almost on the right
+    // edge of the square.
+    let coords = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(1.0 + 1.0e-8, 0.5, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(2.0, 0.5, 0.0),
+        Point3::new(2.0, 1.0, 0.0),
+    ];
+    let idx = vec![
+        Point3::new(0, 1, 2),
+        Point3::new(0, 2, 3),
+        Point3::new(1, 5, 6),
+        Point3::new(1, 6, 4),
+        Point3::new(4, 6, 7),
+        Point3::new(4, 7, 2),
+    ];
+    let mut mesh = TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(idx)));
+    assert_eq!(mesh.validate(1.0e-6).boundary_edges.len(), 10);
+
+    assert_eq!(mesh.split_t_junctions(1.0e-9), 0);
+    assert_eq!(mesh.split_t_junctions(1.0e-6), 1);
+    assert_eq!(mesh.split_t_junctions(1.0e-6), 0);
+
+    let report = mesh.validate(1.0e-6);
+    assert_eq!(report.boundary_edges.len(), 7);
+    assert!(!report.boundary_edges.contains(&Point2::new(1, 2)));
+    assert!(report.is_manifold());
+    assert!(report.is_consistently_oriented());
+    assert!(report.degenerate_triangles.is_empty());
+}
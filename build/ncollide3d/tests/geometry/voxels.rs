@@ -0,0 +1,150 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::query::{self, PointQuery, Proximity, Ray, RayCast};
+use ncollide3d::shape::{Ball, CompositeShape, Cuboid, ShapeHandle, Voxels};
+use ncollide3d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+// A 4x4 floor of unit voxels with a single voxel on top of the voxel (3, 0, 3).
+fn floor() -> Voxels<f32> {
+    let mut cells = Vec::new();
+
+    for i in 0..4 {
+        for k in 0..4 {
+            cells.push(Point3::new(i, 0, k));
+        }
+    }
+
+    cells.push(Point3::new(3, 1, 3));
+    Voxels::from_cells(Vector3::repeat(1.0), Vector3::new(4, 2, 4), cells)
+}
+
+#[test]
+fn voxels_dense_and_sparse_construction() {
+    let mut occupied = vec![false; 4 * 2 * 4];
+    for i in 0..16 {
+        occupied[(i % 4) + (i / 4) * 8] = true;
+    }
+    occupied[3 + 4 + 3 * 8] = true;
+    let dense = Voxels::new(Vector3::repeat(1.0f32), Vector3::new(4, 2, 4), &occupied);
+    let sparse = floor();
+
+    assert_eq!(dense.cells().len(), 17);
+    assert_eq!(sparse.cells().len(), 17);
+    assert_eq!(*dense.aabb(), *sparse.aabb());
+    assert!(dense.is_voxel_occupied(&Point3::new(3, 1, 3)));
+    assert!(!dense.is_voxel_occupied(&Point3::new(2, 1, 3)));
+}
+
+#[test]
+fn voxels_point_queries() {
+    let voxels = floor();
+    let m = Isometry3::translation(0.0, -1.0, 0.0);
+
+    assert!(voxels.contains_point(&m, &Point3::new(0.5, -0.5, 0.5)));
+    assert!(!voxels.contains_point(&m, &Point3::new(0.5, 0.5, 0.5)));
+
+    // Inside: the closest point is on the top face, not on the internal faces.
+    let proj = voxels.project_point(&m, &Point3::new(1.0, -0.2, 1.5), false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, 1.5), epsilon = 1.0e-5);
+
+    let proj = voxels.project_point(&m, &Point3::new(1.0, -0.2, 1.5), true);
+    assert_eq!(proj.point, Point3::new(1.0, -0.2, 1.5));
+
+    // Outside, next to the voxel on top.
+    let proj = voxels.project_point(&m, &Point3::new(2.5, 0.5, 3.5), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Point3::new(3.0, 0.5, 3.5), epsilon = 1.0e-5);
+}
+
+#[test]
+fn voxels_ray_casts() {
+    let voxels = floor();
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(1.5, 5.0, 1.5), -Vector3::y());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-5);
+    assert_relative_eq!(inter.normal, Vector3::y(), epsilon = 1.0e-5);
+
+    // Crosses empty cells before hitting the voxel on top.
+    let ray = Ray::new(Point3::new(-1.0, 1.5, 3.5), Vector3::x());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_relative_eq!(inter.toi, 4.0, epsilon = 1.0e-5);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-5);
+
+    // Passes above the voxels.
+    let ray = Ray::new(Point3::new(-1.0, 1.5, 2.5), Vector3::x());
+    assert!(voxels.toi_with_ray(&m, &ray, true).is_none());
+
+    // From the inside, the internal faces are ignored if the ray is not solid.
+    let ray = Ray::new(Point3::new(0.5, 0.5, 0.5), Vector3::x());
+    assert_eq!(voxels.toi_with_ray(&m, &ray, true), Some(0.0));
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert_relative_eq!(inter.toi, 3.5, epsilon = 1.0e-5);
+    assert_relative_eq!(inter.normal, -Vector3::x(), epsilon = 1.0e-5);
+}
+
+#[test]
+fn voxels_internal_edges_are_ignored() {
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(Isometry3::identity(), ShapeHandle::new(floor()), groups, query, ());
+    // A cuboid deep enough across the internal faces at x = 1 and z = 1 to have the
+    // smallest penetration along the x and z axes with the voxels at x = 0 and z = 0.
+    let _ = world.add(
+        Isometry3::translation(1.4, 1.2, 1.4),
+        ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5))),
+        groups,
+        query,
+        (),
+    );
+    // A ball right above the internal edge at x = 2.
+    let _ = world.add(
+        Isometry3::translation(2.0, 1.2, 2.5),
+        ShapeHandle::new(Ball::new(0.5)),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let mut ncontacts = 0;
+
+    for (_, _, _, manifold) in world.contact_pairs(true) {
+        for c in manifold.contacts() {
+            assert_relative_eq!(*c.contact.normal, Vector3::y(), epsilon = 1.0e-5);
+            assert_relative_eq!(c.contact.depth, 0.3, epsilon = 1.0e-5);
+            ncontacts += 1;
+        }
+    }
+
+    assert!(ncontacts > 0);
+}
+
+#[test]
+fn empty_voxels_ray_casts() {
+    let ray = Ray::new(Point3::new(-1.0, 0.5, 0.5), Vector3::x());
+    let empty = Voxels::from_cells(Vector3::repeat(1.0f32), Vector3::new(2, 0, 2), Vec::new());
+    assert!(empty.toi_with_ray(&Isometry3::identity(), &ray, true).is_none());
+}
+
+#[test]
+fn voxels_queries_use_the_occupancy_grid() {
+    let voxels = floor();
+    let ball = Ball::new(0.3);
+    let m = Isometry3::identity();
+    assert!(voxels.qbvh().is_none());
+
+    // Touching the voxel (3, 1, 3), touching the floor next to it, and hovering above the floor.
+    let on_top = Isometry3::translation(3.5, 2.2, 3.5);
+    let next_to = Isometry3::translation(2.5, 1.2, 3.5);
+    let above = Isometry3::translation(2.5, 1.5, 3.5);
+
+    assert_eq!(query::proximity(&m, &voxels, &on_top, &ball, 0.0), Proximity::Intersecting);
+    assert_eq!(query::proximity(&m, &voxels, &next_to, &ball, 0.0), Proximity::Intersecting);
+    assert_eq!(query::proximity(&m, &voxels, &above, &ball, 0.0), Proximity::Disjoint);
+
+    let contact = query::contact(&m, &voxels, &next_to, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-5);
+}
@@ -0,0 +1,147 @@
+use na::{self, Isometry3, Point3, Unit, Vector3};
+use ncollide3d::narrow_phase::ContactManifoldModifier;
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold, ContactPreprocessor};
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
+use ncollide3d::utils::IdAllocator;
+use ncollide3d::world::{CollisionGroups, CollisionObject, CollisionWorld, GeometricQueryType};
+
+// Only keeps the contacts pushing the other object upward.
+struct OneWayPlatform;
+
+impl ContactPreprocessor<f32> for OneWayPlatform {
+    fn process_contact(
+        &self,
+        c: &mut Contact<f32>,
+        _: &mut ContactKinematic<f32>,
+        is_first: bool,
+    ) -> bool
+    {
+        if is_first {
+            c.normal.y > 0.0
+        } else {
+            c.normal.y < 0.0
+        }
+    }
+}
+
+fn platform_and_ball(ball_y: f32) -> CollisionWorld<f32, ()> {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let platform = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(Cuboid::new(Vector3::new(2.0, 0.1, 2.0))),
+            groups,
+            query,
+            (),
+        )
+        .handle();
+    world.set_contact_preprocessor(platform, Some(Box::new(OneWayPlatform)));
+    let _ = world.add(
+        Isometry3::translation(0.0, ball_y, 0.0),
+        ShapeHandle::new(Ball::new(0.5)),
+        groups,
+        query,
+        (),
+    );
+    world
+}
+
+#[test]
+fn one_way_platform_preprocessor() {
+    let mut world = platform_and_ball(0.55);
+    world.update();
+    assert_eq!(world.contact_pairs(true).count(), 1);
+    assert_eq!(world.contact_events().len(), 1);
+
+    let mut world = platform_and_ball(-0.55);
+    world.update();
+    assert_eq!(world.contact_pairs(true).count(), 0);
+    assert_eq!(world.contact_events().len(), 0);
+}
+
+// Disables every contact shallower than the given depth.
+struct MinDepth(f32);
+
+impl ContactManifoldModifier<f32, ()> for MinDepth {
+    fn modify_manifold(
+        &self,
+        _: &CollisionObject<f32, ()>,
+        _: &CollisionObject<f32, ()>,
+        manifold: &mut ContactManifold<f32>,
+        _: &mut IdAllocator,
+    )
+    {
+        manifold.retain(|c| c.contact.depth >= self.0)
+    }
+}
+
+// Adds one contact at the origin.
+struct AddContact;
+
+impl ContactManifoldModifier<f32, ()> for AddContact {
+    fn modify_manifold(
+        &self,
+        _: &CollisionObject<f32, ()>,
+        _: &CollisionObject<f32, ()>,
+        manifold: &mut ContactManifold<f32>,
+        id_alloc: &mut IdAllocator,
+    )
+    {
+        let contact = Contact::new(Point3::origin(), Point3::origin(), Unit::new_unchecked(Vector3::y()), 1.0);
+        let tracking_pt = Point3::new(10.0, 10.0, 10.0);
+        let _ = manifold.push(contact, ContactKinematic::new(), tracking_pt, None, None, id_alloc);
+    }
+}
+
+#[test]
+fn contact_manifold_modifiers() {
+    let mut world = platform_and_ball(0.55);
+    world.register_contact_manifold_modifier("min depth", MinDepth(0.1));
+    world.update();
+    assert_eq!(world.contact_pairs(true).count(), 0);
+    assert_eq!(world.contact_events().len(), 0);
+
+    // Modifiers are applied in registration order.
+    world.register_contact_manifold_modifier("add", AddContact);
+    world.update();
+    {
+        let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+        assert_eq!(manifold.len(), 1);
+        assert!(na::distance(&manifold.deepest_contact().unwrap().contact.world1, &Point3::origin()) < 1.0e-6);
+    }
+
+    // Unregistering the depth filter brings back the actual contact.
+    world.unregister_contact_manifold_modifier("min depth");
+    world.update();
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert_eq!(manifold.len(), 2);
+}
+
+#[test]
+fn disabled_contacts_keep_their_ids() {
+    let mut manifold = ContactManifold::new();
+    let mut id_alloc = IdAllocator::new();
+    let contact = Contact::new(Point3::origin(), Point3::origin(), Unit::new_unchecked(Vector3::y()), 0.1);
+    let push = |manifold: &mut ContactManifold<f32>, id_alloc: &mut IdAllocator| {
+        let kinematic = ContactKinematic::new();
+        let _ = manifold.push(contact.clone(), kinematic, Point3::origin(), None, None, id_alloc);
+    };
+
+    push(&mut manifold, &mut id_alloc);
+    let id = manifold.deepest_contact().unwrap().id;
+
+    // The contact is generated again but disabled during the next update.
+    manifold.save_cache_and_clear(&mut id_alloc);
+    push(&mut manifold, &mut id_alloc);
+    manifold.retain(|_| false);
+    assert_eq!(manifold.len(), 0);
+    assert_eq!(manifold.contacts().count(), 0);
+
+    // Once enabled again, it is given the same identifier.
+    manifold.save_cache_and_clear(&mut id_alloc);
+    push(&mut manifold, &mut id_alloc);
+    assert_eq!(manifold.len(), 1);
+    assert_eq!(manifold.contacts().next().unwrap().id, id);
+}
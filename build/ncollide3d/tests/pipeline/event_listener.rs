@@ -0,0 +1,97 @@
+use na::Isometry3;
+use ncollide3d::query::{ContactManifold, Proximity};
+use ncollide3d::shape::{Ball, ShapeHandle};
+use ncollide3d::world::{CollisionEventListener, CollisionGroups, CollisionObject, CollisionWorld, GeometricQueryType};
+use std::sync::{Arc, Mutex};
+
+// Records every event using the names stored as user data.
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Recorder {
+    fn push(&self, event: &str, co1: &CollisionObject<f32, &'static str>, co2: &CollisionObject<f32, &'static str>) {
+        // The order of the objects of a pair is unspecified.
+        let mut names = [*co1.data(), *co2.data()];
+        names.sort();
+        self.0.lock().unwrap().push(format!("{} {} {}", event, names[0], names[1]))
+    }
+}
+
+impl CollisionEventListener<f32, &'static str> for Recorder {
+    fn on_contact_started(
+        &mut self,
+        co1: &CollisionObject<f32, &'static str>,
+        co2: &CollisionObject<f32, &'static str>,
+        manifold: &ContactManifold<f32>,
+    )
+    {
+        assert_eq!(manifold.len(), 1);
+        self.push("contact started", co1, co2)
+    }
+
+    fn on_contact_stopped(&mut self, co1: &CollisionObject<f32, &'static str>, co2: &CollisionObject<f32, &'static str>) {
+        self.push("contact stopped", co1, co2)
+    }
+
+    fn on_proximity_changed(
+        &mut self,
+        co1: &CollisionObject<f32, &'static str>,
+        co2: &CollisionObject<f32, &'static str>,
+        _: Proximity,
+        new_status: Proximity,
+    )
+    {
+        self.push(&format!("{:?}", new_status), co1, co2)
+    }
+
+    fn on_broad_phase_pair_added(&mut self, co1: &CollisionObject<f32, &'static str>, co2: &CollisionObject<f32, &'static str>) {
+        self.push("pair added", co1, co2)
+    }
+
+    fn on_broad_phase_pair_removed(&mut self, co1: &CollisionObject<f32, &'static str>, co2: &CollisionObject<f32, &'static str>) {
+        self.push("pair removed", co1, co2)
+    }
+}
+
+#[test]
+fn event_listener() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut world = CollisionWorld::new(0.02);
+    world.register_event_listener("recorder", Recorder(events.clone()));
+
+    let groups = CollisionGroups::new();
+    let shape = ShapeHandle::new(Ball::new(1.0));
+    let contacts = GeometricQueryType::Contacts(0.0, 0.0);
+    let a = world.add(Isometry3::identity(), shape.clone(), groups, contacts, "a").handle();
+    let b = world.add(Isometry3::translation(1.5, 0.0, 0.0), shape.clone(), groups, contacts, "b").handle();
+
+    let mut take = || {
+        let mut events = events.lock().unwrap();
+        let mut result: Vec<String> = events.drain(..).collect();
+        result.sort();
+        result
+    };
+
+    world.update();
+    assert_eq!(take(), vec!["contact started a b", "pair added a b"]);
+
+    world.update();
+    assert!(take().is_empty());
+
+    world.set_position(b, Isometry3::translation(2.01, 0.0, 0.0));
+    world.update();
+    assert_eq!(take(), vec!["contact stopped a b"]);
+
+    world.set_position(b, Isometry3::translation(10.0, 0.0, 0.0));
+    world.update();
+    assert_eq!(take(), vec!["pair removed a b"]);
+
+    let _ = world.add(Isometry3::translation(0.0, 1.5, 0.0), shape, groups, GeometricQueryType::Proximity(0.0), "sensor");
+    world.update();
+    assert_eq!(take(), vec!["Intersecting a sensor", "pair added a sensor"]);
+
+    // Removed listeners are no longer called.
+    assert!(world.unregister_event_listener("recorder").is_some());
+    world.set_position(a, Isometry3::translation(0.0, 10.0, 0.0));
+    world.update();
+    assert!(take().is_empty());
+}
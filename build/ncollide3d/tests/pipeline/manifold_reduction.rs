@@ -0,0 +1,116 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::query::{Contact, ContactKinematic, ContactManifold};
+use ncollide3d::shape::{Cuboid, ShapeHandle, TriMesh};
+use ncollide3d::utils::IdAllocator;
+use ncollide3d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+// A finely triangulated square of side 4 on the plane y = 0.
+fn grid(n: usize) -> TriMesh<f32> {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+    let step = 4.0 / n as f32;
+
+    for i in 0..=n {
+        for j in 0..=n {
+            points.push(Point3::new(-2.0 + i as f32 * step, 0.0, -2.0 + j as f32 * step));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + n + 1;
+            indices.push(Point3::new(a, a + 1, b));
+            indices.push(Point3::new(b, a + 1, b + 1));
+        }
+    }
+
+    TriMesh::new(points, indices, None)
+}
+
+fn box_on_grid() -> CollisionWorld<f32, ()> {
+    let mut world = CollisionWorld::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(Isometry3::identity(), ShapeHandle::new(grid(8)), groups, query, ());
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.05, 0.45, 0.1), Vector3::new(0.05, 0.0, 0.0)),
+        ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 0.5, 1.0))),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+    world
+}
+
+#[test]
+fn manifold_reduction() {
+    let mut world = box_on_grid();
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert!(manifold.len() > 4);
+    let deepest = manifold.deepest_contact().unwrap().id;
+
+    world.set_max_contacts_per_manifold(Some(4));
+    world.update();
+    let ids: Vec<_> = {
+        let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+        assert_eq!(manifold.len(), 4);
+        assert_eq!(manifold.contacts().count(), 4);
+        assert_eq!(manifold.deepest_contact().unwrap().id, deepest);
+
+        // The retained contacts span most of the contact area.
+        let pts: Vec<_> = manifold.contacts().map(|c| c.contact.world1).collect();
+        let extent = |i: usize| {
+            let max = pts.iter().map(|p| p[i]).fold(-1.0e10, f32::max);
+            let min = pts.iter().map(|p| p[i]).fold(1.0e10, f32::min);
+            max - min
+        };
+        assert!(extent(0) > 1.9 && extent(2) > 1.5);
+        manifold.contacts().map(|c| c.id).collect()
+    };
+
+    // Identifiers of the retained contacts persist across updates.
+    world.update();
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert_eq!(manifold.len(), 4);
+    assert!(manifold.contacts().all(|c| ids.contains(&c.id)));
+
+    world.set_max_contacts_per_manifold(Some(1));
+    world.update();
+    let (_, _, _, manifold) = world.contact_pairs(true).next().unwrap();
+    assert_eq!(manifold.len(), 1);
+    assert_eq!(manifold.deepest_contact().unwrap().id, deepest);
+}
+
+#[test]
+fn manifold_reduction_is_stable() {
+    let mut manifold = ContactManifold::new();
+    let mut id_alloc = IdAllocator::new();
+    let mut update = |manifold: &mut ContactManifold<f32>, contacts: &[(Point3<f32>, f32)]| {
+        manifold.save_cache_and_clear(&mut id_alloc);
+
+        for (pt, depth) in contacts {
+            let contact = Contact::new(*pt, *pt, Unit::new_unchecked(Vector3::y()), *depth);
+            let _ = manifold.push(contact, ContactKinematic::new(), *pt, None, None, &mut id_alloc);
+        }
+
+        manifold.reduce(2);
+        manifold.contacts().map(|c| c.id).collect::<Vec<_>>()
+    };
+
+    let deepest = (Point3::origin(), 1.0);
+    let ids = update(
+        &mut manifold,
+        &[deepest, (Point3::new(1.0, 0.0, 0.0), 0.5), (Point3::new(0.0, 0.0, 0.99), 0.5)],
+    );
+    assert_eq!(ids.len(), 2);
+
+    // The second contact is now slightly farther from the deepest one, but the contacts
+    // retained during the previous update are kept.
+    let new_ids = update(
+        &mut manifold,
+        &[deepest, (Point3::new(0.99, 0.0, 0.0), 0.5), (Point3::new(0.0, 0.0, 1.0), 0.5)],
+    );
+    assert_eq!(new_ids, ids);
+}
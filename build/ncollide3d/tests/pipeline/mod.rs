@@ -1,2 +1,7 @@
+mod contact_modification;
 mod duplicate_trimesh_on_world;
+mod event_listener;
 mod is_send_sync;
+mod manifold_reduction;
+mod part_proximities;
+mod trimesh_internal_edges;
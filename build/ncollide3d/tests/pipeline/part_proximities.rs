@@ -0,0 +1,133 @@
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::query::Proximity;
+use ncollide3d::narrow_phase::{DefaultContactDispatcher, NarrowPhase, PartwiseProximityDispatcher};
+use ncollide3d::shape::{Ball, HeightField, ShapeHandle, TriMesh};
+use ncollide3d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+fn partwise_world() -> CollisionWorld<f32, ()> {
+    let mut world = CollisionWorld::new(0.02);
+    world.set_narrow_phase(NarrowPhase::new(
+        Box::new(DefaultContactDispatcher::new()),
+        Box::new(PartwiseProximityDispatcher::new()),
+    ));
+    world
+}
+
+#[test]
+fn heightfield_part_proximities() {
+    let mut world = partwise_world();
+    let groups = CollisionGroups::new();
+    let mut heightfield = HeightField::new(DMatrix::zeros(3, 3), Vector3::new(2.0, 1.0, 2.0));
+    heightfield.set_one_way(Some(0.5));
+    let ground = world
+        .add(Isometry3::identity(), ShapeHandle::new(heightfield), groups, GeometricQueryType::Contacts(0.0, 0.0), ())
+        .handle();
+    // The sensor overlaps the two triangles of one cell.
+    let sensor = world
+        .add(
+            Isometry3::translation(0.5, 0.1, 0.5),
+            ShapeHandle::new(Ball::new(0.2)),
+            groups,
+            GeometricQueryType::Proximity(0.0),
+            (),
+        )
+        .handle();
+    world.update();
+
+    assert_eq!(world.proximity_events().len(), 1);
+    assert_eq!(world.part_proximity_events().len(), 2);
+    for event in world.part_proximity_events().iter() {
+        assert_eq!(event.collider, ground);
+        assert_eq!(event.other_collider, sensor);
+        assert_eq!(event.prev_status, Proximity::Disjoint);
+        assert_eq!(event.new_status, Proximity::Intersecting);
+    }
+
+    let parts: Vec<_> = world.part_proximities(ground, sensor).unwrap().intersecting_parts().collect();
+    assert_eq!(parts.len(), 2);
+    assert!(world.part_proximities(sensor, ground).is_none());
+
+    // Slide the sensor to a position where it overlaps the four cells.
+    world.set_position(sensor, Isometry3::translation(0.0, 0.1, 0.0));
+    world.update();
+    assert_eq!(world.proximity_events().len(), 0);
+    let new_parts: Vec<_> = world.part_proximities(ground, sensor).unwrap().intersecting_parts().collect();
+    assert!(new_parts.len() >= 4);
+    // Only the parts which status changed are reported.
+    let num_changes = parts.iter().filter(|p| !new_parts.contains(p)).count()
+        + new_parts.iter().filter(|p| !parts.contains(p)).count();
+    assert_eq!(world.part_proximity_events().len(), num_changes);
+
+    // The heightfield is one-way so objects below it are ignored.
+    world.set_position(sensor, Isometry3::translation(0.5, -0.05, 0.5));
+    world.update();
+    assert_eq!(world.part_proximities(ground, sensor).unwrap().intersecting_parts().count(), 0);
+    assert!(world.part_proximity_events().iter().all(|e| e.new_status == Proximity::Disjoint));
+}
+
+#[test]
+fn trimesh_part_proximities() {
+    let mut world = partwise_world();
+    let groups = CollisionGroups::new();
+    let points = vec![
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(-1.0, 0.0, 1.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    let mesh = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(TriMesh::new(points, indices, None)),
+            groups,
+            GeometricQueryType::Proximity(0.0),
+            (),
+        )
+        .handle();
+    let ball = world
+        .add(Isometry3::translation(0.5, 0.1, -0.5), ShapeHandle::new(Ball::new(0.2)), groups, GeometricQueryType::Contacts(0.0, 0.0), ())
+        .handle();
+    world.update();
+
+    let parts: Vec<_> = world.part_proximities(mesh, ball).unwrap().intersecting_parts().collect();
+    assert_eq!(parts, vec![0]);
+    assert_eq!(world.part_proximity_events().len(), 1);
+
+    // Removing the pair reports all the parts as disjoint.
+    world.set_position(ball, Isometry3::translation(0.0, 10.0, 0.0));
+    world.update();
+    assert!(world.part_proximities(mesh, ball).is_none());
+    let event = world.part_proximity_events().iter().next().unwrap();
+    assert_eq!((event.part, event.prev_status, event.new_status), (0, Proximity::Intersecting, Proximity::Disjoint));
+}
+
+#[test]
+fn default_dispatcher_does_not_track_parts() {
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let points = vec![
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(-1.0, 0.0, 1.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    let mesh = world
+        .add(
+            Isometry3::identity(),
+            ShapeHandle::new(TriMesh::new(points, indices, None)),
+            groups,
+            GeometricQueryType::Proximity(0.0),
+            (),
+        )
+        .handle();
+    let ball = world
+        .add(Isometry3::translation(0.5, 0.1, -0.5), ShapeHandle::new(Ball::new(0.2)), groups, GeometricQueryType::Contacts(0.0, 0.0), ())
+        .handle();
+    world.update();
+
+    assert_eq!(world.proximity_events().len(), 1);
+    assert!(world.part_proximities(mesh, ball).is_none());
+    assert_eq!(world.part_proximity_events().len(), 0);
+}
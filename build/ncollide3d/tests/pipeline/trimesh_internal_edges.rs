@@ -0,0 +1,222 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::shape::{Ball, Cuboid, DeformableShape, MinkowskiSum, Shape, ShapeHandle, TriMesh};
+use ncollide3d::world::{CollisionGroups, CollisionWorld, GeometricQueryType};
+
+// A tessellated square of side 8 on the plane y = 0, with upward-facing triangles.
+fn tessellated_plane(n: usize) -> TriMesh<f32> {
+    let mut points = Vec::new();
+    let mut indices = Vec::new();
+    let step = 8.0 / n as f32;
+
+    for i in 0..=n {
+        for j in 0..=n {
+            points.push(Point3::new(-4.0 + i as f32 * step, 0.0, -4.0 + j as f32 * step));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + n + 1;
+            indices.push(Point3::new(a, a + 1, b));
+            indices.push(Point3::new(b, a + 1, b + 1));
+        }
+    }
+
+    TriMesh::new(points, indices, None)
+}
+
+// Slides `shape` along the x axis across the plane with a penetration depth of `depth`, and
+// checks that all the contacts push it straight up. Contacts with triangles that are only
+// partially covered by the shape may be shallower than the actual penetration.
+fn slide_across_plane<S: Shape<f32> + Clone>(shape: S, height: f32, depth: f32, oriented: bool) {
+    let mut mesh = tessellated_plane(8);
+    mesh.set_oriented(oriented);
+
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(Isometry3::identity(), ShapeHandle::new(mesh), groups, query, ());
+    let handle = world
+        .add(
+            Isometry3::translation(-2.0, height - depth, 0.3),
+            ShapeHandle::new(shape),
+            groups,
+            query,
+            (),
+        )
+        .handle();
+
+    for i in 0..=80 {
+        let x = -2.0 + i as f32 * 0.05;
+        world.set_position(handle, Isometry3::translation(x, height - depth, 0.3));
+        world.update();
+
+        let mut deepest = -1.0;
+
+        for (_, _, _, manifold) in world.contact_pairs(true) {
+            for c in manifold.contacts() {
+                assert_relative_eq!(c.contact.normal.y.abs(), 1.0, epsilon = 1.0e-4);
+                assert!(c.contact.depth <= depth + 1.0e-4);
+                deepest = c.contact.depth.max(deepest);
+            }
+        }
+
+        assert_relative_eq!(deepest, depth, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn ball_sliding_across_tessellated_plane() {
+    slide_across_plane(Ball::new(0.5), 0.5, 0.1, false);
+    slide_across_plane(Ball::new(0.5), 0.5, 0.1, true);
+}
+
+#[test]
+fn box_sliding_across_tessellated_plane() {
+    slide_across_plane(Cuboid::new(Vector3::new(0.4, 0.5, 0.4)), 0.5, 0.05, false);
+    slide_across_plane(Cuboid::new(Vector3::new(0.4, 0.5, 0.4)), 0.5, 0.05, true);
+}
+
+#[test]
+fn rounded_box_sliding_across_tessellated_plane() {
+    let shape = MinkowskiSum::new(Cuboid::new(Vector3::new(0.3, 0.4, 0.3)), Ball::new(0.1));
+    slide_across_plane(shape.clone(), 0.5, 0.05, false);
+    slide_across_plane(shape, 0.5, 0.05, true);
+}
+
+#[test]
+fn rounded_box_predicted_contacts_with_tessellated_plane() {
+    let shape = MinkowskiSum::new(Cuboid::new(Vector3::new(0.3, 0.4, 0.3)), Ball::new(0.1));
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.1, 0.0);
+    let plane = ShapeHandle::new(tessellated_plane(8));
+    let _ = world.add(Isometry3::identity(), plane, groups, query, ());
+    let pos = Isometry3::translation(0.1, 0.55, 0.3);
+    let _ = world.add(pos, ShapeHandle::new(shape), groups, query, ());
+    world.update();
+
+    // The contacts within the prediction distance are kept by the mesh preprocessor.
+    let contacts: Vec<_> = world
+        .contact_pairs(false)
+        .flat_map(|(_, _, _, manifold)| manifold.contacts().cloned().collect::<Vec<_>>())
+        .collect();
+    assert!(!contacts.is_empty());
+
+    for c in contacts {
+        assert_relative_eq!(c.contact.normal.y.abs(), 1.0, epsilon = 1.0e-4);
+        assert_relative_eq!(c.contact.depth, -0.05, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn deep_box_sliding_across_tessellated_plane() {
+    // Deep enough for the horizontal penetration with some triangles to be smaller than the vertical one.
+    slide_across_plane(Cuboid::new(Vector3::new(0.4, 0.5, 0.4)), 0.5, 0.3, false);
+}
+
+// Two triangles forming a ridge along the z axis, with sides sloping down by `slope`.
+fn ridge_points(slope: f32) -> Vec<Point3<f32>> {
+    vec![
+        Point3::new(-1.0, -slope, -1.0),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(-1.0, -slope, 1.0),
+        Point3::new(1.0, -slope, -1.0),
+        Point3::new(1.0, -slope, 1.0),
+    ]
+}
+
+fn ridge(slope: f32) -> TriMesh<f32> {
+    let indices = vec![
+        Point3::new(0, 2, 1),
+        Point3::new(0, 3, 2),
+        Point3::new(1, 2, 5),
+        Point3::new(1, 5, 4),
+    ];
+    TriMesh::new(ridge_points(slope), indices, None)
+}
+
+// Drops a ball of radius 0.5 with a penetration of 0.1 on top of the ridge, and returns the
+// normal and depth of each contact.
+fn ball_on_ridge(mesh: TriMesh<f32>) -> Vec<(Vector3<f32>, f32)> {
+    let mut world = CollisionWorld::<f32, ()>::new(0.02);
+    let groups = CollisionGroups::new();
+    let query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(Isometry3::identity(), ShapeHandle::new(mesh), groups, query, ());
+    let _ = world.add(
+        Isometry3::translation(0.0, 0.4, 0.0),
+        ShapeHandle::new(Ball::new(0.5f32)),
+        groups,
+        query,
+        (),
+    );
+    world.update();
+
+    let mut normals = Vec::new();
+
+    for (_, _, _, manifold) in world.contact_pairs(true) {
+        for c in manifold.contacts() {
+            normals.push((*c.contact.normal, c.contact.depth));
+        }
+    }
+
+    assert!(!normals.is_empty());
+    normals
+}
+
+#[test]
+fn ball_on_convex_ridge_keeps_edge_normal() {
+    for (n, depth) in ball_on_ridge(ridge(1.0)) {
+        assert_relative_eq!(n.y.abs(), 1.0, epsilon = 1.0e-4);
+        assert_relative_eq!(depth, 0.1, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn deformed_ridge_keeps_edge_normal() {
+    // The mesh is flat when built, and becomes a ridge after deformation.
+    let mut mesh = ridge(0.0);
+    let points = ridge_points(1.0);
+    let coords: Vec<f32> = points.iter().flat_map(|p| p.coords.iter().cloned()).collect();
+    mesh.set_deformations(&coords);
+
+    for (n, depth) in ball_on_ridge(mesh) {
+        assert_relative_eq!(n.y.abs(), 1.0, epsilon = 1.0e-4);
+        assert_relative_eq!(depth, 0.1, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn ridge_within_flat_tolerance_uses_face_normals() {
+    let slope = 0.1f32;
+    let mut mesh = ridge(slope);
+    mesh.set_flat_tolerance(0.2);
+
+    // The edge is considered flat so its normal is replaced by the normal of an adjacent face.
+    for (n, _) in ball_on_ridge(mesh) {
+        assert_relative_eq!(n.y.abs(), slope.atan().cos(), epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn ridge_with_skinny_triangle_is_not_flat() {
+    // A right-angled ridge where the right face is a sliver extending far along the edge: its
+    // vertex opposite to the edge is almost aligned with the edge, but the faces are not coplanar.
+    let points = vec![
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(-1.0, -1.0, 1.0),
+        Point3::new(0.1, -0.1, 21.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2), Point3::new(1, 2, 4)];
+    let mut mesh = TriMesh::new(points, indices, None);
+    mesh.set_flat_tolerance(0.1);
+
+    for (n, depth) in ball_on_ridge(mesh) {
+        assert_relative_eq!(n.y.abs(), 1.0, epsilon = 1.0e-4);
+        assert_relative_eq!(depth, 0.1, epsilon = 1.0e-4);
+    }
+}
@@ -6,3 +6,4 @@ cd $TRAVIS_BUILD_DIR/build/ncollide2d
 cargo build
 cd $TRAVIS_BUILD_DIR/build/ncollide3d
 cargo build
+cargo build --features io
@@ -6,3 +6,4 @@ cd $TRAVIS_BUILD_DIR/build/ncollide2d
 cargo test
 cd $TRAVIS_BUILD_DIR/build/ncollide3d
 cargo test
+cargo test --features io
@@ -1,7 +1,7 @@
 use crate::bounding_volume;
 use crate::bounding_volume::{HasBoundingVolume, AABB};
 use crate::math::Isometry;
-use crate::shape::{Capsule, Segment};
+use crate::shape::{Capsule, MinkowskiSum, Segment, SupportMap, SweptShape};
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, Cylinder};
 use na::RealField;
@@ -60,3 +60,27 @@ impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Segment<N> {
         bounding_volume::support_map_aabb(&Isometry::identity(), self)
     }
 }
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> HasBoundingVolume<N, AABB<N>> for MinkowskiSum<A, B> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::support_map_aabb(&Isometry::identity(), self)
+    }
+}
+
+impl<N: RealField, S: SupportMap<N>> HasBoundingVolume<N, AABB<N>> for SweptShape<N, S> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        bounding_volume::support_map_aabb(&Isometry::identity(), self)
+    }
+}
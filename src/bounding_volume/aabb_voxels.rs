@@ -0,0 +1,16 @@
+use crate::bounding_volume::{HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use na::RealField;
+use crate::shape::Voxels;
+
+impl<N: RealField> HasBoundingVolume<N, AABB<N>> for Voxels<N> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        self.aabb().transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        self.aabb().clone()
+    }
+}
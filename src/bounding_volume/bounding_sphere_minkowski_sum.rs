@@ -0,0 +1,18 @@
+use crate::bounding_volume::{BoundingSphere, HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::shape::{MinkowskiSum, SupportMap};
+use na::RealField;
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> HasBoundingVolume<N, BoundingSphere<N>> for MinkowskiSum<A, B> {
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        let bv: BoundingSphere<N> = self.local_bounding_volume();
+        bv.transform_by(m)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        let aabb: AABB<N> = self.local_bounding_volume();
+        BoundingSphere::new(aabb.center(), aabb.half_extents().norm())
+    }
+}
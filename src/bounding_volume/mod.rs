@@ -13,6 +13,7 @@ pub use crate::bounding_volume::bounding_sphere_utils::{
 };
 #[doc(inline)]
 pub use crate::bounding_volume::bounding_volume::{BoundingVolume, HasBoundingVolume};
+pub use crate::bounding_volume::simd_aabb::{SimdAABB, SimdBool, SIMD_WIDTH};
 
 #[doc(hidden)]
 pub mod bounding_volume;
@@ -30,12 +31,15 @@ mod aabb_heightfield;
 mod aabb_plane;
 mod aabb_polyline;
 mod aabb_shape;
+#[cfg(feature = "dim3")]
+mod aabb_signed_distance_field;
 mod aabb_support_map;
 #[cfg(feature = "dim3")]
 mod aabb_triangle;
 #[cfg(feature = "dim3")]
 mod aabb_trimesh;
 mod aabb_utils;
+mod aabb_voxels;
 
 #[doc(hidden)]
 pub mod bounding_sphere;
@@ -52,15 +56,21 @@ mod bounding_sphere_cuboid;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_cylinder;
 mod bounding_sphere_heightfield;
+mod bounding_sphere_minkowski_sum;
 mod bounding_sphere_plane;
 mod bounding_sphere_polyline;
 mod bounding_sphere_segment;
 mod bounding_sphere_shape;
 #[cfg(feature = "dim3")]
+mod bounding_sphere_signed_distance_field;
+mod bounding_sphere_swept_shape;
+#[cfg(feature = "dim3")]
 mod bounding_sphere_triangle;
 #[cfg(feature = "dim3")]
 mod bounding_sphere_trimesh;
 mod bounding_sphere_utils;
+mod bounding_sphere_voxels;
 
 pub(crate) mod circular_cone;
+mod simd_aabb;
 mod spatialized_normal_cone;
@@ -0,0 +1,483 @@
+//! Four Axis Aligned Bounding Boxes packed in a structure-of-arrays layout.
+
+use crate::bounding_volume::AABB;
+use crate::math::{Point, DIM};
+use crate::query::Ray;
+use na::{self, RealField};
+use self::lanes::{LaneMask, Lanes};
+use std::f32;
+
+/// The number of lanes of a `SimdAABB`.
+pub const SIMD_WIDTH: usize = 4;
+
+/// A mask identifying the lanes of a `SimdAABB` for which a test succeeded.
+pub type SimdBool = [bool; SIMD_WIDTH];
+
+// Relative error used to enlarge the lanes before a ray cast to cover
+// the rounding errors of the single-precision arithmetic.
+const RAY_CAST_EPS: f32 = 1.0e-5;
+
+/// Four AABBs with single-precision coordinates stored in a structure-of-arrays layout.
+///
+/// Each coordinate of the `i`-th AABB is stored in the `i`-th lane of an array of four `f32`
+/// so that all four AABBs are tested at once. The tests use SSE instructions on x86 and x86_64
+/// targets with SSE enabled, and fall back to scalar code elsewhere. The conversion from an
+/// `AABB<N>` rounds its coordinates outward so that all the tests performed here are
+/// conservative wrt. the original bounding volumes.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug)]
+#[repr(C, align(16))]
+pub struct SimdAABB {
+    mins: [[f32; SIMD_WIDTH]; DIM],
+    maxs: [[f32; SIMD_WIDTH]; DIM],
+}
+
+impl SimdAABB {
+    /// Creates a `SimdAABB` with all its lanes empty.
+    ///
+    /// An empty lane never passes any test.
+    #[inline]
+    pub fn new_invalid() -> Self {
+        SimdAABB {
+            mins: [[f32::MAX; SIMD_WIDTH]; DIM],
+            maxs: [[-f32::MAX; SIMD_WIDTH]; DIM],
+        }
+    }
+
+    /// Creates a `SimdAABB` with all its lanes set to `aabb`.
+    #[inline]
+    pub fn splat<N: RealField>(aabb: &AABB<N>) -> Self {
+        let mut res = Self::new_invalid();
+
+        for k in 0..DIM {
+            res.mins[k] = [round_down(aabb.mins()[k]); SIMD_WIDTH];
+            res.maxs[k] = [round_up(aabb.maxs()[k]); SIMD_WIDTH];
+        }
+
+        res
+    }
+
+    /// Sets the `i`-th lane of this `SimdAABB` to a conservative approximation of `aabb`.
+    #[inline]
+    pub fn replace<N: RealField>(&mut self, i: usize, aabb: &AABB<N>) {
+        for k in 0..DIM {
+            self.mins[k][i] = round_down(aabb.mins()[k]);
+            self.maxs[k][i] = round_up(aabb.maxs()[k]);
+        }
+    }
+
+    /// Empties the `i`-th lane of this `SimdAABB`.
+    #[inline]
+    pub fn clear(&mut self, i: usize) {
+        for k in 0..DIM {
+            self.mins[k][i] = f32::MAX;
+            self.maxs[k][i] = -f32::MAX;
+        }
+    }
+
+    /// Sets the `i`-th lane of this `SimdAABB` to the AABB enclosing all the lanes of `other`.
+    #[inline]
+    pub fn replace_with_merged(&mut self, i: usize, other: &SimdAABB) {
+        for k in 0..DIM {
+            let mut min = other.mins[k][0];
+            let mut max = other.maxs[k][0];
+
+            for ii in 1..SIMD_WIDTH {
+                min = min.min(other.mins[k][ii]);
+                max = max.max(other.maxs[k][ii]);
+            }
+
+            self.mins[k][i] = min;
+            self.maxs[k][i] = max;
+        }
+    }
+
+    /// Returns `true` if the `i`-th lane of this `SimdAABB` is empty.
+    #[inline]
+    pub fn is_empty(&self, i: usize) -> bool {
+        self.mins[0][i] > self.maxs[0][i]
+    }
+
+    /// The AABB stored on the `i`-th lane of this `SimdAABB`.
+    ///
+    /// The result encloses the AABB that was used to set this lane.
+    #[inline]
+    pub fn extract<N: RealField>(&self, i: usize) -> AABB<N> {
+        let mut mins = Point::origin();
+        let mut maxs = Point::origin();
+
+        for k in 0..DIM {
+            mins[k] = na::convert(self.mins[k][i] as f64);
+            maxs[k] = na::convert(self.maxs[k][i] as f64);
+        }
+
+        AABB::new(mins, maxs)
+    }
+
+    /// Tests, for each lane, if it intersects the given AABB.
+    #[inline]
+    pub fn intersects_aabb<N: RealField>(&self, aabb: &AABB<N>) -> SimdBool {
+        let mut res = LaneMask::all();
+
+        for k in 0..DIM {
+            let min = Lanes::splat(round_down(aabb.mins()[k]));
+            let max = Lanes::splat(round_up(aabb.maxs()[k]));
+            res = res
+                .and(Lanes::load(&self.mins[k]).le(max))
+                .and(Lanes::load(&self.maxs[k]).ge(min));
+        }
+
+        res.to_bools()
+    }
+
+    /// Tests, for each lane of `self`, if it intersects the `j`-th lane of `other`.
+    #[inline]
+    pub fn intersects_lane(&self, other: &SimdAABB, j: usize) -> SimdBool {
+        let mut res = LaneMask::all();
+
+        for k in 0..DIM {
+            let min = Lanes::splat(other.mins[k][j]);
+            let max = Lanes::splat(other.maxs[k][j]);
+            res = res
+                .and(Lanes::load(&self.mins[k]).le(max))
+                .and(Lanes::load(&self.maxs[k]).ge(min));
+        }
+
+        res.to_bools()
+    }
+
+    /// Tests, for each lane, if it contains the given point.
+    #[inline]
+    pub fn contains_point<N: RealField>(&self, pt: &Point<N>) -> SimdBool {
+        let mut res = LaneMask::all();
+
+        for k in 0..DIM {
+            let x = Lanes::splat(round_nearest(pt[k]));
+            res = res
+                .and(Lanes::load(&self.mins[k]).le(x))
+                .and(Lanes::load(&self.maxs[k]).ge(x));
+        }
+
+        res.to_bools()
+    }
+
+    /// Casts a ray on each lane.
+    ///
+    /// Returns a mask of the lanes hit by the ray at a time of impact smaller than `max_toi`,
+    /// together with a lower bound of the time of impact of the ray with each lane. If the
+    /// ray origin is inside of a lane, the time of impact of this lane is zero.
+    #[inline]
+    pub fn cast_ray<N: RealField>(&self, ray: &Ray<N>, max_toi: N) -> (SimdBool, [N; SIMD_WIDTH]) {
+        let eps = Lanes::splat(RAY_CAST_EPS);
+        let mut tmin = Lanes::splat(0.0);
+        let mut tmax = Lanes::splat(round_up(max_toi));
+        let mut mask = LaneMask::all();
+
+        for k in 0..DIM {
+            let orig = round_nearest(ray.origin[k]);
+            let dir = round_nearest(ray.dir[k]);
+            let orig_err = Lanes::splat(orig.abs() * RAY_CAST_EPS);
+            let mins = Lanes::load(&self.mins[k]);
+            let maxs = Lanes::load(&self.maxs[k]);
+            let min = mins.sub(mins.abs().mul(eps)).sub(orig_err);
+            let max = maxs.add(maxs.abs().mul(eps)).add(orig_err);
+            let orig = Lanes::splat(orig);
+
+            if dir == 0.0 {
+                mask = mask.and(min.le(orig)).and(max.ge(orig));
+            } else {
+                let inv_dir = Lanes::splat(1.0 / dir);
+                let t1 = min.sub(orig).mul(inv_dir);
+                let t2 = max.sub(orig).mul(inv_dir);
+
+                tmin = t1.min(t2).max(tmin);
+                tmax = t1.max(t2).min(tmax);
+            }
+        }
+
+        let mask = mask.and(tmin.le(tmax)).to_bools();
+        let tmin = tmin.sub(tmin.mul(eps)).to_array();
+        let mut res = [false; SIMD_WIDTH];
+        let mut tois = [N::zero(); SIMD_WIDTH];
+
+        for ii in 0..SIMD_WIDTH {
+            res[ii] = mask[ii] && !self.is_empty(ii);
+            tois[ii] = na::convert(tmin[ii] as f64);
+        }
+
+        (res, tois)
+    }
+}
+
+impl Default for SimdAABB {
+    #[inline]
+    fn default() -> Self {
+        Self::new_invalid()
+    }
+}
+
+#[inline]
+fn to_f64<N: RealField>(x: N) -> f64 {
+    na::try_convert::<N, f64>(x).unwrap_or(f64::NAN)
+}
+
+#[inline]
+fn round_nearest<N: RealField>(x: N) -> f32 {
+    to_f64(x) as f32
+}
+
+/// The largest `f32` smaller than or equal to `x`.
+#[inline]
+fn round_down<N: RealField>(x: N) -> f32 {
+    let x = to_f64(x);
+    let res = x as f32;
+
+    if res as f64 > x {
+        next_down(res)
+    } else {
+        res
+    }
+}
+
+/// The smallest `f32` greater than or equal to `x`.
+#[inline]
+fn round_up<N: RealField>(x: N) -> f32 {
+    let x = to_f64(x);
+    let res = x as f32;
+
+    if (res as f64) < x {
+        next_up(res)
+    } else {
+        res
+    }
+}
+
+#[inline]
+fn next_up(x: f32) -> f32 {
+    if x.is_nan() || x == f32::INFINITY {
+        x
+    } else if x == 0.0 {
+        f32::from_bits(1)
+    } else if x > 0.0 {
+        f32::from_bits(x.to_bits() + 1)
+    } else {
+        f32::from_bits(x.to_bits() - 1)
+    }
+}
+
+#[inline]
+fn next_down(x: f32) -> f32 {
+    -next_up(-x)
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
+mod lanes {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    use super::{SimdBool, SIMD_WIDTH};
+
+    // Four `f32` stored in a SSE register.
+    #[derive(Copy, Clone)]
+    pub struct Lanes(__m128);
+
+    // The result of a lane-wise comparison.
+    #[derive(Copy, Clone)]
+    pub struct LaneMask(__m128);
+
+    // The binary operations below put their accumulator as second operand since SSE minimum
+    // and maximum return their second operand if one of them is NaN.
+    impl Lanes {
+        #[inline]
+        pub fn splat(x: f32) -> Self {
+            unsafe { Lanes(_mm_set1_ps(x)) }
+        }
+
+        #[inline]
+        pub fn load(x: &[f32; SIMD_WIDTH]) -> Self {
+            unsafe { Lanes(_mm_loadu_ps(x.as_ptr())) }
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f32; SIMD_WIDTH] {
+            let mut res = [0.0; SIMD_WIDTH];
+            unsafe { _mm_storeu_ps(res.as_mut_ptr(), self.0) };
+            res
+        }
+
+        #[inline]
+        pub fn add(self, other: Self) -> Self {
+            unsafe { Lanes(_mm_add_ps(self.0, other.0)) }
+        }
+
+        #[inline]
+        pub fn sub(self, other: Self) -> Self {
+            unsafe { Lanes(_mm_sub_ps(self.0, other.0)) }
+        }
+
+        #[inline]
+        pub fn mul(self, other: Self) -> Self {
+            unsafe { Lanes(_mm_mul_ps(self.0, other.0)) }
+        }
+
+        #[inline]
+        pub fn min(self, other: Self) -> Self {
+            unsafe { Lanes(_mm_min_ps(self.0, other.0)) }
+        }
+
+        #[inline]
+        pub fn max(self, other: Self) -> Self {
+            unsafe { Lanes(_mm_max_ps(self.0, other.0)) }
+        }
+
+        #[inline]
+        pub fn abs(self) -> Self {
+            unsafe { Lanes(_mm_andnot_ps(_mm_set1_ps(-0.0), self.0)) }
+        }
+
+        #[inline]
+        pub fn le(self, other: Self) -> LaneMask {
+            unsafe { LaneMask(_mm_cmple_ps(self.0, other.0)) }
+        }
+
+        #[inline]
+        pub fn ge(self, other: Self) -> LaneMask {
+            unsafe { LaneMask(_mm_cmpge_ps(self.0, other.0)) }
+        }
+    }
+
+    impl LaneMask {
+        #[inline]
+        pub fn all() -> Self {
+            unsafe { LaneMask(_mm_castsi128_ps(_mm_set1_epi32(-1))) }
+        }
+
+        #[inline]
+        pub fn and(self, other: Self) -> Self {
+            unsafe { LaneMask(_mm_and_ps(self.0, other.0)) }
+        }
+
+        #[inline]
+        pub fn to_bools(self) -> SimdBool {
+            let bits = unsafe { _mm_movemask_ps(self.0) };
+            [bits & 1 != 0, bits & 2 != 0, bits & 4 != 0, bits & 8 != 0]
+        }
+    }
+}
+
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse")))]
+mod lanes {
+    use super::{SimdBool, SIMD_WIDTH};
+
+    // Four `f32` processed one after the other.
+    #[derive(Copy, Clone)]
+    pub struct Lanes([f32; SIMD_WIDTH]);
+
+    // The result of a lane-wise comparison.
+    #[derive(Copy, Clone)]
+    pub struct LaneMask(SimdBool);
+
+    impl Lanes {
+        #[inline]
+        fn map2(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+            let mut res = self.0;
+
+            for ii in 0..SIMD_WIDTH {
+                res[ii] = f(self.0[ii], other.0[ii])
+            }
+
+            Lanes(res)
+        }
+
+        #[inline]
+        fn cmp(self, other: Self, f: impl Fn(f32, f32) -> bool) -> LaneMask {
+            let mut res = [false; SIMD_WIDTH];
+
+            for ii in 0..SIMD_WIDTH {
+                res[ii] = f(self.0[ii], other.0[ii])
+            }
+
+            LaneMask(res)
+        }
+
+        #[inline]
+        pub fn splat(x: f32) -> Self {
+            Lanes([x; SIMD_WIDTH])
+        }
+
+        #[inline]
+        pub fn load(x: &[f32; SIMD_WIDTH]) -> Self {
+            Lanes(*x)
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f32; SIMD_WIDTH] {
+            self.0
+        }
+
+        #[inline]
+        pub fn add(self, other: Self) -> Self {
+            self.map2(other, |a, b| a + b)
+        }
+
+        #[inline]
+        pub fn sub(self, other: Self) -> Self {
+            self.map2(other, |a, b| a - b)
+        }
+
+        #[inline]
+        pub fn mul(self, other: Self) -> Self {
+            self.map2(other, |a, b| a * b)
+        }
+
+        #[inline]
+        pub fn min(self, other: Self) -> Self {
+            self.map2(other, f32::min)
+        }
+
+        #[inline]
+        pub fn max(self, other: Self) -> Self {
+            self.map2(other, f32::max)
+        }
+
+        #[inline]
+        pub fn abs(self) -> Self {
+            self.map2(self, |a, _| a.abs())
+        }
+
+        #[inline]
+        pub fn le(self, other: Self) -> LaneMask {
+            self.cmp(other, |a, b| a <= b)
+        }
+
+        #[inline]
+        pub fn ge(self, other: Self) -> LaneMask {
+            self.cmp(other, |a, b| a >= b)
+        }
+    }
+
+    impl LaneMask {
+        #[inline]
+        pub fn all() -> Self {
+            LaneMask([true; SIMD_WIDTH])
+        }
+
+        #[inline]
+        pub fn and(self, other: Self) -> Self {
+            let mut res = self.0;
+
+            for ii in 0..SIMD_WIDTH {
+                res[ii] &= other.0[ii]
+            }
+
+            LaneMask(res)
+        }
+
+        #[inline]
+        pub fn to_bools(self) -> SimdBool {
+            self.0
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Reading and writing of triangle meshes in common file formats.
+//!
+//! The Wavefront OBJ, STL (binary and ASCII) and PLY (ASCII and binary) formats are supported.
+//! Meshes are read into and written from a `procedural::TriMesh`. The `load` and
+//! `load_trimesh` functions read a file with a format deduced from its extension.
+
+pub use self::obj::{read_obj, write_obj};
+pub use self::ply::{read_ply, write_ply};
+pub use self::stl::{read_stl, write_stl_ascii, write_stl_binary};
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+
+use crate::procedural;
+use crate::shape;
+use na::RealField;
+
+mod obj;
+mod ply;
+mod stl;
+
+/// Error returned when a mesh cannot be read.
+#[derive(Debug)]
+pub enum MeshIoError {
+    /// An error of the underlying reader.
+    Io(io::Error),
+    /// The text of a file is invalid at the given line, starting at 1.
+    Parse {
+        /// The line where the error occurred.
+        line: usize,
+        /// A description of the error.
+        message: String,
+    },
+    /// The content of a binary file is invalid.
+    InvalidData(String),
+    /// The format of the file is not supported.
+    UnsupportedFormat(String),
+}
+
+impl MeshIoError {
+    pub(crate) fn parse(line: usize, message: impl Into<String>) -> Self {
+        MeshIoError::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for MeshIoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MeshIoError::Io(e) => write!(f, "I/O error: {}", e),
+            MeshIoError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            MeshIoError::InvalidData(message) => write!(f, "invalid data: {}", message),
+            MeshIoError::UnsupportedFormat(format) => write!(f, "unsupported format: {}", format),
+        }
+    }
+}
+
+impl Error for MeshIoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MeshIoError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MeshIoError {
+    fn from(e: io::Error) -> Self {
+        MeshIoError::Io(e)
+    }
+}
+
+/// Reads a mesh from a file, with a format deduced from its extension.
+///
+/// The supported extensions are `obj`, `stl` and `ply`, in any case.
+pub fn load<N: RealField>(path: impl AsRef<Path>) -> Result<procedural::TriMesh<N>, MeshIoError> {
+    let path = path.as_ref();
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let reader = BufReader::new(File::open(path)?);
+
+    match &ext[..] {
+        "obj" => read_obj(reader),
+        "stl" => read_stl(reader),
+        "ply" => read_ply(reader),
+        _ => Err(MeshIoError::UnsupportedFormat(ext)),
+    }
+}
+
+/// Reads a mesh from a file, with a format deduced from its extension, and converts it to a
+/// `shape::TriMesh` ready to be used for collision detection.
+pub fn load_trimesh<N: RealField>(path: impl AsRef<Path>) -> Result<shape::TriMesh<N>, MeshIoError> {
+    load(path).map(to_shape)
+}
+
+/// Converts a mesh read from a file to a `shape::TriMesh`.
+///
+/// The index buffer of the mesh is unified first so that its texture coordinates, if any,
+/// are kept.
+pub fn to_shape<N: RealField>(mut mesh: procedural::TriMesh<N>) -> shape::TriMesh<N> {
+    mesh.unify_index_buffer();
+    mesh.into()
+}
+
+// Conversions between the scalar type and the file scalars.
+fn to_f64<N: RealField>(n: N) -> f64 {
+    unsafe { na::convert_unchecked::<N, f64>(n) }
+}
+
+fn from_f64<N: RealField>(n: f64) -> N {
+    na::convert(n)
+}
@@ -0,0 +1,197 @@
+use std::io::{BufRead, Write};
+use std::io;
+
+use super::{from_f64, to_f64, MeshIoError};
+use crate::procedural::{IndexBuffer, TriMesh};
+use na::{Point2, Point3, RealField, Vector3};
+
+/// Reads a mesh in the Wavefront OBJ format.
+///
+/// Only the vertex positions (`v`), normals (`vn`), texture coordinates (`vt`) and faces (`f`)
+/// are read, other statements are ignored. Polygonal faces are split into triangles as a fan
+/// around their first vertex. Negative (relative) indices are supported.
+///
+/// If the faces reference normals or texture coordinates, the resulting mesh has an
+/// `IndexBuffer::Split` index buffer. Otherwise, it has an `IndexBuffer::Unified` one.
+pub fn read_obj<N: RealField, R: BufRead>(reader: R) -> Result<TriMesh<N>, MeshIoError> {
+    let mut coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut faces = Vec::new();
+    // Whether the faces reference texture coordinates and normals.
+    let mut format = None;
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let lineno = i + 1;
+        let line = line.split('#').next().unwrap();
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("v") => {
+                let v = parse_floats(lineno, words, 3, 4)?;
+                coords.push(Point3::new(v[0], v[1], v[2]))
+            }
+            Some("vn") => {
+                let v = parse_floats(lineno, words, 3, 3)?;
+                normals.push(Vector3::new(v[0], v[1], v[2]))
+            }
+            Some("vt") => {
+                let v = parse_floats(lineno, words, 1, 3)?;
+                uvs.push(Point2::new(v[0], v.get(1).cloned().unwrap_or_else(N::zero)))
+            }
+            Some("f") => {
+                let mut face = Vec::new();
+
+                for word in words {
+                    let mut parts = word.split('/');
+                    let v = parse_index(lineno, parts.next(), coords.len(), "vertex")?;
+                    let vt = match parts.next() {
+                        None | Some("") => None,
+                        s => Some(parse_index(lineno, s, uvs.len(), "texture coordinate")?),
+                    };
+                    let vn = match parts.next() {
+                        None => None,
+                        s => Some(parse_index(lineno, s, normals.len(), "normal")?),
+                    };
+
+                    if parts.next().is_some() {
+                        return Err(MeshIoError::parse(
+                            lineno,
+                            format!("invalid face vertex `{}`", word),
+                        ));
+                    }
+
+                    let curr = (vt.is_some(), vn.is_some());
+
+                    if *format.get_or_insert(curr) != curr {
+                        return Err(MeshIoError::parse(
+                            lineno,
+                            "all face vertices must reference the same attributes",
+                        ));
+                    }
+
+                    face.push(Point3::new(v, vn.unwrap_or(0), vt.unwrap_or(0)))
+                }
+
+                if face.len() < 3 {
+                    return Err(MeshIoError::parse(lineno, "a face must have at least 3 vertices"));
+                }
+
+                for k in 1..face.len() - 1 {
+                    faces.push(Point3::new(face[0], face[k], face[k + 1]))
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (has_uvs, has_normals) = format.unwrap_or((false, false));
+
+    if !has_uvs && !has_normals {
+        let faces = faces.iter().map(|f| Point3::new(f.x.x, f.y.x, f.z.x)).collect();
+        Ok(TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(faces))))
+    } else {
+        let normals = if has_normals { Some(normals) } else { None };
+        let uvs = if has_uvs { Some(uvs) } else { None };
+        Ok(TriMesh::new(coords, normals, uvs, Some(IndexBuffer::Split(faces))))
+    }
+}
+
+/// Writes a mesh in the Wavefront OBJ format.
+///
+/// The normals and texture coordinates of the mesh are written too, if it has any.
+pub fn write_obj<N: RealField, W: Write>(mesh: &TriMesh<N>, mut writer: W) -> io::Result<()> {
+    for pt in &mesh.coords {
+        writeln!(writer, "v {} {} {}", to_f64(pt.x), to_f64(pt.y), to_f64(pt.z))?;
+    }
+
+    if let Some(uvs) = &mesh.uvs {
+        for uv in uvs {
+            writeln!(writer, "vt {} {}", to_f64(uv.x), to_f64(uv.y))?;
+        }
+    }
+
+    if let Some(normals) = &mesh.normals {
+        for n in normals {
+            writeln!(writer, "vn {} {} {}", to_f64(n.x), to_f64(n.y), to_f64(n.z))?;
+        }
+    }
+
+    let (has_uvs, has_normals) = (mesh.has_uvs(), mesh.has_normals());
+    let write_vertex = |writer: &mut W, v: u32, vn: u32, vt: u32| match (has_uvs, has_normals) {
+        (false, false) => write!(writer, " {}", v + 1),
+        (true, false) => write!(writer, " {}/{}", v + 1, vt + 1),
+        (false, true) => write!(writer, " {}//{}", v + 1, vn + 1),
+        (true, true) => write!(writer, " {}/{}/{}", v + 1, vt + 1, vn + 1),
+    };
+
+    match &mesh.indices {
+        IndexBuffer::Unified(idx) => {
+            for t in idx {
+                write!(writer, "f")?;
+
+                for i in t.iter() {
+                    write_vertex(&mut writer, *i, *i, *i)?;
+                }
+
+                writeln!(writer)?;
+            }
+        }
+        IndexBuffer::Split(idx) => {
+            for t in idx {
+                write!(writer, "f")?;
+
+                for i in t.iter() {
+                    write_vertex(&mut writer, i.x, i.y, i.z)?;
+                }
+
+                writeln!(writer)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_floats<'a, N: RealField>(
+    line: usize,
+    words: impl Iterator<Item = &'a str>,
+    min: usize,
+    max: usize,
+) -> Result<Vec<N>, MeshIoError>
+{
+    let mut res = Vec::new();
+
+    for word in words {
+        let val: f64 = word
+            .parse()
+            .map_err(|_| MeshIoError::parse(line, format!("invalid number `{}`", word)))?;
+        res.push(from_f64(val));
+    }
+
+    if res.len() < min || res.len() > max {
+        let message = format!("expected {} to {} numbers, found {}", min, max, res.len());
+        return Err(MeshIoError::parse(line, message));
+    }
+
+    Ok(res)
+}
+
+// Converts a one-based or negative OBJ index to a zero-based index.
+fn parse_index(line: usize, word: Option<&str>, len: usize, what: &str) -> Result<u32, MeshIoError> {
+    let word = word.unwrap_or("");
+    let idx: i64 = word
+        .parse()
+        .map_err(|_| MeshIoError::parse(line, format!("invalid {} index `{}`", what, word)))?;
+    let res = if idx < 0 { len as i64 + idx } else { idx - 1 };
+
+    if res < 0 || res >= len as i64 {
+        return Err(MeshIoError::parse(
+            line,
+            format!("the {} index {} is out of range", what, idx),
+        ));
+    }
+
+    Ok(res as u32)
+}
@@ -0,0 +1,467 @@
+use std::io::{self, Read, Write};
+use std::str;
+
+use super::{from_f64, to_f64, MeshIoError};
+use crate::procedural::{IndexBuffer, TriMesh};
+use na::{Point2, Point3, RealField, Vector3};
+
+/// Reads a mesh in the PLY format, either ASCII or binary.
+///
+/// The vertex positions are read from the `x`, `y` and `z` properties of the `vertex`
+/// elements, the normals from `nx`, `ny` and `nz`, and the texture coordinates from `u` and
+/// `v` (or `s` and `t`). The faces are read from the `vertex_indices` (or `vertex_index`)
+/// property of the `face` elements and split into triangles as a fan around their first
+/// vertex. Other elements and properties are ignored.
+///
+/// The resulting mesh has an `IndexBuffer::Unified` index buffer.
+pub fn read_ply<N: RealField, R: Read>(mut reader: R) -> Result<TriMesh<N>, MeshIoError> {
+    let mut bytes = Vec::new();
+    let _ = reader.read_to_end(&mut bytes)?;
+
+    let header_end = find_header_end(&bytes).ok_or_else(|| {
+        MeshIoError::InvalidData("the PLY header is not terminated by `end_header`".to_string())
+    })?;
+    let header = str::from_utf8(&bytes[..header_end])
+        .map_err(|_| MeshIoError::InvalidData("the PLY header is not valid text".to_string()))?;
+    let (format, elements) = parse_header(header)?;
+    let body = &bytes[header_end..];
+    let mut source = match format {
+        Format::Ascii => {
+            let text = str::from_utf8(body).map_err(|_| {
+                MeshIoError::InvalidData("the body of an ASCII PLY file is not valid text".to_string())
+            })?;
+            Source::Ascii {
+                lines: text.lines(),
+                lineno: header.lines().count(),
+                words: Vec::new(),
+            }
+        }
+        Format::Binary(big_endian) => Source::Binary {
+            bytes: body,
+            big_endian,
+        },
+    };
+
+    let mut mesh = MeshData::default();
+
+    for element in &elements {
+        for _ in 0..element.count {
+            source.begin_element()?;
+            let mut vertex = [0.0; 8];
+
+            for prop in &element.properties {
+                match prop {
+                    Property::Scalar(ty, name) => {
+                        let val = source.read(*ty)?;
+
+                        if element.name == "vertex" {
+                            if let Some(k) = VERTEX_PROPERTIES.iter().position(|p| p.contains(&&name[..])) {
+                                vertex[k] = val
+                            }
+                        }
+                    }
+                    Property::List(count_ty, item_ty, name) => {
+                        // The count is not trusted for preallocation: reading stops at the end of the input.
+                        let count = source.read(*count_ty)? as usize;
+                        let mut items = Vec::new();
+
+                        for _ in 0..count {
+                            items.push(source.read(*item_ty)?);
+                        }
+
+                        if element.name == "face" && (name == "vertex_indices" || name == "vertex_index") {
+                            mesh.push_face(&items, &source)?;
+                        }
+                    }
+                }
+            }
+
+            source.end_element()?;
+
+            if element.name == "vertex" {
+                mesh.vertices.push(vertex)
+            }
+        }
+    }
+
+    let has = |names: &[&str]| {
+        elements.iter().any(|e| {
+            e.name == "vertex"
+                && names.iter().all(|n| {
+                    e.properties.iter().any(|p| match p {
+                        Property::Scalar(_, name) => VERTEX_PROPERTIES
+                            .iter()
+                            .any(|alts| alts[0] == *n && alts.contains(&&name[..])),
+                        _ => false,
+                    })
+                })
+        })
+    };
+
+    mesh.check_indices()?;
+
+    let coords = mesh
+        .vertices
+        .iter()
+        .map(|v| Point3::new(from_f64(v[0]), from_f64(v[1]), from_f64(v[2])))
+        .collect();
+    let normals = if has(&["nx", "ny", "nz"]) {
+        Some(
+            mesh.vertices
+                .iter()
+                .map(|v| Vector3::new(from_f64(v[3]), from_f64(v[4]), from_f64(v[5])))
+                .collect(),
+        )
+    } else {
+        None
+    };
+    let uvs = if has(&["u", "v"]) {
+        Some(
+            mesh.vertices
+                .iter()
+                .map(|v| Point2::new(from_f64(v[6]), from_f64(v[7])))
+                .collect(),
+        )
+    } else {
+        None
+    };
+
+    Ok(TriMesh::new(coords, normals, uvs, Some(IndexBuffer::Unified(mesh.faces))))
+}
+
+/// Writes a mesh in the ASCII PLY format.
+///
+/// The normals and texture coordinates of the mesh are written too, if it has any. The
+/// index buffer of the mesh is unified first if it is split.
+pub fn write_ply<N: RealField, W: Write>(mesh: &TriMesh<N>, mut writer: W) -> io::Result<()> {
+    let mut unified;
+    let mesh = match mesh.indices {
+        IndexBuffer::Unified(_) => mesh,
+        IndexBuffer::Split(_) => {
+            unified = mesh.clone();
+            unified.unify_index_buffer();
+            &unified
+        }
+    };
+
+    writeln!(writer, "ply")?;
+    writeln!(writer, "format ascii 1.0")?;
+    writeln!(writer, "element vertex {}", mesh.coords.len())?;
+    writeln!(writer, "property double x\nproperty double y\nproperty double z")?;
+
+    if mesh.has_normals() {
+        writeln!(writer, "property double nx\nproperty double ny\nproperty double nz")?;
+    }
+
+    if mesh.has_uvs() {
+        writeln!(writer, "property double u\nproperty double v")?;
+    }
+
+    writeln!(writer, "element face {}", mesh.num_triangles())?;
+    writeln!(writer, "property list uchar uint vertex_indices")?;
+    writeln!(writer, "end_header")?;
+
+    for (i, pt) in mesh.coords.iter().enumerate() {
+        write!(writer, "{} {} {}", to_f64(pt.x), to_f64(pt.y), to_f64(pt.z))?;
+
+        if let Some(normals) = &mesh.normals {
+            let n = normals[i];
+            write!(writer, " {} {} {}", to_f64(n.x), to_f64(n.y), to_f64(n.z))?;
+        }
+
+        if let Some(uvs) = &mesh.uvs {
+            write!(writer, " {} {}", to_f64(uvs[i].x), to_f64(uvs[i].y))?;
+        }
+
+        writeln!(writer)?;
+    }
+
+    for t in mesh.flat_indices().chunks(3) {
+        writeln!(writer, "3 {} {} {}", t[0], t[1], t[2])?;
+    }
+
+    Ok(())
+}
+
+// The accepted names of the vertex properties, in the order of `MeshData::vertices`.
+const VERTEX_PROPERTIES: [&[&str]; 8] = [
+    &["x"],
+    &["y"],
+    &["z"],
+    &["nx"],
+    &["ny"],
+    &["nz"],
+    &["u", "s", "texture_u"],
+    &["v", "t", "texture_v"],
+];
+
+#[derive(Default)]
+struct MeshData {
+    vertices: Vec<[f64; 8]>,
+    faces: Vec<Point3<u32>>,
+    // The first invalid index, or else the largest index, and where it was read.
+    worst_index: Option<(f64, Option<usize>)>,
+}
+
+impl MeshData {
+    fn push_face(&mut self, items: &[f64], source: &Source) -> Result<(), MeshIoError> {
+        if items.len() < 3 {
+            return Err(source.error("a face must have at least 3 vertices"));
+        }
+
+        // The vertices may be defined after the faces, so the indices are checked at the end.
+        for i in items {
+            let is_invalid = |i: f64| i < 0.0 || i.fract() != 0.0 || i > u32::max_value() as f64;
+
+            match self.worst_index {
+                Some((worst, _)) if is_invalid(worst) || worst >= *i => {}
+                _ => self.worst_index = Some((*i, source.line())),
+            }
+        }
+
+        for k in 1..items.len() - 1 {
+            self.faces
+                .push(Point3::new(items[0] as u32, items[k] as u32, items[k + 1] as u32))
+        }
+
+        Ok(())
+    }
+
+    fn check_indices(&self) -> Result<(), MeshIoError> {
+        match self.worst_index {
+            Some((i, line))
+                if i < 0.0 || i.fract() != 0.0 || i >= self.vertices.len() as f64 =>
+            {
+                let message = format!("the vertex index {} is out of range", i);
+
+                match line {
+                    Some(line) => Err(MeshIoError::parse(line, message)),
+                    None => Err(MeshIoError::InvalidData(message)),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+enum Format {
+    Ascii,
+    // Whether the numbers are big endian.
+    Binary(bool),
+}
+
+#[derive(Copy, Clone)]
+enum ScalarType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl ScalarType {
+    fn parse(line: usize, name: &str) -> Result<Self, MeshIoError> {
+        match name {
+            "char" | "int8" => Ok(ScalarType::I8),
+            "uchar" | "uint8" => Ok(ScalarType::U8),
+            "short" | "int16" => Ok(ScalarType::I16),
+            "ushort" | "uint16" => Ok(ScalarType::U16),
+            "int" | "int32" => Ok(ScalarType::I32),
+            "uint" | "uint32" => Ok(ScalarType::U32),
+            "float" | "float32" => Ok(ScalarType::F32),
+            "double" | "float64" => Ok(ScalarType::F64),
+            _ => Err(MeshIoError::parse(line, format!("unknown property type `{}`", name))),
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            ScalarType::I8 | ScalarType::U8 => 1,
+            ScalarType::I16 | ScalarType::U16 => 2,
+            ScalarType::I32 | ScalarType::U32 | ScalarType::F32 => 4,
+            ScalarType::F64 => 8,
+        }
+    }
+}
+
+enum Property {
+    Scalar(ScalarType, String),
+    List(ScalarType, ScalarType, String),
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+// Position of the first byte after the `end_header` line.
+fn find_header_end(bytes: &[u8]) -> Option<usize> {
+    let marker = b"end_header";
+    let start = bytes.windows(marker.len()).position(|w| w == marker)?;
+    let newline = bytes[start..].iter().position(|b| *b == b'\n')?;
+    Some(start + newline + 1)
+}
+
+fn parse_header(header: &str) -> Result<(Format, Vec<Element>), MeshIoError> {
+    let mut lines = header.lines().enumerate().map(|(i, l)| (i + 1, l));
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+
+    if lines.next().map(|l| l.1.trim()) != Some("ply") {
+        return Err(MeshIoError::parse(1, "expected `ply`"));
+    }
+
+    for (lineno, line) in lines {
+        let words: Vec<_> = line.split_whitespace().collect();
+
+        match words.first().cloned() {
+            Some("format") => {
+                format = match words.get(1).cloned() {
+                    Some("ascii") => Some(Format::Ascii),
+                    Some("binary_little_endian") => Some(Format::Binary(false)),
+                    Some("binary_big_endian") => Some(Format::Binary(true)),
+                    _ => return Err(MeshIoError::parse(lineno, "unknown PLY format")),
+                }
+            }
+            Some("element") => {
+                if words.len() != 3 {
+                    return Err(MeshIoError::parse(lineno, "expected `element <name> <count>`"));
+                }
+
+                let count = words[2]
+                    .parse()
+                    .map_err(|_| MeshIoError::parse(lineno, format!("invalid count `{}`", words[2])))?;
+                elements.push(Element {
+                    name: words[1].to_string(),
+                    count,
+                    properties: Vec::new(),
+                })
+            }
+            Some("property") => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| MeshIoError::parse(lineno, "property declared before any element"))?;
+                let property = match &words[1..] {
+                    ["list", count_ty, item_ty, name] => Property::List(
+                        ScalarType::parse(lineno, count_ty)?,
+                        ScalarType::parse(lineno, item_ty)?,
+                        name.to_string(),
+                    ),
+                    [ty, name] => Property::Scalar(ScalarType::parse(lineno, ty)?, name.to_string()),
+                    _ => return Err(MeshIoError::parse(lineno, "invalid property declaration")),
+                };
+                element.properties.push(property)
+            }
+            Some("comment") | Some("obj_info") | Some("end_header") | None => {}
+            Some(word) => {
+                return Err(MeshIoError::parse(lineno, format!("unexpected `{}`", word)));
+            }
+        }
+    }
+
+    let format = format.ok_or_else(|| MeshIoError::InvalidData("missing PLY format".to_string()))?;
+    Ok((format, elements))
+}
+
+// The body of a PLY file, read one element after the other.
+enum Source<'a> {
+    Ascii {
+        lines: str::Lines<'a>,
+        lineno: usize,
+        // The remaining words of the current line, reversed.
+        words: Vec<&'a str>,
+    },
+    Binary {
+        bytes: &'a [u8],
+        big_endian: bool,
+    },
+}
+
+impl<'a> Source<'a> {
+    fn line(&self) -> Option<usize> {
+        match self {
+            Source::Ascii { lineno, .. } => Some(*lineno),
+            Source::Binary { .. } => None,
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> MeshIoError {
+        match self.line() {
+            Some(line) => MeshIoError::parse(line, message),
+            None => MeshIoError::InvalidData(message.into()),
+        }
+    }
+
+    fn begin_element(&mut self) -> Result<(), MeshIoError> {
+        if let Source::Ascii { lines, lineno, words } = self {
+            loop {
+                *lineno += 1;
+
+                match lines.next() {
+                    Some(line) if line.trim().is_empty() => continue,
+                    Some(line) => {
+                        *words = line.split_whitespace().rev().collect();
+                        break;
+                    }
+                    None => return Err(MeshIoError::parse(*lineno, "unexpected end of file")),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn end_element(&mut self) -> Result<(), MeshIoError> {
+        match self {
+            Source::Ascii { words, lineno, .. } if !words.is_empty() => Err(MeshIoError::parse(
+                *lineno,
+                "too many values for the element",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn read(&mut self, ty: ScalarType) -> Result<f64, MeshIoError> {
+        match self {
+            Source::Ascii { words, lineno, .. } => {
+                let word = words
+                    .pop()
+                    .ok_or_else(|| MeshIoError::parse(*lineno, "not enough values for the element"))?;
+                word.parse()
+                    .map_err(|_| MeshIoError::parse(*lineno, format!("invalid number `{}`", word)))
+            }
+            Source::Binary { bytes, big_endian } => {
+                let size = ty.size();
+
+                if bytes.len() < size {
+                    return Err(MeshIoError::InvalidData("unexpected end of file".to_string()));
+                }
+
+                let mut buf = [0; 8];
+                buf[..size].copy_from_slice(&bytes[..size]);
+                *bytes = &bytes[size..];
+
+                if *big_endian {
+                    buf[..size].reverse();
+                }
+
+                let val = match ty {
+                    ScalarType::I8 => buf[0] as i8 as f64,
+                    ScalarType::U8 => buf[0] as f64,
+                    ScalarType::I16 => i16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    ScalarType::U16 => u16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    ScalarType::I32 => i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::U32 => u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::F32 => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::F64 => f64::from_le_bytes(buf),
+                };
+
+                Ok(val)
+            }
+        }
+    }
+}
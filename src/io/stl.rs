@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::str;
+
+use super::{from_f64, to_f64, MeshIoError};
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::utils::DeterministicState;
+use na::{Point3, RealField, Vector3};
+
+/// Reads a mesh in the binary or ASCII STL format.
+///
+/// The format is detected automatically. Vertices with identical coordinates are merged so
+/// that the resulting mesh is connected. The normal of each facet is shared by its three
+/// vertices through an `IndexBuffer::Split` index buffer.
+pub fn read_stl<N: RealField, R: Read>(mut reader: R) -> Result<TriMesh<N>, MeshIoError> {
+    let mut bytes = Vec::new();
+    let _ = reader.read_to_end(&mut bytes)?;
+
+    let is_binary = bytes.len() >= 84 && {
+        let n = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]) as usize;
+        bytes.len() == 84 + n * 50
+    };
+
+    let mut builder = StlBuilder::new();
+
+    if is_binary {
+        for facet in bytes[84..].chunks(50) {
+            let mut vals = [0.0; 12];
+
+            for (k, val) in vals.iter_mut().enumerate() {
+                let b = &facet[k * 4..k * 4 + 4];
+                *val = f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64;
+            }
+
+            builder.push_facet(&vals);
+        }
+    } else {
+        let text = str::from_utf8(&bytes)
+            .ok()
+            .filter(|t| t.trim_start().starts_with("solid"))
+            .ok_or_else(|| {
+                MeshIoError::InvalidData(
+                    "the file is neither an ASCII STL file nor a binary STL file of the expected size"
+                        .to_string(),
+                )
+            })?;
+        read_ascii(text, &mut builder)?;
+    }
+
+    Ok(builder.build())
+}
+
+fn read_ascii(text: &str, builder: &mut StlBuilder) -> Result<(), MeshIoError> {
+    // The facet normal followed by its vertices.
+    let mut facet = Vec::new();
+    let mut in_facet = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let lineno = i + 1;
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("facet") => {
+                if in_facet {
+                    return Err(MeshIoError::parse(lineno, "unexpected `facet`, expected `endfacet`"));
+                }
+
+                if words.next() != Some("normal") {
+                    return Err(MeshIoError::parse(lineno, "expected `facet normal`"));
+                }
+
+                facet.clear();
+                facet.extend_from_slice(&parse_vector(lineno, words)?);
+                in_facet = true;
+            }
+            Some("vertex") => {
+                if !in_facet {
+                    return Err(MeshIoError::parse(lineno, "`vertex` outside of a facet"));
+                }
+
+                facet.extend_from_slice(&parse_vector(lineno, words)?);
+            }
+            Some("endfacet") => {
+                if !in_facet || facet.len() != 12 {
+                    return Err(MeshIoError::parse(lineno, "a facet must have exactly 3 vertices"));
+                }
+
+                builder.push_facet(&facet);
+                in_facet = false;
+            }
+            Some("solid") | Some("endsolid") | Some("outer") | Some("endloop") | None => {}
+            Some(word) => {
+                return Err(MeshIoError::parse(lineno, format!("unexpected `{}`", word)));
+            }
+        }
+    }
+
+    if in_facet {
+        return Err(MeshIoError::parse(text.lines().count(), "unterminated facet"));
+    }
+
+    Ok(())
+}
+
+fn parse_vector<'a>(line: usize, words: impl Iterator<Item = &'a str>) -> Result<[f64; 3], MeshIoError> {
+    let mut res = [0.0; 3];
+    let mut n = 0;
+
+    for word in words {
+        if n == 3 {
+            return Err(MeshIoError::parse(line, "expected 3 numbers, found more"));
+        }
+
+        res[n] = word
+            .parse()
+            .map_err(|_| MeshIoError::parse(line, format!("invalid number `{}`", word)))?;
+        n += 1;
+    }
+
+    if n != 3 {
+        return Err(MeshIoError::parse(line, format!("expected 3 numbers, found {}", n)));
+    }
+
+    Ok(res)
+}
+
+struct StlBuilder {
+    coords: Vec<[f64; 3]>,
+    normals: Vec<[f64; 3]>,
+    faces: Vec<Point3<Point3<u32>>>,
+    ids: HashMap<[u64; 3], u32, DeterministicState>,
+}
+
+impl StlBuilder {
+    fn new() -> Self {
+        StlBuilder {
+            coords: Vec::new(),
+            normals: Vec::new(),
+            faces: Vec::new(),
+            ids: HashMap::with_hasher(DeterministicState::new()),
+        }
+    }
+
+    // Adds a facet given by its normal followed by its three vertices.
+    fn push_facet(&mut self, vals: &[f64]) {
+        let normal = self.normals.len() as u32;
+        self.normals.push([vals[0], vals[1], vals[2]]);
+
+        let mut face = Point3::new(Point3::origin(), Point3::origin(), Point3::origin());
+
+        for k in 0..3 {
+            let pt = [vals[3 + k * 3], vals[4 + k * 3], vals[5 + k * 3]];
+            // Normalize the negative zero so that it is merged with the positive one.
+            let key = [(pt[0] + 0.0).to_bits(), (pt[1] + 0.0).to_bits(), (pt[2] + 0.0).to_bits()];
+            let coords = &mut self.coords;
+            let id = *self.ids.entry(key).or_insert_with(|| {
+                coords.push(pt);
+                coords.len() as u32 - 1
+            });
+
+            face[k] = Point3::new(id, normal, 0);
+        }
+
+        self.faces.push(face)
+    }
+
+    fn build<N: RealField>(self) -> TriMesh<N> {
+        let coords = self
+            .coords
+            .iter()
+            .map(|c| Point3::new(from_f64(c[0]), from_f64(c[1]), from_f64(c[2])))
+            .collect();
+        let normals = self
+            .normals
+            .iter()
+            .map(|n| Vector3::new(from_f64(n[0]), from_f64(n[1]), from_f64(n[2])))
+            .collect();
+
+        TriMesh::new(coords, Some(normals), None, Some(IndexBuffer::Split(self.faces)))
+    }
+}
+
+// The vertices of each triangle of `mesh` followed by its unit normal.
+fn triangles<N: RealField>(mesh: &TriMesh<N>) -> Vec<[Vector3<f64>; 4]> {
+    mesh.flat_indices()
+        .chunks(3)
+        .map(|t| {
+            let a = mesh.coords[t[0] as usize].coords.map(to_f64);
+            let b = mesh.coords[t[1] as usize].coords.map(to_f64);
+            let c = mesh.coords[t[2] as usize].coords.map(to_f64);
+            let n = (b - a).cross(&(c - a)).try_normalize(0.0).unwrap_or_else(Vector3::zeros);
+            [a, b, c, n]
+        })
+        .collect()
+}
+
+/// Writes a mesh in the binary STL format.
+///
+/// The normal of each facet is computed from its vertices, and coordinates are written as
+/// single-precision floats.
+pub fn write_stl_binary<N: RealField, W: Write>(mesh: &TriMesh<N>, mut writer: W) -> io::Result<()> {
+    writer.write_all(&[0; 80])?;
+    writer.write_all(&(mesh.num_triangles() as u32).to_le_bytes())?;
+
+    for [a, b, c, n] in triangles(mesh) {
+        for v in [n, a, b, c].iter() {
+            for k in 0..3 {
+                writer.write_all(&(v[k] as f32).to_le_bytes())?;
+            }
+        }
+
+        writer.write_all(&[0; 2])?;
+    }
+
+    Ok(())
+}
+
+/// Writes a mesh in the ASCII STL format.
+///
+/// The normal of each facet is computed from its vertices.
+pub fn write_stl_ascii<N: RealField, W: Write>(mesh: &TriMesh<N>, mut writer: W) -> io::Result<()> {
+    writeln!(writer, "solid mesh")?;
+
+    for [a, b, c, n] in triangles(mesh) {
+        writeln!(writer, "  facet normal {} {} {}", n.x, n.y, n.z)?;
+        writeln!(writer, "    outer loop")?;
+
+        for v in [a, b, c].iter() {
+            writeln!(writer, "      vertex {} {} {}", v.x, v.y, v.z)?;
+        }
+
+        writeln!(writer, "    endloop")?;
+        writeln!(writer, "  endfacet")?;
+    }
+
+    writeln!(writer, "endsolid mesh")
+}
@@ -68,6 +68,9 @@ extern crate smallvec;
 pub use crate::pipeline::{broad_phase, events, narrow_phase, world};
 
 pub mod bounding_volume;
+#[cfg(all(feature = "dim3", feature = "io"))]
+pub mod io;
+pub mod mass_properties;
 pub mod partitioning;
 mod pipeline;
 pub mod procedural;
@@ -105,6 +108,9 @@ pub mod math {
     /// The matrix type.
     pub type Matrix<N> = Matrix3<N>;
 
+    /// The angular inertia type.
+    pub type AngularInertia<N> = Matrix3<N>;
+
     /// The vector type with dimension `SpatialDim × 1`.
     pub type SpatialVector<N> = Vector6<N>;
 
@@ -141,6 +147,9 @@ pub mod math {
     /// The matrix type.
     pub type Matrix<N> = Matrix2<N>;
 
+    /// The angular inertia type.
+    pub type AngularInertia<N> = N;
+
     /// The orientation type.
     pub type Orientation<N> = Vector1<N>;
 
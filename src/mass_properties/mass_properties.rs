@@ -0,0 +1,244 @@
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+use crate::math::{AngularInertia, Isometry, Point, Vector};
+use na::{self, RealField};
+
+/// The mass properties of a shape with an uniform density.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MassProperties<N: RealField> {
+    /// The volume of the shape, or its area in 2D.
+    pub volume: N,
+    /// The mass of the shape.
+    pub mass: N,
+    /// The center of mass of the shape, in its local frame.
+    pub local_com: Point<N>,
+    /// The angular inertia of the shape with respect to its center of mass, expressed with
+    /// the axes of its local frame. This is a scalar in 2D and a 3x3 tensor in 3D.
+    pub angular_inertia: AngularInertia<N>,
+}
+
+/// Trait implemented by shapes with a volume, or an area in 2D.
+pub trait Volumetric<N: RealField> {
+    /// The mass properties of this shape with the given uniform density.
+    fn mass_properties(&self, density: N) -> MassProperties<N>;
+
+    /// The volume of this shape, or its area in 2D.
+    #[inline]
+    fn volume(&self) -> N {
+        self.mass_properties(N::one()).volume
+    }
+
+    /// The center of mass of this shape, in its local frame.
+    #[inline]
+    fn center_of_mass(&self) -> Point<N> {
+        self.mass_properties(N::one()).local_com
+    }
+}
+
+impl<N: RealField> MassProperties<N> {
+    /// The mass properties of an object without volume nor mass.
+    pub fn zero() -> Self {
+        MassProperties {
+            volume: N::zero(),
+            mass: N::zero(),
+            local_com: Point::origin(),
+            angular_inertia: na::zero(),
+        }
+    }
+
+    /// Creates mass properties from a volume, a density, a center of mass, and an angular
+    /// inertia per unit mass.
+    pub fn from_unit_inertia(
+        volume: N,
+        density: N,
+        local_com: Point<N>,
+        unit_angular_inertia: AngularInertia<N>,
+    ) -> Self
+    {
+        let mass = volume * density;
+
+        MassProperties {
+            volume,
+            mass,
+            local_com,
+            angular_inertia: unit_angular_inertia * mass,
+        }
+    }
+
+    /// The mass properties of the same object placed at the position `m`.
+    ///
+    /// The center of mass is transformed by `m` and the angular inertia is rotated
+    /// accordingly.
+    pub fn transform_by(&self, m: &Isometry<N>) -> Self {
+        MassProperties {
+            volume: self.volume,
+            mass: self.mass,
+            local_com: m * self.local_com,
+            angular_inertia: rotate_inertia(&self.angular_inertia, m),
+        }
+    }
+
+    /// The angular inertia of this object with respect to the point `pt`.
+    ///
+    /// This is computed with the parallel axis theorem.
+    pub fn angular_inertia_at(&self, pt: &Point<N>) -> AngularInertia<N> {
+        self.angular_inertia + shift_inertia(&(self.local_com - pt), self.mass)
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn rotate_inertia<N: RealField>(inertia: &AngularInertia<N>, m: &Isometry<N>) -> AngularInertia<N> {
+    let rot = m.rotation.to_rotation_matrix();
+    rot * inertia * rot.transpose()
+}
+
+#[cfg(feature = "dim2")]
+fn rotate_inertia<N: RealField>(inertia: &AngularInertia<N>, _: &Isometry<N>) -> AngularInertia<N> {
+    *inertia
+}
+
+// The angular inertia of a point mass at `shift` with respect to the origin.
+#[cfg(feature = "dim3")]
+fn shift_inertia<N: RealField>(shift: &Vector<N>, mass: N) -> AngularInertia<N> {
+    (AngularInertia::identity() * shift.norm_squared() - shift * shift.transpose()) * mass
+}
+
+#[cfg(feature = "dim2")]
+fn shift_inertia<N: RealField>(shift: &Vector<N>, mass: N) -> AngularInertia<N> {
+    shift.norm_squared() * mass
+}
+
+impl<N: RealField> Add for MassProperties<N> {
+    type Output = Self;
+
+    /// The mass properties of the union of two objects.
+    ///
+    /// The centers of mass are averaged with the masses as weights, or with the volumes if
+    /// both masses are zero.
+    fn add(self, other: Self) -> Self {
+        let volume = self.volume + other.volume;
+        let mass = self.mass + other.mass;
+        let (w1, w2) = if mass != N::zero() {
+            (self.mass / mass, other.mass / mass)
+        } else if volume != N::zero() {
+            (self.volume / volume, other.volume / volume)
+        } else {
+            (na::convert(0.5), na::convert(0.5))
+        };
+        let local_com = Point::from(self.local_com.coords * w1 + other.local_com.coords * w2);
+
+        MassProperties {
+            volume,
+            mass,
+            local_com,
+            angular_inertia: self.angular_inertia_at(&local_com)
+                + other.angular_inertia_at(&local_com),
+        }
+    }
+}
+
+impl<N: RealField> AddAssign for MassProperties<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other
+    }
+}
+
+impl<N: RealField> Sum for MassProperties<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut res = MassProperties::zero();
+
+        for props in iter {
+            res += props
+        }
+
+        res
+    }
+}
+
+// The mass properties of the polyhedron bounded by the given triangles, oriented
+// counter-clockwise when seen from the outside, computed with the divergence theorem.
+#[cfg(feature = "dim3")]
+pub(crate) fn polyhedron_mass_properties<N: RealField>(
+    triangles: impl Iterator<Item = [Point<N>; 3]>,
+    density: N,
+) -> MassProperties<N>
+{
+    use crate::math::Matrix;
+
+    let mut volume = N::zero();
+    let mut first_moment = Vector::zeros();
+    // The integral of `x x^T` over the volume.
+    let mut covariance = Matrix::zeros();
+    let mut reference = None;
+
+    for [a, b, c] in triangles {
+        // Integrate relative to a point of the surface for better accuracy.
+        let origin = *reference.get_or_insert(a);
+        let (a, b, c) = (a - origin, b - origin, c - origin);
+        let det = a.dot(&b.cross(&c));
+        let s = a + b + c;
+
+        volume += det;
+        first_moment += s * det;
+        covariance += (a * a.transpose() + b * b.transpose() + c * c.transpose() + s * s.transpose()) * det;
+    }
+
+    let reference = match reference {
+        Some(pt) => pt,
+        None => return MassProperties::zero(),
+    };
+
+    volume /= na::convert(6.0);
+    first_moment /= na::convert::<_, N>(24.0);
+    covariance /= na::convert::<_, N>(120.0);
+
+    if volume == N::zero() {
+        return MassProperties::zero();
+    }
+
+    let com = first_moment / volume;
+    let covariance = covariance - com * com.transpose() * volume;
+    let unit_inertia = (Matrix::identity() * covariance.trace() - covariance) / volume;
+
+    // Meshes oriented inside-out yield a negative volume and covariance.
+    MassProperties::from_unit_inertia(volume.abs(), density, reference + com, unit_inertia)
+}
+
+// The mass properties of a polygon with counter-clockwise vertices.
+#[cfg(feature = "dim2")]
+pub(crate) fn polygon_mass_properties<N: RealField>(points: &[Point<N>], density: N) -> MassProperties<N> {
+    if points.len() < 3 {
+        return MassProperties::zero();
+    }
+
+    let origin = points[0];
+    let mut area = N::zero();
+    let mut first_moment = Vector::zeros();
+    // The integral of `|x|^2` over the area.
+    let mut second_moment = N::zero();
+
+    for i in 1..points.len() - 1 {
+        let a = points[i] - origin;
+        let b = points[i + 1] - origin;
+        let det = a.perp(&b);
+
+        area += det;
+        first_moment += (a + b) * det;
+        second_moment += (a.dot(&a) + a.dot(&b) + b.dot(&b)) * det;
+    }
+
+    if area == N::zero() {
+        return MassProperties::zero();
+    }
+
+    area /= na::convert(2.0);
+    first_moment /= na::convert::<_, N>(6.0);
+    second_moment /= na::convert(12.0);
+
+    let com = first_moment / area;
+    let unit_inertia = (second_moment - com.norm_squared() * area) / area;
+
+    MassProperties::from_unit_inertia(area.abs(), density, origin + com, unit_inertia)
+}
@@ -0,0 +1,26 @@
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::math::{AngularInertia, Point};
+use crate::shape::Ball;
+use na::{self, RealField};
+
+#[cfg(feature = "dim2")]
+impl<N: RealField> Volumetric<N> for Ball<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let r2 = self.radius() * self.radius();
+        let area = N::pi() * r2;
+        let unit_inertia: AngularInertia<N> = r2 * na::convert(0.5);
+
+        MassProperties::from_unit_inertia(area, density, Point::origin(), unit_inertia)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Volumetric<N> for Ball<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let r = self.radius();
+        let volume = N::pi() * r * r * r * na::convert(4.0 / 3.0);
+        let unit_inertia = AngularInertia::identity() * (r * r * na::convert(2.0 / 5.0));
+
+        MassProperties::from_unit_inertia(volume, density, Point::origin(), unit_inertia)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::math::{AngularInertia, Point};
+use crate::shape::Capsule;
+use na::{self, RealField};
+
+// A capsule is the union of a cylinder (a rectangle in 2D) and of two half-balls whose
+// centers of mass are at the distance `d` from the ends of the segment.
+#[cfg(feature = "dim2")]
+impl<N: RealField> Volumetric<N> for Capsule<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let (h, r) = (self.half_height(), self.radius());
+        let rect_area = h * r * na::convert(4.0);
+        let disk_area = N::pi() * r * r;
+        let d = r * na::convert(4.0) / (N::pi() * na::convert(3.0));
+        let area = rect_area + disk_area;
+        let inertia: AngularInertia<N> = rect_area * (r * r + h * h) / na::convert(3.0)
+            + disk_area * (r * r * na::convert(0.5) + h * h + h * d * na::convert(2.0));
+
+        MassProperties::from_unit_inertia(area, density, Point::origin(), inertia / area)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Volumetric<N> for Capsule<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let (h, r) = (self.half_height(), self.radius());
+        let r2 = r * r;
+        let cyl_volume = N::pi() * r2 * h * na::convert(2.0);
+        let ball_volume = N::pi() * r2 * r * na::convert(4.0 / 3.0);
+        let d = r * na::convert(3.0 / 8.0);
+        let volume = cyl_volume + ball_volume;
+        let axial = cyl_volume * r2 * na::convert(0.5) + ball_volume * r2 * na::convert(2.0 / 5.0);
+        let transverse = cyl_volume * (r2 * na::convert(3.0) + h * h * na::convert(4.0)) / na::convert(12.0)
+            + ball_volume * (r2 * na::convert(2.0 / 5.0) + h * h + h * d * na::convert(2.0));
+        let unit_inertia =
+            AngularInertia::from_diagonal(&na::Vector3::new(transverse, axial, transverse)) / volume;
+
+        MassProperties::from_unit_inertia(volume, density, Point::origin(), unit_inertia)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::mass_properties::{shape_mass_properties, MassProperties, Volumetric};
+use crate::shape::Compound;
+use na::RealField;
+
+/// The mass properties of a compound shape aggregate those of its parts.
+///
+/// Parts without mass properties, e.g., planes or polylines, are ignored. Overlapping parts
+/// are counted as many times as they overlap.
+impl<N: RealField> Volumetric<N> for Compound<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        self.shapes()
+            .iter()
+            .filter_map(|(m, part)| {
+                shape_mass_properties(&**part, density).map(|props| props.transform_by(m))
+            })
+            .sum()
+    }
+}
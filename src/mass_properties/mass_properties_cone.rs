@@ -0,0 +1,19 @@
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::math::{AngularInertia, Point};
+use crate::shape::Cone;
+use na::{self, RealField};
+
+impl<N: RealField> Volumetric<N> for Cone<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let (h, r) = (self.half_height(), self.radius());
+        let height = h * na::convert(2.0);
+        let volume = N::pi() * r * r * height / na::convert(3.0);
+        // The apex is at `y = h` and the center of mass at a quarter of the height from the base.
+        let com = Point::new(N::zero(), -h * na::convert(0.5), N::zero());
+        let axial = r * r * na::convert(3.0 / 10.0);
+        let transverse = r * r * na::convert(3.0 / 20.0) + height * height * na::convert(3.0 / 80.0);
+        let unit_inertia = AngularInertia::from_diagonal(&na::Vector3::new(transverse, axial, transverse));
+
+        MassProperties::from_unit_inertia(volume, density, com, unit_inertia)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::mass_properties::mass_properties::polyhedron_mass_properties;
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::shape::ConvexHull;
+use na::RealField;
+
+impl<N: RealField> Volumetric<N> for ConvexHull<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let pts = self.points();
+        let triangles = self.face_vertex_indices().flat_map(|face| {
+            (1..face.len() - 1).map(move |k| [pts[face[0]], pts[face[k]], pts[face[k + 1]]])
+        });
+
+        polyhedron_mass_properties(triangles, density)
+    }
+}
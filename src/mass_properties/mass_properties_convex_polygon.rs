@@ -0,0 +1,10 @@
+use crate::mass_properties::mass_properties::polygon_mass_properties;
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::shape::ConvexPolygon;
+use na::RealField;
+
+impl<N: RealField> Volumetric<N> for ConvexPolygon<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        polygon_mass_properties(self.points(), density)
+    }
+}
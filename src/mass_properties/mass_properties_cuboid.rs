@@ -0,0 +1,28 @@
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::math::{AngularInertia, Point};
+use crate::shape::Cuboid;
+use na::{self, RealField};
+
+#[cfg(feature = "dim2")]
+impl<N: RealField> Volumetric<N> for Cuboid<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let he = self.half_extents();
+        let area = he.x * he.y * na::convert(4.0);
+        let unit_inertia: AngularInertia<N> = he.norm_squared() / na::convert(3.0);
+
+        MassProperties::from_unit_inertia(area, density, Point::origin(), unit_inertia)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> Volumetric<N> for Cuboid<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let he = self.half_extents();
+        let volume = he.x * he.y * he.z * na::convert(8.0);
+        let sq = he.component_mul(he) / na::convert::<_, N>(3.0);
+        let unit_inertia =
+            AngularInertia::from_diagonal(&na::Vector3::new(sq.y + sq.z, sq.x + sq.z, sq.x + sq.y));
+
+        MassProperties::from_unit_inertia(volume, density, Point::origin(), unit_inertia)
+    }
+}
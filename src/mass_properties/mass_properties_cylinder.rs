@@ -0,0 +1,16 @@
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::math::{AngularInertia, Point};
+use crate::shape::Cylinder;
+use na::{self, RealField};
+
+impl<N: RealField> Volumetric<N> for Cylinder<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let (h, r) = (self.half_height(), self.radius());
+        let volume = N::pi() * r * r * h * na::convert(2.0);
+        let axial = r * r * na::convert(0.5);
+        let transverse = (r * r * na::convert(3.0) + h * h * na::convert(4.0)) / na::convert(12.0);
+        let unit_inertia = AngularInertia::from_diagonal(&na::Vector3::new(transverse, axial, transverse));
+
+        MassProperties::from_unit_inertia(volume, density, Point::origin(), unit_inertia)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::shape::{Ball, Capsule, Compound, Cuboid, Shape, Voxels};
+#[cfg(feature = "dim3")]
+use crate::shape::{ConvexHull, TriMesh, Triangle};
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use na::RealField;
+
+/// Computes the mass properties of a shape of any type known by ncollide.
+///
+/// Returns `None` for shapes without volume (or area in 2D), like planes, segments,
+/// polylines, and heightfields, as well as for user-defined shapes. Triangle meshes are
+/// assumed to be closed.
+pub fn shape_mass_properties<N: RealField>(shape: &dyn Shape<N>, density: N) -> Option<MassProperties<N>> {
+    if let Some(s) = shape.as_shape::<Ball<N>>() {
+        Some(s.mass_properties(density))
+    } else if let Some(s) = shape.as_shape::<Cuboid<N>>() {
+        Some(s.mass_properties(density))
+    } else if let Some(s) = shape.as_shape::<Capsule<N>>() {
+        Some(s.mass_properties(density))
+    } else if let Some(s) = shape.as_shape::<Voxels<N>>() {
+        Some(s.mass_properties(density))
+    } else if let Some(s) = shape.as_shape::<Compound<N>>() {
+        Some(s.mass_properties(density))
+    } else {
+        dimension_specific_mass_properties(shape, density)
+    }
+}
+
+#[cfg(feature = "dim2")]
+fn dimension_specific_mass_properties<N: RealField>(
+    shape: &dyn Shape<N>,
+    density: N,
+) -> Option<MassProperties<N>>
+{
+    shape
+        .as_shape::<ConvexPolygon<N>>()
+        .map(|s| s.mass_properties(density))
+}
+
+#[cfg(feature = "dim3")]
+fn dimension_specific_mass_properties<N: RealField>(
+    shape: &dyn Shape<N>,
+    density: N,
+) -> Option<MassProperties<N>>
+{
+    if let Some(s) = shape.as_shape::<ConvexHull<N>>() {
+        Some(s.mass_properties(density))
+    } else if let Some(s) = shape.as_shape::<Triangle<N>>() {
+        Some(s.mass_properties(density))
+    } else if let Some(s) = shape.as_shape::<TriMesh<N>>() {
+        Some(s.mass_properties(density))
+    } else {
+        None
+    }
+}
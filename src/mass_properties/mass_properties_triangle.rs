@@ -0,0 +1,30 @@
+#[cfg(feature = "dim2")]
+use crate::mass_properties::mass_properties::polygon_mass_properties;
+use crate::mass_properties::{MassProperties, Volumetric};
+#[cfg(feature = "dim3")]
+use crate::math::Point;
+use crate::shape::Triangle;
+use na::RealField;
+
+#[cfg(feature = "dim2")]
+impl<N: RealField> Volumetric<N> for Triangle<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let mut pts = [*self.a(), *self.b(), *self.c()];
+
+        if (pts[1] - pts[0]).perp(&(pts[2] - pts[0])) < N::zero() {
+            pts.swap(1, 2)
+        }
+
+        polygon_mass_properties(&pts, density)
+    }
+}
+
+/// A triangle has no volume in 3D: its mass is zero and its center of mass is its centroid.
+#[cfg(feature = "dim3")]
+impl<N: RealField> Volumetric<N> for Triangle<N> {
+    fn mass_properties(&self, _density: N) -> MassProperties<N> {
+        let mut res = MassProperties::zero();
+        res.local_com = Point::from((self.a().coords + self.b().coords + self.c().coords) / na::convert::<_, N>(3.0));
+        res
+    }
+}
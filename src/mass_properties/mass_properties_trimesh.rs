@@ -0,0 +1,19 @@
+use crate::mass_properties::mass_properties::polyhedron_mass_properties;
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::shape::TriMesh;
+use na::RealField;
+
+/// The mass properties of the solid bounded by the triangle mesh.
+///
+/// The mesh must be closed for the result to be meaningful. Its triangles may be oriented
+/// either outward or inward, as long as this orientation is consistent.
+impl<N: RealField> Volumetric<N> for TriMesh<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let pts = self.points();
+        let triangles = self.faces().iter().map(|f| {
+            [pts[f.indices.x], pts[f.indices.y], pts[f.indices.z]]
+        });
+
+        polyhedron_mass_properties(triangles, density)
+    }
+}
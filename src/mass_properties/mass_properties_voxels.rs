@@ -0,0 +1,18 @@
+use crate::mass_properties::{MassProperties, Volumetric};
+use crate::shape::Voxels;
+use na::RealField;
+
+impl<N: RealField> Volumetric<N> for Voxels<N> {
+    fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let voxel = self.voxel_shape().mass_properties(density);
+
+        self.cells()
+            .iter()
+            .map(|cell| {
+                let mut props = voxel;
+                props.local_com = self.voxel_center(cell);
+                props
+            })
+            .sum()
+    }
+}
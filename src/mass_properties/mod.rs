@@ -0,0 +1,25 @@
+//! Volume, center of mass, and angular inertia of shapes.
+
+#[doc(inline)]
+pub use self::mass_properties::{MassProperties, Volumetric};
+pub use self::mass_properties_shape::shape_mass_properties;
+
+#[doc(hidden)]
+pub mod mass_properties;
+mod mass_properties_ball;
+mod mass_properties_capsule;
+mod mass_properties_compound;
+#[cfg(feature = "dim3")]
+mod mass_properties_cone;
+#[cfg(feature = "dim3")]
+mod mass_properties_convex;
+#[cfg(feature = "dim2")]
+mod mass_properties_convex_polygon;
+mod mass_properties_cuboid;
+#[cfg(feature = "dim3")]
+mod mass_properties_cylinder;
+mod mass_properties_shape;
+mod mass_properties_triangle;
+#[cfg(feature = "dim3")]
+mod mass_properties_trimesh;
+mod mass_properties_voxels;
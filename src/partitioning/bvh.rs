@@ -1,7 +1,7 @@
 use na::RealField;
 use crate::partitioning::{
-    BestFirstBVVisitStatus, BestFirstDataVisitStatus, BestFirstVisitor, SimultaneousVisitor,
-    VisitStatus, Visitor, BVT, DBVT,
+    BestFirstBVVisitStatus, BestFirstDataVisitStatus, BestFirstVisitor,
+    SimultaneousBestFirstVisitor, SimultaneousVisitor, VisitStatus, Visitor, BVT, DBVT,
 };
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -172,6 +172,103 @@ pub trait BVH<T, BV> {
 
         result
     }
+
+    /// Performs a best-first-search on the bounding volume test tree implicitly formed with `other`.
+    ///
+    /// Returns the content of the pair of leaves with the smallest associated cost, and a result of
+    /// user-defined type.
+    fn best_first_search_bvtt<N, BFS>(
+        &self,
+        other: &impl BVH<T, BV>,
+        visitor: &mut BFS,
+    ) -> Option<BFS::Result>
+    where
+        N: RealField,
+        BFS: SimultaneousBestFirstVisitor<N, T, BV>,
+    {
+        let mut queue = BinaryHeap::new();
+        let mut best_cost = N::max_value();
+        let mut result = None;
+        // FIXME: find a way to avoid the allocation.
+        let mut pairs = Vec::new();
+
+        if let (Some(root1), Some(root2)) = (self.root(), other.root()) {
+            pairs.push((root1, root2));
+
+            loop {
+                for (node1, node2) in pairs.drain(..) {
+                    let content1 = self.content(node1);
+                    let content2 = other.content(node2);
+
+                    match visitor.visit_bv(content1.0, content2.0) {
+                        BestFirstBVVisitStatus::ContinueWithCost(cost) => {
+                            if cost >= best_cost {
+                                continue;
+                            }
+
+                            if self.num_children(node1) != 0 || other.num_children(node2) != 0 {
+                                queue.push(WeightedValue::new((node1, node2), -cost));
+                            } else if let (Some(data1), Some(data2)) = (content1.1, content2.1) {
+                                match visitor.visit_data(data1, data2) {
+                                    BestFirstDataVisitStatus::ContinueWithResult(res_cost, res) => {
+                                        if res_cost < best_cost {
+                                            best_cost = res_cost;
+                                            result = Some(res)
+                                        }
+                                    }
+                                    BestFirstDataVisitStatus::Continue => {}
+                                    BestFirstDataVisitStatus::ExitEarly => return result,
+                                    BestFirstDataVisitStatus::ExitEarlyWithResult(res) => {
+                                        return Some(res)
+                                    }
+                                }
+                            }
+                        }
+                        BestFirstBVVisitStatus::ExitEarly => return result,
+                        BestFirstBVVisitStatus::Stop => {}
+                    }
+                }
+
+                let entry = match queue.pop() {
+                    Some(entry) => entry,
+                    None => break,
+                };
+
+                if -entry.cost >= best_cost {
+                    break; // Solution found.
+                }
+
+                let (node1, node2): (Self::Node, _) = entry.value;
+                let nchild1 = self.num_children(node1);
+                let nchild2 = other.num_children(node2);
+
+                // Descend on both trees at once, unless one of the nodes is a leaf.
+                match (nchild1, nchild2) {
+                    (0, _) => {
+                        for j in 0..nchild2 {
+                            pairs.push((node1, other.child(j, node2)))
+                        }
+                    }
+                    (_, 0) => {
+                        for i in 0..nchild1 {
+                            pairs.push((self.child(i, node1), node2))
+                        }
+                    }
+                    (_, _) => {
+                        for i in 0..nchild1 {
+                            let n1 = self.child(i, node1);
+
+                            for j in 0..nchild2 {
+                                pairs.push((n1, other.child(j, node2)))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
 }
 
 /// An enum grouping references to all the BVH implementations on ncollide.
@@ -237,6 +334,34 @@ impl<'a, N: RealField, T, BV> BVHImpl<'a, N, T, BV> {
         }
     }
 
+    /// Performs a best-first-search on the bounding volume test tree implicitly formed with `other`.
+    ///
+    /// Returns the content of the pair of leaves with the smallest associated cost, and a result of
+    /// user-defined type.
+    pub fn best_first_search_bvtt<'b, BFS>(
+        self,
+        other: BVHImpl<'b, N, T, BV>,
+        visitor: &mut BFS,
+    ) -> Option<BFS::Result>
+    where BFS: SimultaneousBestFirstVisitor<N, T, BV> {
+        match other {
+            BVHImpl::BVT(bvh2) => self.best_first_search_bvtt_dispatch(bvh2, visitor),
+            BVHImpl::DBVT(bvh2) => self.best_first_search_bvtt_dispatch(bvh2, visitor),
+        }
+    }
+
+    fn best_first_search_bvtt_dispatch<BFS>(
+        self,
+        bvh2: &impl BVH<T, BV>,
+        visitor: &mut BFS,
+    ) -> Option<BFS::Result>
+    where BFS: SimultaneousBestFirstVisitor<N, T, BV> {
+        match self {
+            BVHImpl::BVT(bvh1) => bvh1.best_first_search_bvtt(bvh2, visitor),
+            BVHImpl::DBVT(bvh1) => bvh1.best_first_search_bvtt(bvh2, visitor),
+        }
+    }
+
     /// Performs a best-fist-search on the tree.
     ///
     /// Returns the content of the leaf with the smallest associated cost, and a result of
@@ -250,7 +375,7 @@ impl<'a, N: RealField, T, BV> BVHImpl<'a, N, T, BV> {
     }
 }
 
-struct WeightedValue<N, T> {
+pub(crate) struct WeightedValue<N, T> {
     pub value: T,
     pub cost: N,
 }
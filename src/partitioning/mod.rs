@@ -3,12 +3,16 @@
 pub use self::bvh::{BVHImpl, BVH};
 pub use self::bvt::{BVTNodeId, BinaryPartition, BVT};
 pub use self::dbvt::{DBVTLeaf, DBVTLeafId, DBVT};
+pub use self::qbvh::QBVH;
 pub use self::visitor::{
-    BestFirstBVVisitStatus, BestFirstDataVisitStatus, BestFirstVisitor, SimultaneousVisitor,
-    VisitStatus, Visitor,
+    BestFirstBVVisitStatus, BestFirstDataVisitStatus, BestFirstVisitor, SimdBestFirstVisitStatus,
+    SimdBestFirstVisitor, SimdVisitStatus, SimdVisitor, SimultaneousBestFirstVisitor,
+    SimultaneousVisitor, VisitStatus, Visitor,
 };
+pub(crate) use self::bvh::WeightedValue;
 
 mod bvh;
 mod bvt;
 mod dbvt;
+mod qbvh;
 mod visitor;
@@ -0,0 +1,315 @@
+//! A read-only Bounding Volume Hierarchy with four-wide nodes.
+
+use crate::bounding_volume::{SimdAABB, AABB, SIMD_WIDTH};
+use crate::partitioning::{
+    SimdBestFirstVisitStatus, SimdBestFirstVisitor, SimdVisitStatus, SimdVisitor, WeightedValue,
+    BVH,
+};
+use na::RealField;
+use std::collections::BinaryHeap;
+
+/// The identifier of the content of a lane of a QBVH node.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+enum QBVHNodeId {
+    /// Identifier of an internal node.
+    Internal(usize),
+    /// Identifier of a leaf data.
+    Leaf(usize),
+    /// This lane is not used.
+    Empty,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug)]
+struct QBVHNode {
+    simd_aabb: SimdAABB,
+    children: [QBVHNodeId; SIMD_WIDTH],
+    parent: usize,
+}
+
+/// A flattened Bounding Volume Hierarchy where each node has up to four children.
+///
+/// The AABBs of the four children of a node are stored contiguously with single-precision
+/// coordinates so they can be tested against a ray, a point, or another AABB at once. Nodes are
+/// stored in a flat array where every node is located after its parent.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct QBVH<T> {
+    nodes: Vec<QBVHNode>,
+    data: Vec<T>,
+    // The node containing each leaf data.
+    leaf_nodes: Vec<usize>,
+    // The nodes with a modified lane, since the last refit.
+    dirty_nodes: BinaryHeap<usize>,
+}
+
+impl<T> QBVH<T> {
+    /// Creates an empty QBVH.
+    pub fn new() -> Self {
+        QBVH {
+            nodes: Vec::new(),
+            data: Vec::new(),
+            leaf_nodes: Vec::new(),
+            dirty_nodes: BinaryHeap::new(),
+        }
+    }
+
+    /// Builds a QBVH by collapsing the nodes of the given bounding volume hierarchy.
+    ///
+    /// Each node of the result groups up to four nodes of `bvh` taken among the children and
+    /// grand-children of one of its node.
+    pub fn from_bvh<N: RealField>(bvh: &impl BVH<T, AABB<N>>) -> Self
+    where T: Clone {
+        let mut res = Self::new();
+
+        if let Some(root) = bvh.root() {
+            let _ = res.build_node(bvh, root, usize::max_value());
+        }
+
+        res.nodes.shrink_to_fit();
+        res.data.shrink_to_fit();
+        res.leaf_nodes.shrink_to_fit();
+        res
+    }
+
+    fn build_node<N: RealField, B: BVH<T, AABB<N>>>(
+        &mut self,
+        bvh: &B,
+        node: B::Node,
+        parent: usize,
+    ) -> usize
+    where
+        T: Clone,
+    {
+        let mut lanes = Vec::with_capacity(SIMD_WIDTH);
+
+        if bvh.num_children(node) == 0 {
+            lanes.push(node);
+        } else {
+            for i in 0..bvh.num_children(node) {
+                lanes.push(bvh.child(i, node))
+            }
+        }
+
+        assert!(
+            lanes.len() <= SIMD_WIDTH,
+            "Cannot build a QBVH from nodes with more than four children."
+        );
+
+        // Replace internal lanes by their children while they fit into the four lanes.
+        loop {
+            let to_expand = lanes.iter().position(|lane| {
+                let nchildren = bvh.num_children(*lane);
+                nchildren != 0 && lanes.len() - 1 + nchildren <= SIMD_WIDTH
+            });
+
+            match to_expand {
+                Some(i) => {
+                    let lane = lanes.swap_remove(i);
+
+                    for j in 0..bvh.num_children(lane) {
+                        lanes.push(bvh.child(j, lane))
+                    }
+                }
+                None => break,
+            }
+        }
+
+        let id = self.nodes.len();
+        self.nodes.push(QBVHNode {
+            simd_aabb: SimdAABB::new_invalid(),
+            children: [QBVHNodeId::Empty; SIMD_WIDTH],
+            parent,
+        });
+
+        for (ii, lane) in lanes.into_iter().enumerate() {
+            let (bv, data) = bvh.content(lane);
+
+            let child = if bvh.num_children(lane) == 0 {
+                let data = data.expect("A BVH leaf must contain some data.");
+                self.data.push(data.clone());
+                self.leaf_nodes.push(id);
+                QBVHNodeId::Leaf(self.data.len() - 1)
+            } else {
+                QBVHNodeId::Internal(self.build_node(bvh, lane, id))
+            };
+
+            self.nodes[id].simd_aabb.replace(ii, bv);
+            self.nodes[id].children[ii] = child;
+        }
+
+        id
+    }
+
+    /// The data stored on the leaves of this QBVH.
+    #[inline]
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Updates the bounding volumes of all the nodes of this QBVH.
+    ///
+    /// The `aabb` closure must return the new AABB of the given leaf data.
+    pub fn refit<N: RealField>(&mut self, mut aabb: impl FnMut(&T) -> AABB<N>) {
+        self.dirty_nodes.clear();
+
+        // Children are always stored after their parent.
+        for i in (0..self.nodes.len()).rev() {
+            for ii in 0..SIMD_WIDTH {
+                match self.nodes[i].children[ii] {
+                    QBVHNodeId::Internal(child) => {
+                        let child_aabb = self.nodes[child].simd_aabb;
+                        self.nodes[i].simd_aabb.replace_with_merged(ii, &child_aabb)
+                    }
+                    QBVHNodeId::Leaf(leaf) => {
+                        let leaf_aabb = aabb(&self.data[leaf]);
+                        self.nodes[i].simd_aabb.replace(ii, &leaf_aabb)
+                    }
+                    QBVHNodeId::Empty => self.nodes[i].simd_aabb.clear(ii),
+                }
+            }
+        }
+    }
+
+    /// Sets the AABB of the `i`-th leaf data of this QBVH, i.e., of `self.data()[i]`.
+    ///
+    /// The ancestors of this leaf are not updated until `.refit_modified_leaves()` is called.
+    /// This is useful to refit the tree only once after several leaf modifications.
+    pub fn set_leaf_aabb<N: RealField>(&mut self, i: usize, aabb: &AABB<N>) {
+        let id = self.leaf_nodes[i];
+        let node = &mut self.nodes[id];
+
+        for ii in 0..SIMD_WIDTH {
+            if node.children[ii] == QBVHNodeId::Leaf(i) {
+                node.simd_aabb.replace(ii, aabb);
+            }
+        }
+
+        self.dirty_nodes.push(id);
+    }
+
+    /// Updates the bounding volumes of the ancestors of the leaves modified by
+    /// `.set_leaf_aabb(...)` since the last refit.
+    ///
+    /// Only the nodes on the paths between those leaves and the root are visited.
+    pub fn refit_modified_leaves(&mut self) {
+        let mut last = usize::max_value();
+
+        // Children are always stored after their parent so the max-heap yields
+        // all the children of a node before the node itself.
+        while let Some(id) = self.dirty_nodes.pop() {
+            if id == last {
+                continue;
+            }
+
+            last = id;
+            let parent = self.nodes[id].parent;
+
+            if parent != usize::max_value() {
+                let simd_aabb = self.nodes[id].simd_aabb;
+
+                for ii in 0..SIMD_WIDTH {
+                    if self.nodes[parent].children[ii] == QBVHNodeId::Internal(id) {
+                        self.nodes[parent].simd_aabb.replace_with_merged(ii, &simd_aabb);
+                    }
+                }
+
+                self.dirty_nodes.push(parent);
+            }
+        }
+    }
+
+    #[inline]
+    fn node_data(&self, node: &QBVHNode) -> [Option<&T>; SIMD_WIDTH] {
+        let mut res = [None; SIMD_WIDTH];
+
+        for ii in 0..SIMD_WIDTH {
+            if let QBVHNodeId::Leaf(leaf) = node.children[ii] {
+                res[ii] = Some(&self.data[leaf])
+            }
+        }
+
+        res
+    }
+
+    /// Traverses this QBVH using a visitor.
+    pub fn visit(&self, visitor: &mut impl SimdVisitor<T>) {
+        // FIXME: find a way to avoid the allocation.
+        let mut stack = Vec::new();
+
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+
+        while let Some(id) = stack.pop() {
+            let node = &self.nodes[id];
+
+            match visitor.visit(&node.simd_aabb, self.node_data(node)) {
+                SimdVisitStatus::MaybeContinue(mask) => {
+                    for ii in 0..SIMD_WIDTH {
+                        if mask[ii] {
+                            if let QBVHNodeId::Internal(child) = node.children[ii] {
+                                stack.push(child)
+                            }
+                        }
+                    }
+                }
+                SimdVisitStatus::ExitEarly => return,
+            }
+        }
+    }
+
+    /// Performs a best-first-search on this QBVH.
+    ///
+    /// Returns the result with the smallest associated cost.
+    pub fn best_first_search<N, BFS>(&self, visitor: &mut BFS) -> Option<BFS::Result>
+    where
+        N: RealField,
+        BFS: SimdBestFirstVisitor<N, T>,
+    {
+        let mut queue: BinaryHeap<WeightedValue<N, usize>> = BinaryHeap::new();
+        let mut best_cost = N::max_value();
+        let mut result = None;
+
+        if !self.nodes.is_empty() {
+            queue.push(WeightedValue::new(0, N::zero()));
+        }
+
+        while let Some(entry) = queue.pop() {
+            if -entry.cost >= best_cost {
+                break; // Solution found.
+            }
+
+            let node = &self.nodes[entry.value];
+
+            match visitor.visit(best_cost, &node.simd_aabb, self.node_data(node)) {
+                SimdBestFirstVisitStatus::MaybeContinue {
+                    weights,
+                    mask,
+                    mut results,
+                } => {
+                    for ii in 0..SIMD_WIDTH {
+                        if !mask[ii] || weights[ii] >= best_cost {
+                            continue;
+                        }
+
+                        match (results[ii].take(), node.children[ii]) {
+                            (Some(res), _) => {
+                                best_cost = weights[ii];
+                                result = Some(res);
+                            }
+                            (None, QBVHNodeId::Internal(child)) => {
+                                queue.push(WeightedValue::new(child, -weights[ii]))
+                            }
+                            (None, _) => {}
+                        }
+                    }
+                }
+                SimdBestFirstVisitStatus::ExitEarly(res) => return res.or(result),
+            }
+        }
+
+        result
+    }
+}
@@ -1,3 +1,5 @@
+use crate::bounding_volume::{SimdAABB, SimdBool, SIMD_WIDTH};
+
 /// The status of the spatial partitoning structure traversal.
 pub enum VisitStatus {
     /// The traversal should continue on the children of the currently visited nodes.
@@ -65,3 +67,67 @@ pub trait BestFirstVisitor<N, T, BV> {
     /// Compute the next action to be taken by the best-first-search after visiting a node containing the given data.
     fn visit_data(&mut self, data: &T) -> BestFirstDataVisitStatus<N, Self::Result>;
 }
+
+/// Trait implemented by cost functions used by the best-first search on the bounding volume test tree
+/// implicitly formed by two BVHs.
+pub trait SimultaneousBestFirstVisitor<N, T, BV> {
+    /// The result of a best-fist traversal.
+    type Result;
+
+    /// Compute the next action to be taken by the best-first-search after visiting a pair of nodes
+    /// containing the given bounding volumes, one from each structure.
+    fn visit_bv(&mut self, left_bv: &BV, right_bv: &BV) -> BestFirstBVVisitStatus<N>;
+    /// Compute the next action to be taken by the best-first-search after visiting a pair of leaves
+    /// containing the given data, one from each structure.
+    fn visit_data(&mut self, left_data: &T, right_data: &T) -> BestFirstDataVisitStatus<N, Self::Result>;
+}
+
+/// The status of the traversal of a spatial partitioning structure with four-wide nodes.
+pub enum SimdVisitStatus {
+    /// The traversal should continue on the children of the lanes set to `true` on the mask.
+    MaybeContinue(SimdBool),
+    /// The traversal should exit immediately.
+    ExitEarly,
+}
+
+/// Trait implemented by visitor called during the traversal of a spatial partitioning data
+/// structure with four-wide nodes.
+pub trait SimdVisitor<T> {
+    /// Execute an operation on the content of the four lanes of a node.
+    ///
+    /// The `i`-th element of `data` is set if the `i`-th lane of `bv` is a leaf. Returns a mask of the lanes
+    /// the traversal should continue on, or if the whole traversal should be exited early.
+    fn visit(&mut self, bv: &SimdAABB, data: [Option<&T>; SIMD_WIDTH]) -> SimdVisitStatus;
+}
+
+/// The next action to be taken by a best-first traversal after having visited a node with four lanes.
+pub enum SimdBestFirstVisitStatus<N, Res> {
+    /// The traversal continues on the lanes set to `true` on `mask`.
+    MaybeContinue {
+        /// The cost associated to each lane.
+        weights: [N; SIMD_WIDTH],
+        /// The lanes the traversal should continue on.
+        mask: SimdBool,
+        /// The result associated to each leaf lane, if any. Its cost is given by `weights`.
+        results: [Option<Res>; SIMD_WIDTH],
+    },
+    /// The traversal aborts, returning the given result, or the last best result found if it is `None`.
+    ExitEarly(Option<Res>),
+}
+
+/// Trait implemented by cost functions used by the best-first search on a structure with four-wide nodes.
+pub trait SimdBestFirstVisitor<N, T> {
+    /// The result of a best-first traversal.
+    type Result;
+
+    /// Compute the next action to be taken by the best-first-search after visiting a node.
+    ///
+    /// The `i`-th element of `data` is set if the `i`-th lane of `bv` is a leaf. Lanes with a cost
+    /// greater than `best_cost_so_far` will be ignored anyway.
+    fn visit(
+        &mut self,
+        best_cost_so_far: N,
+        bv: &SimdAABB,
+        data: [Option<&T>; SIMD_WIDTH],
+    ) -> SimdBestFirstVisitStatus<N, Self::Result>;
+}
@@ -49,6 +49,17 @@ pub trait BroadPhase<N: RealField, BV, T>: Any + Sync + Send {
     /// Sets the next bounding volume to be used during the update of this broad phase.
     fn deferred_set_bounding_volume(&mut self, handle: ProxyHandle, bv: BV);
 
+    /// Sets the next bounding volume to be used during the update of this broad phase, even if
+    /// it is much smaller than the current one.
+    ///
+    /// A broad phase may keep a larger bounding volume after `deferred_set_bounding_volume` in
+    /// order to avoid updating its proxies after each small motion. This forces the proxy to
+    /// shrink, e.g., at the end of the motion swept by an object with continuous collision
+    /// detection enabled.
+    fn deferred_reset_bounding_volume(&mut self, handle: ProxyHandle, bv: BV) {
+        self.deferred_set_bounding_volume(handle, bv)
+    }
+
     /// Forces the broad-phase to recompute and re-report all the proximities with the given object.
     fn deferred_recompute_all_proximities_with(&mut self, handle: ProxyHandle);
 
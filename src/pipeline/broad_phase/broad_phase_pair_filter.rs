@@ -1,38 +1,37 @@
 use na::RealField;
-use std::any::Any;
 
-use crate::pipeline::world::CollisionObject;
+use crate::pipeline::world::{CollisionGroupsBitSet, CollisionObject};
 
 /// A signal handler for contact detection.
-pub trait BroadPhasePairFilter<N: RealField, T>: Any + Send + Sync {
+pub trait BroadPhasePairFilter<N: RealField, T, B: CollisionGroupsBitSet = u32>: Send + Sync {
     /// Activate an action for when two objects start or stop to be close to each other.
-    fn is_pair_valid(&self, b1: &CollisionObject<N, T>, b2: &CollisionObject<N, T>) -> bool;
+    fn is_pair_valid(&self, b1: &CollisionObject<N, T, B>, b2: &CollisionObject<N, T, B>) -> bool;
 }
 
 /// Filters deciding whether a proximity is to be further investigated by the narrow phase or not.
 ///
 /// All filters have have to return `true` in order to allow a proximity to be further handled.
-pub struct BroadPhasePairFilters<N: RealField, T> {
-    filters: Vec<(String, Box<dyn BroadPhasePairFilter<N, T>>)>,
+pub struct BroadPhasePairFilters<N: RealField, T, B: CollisionGroupsBitSet = u32> {
+    filters: Vec<(String, Box<dyn BroadPhasePairFilter<N, T, B>>)>,
 }
 
 #[cfg(feature = "serde-serialize")]
-impl<N: RealField, T> serde::Serialize for BroadPhasePairFilters<N, T> {
+impl<N: RealField, T, B: CollisionGroupsBitSet> serde::Serialize for BroadPhasePairFilters<N, T, B> {
     fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
         unimplemented!()
     }
 }
 
 #[cfg(feature = "serde-serialize")]
-impl<'de, N: RealField, T> serde::Deserialize<'de> for BroadPhasePairFilters<N, T> {
-    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<BroadPhasePairFilters<N, T>, D::Error> {
+impl<'de, N: RealField, T, B: CollisionGroupsBitSet> serde::Deserialize<'de> for BroadPhasePairFilters<N, T, B> {
+    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<BroadPhasePairFilters<N, T, B>, D::Error> {
         unimplemented!()
     }
 }
 
-impl<N: RealField, T> BroadPhasePairFilters<N, T> {
+impl<N: RealField, T, B: CollisionGroupsBitSet> BroadPhasePairFilters<N, T, B> {
     /// Creates a new set of collision filters.
-    pub fn new() -> BroadPhasePairFilters<N, T> {
+    pub fn new() -> BroadPhasePairFilters<N, T, B> {
         BroadPhasePairFilters {
             filters: Vec::new(),
         }
@@ -42,7 +41,7 @@ impl<N: RealField, T> BroadPhasePairFilters<N, T> {
     pub fn register_collision_filter(
         &mut self,
         name: &str,
-        callback: Box<dyn BroadPhasePairFilter<N, T>>,
+        callback: Box<dyn BroadPhasePairFilter<N, T, B>>,
     )
     {
         for &mut (ref mut n, ref mut f) in self.filters.iter_mut() {
@@ -76,7 +75,7 @@ impl<N: RealField, T> BroadPhasePairFilters<N, T> {
     }
 
     /// Tells if the collision between `b1` and `b2` is to be handled by the narrow-phase.
-    pub fn is_pair_valid(&self, b1: &CollisionObject<N, T>, b2: &CollisionObject<N, T>) -> bool {
+    pub fn is_pair_valid(&self, b1: &CollisionObject<N, T, B>, b2: &CollisionObject<N, T, B>) -> bool {
         self.filters
             .iter()
             .all(|&(_, ref f)| f.is_pair_valid(b1, b2))
@@ -327,6 +327,15 @@ impl<N, BV, T> BroadPhase<N, BV, T> for DBVTBroadPhase<N, BV, T>
         }
     }
 
+    fn deferred_reset_bounding_volume(&mut self, handle: ProxyHandle, bounding_volume: BV) {
+        if self.proxies.get(handle.uid()).is_some() {
+            let new_bv = bounding_volume.loosened(self.margin);
+            self.proxies_to_update.push((handle, new_bv));
+        } else {
+            panic!("Attempting to set the bounding volume of an object that does not exist.");
+        }
+    }
+
     fn deferred_recompute_all_proximities_with(&mut self, handle: ProxyHandle) {
         if let Some(proxy) = self.proxies.get(handle.uid()) {
             let bv = match proxy.status {
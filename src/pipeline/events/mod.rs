@@ -1,7 +1,11 @@
 //! Structures for describing and storing collision-related events.
 
+use na::{RealField, Unit};
+
+use crate::math::{Point, Vector};
 use crate::pipeline::world::CollisionObjectHandle;
-use crate::query::Proximity;
+use crate::query::{Contact, ContactManifold, Proximity};
+use crate::shape::FeatureId;
 use std::iter::IntoIterator;
 use std::slice::Iter;
 
@@ -17,6 +21,12 @@ pub struct EventPool<E> {
 pub type ContactEvents = EventPool<ContactEvent>;
 /// A set of proximity events.
 pub type ProximityEvents = EventPool<ProximityEvent>;
+/// A set of detailed contact events.
+pub type DetailedContactEvents<N> = EventPool<DetailedContactEvent<N>>;
+/// A set of time of impact events.
+pub type TOIEvents<N> = EventPool<TOIEvent<N>>;
+/// A set of per-part proximity events.
+pub type PartProximityEvents = EventPool<PartProximityEvent>;
 
 impl<E> EventPool<E> {
     /// Creates a new empty set of events.
@@ -75,6 +85,76 @@ pub enum ContactEvent {
     Stopped(CollisionObjectHandle, CollisionObjectHandle),
 }
 
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
+/// A summary of a contact manifold attached to detailed contact events.
+pub struct ContactManifoldSummary<N: RealField> {
+    /// The deepest contact of the manifold.
+    pub deepest: Contact<N>,
+    /// The feature of the first collision object on which the deepest contact lies.
+    pub feature1: FeatureId,
+    /// The feature of the second collision object on which the deepest contact lies.
+    pub feature2: FeatureId,
+    /// The number of contacts of the manifold.
+    pub num_contacts: usize,
+}
+
+impl<N: RealField> ContactManifoldSummary<N> {
+    /// Summarizes the given contact manifold.
+    ///
+    /// Returns `None` if the manifold does not contain any contact.
+    pub fn new(manifold: &ContactManifold<N>) -> Option<ContactManifoldSummary<N>> {
+        manifold.deepest_contact().map(|c| ContactManifoldSummary {
+            deepest: c.contact.clone(),
+            feature1: c.kinematic.feature1(),
+            feature2: c.kinematic.feature2(),
+            num_contacts: manifold.len(),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
+/// Contact events carrying information about the contact manifold of the collision objects.
+///
+/// Those events are only generated if they have been enabled on the collision world or the
+/// narrow phase.
+pub enum DetailedContactEvent<N: RealField> {
+    /// Event occuring when two collision objects start being in contact.
+    ///
+    /// The summary describes the manifold computed during this update.
+    Started(CollisionObjectHandle, CollisionObjectHandle, ContactManifoldSummary<N>),
+    /// Event occuring at each update during which two collision objects remain in contact.
+    ///
+    /// The last value is the number of updates, including this one, during which the collision
+    /// objects have been in contact.
+    Persisted(CollisionObjectHandle, CollisionObjectHandle, ContactManifoldSummary<N>, usize),
+    /// Event occuring when two collision objects stop being in contact.
+    ///
+    /// The summary describes the last manifold computed while they were in contact.
+    Stopped(CollisionObjectHandle, CollisionObjectHandle, ContactManifoldSummary<N>),
+}
+
+impl<N: RealField> DetailedContactEvent<N> {
+    /// The handles of the two collision objects involved in this event.
+    pub fn collision_objects(&self) -> (CollisionObjectHandle, CollisionObjectHandle) {
+        match *self {
+            DetailedContactEvent::Started(co1, co2, _)
+            | DetailedContactEvent::Persisted(co1, co2, _, _)
+            | DetailedContactEvent::Stopped(co1, co2, _) => (co1, co2),
+        }
+    }
+
+    /// The summary of the contact manifold attached to this event.
+    pub fn manifold_summary(&self) -> &ContactManifoldSummary<N> {
+        match self {
+            DetailedContactEvent::Started(_, _, summary)
+            | DetailedContactEvent::Persisted(_, _, summary, _)
+            | DetailedContactEvent::Stopped(_, _, summary) => summary,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
 /// Events occuring when two collision objects start or stop being in close proximity, contact, or disjoint.
@@ -109,3 +189,43 @@ impl ProximityEvent {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
+/// Event occuring when two collision objects with continuous collision detection enabled hit
+/// each other during their motion from their previous to their current positions.
+pub struct TOIEvent<N: RealField> {
+    /// The first collision object involved in the impact.
+    pub collider1: CollisionObjectHandle,
+    /// The second collision object involved in the impact.
+    pub collider2: CollisionObjectHandle,
+    /// The time of impact, between `0` (previous positions) and `1` (current positions).
+    pub toi: N,
+    /// The witness point on the first collision object at the time of impact, in world-space.
+    pub world1: Point<N>,
+    /// The witness point on the second collision object at the time of impact, in world-space.
+    pub world2: Point<N>,
+    /// The contact normal at the time of impact, pointing toward the second collision object.
+    pub normal: Unit<Vector<N>>,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
+/// Events occuring when one part of a collision object (e.g. a triangle of a mesh or a cell of an
+/// heightfield) starts or stops being in close proximity, contact, or disjoint with another
+/// collision object.
+///
+/// Those events are only generated by proximity detectors that keep track of the proximity of
+/// each part of a shape, e.g., for heightfields and triangle meshes.
+pub struct PartProximityEvent {
+    /// The collision object owning the part.
+    pub collider: CollisionObjectHandle,
+    /// The identifier of the part of `collider`.
+    pub part: usize,
+    /// The other collision object.
+    pub other_collider: CollisionObjectHandle,
+    /// The previous state of proximity between the part and the other collision object.
+    pub prev_status: Proximity,
+    /// The new state of proximity between the part and the other collision object.
+    pub new_status: Proximity,
+}
@@ -2,8 +2,7 @@ use crate::bounding_volume::{self, BoundingVolume};
 use crate::math::Isometry;
 use na::{self, RealField};
 use crate::pipeline::narrow_phase::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
-use crate::query::{visitors::BoundingVolumeInterferencesCollector, ContactManifold, ContactPrediction,
-                   ContactPreprocessor, ContactTrackingMode};
+use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor, ContactTrackingMode};
 use crate::shape::{CompositeShape, Shape};
 use std::collections::{hash_map::Entry, HashMap};
 use crate::utils::DeterministicState;
@@ -49,11 +48,7 @@ impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
         let ls_m2 = m1.inverse() * m2.clone();
         let ls_aabb2 = bounding_volume::aabb(g2, &ls_m2).loosened(prediction.linear());
         
-        {
-            let mut visitor =
-                BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut self.interferences);
-            g1.bvh().visit(&mut visitor);
-        }
+        g1.parts_intersecting_local_aabb(&ls_aabb2, &mut self.interferences);
         
         for i in self.interferences.drain(..) {
             match self.sub_detectors.entry(i) {
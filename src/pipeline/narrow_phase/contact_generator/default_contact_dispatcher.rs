@@ -4,13 +4,15 @@ use crate::pipeline::narrow_phase::{
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
     ContactAlgorithm, ContactDispatcher, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
     PlaneBallManifoldGenerator, PlaneConvexPolyhedronManifoldGenerator, CapsuleShapeManifoldGenerator,
-    CapsuleCapsuleManifoldGenerator, HeightFieldShapeManifoldGenerator
+    CapsuleCapsuleManifoldGenerator, HeightFieldShapeManifoldGenerator, SupportMapSupportMapManifoldGenerator
 };
 #[cfg(feature = "dim3")]
-use crate::pipeline::narrow_phase::TriMeshTriMeshManifoldGenerator;
+use crate::pipeline::narrow_phase::{SignedDistanceFieldShapeManifoldGenerator, TriMeshTriMeshManifoldGenerator};
 #[cfg(feature = "dim3")]
-use crate::shape::TriMesh;
-use crate::shape::{Ball, Plane, Shape, Capsule, HeightField};
+use crate::query::contacts_internal;
+#[cfg(feature = "dim3")]
+use crate::shape::{SignedDistanceField, TriMesh};
+use crate::shape::{Ball, Plane, Shape, Capsule, HeightField, Voxels};
 
 /// Collision dispatcher for shapes defined by `ncollide_entities`.
 pub struct DefaultContactDispatcher {}
@@ -32,6 +34,8 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
         let b_is_capsule = b.is_shape::<Capsule<N>>();
         let a_is_heightfield = a.is_shape::<HeightField<N>>();
         let b_is_heightfield = b.is_shape::<HeightField<N>>();
+        let a_is_voxels = a.is_shape::<Voxels<N>>();
+        let b_is_voxels = b.is_shape::<Voxels<N>>();
 
         #[cfg(feature = "dim3")]
         {
@@ -41,6 +45,19 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
             if a_is_trimesh && b_is_trimesh {
                 return Some(Box::new(TriMeshTriMeshManifoldGenerator::<N>::new()));
             }
+
+            let a_is_sdf = a.is_shape::<SignedDistanceField<N>>();
+            let b_is_sdf = b.is_shape::<SignedDistanceField<N>>();
+
+            if a_is_sdf || b_is_sdf {
+                let other = if b_is_sdf { a } else { b };
+
+                if !contacts_internal::can_sample_shape(other) {
+                    return None;
+                }
+
+                return Some(Box::new(SignedDistanceFieldShapeManifoldGenerator::<N>::new(b_is_sdf)));
+            }
         }
 
         {
@@ -48,7 +65,10 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
 
 
 
-        if a_is_heightfield || b_is_heightfield {
+        if a_is_voxels || b_is_voxels {
+            // The voxels find their parts intersecting the other shape from their occupancy grid.
+            return Some(Box::new(CompositeShapeShapeManifoldGenerator::<N>::new(b_is_voxels)));
+        } else if a_is_heightfield || b_is_heightfield {
             return Some(Box::new(HeightFieldShapeManifoldGenerator::<N>::new(b_is_heightfield)));
         } else if a_is_capsule && b_is_capsule {
             Some(Box::new(CapsuleCapsuleManifoldGenerator::<N>::new()))
@@ -75,6 +95,9 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
         } else if a.is_convex_polyhedron() && b.is_convex_polyhedron() {
             let gen = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
             Some(Box::new(gen))
+        } else if a.is_support_map() && b.is_support_map() {
+            let gen = SupportMapSupportMapManifoldGenerator::new();
+            Some(Box::new(gen))
         } else if a.is_composite_shape() && b.is_composite_shape() {
             Some(Box::new(
                 CompositeShapeCompositeShapeManifoldGenerator::<N>::new(),
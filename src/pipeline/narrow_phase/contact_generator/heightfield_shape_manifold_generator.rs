@@ -2,6 +2,7 @@ use crate::bounding_volume::{self, BoundingVolume};
 use crate::math::Isometry;
 use na::{self, RealField};
 use crate::pipeline::narrow_phase::{ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator};
+use crate::query::contacts_internal::OneWayContactPreprocessor;
 use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor};
 use crate::shape::{Shape, HeightField};
 use std::collections::{hash_map::Entry, HashMap};
@@ -47,6 +48,15 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
         let ls_aabb2 = bounding_volume::aabb(g2, &ls_m2).loosened(prediction.linear());
 
         g1.map_elements_in_local_aabb(&ls_aabb2, &mut |i, elt1, part_proc1| {
+            // Heightfields face upward.
+            let one_way = g1.one_way().and_then(|tolerance| {
+                elt1.normal().map(|n| {
+                    let n = if n.y < N::zero() { -n } else { n };
+                    OneWayContactPreprocessor::new(m1, n, tolerance)
+                })
+            });
+            let part_proc1 = (one_way.as_ref(), part_proc1);
+
             match self.sub_detectors.entry(i) {
                 Entry::Occupied(mut entry) => {
                     let ok = if flip {
@@ -57,7 +67,7 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
                             proc2,
                             m1,
                             elt1,
-                            Some(&(proc1, part_proc1)),
+                            Some(&(proc1, &part_proc1)),
                             prediction,
                             id_alloc,
                             manifold
@@ -67,7 +77,7 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
                             dispatcher,
                             m1,
                             elt1,
-                            Some(&(proc1, part_proc1)),
+                            Some(&(proc1, &part_proc1)),
                             m2,
                             g2,
                             proc2,
@@ -97,7 +107,7 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
                                 proc2,
                                 m1,
                                 elt1,
-                                Some(&(proc1, part_proc1)),
+                                Some(&(proc1, &part_proc1)),
                                 prediction,
                                 id_alloc,
                                 manifold
@@ -107,7 +117,7 @@ impl<N: RealField> HeightFieldShapeManifoldGenerator<N> {
                                 dispatcher,
                                 m1,
                                 elt1,
-                                Some(&(proc1, part_proc1)),
+                                Some(&(proc1, &part_proc1)),
                                 m2,
                                 g2,
                                 proc2,
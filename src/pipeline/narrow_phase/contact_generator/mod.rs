@@ -12,6 +12,9 @@ pub use self::default_contact_dispatcher::DefaultContactDispatcher;
 pub use self::plane_ball_manifold_generator::PlaneBallManifoldGenerator;
 pub use self::plane_convex_polyhedron_manifold_generator::PlaneConvexPolyhedronManifoldGenerator;
 #[cfg(feature = "dim3")]
+pub use self::signed_distance_field_shape_manifold_generator::SignedDistanceFieldShapeManifoldGenerator;
+pub use self::support_map_support_map_manifold_generator::SupportMapSupportMapManifoldGenerator;
+#[cfg(feature = "dim3")]
 pub use self::trimesh_trimesh_manifold_generator::TriMeshTriMeshManifoldGenerator;
 pub use self::heightfield_shape_manifold_generator::HeightFieldShapeManifoldGenerator;
 pub use self::capsule_shape_manifold_generator::CapsuleShapeManifoldGenerator;
@@ -29,7 +32,10 @@ mod default_contact_dispatcher;
 mod plane_ball_manifold_generator;
 mod plane_convex_polyhedron_manifold_generator;
 #[cfg(feature = "dim3")]
+mod signed_distance_field_shape_manifold_generator;
+mod support_map_support_map_manifold_generator;
+#[cfg(feature = "dim3")]
 mod trimesh_trimesh_manifold_generator;
 mod heightfield_shape_manifold_generator;
 mod capsule_shape_manifold_generator;
-mod capsule_capsule_manifold_generator;
\ No newline at end of file
+mod capsule_capsule_manifold_generator;
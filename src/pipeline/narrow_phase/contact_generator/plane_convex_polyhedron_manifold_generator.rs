@@ -74,6 +74,37 @@ impl<N: RealField> PlaneConvexPolyhedronManifoldGenerator<N> {
                 }
             }
 
+            true
+        } else if let (Some(plane), Some(sm)) = (g1.as_shape::<Plane<N>>(), g2.as_support_map()) {
+            // Shapes without polyhedral features yield their deepest point only.
+            let plane_normal = m1 * plane.normal();
+            let plane_center = Point::from(m1.translation.vector);
+            let world2 = sm.support_point_toward(m2, &-plane_normal);
+            let dist = (world2 - plane_center).dot(plane_normal.as_ref());
+
+            if dist <= prediction.linear() {
+                let world1 = world2 + (-*plane_normal * dist);
+                let local1 = m1.inverse_transform_point(&world1);
+                let local2 = m2.inverse_transform_point(&world2);
+                let f1 = FeatureId::Face(0);
+                let f2 = FeatureId::Unknown;
+                let mut kinematic = ContactKinematic::new();
+                let approx_plane = NeighborhoodGeometry::Plane(*plane.normal());
+                let approx2 = NeighborhoodGeometry::Point;
+
+                if !flip {
+                    let contact = Contact::new(world1, world2, plane_normal, -dist);
+                    kinematic.set_approx1(f1, local1, approx_plane);
+                    kinematic.set_approx2(f2, local2, approx2);
+                    let _ = manifold.push(contact, kinematic, local2, proc1, proc2, id_alloc);
+                } else {
+                    let contact = Contact::new(world2, world1, -plane_normal, -dist);
+                    kinematic.set_approx1(f2, local2, approx2);
+                    kinematic.set_approx2(f1, local1, approx_plane);
+                    let _ = manifold.push(contact, kinematic, local2, proc2, proc1, id_alloc);
+                }
+            }
+
             true
         } else {
             false
@@ -0,0 +1,96 @@
+use crate::math::Isometry;
+use na::RealField;
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{contacts_internal, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor, NeighborhoodGeometry};
+use crate::shape::{FeatureId, Shape, SignedDistanceField};
+use crate::utils::{IdAllocator, IsometryOps};
+
+/// Collision detector between a signed distance field and any other shape.
+///
+/// One contact is generated for each sample point of the other shape close to the surface of
+/// the signed distance field. See `query::contacts_internal::signed_distance_field_against_shape`
+/// for the sample points used for each shape.
+#[derive(Clone)]
+pub struct SignedDistanceFieldShapeManifoldGenerator<N: RealField> {
+    flip: bool,
+    _phantom: std::marker::PhantomData<N>,
+}
+
+impl<N: RealField> SignedDistanceFieldShapeManifoldGenerator<N> {
+    /// Creates a new collision detector between a signed distance field and another shape.
+    ///
+    /// If `flip` is `true`, the signed distance field is expected to be the second shape.
+    pub fn new(flip: bool) -> SignedDistanceFieldShapeManifoldGenerator<N> {
+        SignedDistanceFieldShapeManifoldGenerator {
+            flip,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    fn do_update_to(
+        m1: &Isometry<N>,
+        g1: &SignedDistanceField<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        id_alloc: &mut IdAllocator,
+        manifold: &mut ContactManifold<N>,
+        flip: bool,
+    )
+    {
+        contacts_internal::signed_distance_field_contacts(m1, g1, m2, g2, prediction.linear(), &mut |i, radius, mut contact| {
+            let local1 = m1.inverse_transform_point(&contact.world1);
+            let local_normal1 = m1.inverse_transform_unit_vector(&contact.normal);
+            let sample2 = m2.inverse_transform_point(&(contact.world2 + *contact.normal * radius));
+            let approx1 = NeighborhoodGeometry::Plane(local_normal1);
+            let approx2 = NeighborhoodGeometry::Point;
+            let mut kinematic = ContactKinematic::new();
+
+            if !flip {
+                kinematic.set_approx1(FeatureId::Unknown, local1, approx1);
+                kinematic.set_approx2(FeatureId::Vertex(i), sample2, approx2);
+                kinematic.set_dilation2(radius);
+                let _ = manifold.push(contact, kinematic, sample2, proc1, proc2, id_alloc);
+            } else {
+                contact.flip();
+                kinematic.set_approx1(FeatureId::Vertex(i), sample2, approx2);
+                kinematic.set_dilation1(radius);
+                kinematic.set_approx2(FeatureId::Unknown, local1, approx1);
+                let _ = manifold.push(contact, kinematic, sample2, proc2, proc1, id_alloc);
+            }
+        });
+    }
+}
+
+impl<N: RealField> ContactManifoldGenerator<N> for SignedDistanceFieldShapeManifoldGenerator<N> {
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        id_alloc: &mut IdAllocator,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool
+    {
+        if !self.flip {
+            if let Some(sdf) = g1.as_shape::<SignedDistanceField<N>>() {
+                Self::do_update_to(m1, sdf, proc1, m2, g2, proc2, prediction, id_alloc, manifold, false);
+                return true;
+            }
+        } else {
+            if let Some(sdf) = g2.as_shape::<SignedDistanceField<N>>() {
+                Self::do_update_to(m2, sdf, proc2, m1, g1, proc1, prediction, id_alloc, manifold, true);
+                return true;
+            }
+        }
+
+        false
+    }
+}
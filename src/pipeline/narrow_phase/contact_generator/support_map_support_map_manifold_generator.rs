@@ -0,0 +1,147 @@
+use crate::math::{Isometry, Vector};
+use na::{RealField, Unit};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::algorithms::gjk::GJKResult;
+use crate::query::algorithms::VoronoiSimplex;
+use crate::query::contacts_internal;
+use crate::query::{Contact, ContactKinematic, ContactManifold, ContactPrediction, NeighborhoodGeometry, ContactPreprocessor};
+use crate::shape::{FeatureId, Shape};
+use crate::utils::{IdAllocator, IsometryOps};
+
+/// Collision detector between two shapes implementing the `SupportMap` trait.
+///
+/// This is used for support-mapped shapes without polyhedral features, e.g., Minkowski sums
+/// and swept shapes. It generates at most one contact per update, at the deepest point, so a
+/// flat side of such a shape resting on another shape is not stable: convert the shape to a
+/// `ConvexHull` if several contacts are needed.
+///
+/// The contacts are approximated by the feature of the shape that has polyhedral features, if
+/// any, and by the tangent plane at the contact point of the other shape, so that they can be
+/// updated and filtered like other contacts.
+#[derive(Clone)]
+pub struct SupportMapSupportMapManifoldGenerator<N: RealField> {
+    simplex: VoronoiSimplex<N>,
+    last_gjk_dir: Option<Unit<Vector<N>>>,
+}
+
+impl<N: RealField> SupportMapSupportMapManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between two shapes implementing the
+    /// `SupportMap` trait.
+    pub fn new() -> Self {
+        SupportMapSupportMapManifoldGenerator {
+            simplex: VoronoiSimplex::new(),
+            last_gjk_dir: None,
+        }
+    }
+}
+
+impl<N: RealField> ContactManifoldGenerator<N> for SupportMapSupportMapManifoldGenerator<N> {
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N>,
+        ma: &Isometry<N>,
+        a: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        mb: &Isometry<N>,
+        b: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        id_alloc: &mut IdAllocator,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool
+    {
+        if let (Some(sa), Some(sb)) = (a.as_support_map(), b.as_support_map()) {
+            let contact = contacts_internal::support_map_against_support_map_with_params(
+                ma,
+                sa,
+                mb,
+                sb,
+                prediction.linear(),
+                &mut self.simplex,
+                self.last_gjk_dir,
+            );
+
+            match contact {
+                GJKResult::ClosestPoints(world1, world2, dir) => {
+                    self.last_gjk_dir = Some(dir);
+
+                    let local1 = ma.inverse_transform_point(&world1);
+                    let local2 = mb.inverse_transform_point(&world2);
+                    let n1 = ma.inverse_transform_unit_vector(&dir);
+                    let n2 = mb.inverse_transform_unit_vector(&-dir);
+                    let mut kinematic = ContactKinematic::new();
+
+                    let ((f1, geom1), (f2, geom2)) =
+                        match (polyhedral_approximation(a, &n1), polyhedral_approximation(b, &n2)) {
+                            (Some(approx1), Some(approx2)) => (approx1, approx2),
+                            (Some(approx1), None) => {
+                                let approx2 = tangent_approximation(&n2, &approx1.1);
+                                (approx1, approx2)
+                            }
+                            (None, Some(approx2)) => {
+                                let approx1 = tangent_approximation(&n1, &approx2.1);
+                                (approx1, approx2)
+                            }
+                            (None, None) => (
+                                (FeatureId::Face(0), NeighborhoodGeometry::Plane(n1)),
+                                (FeatureId::Face(0), NeighborhoodGeometry::Point),
+                            ),
+                        };
+
+                    kinematic.set_approx1(f1, local1, geom1);
+                    kinematic.set_approx2(f2, local2, geom2);
+
+                    // Make the contact consistent with its approximation, e.g., use the normal of
+                    // the face of a polyhedron instead of the less accurate one from GJK or EPA.
+                    let contact = kinematic
+                        .contact(ma, a, None, mb, b, None, &n1)
+                        .unwrap_or_else(|| Contact::new_wo_depth(world1, world2, dir));
+
+                    let _ = manifold.push(contact, kinematic, local1, proc1, proc2, id_alloc);
+                }
+                GJKResult::NoIntersection(dir) => self.last_gjk_dir = Some(dir),
+                _ => {}
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// The feature of `shape` with the outward normal `normal` and its neighborhood, if `shape` is
+// a convex polyhedron.
+fn polyhedral_approximation<N: RealField>(
+    shape: &dyn Shape<N>,
+    normal: &Unit<Vector<N>>,
+) -> Option<(FeatureId, NeighborhoodGeometry<N>)>
+{
+    let cp = shape.as_convex_polyhedron()?;
+    let feature = cp.support_feature_id_toward(normal);
+    let geom = match feature {
+        FeatureId::Face(_) => NeighborhoodGeometry::Plane(cp.feature_normal(feature)),
+        #[cfg(feature = "dim3")]
+        FeatureId::Edge(_) => {
+            let edge = cp.edge(feature);
+            NeighborhoodGeometry::Line(Unit::new_normalize(edge.1 - edge.0))
+        }
+        _ => NeighborhoodGeometry::Point,
+    };
+
+    Some((feature, geom))
+}
+
+// The approximation of a shape without polyhedral features by its tangent plane with the
+// outward normal `normal`. The contact kinematic cannot pair a plane with anything but a
+// point, so a point is used otherwise.
+fn tangent_approximation<N: RealField>(
+    normal: &Unit<Vector<N>>,
+    other: &NeighborhoodGeometry<N>,
+) -> (FeatureId, NeighborhoodGeometry<N>)
+{
+    match other {
+        NeighborhoodGeometry::Point => (FeatureId::Face(0), NeighborhoodGeometry::Plane(*normal)),
+        _ => (FeatureId::Face(0), NeighborhoodGeometry::Point),
+    }
+}
@@ -0,0 +1,106 @@
+use na::RealField;
+
+use crate::pipeline::world::{CollisionGroupsBitSet, CollisionObject};
+use crate::query::ContactManifold;
+use crate::utils::IdAllocator;
+
+/// A user-defined hook called by the narrow phase after a contact manifold has been generated.
+///
+/// This can be used to modify the contacts between two specific collision objects, e.g., to
+/// implement one-way platforms. Contacts can be modified in-place with
+/// `manifold.contacts_mut()`, disabled with `manifold.retain(...)`, or added with
+/// `manifold.push(...)`. Note that `manifold.retain(|_| true)` should be called after the
+/// penetration depth of some contacts has been modified in-place in order to keep track of the
+/// deepest contact.
+pub trait ContactManifoldModifier<N: RealField, T, B: CollisionGroupsBitSet = u32>: Send + Sync {
+    /// Modifies the contact manifold computed between `co1` and `co2`.
+    ///
+    /// The identifiers of the contacts added to `manifold` must be allocated by `id_alloc`.
+    fn modify_manifold(
+        &self,
+        co1: &CollisionObject<N, T, B>,
+        co2: &CollisionObject<N, T, B>,
+        manifold: &mut ContactManifold<N>,
+        id_alloc: &mut IdAllocator,
+    );
+}
+
+/// Contact manifold modifiers called one after the other by the narrow phase.
+pub struct ContactManifoldModifiers<N: RealField, T, B: CollisionGroupsBitSet = u32> {
+    modifiers: Vec<(String, Box<dyn ContactManifoldModifier<N, T, B>>)>,
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<N: RealField, T, B: CollisionGroupsBitSet> serde::Serialize for ContactManifoldModifiers<N, T, B> {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        unimplemented!()
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, N: RealField, T, B: CollisionGroupsBitSet> serde::Deserialize<'de> for ContactManifoldModifiers<N, T, B> {
+    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<ContactManifoldModifiers<N, T, B>, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl<N: RealField, T, B: CollisionGroupsBitSet> ContactManifoldModifiers<N, T, B> {
+    /// Creates a new empty set of contact manifold modifiers.
+    pub fn new() -> ContactManifoldModifiers<N, T, B> {
+        ContactManifoldModifiers {
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Registers a contact manifold modifier.
+    ///
+    /// If a modifier named `name` already exists, it is replaced by `modifier`.
+    pub fn register_contact_manifold_modifier(
+        &mut self,
+        name: &str,
+        modifier: Box<dyn ContactManifoldModifier<N, T, B>>,
+    )
+    {
+        for &mut (ref n, ref mut m) in self.modifiers.iter_mut() {
+            if name == &n[..] {
+                *m = modifier;
+                return;
+            }
+        }
+
+        self.modifiers.push((name.to_string(), modifier))
+    }
+
+    /// Unregisters a contact manifold modifier.
+    ///
+    /// Returns `true` if the modifier was found.
+    pub fn unregister_contact_manifold_modifier(&mut self, name: &str) -> bool {
+        match self.modifiers.iter().position(|m| m.0 == name) {
+            Some(i) => {
+                let _ = self.modifiers.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if no contact manifold modifier is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.modifiers.is_empty()
+    }
+
+    /// Applies all the registered modifiers, in registration order, to `manifold`.
+    pub fn modify_manifold(
+        &self,
+        co1: &CollisionObject<N, T, B>,
+        co2: &CollisionObject<N, T, B>,
+        manifold: &mut ContactManifold<N>,
+        id_alloc: &mut IdAllocator,
+    )
+    {
+        for &(_, ref m) in self.modifiers.iter() {
+            m.modify_manifold(co1, co2, manifold, id_alloc)
+        }
+    }
+}
@@ -7,14 +7,17 @@ pub use self::contact_generator::{
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
     ConvexPolyhedronConvexPolyhedronManifoldGenerator, DefaultContactDispatcher,
     PlaneBallManifoldGenerator, PlaneConvexPolyhedronManifoldGenerator, CapsuleShapeManifoldGenerator,
-    CapsuleCapsuleManifoldGenerator, HeightFieldShapeManifoldGenerator
+    CapsuleCapsuleManifoldGenerator, HeightFieldShapeManifoldGenerator, SupportMapSupportMapManifoldGenerator
 };
 #[cfg(feature = "dim3")]
-pub use self::contact_generator::{TriMeshTriMeshManifoldGenerator};
+pub use self::contact_generator::{SignedDistanceFieldShapeManifoldGenerator, TriMeshTriMeshManifoldGenerator};
+pub use self::contact_manifold_modifier::{ContactManifoldModifier, ContactManifoldModifiers};
 pub use self::narrow_phase::NarrowPhase;
 #[doc(inline)]
 pub use self::proximity_detector::{
-    BallBallProximityDetector, CompositeShapeShapeProximityDetector, DefaultProximityDispatcher,
+    BallBallProximityDetector, CompositeShapeShapePartwiseProximityDetector,
+    CompositeShapeShapeProximityDetector, DefaultProximityDispatcher,
+    HeightFieldShapeProximityDetector, PartProximities, PartwiseProximityDispatcher,
     PlaneSupportMapProximityDetector, ProximityAlgorithm, ProximityDetector, ProximityDispatcher,
     SupportMapPlaneProximityDetector, SupportMapSupportMapProximityDetector,
 };
@@ -22,6 +25,7 @@ pub use self::interaction_graph::{InteractionGraph, InteractionGraphIndex, Inter
 
 #[doc(hidden)]
 pub mod contact_generator;
+mod contact_manifold_modifier;
 mod narrow_phase;
 #[doc(hidden)]
 pub mod proximity_detector;
@@ -1,11 +1,22 @@
 use na::RealField;
+use std::collections::HashMap;
 
-use crate::pipeline::events::{ContactEvent, ContactEvents, ProximityEvent, ProximityEvents};
+use crate::pipeline::events::{
+    ContactEvent, ContactEvents, ContactManifoldSummary, DetailedContactEvent,
+    DetailedContactEvents, PartProximityEvent, PartProximityEvents, ProximityEvent,
+    ProximityEvents, TOIEvent, TOIEvents,
+};
 use crate::pipeline::narrow_phase::{
-    ContactDispatcher, ProximityDispatcher, InteractionGraph, Interaction, InteractionGraphIndex
+    ContactDispatcher, ProximityDispatcher, InteractionGraph, Interaction, InteractionGraphIndex,
+    ContactManifoldModifiers, PartProximities,
+};
+use crate::pipeline::world::{
+    CollisionGroupsBitSet, CollisionObjectHandle, CollisionObjectSlab, CollisionObject,
+    GeometricQueryType,
 };
-use crate::pipeline::world::{CollisionObjectHandle, CollisionObjectSlab, CollisionObject, GeometricQueryType};
-use crate::query::Proximity;
+use crate::math::Translation;
+use crate::query::{self, ContactManifold, Proximity};
+use crate::shape::{Plane, Shape};
 use crate::utils::IdAllocator;
 use crate::utils::SortedPair;
 
@@ -15,7 +26,10 @@ pub struct NarrowPhase<N: RealField> {
     id_alloc: IdAllocator,
     contact_dispatcher: Box<dyn ContactDispatcher<N>>,
     proximity_dispatcher: Box<dyn ProximityDispatcher<N>>,
-    interactions: InteractionGraph<N>
+    interactions: InteractionGraph<N>,
+    max_contacts_per_manifold: Option<usize>,
+    // Number of updates each pair has been in contact for, if detailed contact events are enabled.
+    contact_frames: Option<HashMap<SortedPair<CollisionObjectHandle>, usize>>,
 }
 
 #[cfg(feature = "serde-serialize")]
@@ -43,19 +57,58 @@ impl<N: RealField> NarrowPhase<N> {
             id_alloc: IdAllocator::new(),
             contact_dispatcher,
             proximity_dispatcher,
-            interactions: InteractionGraph::new()
+            interactions: InteractionGraph::new(),
+            max_contacts_per_manifold: None,
+            contact_frames: None,
+        }
+    }
+
+    /// Whether detailed contact events are generated by this narrow phase.
+    #[inline]
+    pub fn detailed_contact_events_enabled(&self) -> bool {
+        self.contact_frames.is_some()
+    }
+
+    /// Enables or disables the generation of detailed contact events.
+    ///
+    /// Those events are pushed to the `detailed_contact_events` pool given to `self.update` and
+    /// `self.handle_interaction`. They are disabled by default.
+    pub fn set_detailed_contact_events_enabled(&mut self, enabled: bool) {
+        if enabled != self.detailed_contact_events_enabled() {
+            self.contact_frames = if enabled { Some(HashMap::new()) } else { None };
         }
     }
 
+    /// The maximum number of contacts kept in each contact manifold, if any.
+    #[inline]
+    pub fn max_contacts_per_manifold(&self) -> Option<usize> {
+        self.max_contacts_per_manifold
+    }
+
+    /// Sets the maximum number of contacts kept in each contact manifold.
+    ///
+    /// If set, each contact manifold is reduced with `ContactManifold::reduce` after its
+    /// generation and the application of the contact manifold modifiers.
+    #[inline]
+    pub fn set_max_contacts_per_manifold(&mut self, max_contacts: Option<usize>) {
+        self.max_contacts_per_manifold = max_contacts
+    }
+
     /// Updates the narrow-phase by actually computing contact points and proximities between the
     /// interactions pairs reported by the broad-phase.
     ///
-    /// This will push relevant events to `contact_events` and `proximity_events`.
-    pub fn update<T>(
+    /// This will push relevant events to `contact_events`, `detailed_contact_events` (if enabled),
+    /// `proximity_events`, and `part_proximity_events`. The
+    /// `contact_modifiers` are applied to each contact manifold right after its generation, before
+    /// its reduction to `self.max_contacts_per_manifold()` contacts.
+    pub fn update<T, B: CollisionGroupsBitSet>(
         &mut self,
-        objects: &CollisionObjectSlab<N, T>,
+        objects: &CollisionObjectSlab<N, T, B>,
+        contact_modifiers: &ContactManifoldModifiers<N, T, B>,
         contact_events: &mut ContactEvents,
+        detailed_contact_events: &mut DetailedContactEvents<N>,
         proximity_events: &mut ProximityEvents,
+        part_proximity_events: &mut PartProximityEvents,
         timestamp: usize,
     )
     {
@@ -68,6 +121,11 @@ impl<N: RealField> NarrowPhase<N> {
                 match self.interactions.graph.edge_weight_mut(eid).unwrap() {
                     Interaction::Contact(detector, manifold) => {
                         let had_contacts = manifold.len() != 0;
+                        let prev_summary = if had_contacts && self.contact_frames.is_some() {
+                            ContactManifoldSummary::new(manifold)
+                        } else {
+                            None
+                        };
 
                         if let Some(prediction) = co1
                             .query_type()
@@ -78,14 +136,20 @@ impl<N: RealField> NarrowPhase<N> {
                                     &*self.contact_dispatcher,
                                     &co1.position(),
                                     co1.shape().as_ref(),
-                                    None,
+                                    co1.contact_preprocessor(),
                                     &co2.position(),
                                     co2.shape().as_ref(),
-                                    None,
+                                    co2.contact_preprocessor(),
                                     &prediction,
                                     &mut self.id_alloc,
                                     manifold,
                                 );
+
+                                contact_modifiers.modify_manifold(co1, co2, manifold, &mut self.id_alloc);
+
+                                if let Some(max_contacts) = self.max_contacts_per_manifold {
+                                    manifold.reduce(max_contacts);
+                                }
                             } else {
                             panic!("Unable to compute contact between collision objects with query types different from `GeometricQueryType::Contacts(..)`.")
                         }
@@ -93,11 +157,27 @@ impl<N: RealField> NarrowPhase<N> {
                         if manifold.len() == 0 {
                             if had_contacts {
                                 contact_events.push(ContactEvent::Stopped(co1.handle(), co2.handle()));
+
+                                if let (Some(frames), Some(summary)) = (&mut self.contact_frames, prev_summary) {
+                                    let _ = frames.remove(&SortedPair::new(co1.handle(), co2.handle()));
+                                    detailed_contact_events.push(DetailedContactEvent::Stopped(co1.handle(), co2.handle(), summary));
+                                }
                             }
                         } else {
                             if !had_contacts {
                                 contact_events.push(ContactEvent::Started(co1.handle(), co2.handle()));
                             }
+
+                            if let Some(frames) = &mut self.contact_frames {
+                                push_contact_started_or_persisted(
+                                    frames,
+                                    detailed_contact_events,
+                                    co1.handle(),
+                                    co2.handle(),
+                                    manifold,
+                                    !had_contacts,
+                                );
+                            }
                         }
                     }
                     Interaction::Proximity(detector) => {
@@ -122,18 +202,106 @@ impl<N: RealField> NarrowPhase<N> {
                                 new_prox,
                             ));
                         }
+
+                        if let Some(parts) = detector.part_proximities() {
+                            let changes = parts.changes().iter().cloned();
+                            push_part_proximity_events(part_proximity_events, parts, co1.handle(), co2.handle(), changes);
+                        }
+                    }
+                }
+            } else if let Some(frames) = &mut self.contact_frames {
+                // The contact manifold did not change but the contact still persists.
+                if let Interaction::Contact(_, manifold) = &self.interactions.graph[eid] {
+                    if manifold.len() != 0 {
+                        push_contact_started_or_persisted(
+                            frames,
+                            detailed_contact_events,
+                            co1.handle(),
+                            co2.handle(),
+                            manifold,
+                            false,
+                        );
                     }
                 }
             }
         }
     }
 
+    /// Computes the time of impact of each interaction pair involving at least one collision object
+    /// with continuous collision detection enabled that moved since its previous position.
+    ///
+    /// The motion of each collision object is approximated by a translation from its previous
+    /// position to its current one. Pairs already touching at the beginning of this motion are
+    /// ignored as they are handled by the discrete contact and proximity computations.
+    pub fn compute_times_of_impact<T, B: CollisionGroupsBitSet>(
+        &self,
+        objects: &CollisionObjectSlab<N, T, B>,
+        toi_events: &mut TOIEvents<N>,
+    )
+    {
+        for eid in self.interactions.graph.edge_indices() {
+            let (id1, id2) = self.interactions.graph.edge_endpoints(eid).unwrap();
+            let co1 = &objects[self.interactions.graph[id1]];
+            let co2 = &objects[self.interactions.graph[id2]];
+            let (prev1, prev2) = (co1.previous_position(), co2.previous_position());
+            let vel1 = co1.position().translation.vector - prev1.translation.vector;
+            let vel2 = co2.position().translation.vector - prev2.translation.vector;
+
+            if vel1 == vel2
+                || !is_toi_supported(co1.shape().as_ref(), co2.shape().as_ref())
+            {
+                continue;
+            }
+
+            let toi = query::time_of_impact(
+                prev1,
+                &vel1,
+                co1.shape().as_ref(),
+                prev2,
+                &vel2,
+                co2.shape().as_ref(),
+            );
+
+            if let Some(toi) = toi {
+                if toi <= N::zero() || toi > N::one() {
+                    continue;
+                }
+
+                let pos1 = Translation::from(vel1 * toi) * prev1;
+                let pos2 = Translation::from(vel2 * toi) * prev2;
+                let prediction = co1.query_type().query_limit()
+                    + co2.query_type().query_limit()
+                    + N::default_epsilon().sqrt();
+                let contact = query::contact(
+                    &pos1,
+                    co1.shape().as_ref(),
+                    &pos2,
+                    co2.shape().as_ref(),
+                    prediction,
+                );
+
+                if let Some(contact) = contact {
+                    toi_events.push(TOIEvent {
+                        collider1: co1.handle(),
+                        collider2: co2.handle(),
+                        toi,
+                        world1: contact.world1,
+                        world2: contact.world2,
+                        normal: contact.normal,
+                    });
+                }
+            }
+        }
+    }
+
     /// Handles a pair of collision objects detected as either started or stopped interacting.
-    pub fn handle_interaction<T>(
+    pub fn handle_interaction<T, B: CollisionGroupsBitSet>(
         &mut self,
         contact_events: &mut ContactEvents,
+        detailed_contact_events: &mut DetailedContactEvents<N>,
         proximity_events: &mut ProximityEvents,
-        objects: &CollisionObjectSlab<N, T>,
+        part_proximity_events: &mut PartProximityEvents,
+        objects: &CollisionObjectSlab<N, T, B>,
         handle1: CollisionObjectHandle,
         handle2: CollisionObjectHandle,
         started: bool,
@@ -177,6 +345,12 @@ impl<N: RealField> NarrowPhase<N> {
                             // Register a collision lost event if there was a contact.
                             if manifold.len() != 0 {
                                 contact_events.push(ContactEvent::Stopped(co1.handle(), co2.handle()));
+
+                                if let Some(frames) = &mut self.contact_frames {
+                                    let _ = frames.remove(&key);
+                                    let summary = ContactManifoldSummary::new(&manifold).unwrap();
+                                    detailed_contact_events.push(DetailedContactEvent::Stopped(co1.handle(), co2.handle(), summary));
+                                }
                             }
 
                             manifold.clear(&mut self.id_alloc);
@@ -194,6 +368,14 @@ impl<N: RealField> NarrowPhase<N> {
                                 );
                                 proximity_events.push(event);
                             }
+
+                            if let Some(parts) = detector.part_proximities() {
+                                let changes = parts
+                                    .proximities()
+                                    .iter()
+                                    .map(|p| (p.0, p.1, Proximity::Disjoint));
+                                push_part_proximity_events(part_proximity_events, parts, co1.handle(), co2.handle(), changes);
+                            }
                         }
                     }
                 }
@@ -202,9 +384,9 @@ impl<N: RealField> NarrowPhase<N> {
     }
 
     /// Handles the removal of a collision object.
-    pub fn handle_removal<T>(
+    pub fn handle_removal<T, B: CollisionGroupsBitSet>(
         &mut self,
-        objects: &CollisionObjectSlab<N, T>,
+        objects: &CollisionObjectSlab<N, T, B>,
         handle1: CollisionObjectHandle,
         handle2: CollisionObjectHandle,
     )
@@ -218,6 +400,10 @@ impl<N: RealField> NarrowPhase<N> {
             if let Some(Interaction::Contact(_, mut manifold)) = interaction {
                 manifold.clear(&mut self.id_alloc)
             }
+
+            if let Some(frames) = &mut self.contact_frames {
+                let _ = frames.remove(&key);
+            }
         }
     }
 
@@ -227,12 +413,17 @@ impl<N: RealField> NarrowPhase<N> {
     }
 
     /// Handles the removal of a collision object.
-    pub fn handle_collision_object_removed<T>(&mut self, object: &CollisionObject<N, T>) -> Option<CollisionObjectHandle> {
+    pub fn handle_collision_object_removed<T, B: CollisionGroupsBitSet>(&mut self, object: &CollisionObject<N, T, B>) -> Option<CollisionObjectHandle> {
         let id = object.graph_index();
         let mut nbhs = self.interactions.graph.neighbors(id).detach();
 
         // Clear all the manifold to avoid leaking contact IDs.
-        while let Some((eid, _)) = nbhs.next(&self.interactions.graph) {
+        while let Some((eid, nid)) = nbhs.next(&self.interactions.graph) {
+            if let Some(frames) = &mut self.contact_frames {
+                let other = self.interactions.graph[nid];
+                let _ = frames.remove(&SortedPair::new(object.handle(), other));
+            }
+
             match self.interactions.graph.edge_weight_mut(eid).unwrap() {
                 Interaction::Contact(_, manifold) => manifold.clear(&mut self.id_alloc),
                 Interaction::Proximity(_) => {}
@@ -248,3 +439,55 @@ impl<N: RealField> NarrowPhase<N> {
         &self.interactions
     }
 }
+
+fn push_contact_started_or_persisted<N: RealField>(
+    frames: &mut HashMap<SortedPair<CollisionObjectHandle>, usize>,
+    events: &mut DetailedContactEvents<N>,
+    handle1: CollisionObjectHandle,
+    handle2: CollisionObjectHandle,
+    manifold: &ContactManifold<N>,
+    started: bool,
+)
+{
+    let summary = ContactManifoldSummary::new(manifold).unwrap();
+    let nframes = frames.entry(SortedPair::new(handle1, handle2)).or_insert(0);
+
+    if started {
+        *nframes = 1;
+        events.push(DetailedContactEvent::Started(handle1, handle2, summary));
+    } else {
+        *nframes += 1;
+        events.push(DetailedContactEvent::Persisted(handle1, handle2, summary, *nframes));
+    }
+}
+
+// Whether `query::time_of_impact` knows how to handle this pair of shapes.
+fn is_toi_supported<N: RealField>(g1: &dyn Shape<N>, g2: &dyn Shape<N>) -> bool {
+    let supported = |g: &dyn Shape<N>| g.is_support_map() || g.is_composite_shape() || g.is_shape::<Plane<N>>();
+    supported(g1) && supported(g2) && !(g1.is_shape::<Plane<N>>() && g2.is_shape::<Plane<N>>())
+}
+
+fn push_part_proximity_events(
+    events: &mut PartProximityEvents,
+    parts: &PartProximities,
+    handle1: CollisionObjectHandle,
+    handle2: CollisionObjectHandle,
+    changes: impl Iterator<Item = (usize, Proximity, Proximity)>,
+)
+{
+    let (collider, other_collider) = if parts.parts_of_second_shape() {
+        (handle2, handle1)
+    } else {
+        (handle1, handle2)
+    };
+
+    for (part, prev_status, new_status) in changes {
+        events.push(PartProximityEvent {
+            collider,
+            part,
+            other_collider,
+            prev_status,
+            new_status,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use crate::bounding_volume::{self, BoundingVolume};
+use crate::math::Isometry;
+use na::RealField;
+use crate::pipeline::narrow_phase::proximity_detector::PartProximities;
+use crate::pipeline::narrow_phase::{ProximityAlgorithm, ProximityDetector, ProximityDispatcher};
+use crate::query::Proximity;
+use crate::shape::{CompositeShape, Shape};
+use std::collections::{hash_map::Entry, HashMap};
+use crate::utils::DeterministicState;
+
+/// Proximity detector between a concave shape and another shape, keeping track of the proximity
+/// of each part of the concave shape.
+///
+/// Contrary to the `CompositeShapeShapeProximityDetector`, this tests all the parts with an AABB
+/// intersecting the other shape instead of stopping at the first intersecting part.
+pub struct CompositeShapeShapePartwiseProximityDetector<N> {
+    sub_detectors: HashMap<usize, (ProximityAlgorithm<N>, usize), DeterministicState>,
+    interferences: Vec<usize>,
+    parts: PartProximities,
+    timestamp: usize,
+}
+
+impl<N> CompositeShapeShapePartwiseProximityDetector<N> {
+    /// Creates a new part-wise proximity detector between a concave shape and another shape.
+    pub fn new(flip: bool) -> CompositeShapeShapePartwiseProximityDetector<N> {
+        CompositeShapeShapePartwiseProximityDetector {
+            sub_detectors: HashMap::with_hasher(DeterministicState),
+            interferences: Vec::new(),
+            parts: PartProximities::new(flip),
+            timestamp: 0,
+        }
+    }
+}
+
+impl<N: RealField> CompositeShapeShapePartwiseProximityDetector<N> {
+    fn do_update(
+        &mut self,
+        dispatcher: &dyn ProximityDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &dyn CompositeShape<N>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        margin: N,
+        flip: bool,
+    )
+    {
+        self.timestamp += 1;
+        self.interferences.clear();
+
+        let m12 = m1.inverse() * m2.clone();
+        let ls_aabb2 = bounding_volume::aabb(g2, &m12).loosened(margin);
+
+        g1.parts_intersecting_local_aabb(&ls_aabb2, &mut self.interferences);
+
+        let mut proximities = Vec::new();
+
+        for key in &self.interferences {
+            if g1.is_aabb_behind_part(*key, &ls_aabb2) {
+                // This part ignores objects located behind it.
+                continue;
+            }
+
+            let detector = match self.sub_detectors.entry(*key) {
+                Entry::Occupied(entry) => Some(entry.into_mut()),
+                Entry::Vacant(entry) => {
+                    let mut new_detector = None;
+
+                    g1.map_part_at(*key, &Isometry::identity(), &mut |_, g1| {
+                        if flip {
+                            new_detector = dispatcher.get_proximity_algorithm(g2, g1)
+                        } else {
+                            new_detector = dispatcher.get_proximity_algorithm(g1, g2)
+                        }
+                    });
+
+                    new_detector.map(|d| entry.insert((d, 0)))
+                }
+            };
+
+            if let Some(sub_detector) = detector {
+                g1.map_part_at(*key, m1, &mut |m1, g1| {
+                    if flip {
+                        let _ = sub_detector.0.update(dispatcher, m2, g2, m1, g1, margin);
+                    } else {
+                        let _ = sub_detector.0.update(dispatcher, m1, g1, m2, g2, margin);
+                    }
+                });
+
+                sub_detector.1 = self.timestamp;
+                proximities.push((*key, sub_detector.0.proximity()));
+            }
+        }
+
+        // Remove outdated sub detectors.
+        let timestamp = self.timestamp;
+        self.sub_detectors.retain(|_, detector| detector.1 == timestamp);
+        self.parts.set_proximities(proximities);
+    }
+}
+
+impl<N: RealField> ProximityDetector<N> for CompositeShapeShapePartwiseProximityDetector<N> {
+    fn update(
+        &mut self,
+        dispatcher: &dyn ProximityDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        margin: N,
+    ) -> bool
+    {
+        if !self.parts.parts_of_second_shape() {
+            if let Some(cs) = g1.as_composite_shape() {
+                self.do_update(dispatcher, m1, cs, m2, g2, margin, false);
+                return true;
+            }
+        } else {
+            if let Some(cs) = g2.as_composite_shape() {
+                self.do_update(dispatcher, m2, cs, m1, g1, margin, true);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn proximity(&self) -> Proximity {
+        self.parts.proximity()
+    }
+
+    fn part_proximities(&self) -> Option<&PartProximities> {
+        Some(&self.parts)
+    }
+}
@@ -2,7 +2,7 @@ use crate::bounding_volume::{self, BoundingVolume};
 use crate::math::Isometry;
 use na::{self, RealField};
 use crate::pipeline::narrow_phase::{ProximityAlgorithm, ProximityDetector, ProximityDispatcher};
-use crate::query::{visitors::BoundingVolumeInterferencesCollector, Proximity};
+use crate::query::Proximity;
 use crate::shape::{CompositeShape, Shape};
 use std::collections::{hash_map::Entry, HashMap};
 use crate::utils::DeterministicState;
@@ -51,8 +51,13 @@ impl<N: RealField> CompositeShapeShapeProximityDetector<N> {
         self.to_delete.clear();
         self.interferences.clear();
 
+        let m12 = m1.inverse() * m2.clone();
+        let ls_aabb2 = bounding_volume::aabb(g2, &m12).loosened(margin);
+
         // First, test if the previously intersecting shapes are still intersecting.
-        if self.proximity == Proximity::Intersecting {
+        if self.proximity == Proximity::Intersecting
+            && !g1.is_aabb_behind_part(self.intersecting_key, &ls_aabb2)
+        {
             let detector = self.sub_detectors.get_mut(&self.intersecting_key).unwrap();
             g1.map_part_at(self.intersecting_key, m1, &mut |m1, g1| {
                 assert!(
@@ -70,9 +75,6 @@ impl<N: RealField> CompositeShapeShapeProximityDetector<N> {
 
         self.proximity = Proximity::Disjoint;
 
-        let m12 = m1.inverse() * m2.clone();
-        let ls_aabb2 = bounding_volume::aabb(g2, &m12).loosened(margin);
-
         // Update all collisions
         for detector in &mut self.sub_detectors {
             let key = *detector.0;
@@ -82,7 +84,7 @@ impl<N: RealField> CompositeShapeShapeProximityDetector<N> {
                 continue;
             }
 
-            if ls_aabb2.intersects(&g1.aabb_at(key)) {
+            if ls_aabb2.intersects(&g1.aabb_at(key)) && !g1.is_aabb_behind_part(key, &ls_aabb2) {
                 g1.map_part_at(key, m1, &mut |m1, g1| {
                     assert!(
                         detector.1.update(dispatcher, m1, g1, m2, g2, margin),
@@ -106,13 +108,14 @@ impl<N: RealField> CompositeShapeShapeProximityDetector<N> {
         }
 
         // Find new proximities.
-        {
-            let mut visitor =
-                BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut self.interferences);
-            g1.bvh().visit(&mut visitor);
-        }
+        g1.parts_intersecting_local_aabb(&ls_aabb2, &mut self.interferences);
 
         for key in &self.interferences {
+            if g1.is_aabb_behind_part(*key, &ls_aabb2) {
+                // This part ignores objects located behind it.
+                continue;
+            }
+
             let entry = self.sub_detectors.entry(*key);
             let detector = match entry {
                 Entry::Occupied(entry) => Some(entry.into_mut()),
@@ -0,0 +1,120 @@
+use crate::bounding_volume::{self, BoundingVolume};
+use crate::math::Isometry;
+use na::RealField;
+use crate::pipeline::narrow_phase::proximity_detector::PartProximities;
+use crate::pipeline::narrow_phase::{ProximityAlgorithm, ProximityDetector, ProximityDispatcher};
+use crate::query::Proximity;
+use crate::shape::{HeightField, Shape};
+use std::collections::{hash_map::Entry, HashMap};
+use crate::utils::DeterministicState;
+
+/// Proximity detector between an heightfield and another shape, keeping track of the proximity
+/// of each element of the heightfield.
+pub struct HeightFieldShapeProximityDetector<N> {
+    sub_detectors: HashMap<usize, (ProximityAlgorithm<N>, usize), DeterministicState>,
+    parts: PartProximities,
+    timestamp: usize,
+}
+
+impl<N> HeightFieldShapeProximityDetector<N> {
+    /// Creates a new proximity detector between an heightfield and another shape.
+    pub fn new(flip: bool) -> HeightFieldShapeProximityDetector<N> {
+        HeightFieldShapeProximityDetector {
+            sub_detectors: HashMap::with_hasher(DeterministicState),
+            parts: PartProximities::new(flip),
+            timestamp: 0,
+        }
+    }
+}
+
+impl<N: RealField> HeightFieldShapeProximityDetector<N> {
+    fn do_update(
+        &mut self,
+        dispatcher: &dyn ProximityDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &HeightField<N>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        margin: N,
+        flip: bool,
+    )
+    {
+        self.timestamp += 1;
+
+        let ls_m2 = m1.inverse() * m2.clone();
+        let ls_aabb2 = bounding_volume::aabb(g2, &ls_m2).loosened(margin);
+        let timestamp = self.timestamp;
+        let sub_detectors = &mut self.sub_detectors;
+        let mut proximities = Vec::new();
+
+        g1.map_elements_in_local_aabb(&ls_aabb2, &mut |i, elt1, _| {
+            if g1.is_aabb_behind_element(elt1, &ls_aabb2) {
+                // This element ignores objects located behind it.
+                return;
+            }
+
+            let detector = match sub_detectors.entry(i) {
+                Entry::Occupied(entry) => Some(entry.into_mut()),
+                Entry::Vacant(entry) => {
+                    let new_detector = if flip {
+                        dispatcher.get_proximity_algorithm(g2, elt1)
+                    } else {
+                        dispatcher.get_proximity_algorithm(elt1, g2)
+                    };
+
+                    new_detector.map(|d| entry.insert((d, 0)))
+                }
+            };
+
+            if let Some(sub_detector) = detector {
+                if flip {
+                    let _ = sub_detector.0.update(dispatcher, m2, g2, m1, elt1, margin);
+                } else {
+                    let _ = sub_detector.0.update(dispatcher, m1, elt1, m2, g2, margin);
+                }
+
+                sub_detector.1 = timestamp;
+                proximities.push((i, sub_detector.0.proximity()));
+            }
+        });
+
+        // Remove outdated sub detectors.
+        self.sub_detectors.retain(|_, detector| detector.1 == timestamp);
+        self.parts.set_proximities(proximities);
+    }
+}
+
+impl<N: RealField> ProximityDetector<N> for HeightFieldShapeProximityDetector<N> {
+    fn update(
+        &mut self,
+        dispatcher: &dyn ProximityDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        margin: N,
+    ) -> bool
+    {
+        if !self.parts.parts_of_second_shape() {
+            if let Some(hf) = g1.as_shape::<HeightField<N>>() {
+                self.do_update(dispatcher, m1, hf, m2, g2, margin, false);
+                return true;
+            }
+        } else {
+            if let Some(hf) = g2.as_shape::<HeightField<N>>() {
+                self.do_update(dispatcher, m2, hf, m1, g1, margin, true);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn proximity(&self) -> Proximity {
+        self.parts.proximity()
+    }
+
+    fn part_proximities(&self) -> Option<&PartProximities> {
+        Some(&self.parts)
+    }
+}
@@ -1,8 +1,12 @@
 //! Persistant proximity detection algorithms.
 
 pub use self::ball_ball_proximity_detector::BallBallProximityDetector;
+pub use self::composite_shape_shape_partwise_proximity_detector::CompositeShapeShapePartwiseProximityDetector;
 pub use self::composite_shape_shape_proximity_detector::CompositeShapeShapeProximityDetector;
 pub use self::default_proximity_dispatcher::DefaultProximityDispatcher;
+pub use self::heightfield_shape_proximity_detector::HeightFieldShapeProximityDetector;
+pub use self::part_proximities::PartProximities;
+pub use self::partwise_proximity_dispatcher::PartwiseProximityDispatcher;
 pub use self::plane_support_map_proximity_detector::{
     PlaneSupportMapProximityDetector, SupportMapPlaneProximityDetector,
 };
@@ -10,8 +14,12 @@ pub use self::proximity_detector::{ProximityAlgorithm, ProximityDetector, Proxim
 pub use self::support_map_support_map_proximity_detector::SupportMapSupportMapProximityDetector;
 
 mod ball_ball_proximity_detector;
+mod composite_shape_shape_partwise_proximity_detector;
 mod composite_shape_shape_proximity_detector;
 mod default_proximity_dispatcher;
+mod heightfield_shape_proximity_detector;
+mod part_proximities;
+mod partwise_proximity_dispatcher;
 mod plane_support_map_proximity_detector;
 #[doc(hidden)]
 pub mod proximity_detector;
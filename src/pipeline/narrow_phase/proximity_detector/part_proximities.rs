@@ -0,0 +1,98 @@
+use crate::query::Proximity;
+
+/// The proximity status of each part of a shape (e.g. the triangles of a mesh or the cells of an
+/// heightfield) with another shape.
+#[derive(Clone, Debug)]
+pub struct PartProximities {
+    flip: bool,
+    // Sorted by part identifier. Disjoint parts are not stored.
+    proximities: Vec<(usize, Proximity)>,
+    changes: Vec<(usize, Proximity, Proximity)>,
+}
+
+impl PartProximities {
+    /// Creates an empty set of part proximities.
+    ///
+    /// If `flip` is `true`, the parts are those of the second shape given to the proximity
+    /// detector owning this set.
+    pub fn new(flip: bool) -> PartProximities {
+        PartProximities {
+            flip,
+            proximities: Vec::new(),
+            changes: Vec::new(),
+        }
+    }
+
+    /// Whether the parts are those of the second shape given to the proximity detector.
+    #[inline]
+    pub fn parts_of_second_shape(&self) -> bool {
+        self.flip
+    }
+
+    /// The identifier and proximity status of each part that is not disjoint from the other shape.
+    #[inline]
+    pub fn proximities(&self) -> &[(usize, Proximity)] {
+        &self.proximities[..]
+    }
+
+    /// The identifiers of the parts intersecting the other shape.
+    pub fn intersecting_parts<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        self.proximities
+            .iter()
+            .filter(|p| p.1 == Proximity::Intersecting)
+            .map(|p| p.0)
+    }
+
+    /// The identifier, previous, and new proximity status of each part which status changed
+    /// during the last update.
+    #[inline]
+    pub fn changes(&self) -> &[(usize, Proximity, Proximity)] {
+        &self.changes[..]
+    }
+
+    /// The proximity of the whole shape, i.e., the closest proximity status among all its parts.
+    pub fn proximity(&self) -> Proximity {
+        let mut result = Proximity::Disjoint;
+
+        for p in &self.proximities {
+            match p.1 {
+                Proximity::Intersecting => return Proximity::Intersecting,
+                Proximity::WithinMargin => result = Proximity::WithinMargin,
+                Proximity::Disjoint => {}
+            }
+        }
+
+        result
+    }
+
+    /// Replaces the proximity status of all the parts, and records the changes.
+    ///
+    /// Parts missing from `proximities` are considered disjoint.
+    pub fn set_proximities(&mut self, mut proximities: Vec<(usize, Proximity)>) {
+        proximities.retain(|p| p.1 != Proximity::Disjoint);
+        proximities.sort_by_key(|p| p.0);
+        self.changes.clear();
+
+        let (mut i, mut j) = (0, 0);
+        let (old, new) = (&self.proximities, &proximities);
+
+        while i < old.len() || j < new.len() {
+            if j == new.len() || (i < old.len() && old[i].0 < new[j].0) {
+                self.changes.push((old[i].0, old[i].1, Proximity::Disjoint));
+                i += 1;
+            } else if i == old.len() || new[j].0 < old[i].0 {
+                self.changes.push((new[j].0, Proximity::Disjoint, new[j].1));
+                j += 1;
+            } else {
+                if old[i].1 != new[j].1 {
+                    self.changes.push((old[i].0, old[i].1, new[j].1));
+                }
+
+                i += 1;
+                j += 1;
+            }
+        }
+
+        self.proximities = proximities;
+    }
+}
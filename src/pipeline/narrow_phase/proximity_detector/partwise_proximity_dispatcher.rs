@@ -0,0 +1,60 @@
+use na::RealField;
+#[cfg(feature = "dim3")]
+use crate::pipeline::narrow_phase::proximity_detector::CompositeShapeShapePartwiseProximityDetector;
+use crate::pipeline::narrow_phase::proximity_detector::{
+    DefaultProximityDispatcher, HeightFieldShapeProximityDetector, ProximityAlgorithm,
+    ProximityDispatcher,
+};
+use crate::shape::{HeightField, Shape};
+#[cfg(feature = "dim3")]
+use crate::shape::TriMesh;
+
+/// Proximity dispatcher keeping track of the proximity of each part of heightfields and triangle
+/// meshes.
+///
+/// Those detectors test every part overlapping the other shape instead of stopping at the first
+/// intersection, so they are more expensive than the ones selected by the
+/// `DefaultProximityDispatcher`. Every other pair of shapes is handled by the
+/// `DefaultProximityDispatcher`.
+pub struct PartwiseProximityDispatcher {
+    default: DefaultProximityDispatcher,
+}
+
+impl PartwiseProximityDispatcher {
+    /// Creates a new proximity dispatcher tracking the proximity of each part of composite shapes.
+    pub fn new() -> PartwiseProximityDispatcher {
+        PartwiseProximityDispatcher {
+            default: DefaultProximityDispatcher::new(),
+        }
+    }
+}
+
+impl<N: RealField> ProximityDispatcher<N> for PartwiseProximityDispatcher {
+    fn get_proximity_algorithm(&self, a: &dyn Shape<N>, b: &dyn Shape<N>) -> Option<ProximityAlgorithm<N>> {
+        if a.is_shape::<HeightField<N>>() {
+            Some(Box::new(HeightFieldShapeProximityDetector::new(false)))
+        } else if b.is_shape::<HeightField<N>>() {
+            Some(Box::new(HeightFieldShapeProximityDetector::new(true)))
+        } else if let Some(detector) = trimesh_proximity_detector(a, b) {
+            Some(detector)
+        } else {
+            self.default.get_proximity_algorithm(a, b)
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn trimesh_proximity_detector<N: RealField>(a: &dyn Shape<N>, b: &dyn Shape<N>) -> Option<ProximityAlgorithm<N>> {
+    if a.is_shape::<TriMesh<N>>() {
+        Some(Box::new(CompositeShapeShapePartwiseProximityDetector::new(false)))
+    } else if b.is_shape::<TriMesh<N>>() {
+        Some(Box::new(CompositeShapeShapePartwiseProximityDetector::new(true)))
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "dim2")]
+fn trimesh_proximity_detector<N: RealField>(_: &dyn Shape<N>, _: &dyn Shape<N>) -> Option<ProximityAlgorithm<N>> {
+    None
+}
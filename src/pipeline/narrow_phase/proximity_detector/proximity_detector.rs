@@ -1,5 +1,6 @@
 use crate::math::Isometry;
 use na::RealField;
+use crate::pipeline::narrow_phase::proximity_detector::PartProximities;
 use crate::query::Proximity;
 use crate::shape::Shape;
 use std::any::Any;
@@ -20,6 +21,11 @@ pub trait ProximityDetector<N: RealField>: Any + Send + Sync {
 
     /// The number of collision detected during the last update.
     fn proximity(&self) -> Proximity;
+
+    /// The proximity status of each part of the shape, if this detector keeps track of them.
+    fn part_proximities(&self) -> Option<&PartProximities> {
+        None
+    }
 }
 
 pub type ProximityAlgorithm<N> = Box<dyn ProximityDetector<N>>;
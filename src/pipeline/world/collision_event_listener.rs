@@ -0,0 +1,106 @@
+use na::RealField;
+
+use crate::pipeline::world::{CollisionGroupsBitSet, CollisionObject};
+use crate::query::{ContactManifold, Proximity};
+
+/// A user-defined set of callbacks invoked by the collision world whenever a collision event occurs.
+///
+/// All the methods do nothing by default. They are called at the end of `CollisionWorld::update`,
+/// in the following order: broad phase pair events, contact events, and proximity events. The
+/// collision objects passed to each method give access to their user-defined data.
+pub trait CollisionEventListener<N: RealField, T, B: CollisionGroupsBitSet = u32>: Send + Sync {
+    /// Called when `co1` and `co2` start being in contact.
+    fn on_contact_started(
+        &mut self,
+        _co1: &CollisionObject<N, T, B>,
+        _co2: &CollisionObject<N, T, B>,
+        _manifold: &ContactManifold<N>,
+    )
+    {
+    }
+
+    /// Called when `co1` and `co2` stop being in contact.
+    fn on_contact_stopped(&mut self, _co1: &CollisionObject<N, T, B>, _co2: &CollisionObject<N, T, B>) {}
+
+    /// Called when the proximity status of `co1` and `co2` changes from `_prev_status` to `_new_status`.
+    fn on_proximity_changed(
+        &mut self,
+        _co1: &CollisionObject<N, T, B>,
+        _co2: &CollisionObject<N, T, B>,
+        _prev_status: Proximity,
+        _new_status: Proximity,
+    )
+    {
+    }
+
+    /// Called when the broad phase detects that the bounding volumes of `co1` and `co2` started
+    /// to overlap.
+    fn on_broad_phase_pair_added(&mut self, _co1: &CollisionObject<N, T, B>, _co2: &CollisionObject<N, T, B>) {}
+
+    /// Called when the broad phase detects that the bounding volumes of `co1` and `co2` stopped
+    /// overlapping.
+    fn on_broad_phase_pair_removed(&mut self, _co1: &CollisionObject<N, T, B>, _co2: &CollisionObject<N, T, B>) {}
+}
+
+/// Collision event listeners called one after the other by the collision world.
+pub struct CollisionEventListeners<N: RealField, T, B: CollisionGroupsBitSet = u32> {
+    listeners: Vec<(String, Box<dyn CollisionEventListener<N, T, B>>)>,
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<N: RealField, T, B: CollisionGroupsBitSet> serde::Serialize for CollisionEventListeners<N, T, B> {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        unimplemented!()
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, N: RealField, T, B: CollisionGroupsBitSet> serde::Deserialize<'de> for CollisionEventListeners<N, T, B> {
+    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<CollisionEventListeners<N, T, B>, D::Error> {
+        unimplemented!()
+    }
+}
+
+impl<N: RealField, T, B: CollisionGroupsBitSet> CollisionEventListeners<N, T, B> {
+    /// Creates a new empty set of collision event listeners.
+    pub fn new() -> CollisionEventListeners<N, T, B> {
+        CollisionEventListeners {
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Registers a collision event listener.
+    ///
+    /// If a listener named `name` already exists, it is replaced by `listener`.
+    pub fn register_event_listener(&mut self, name: &str, listener: Box<dyn CollisionEventListener<N, T, B>>) {
+        for &mut (ref n, ref mut l) in self.listeners.iter_mut() {
+            if name == &n[..] {
+                *l = listener;
+                return;
+            }
+        }
+
+        self.listeners.push((name.to_string(), listener))
+    }
+
+    /// Unregisters a collision event listener.
+    ///
+    /// Returns the listener if it was found.
+    pub fn unregister_event_listener(&mut self, name: &str) -> Option<Box<dyn CollisionEventListener<N, T, B>>> {
+        self.listeners
+            .iter()
+            .position(|l| l.0 == name)
+            .map(|i| self.listeners.remove(i).1)
+    }
+
+    /// Returns `true` if no collision event listener is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+
+    /// Iterates mutably through all the registered listeners, in registration order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut dyn CollisionEventListener<N, T, B>> {
+        self.listeners.iter_mut().map(|l| &mut *l.1 as &mut dyn CollisionEventListener<N, T, B>)
+    }
+}
@@ -1,10 +1,105 @@
 use na::RealField;
+use std::fmt::Debug;
 use crate::pipeline::broad_phase::BroadPhasePairFilter;
 use crate::pipeline::world::CollisionObject;
 
-const SELF_COLLISION: u32 = 1 << 31;
-const ALL_GROUPS: u32 = (1 << 30) - 1;
-const NO_GROUP: u32 = 0;
+/// A fixed-size set of bits used to store the group masks of `CollisionGroups`.
+///
+/// This is implemented for `u32` (30 groups, the default), `u64` (64 groups), `u128` (128 groups),
+/// and for arrays of 2, 4, 8, and 16 `u64` (from 128 up to 1024 groups).
+pub trait CollisionGroupsBitSet: Copy + Debug + Send + Sync + 'static {
+    /// The number of groups that can be represented by this bit set.
+    const NUM_GROUPS: usize;
+
+    /// A bit set with no bit set.
+    fn empty() -> Self;
+
+    /// A bit set with the first `Self::NUM_GROUPS` bits set.
+    fn full() -> Self;
+
+    /// Sets or unsets the `i`-th bit of this bit set.
+    fn set(&mut self, i: usize, value: bool);
+
+    /// Tests if the `i`-th bit of this bit set is set.
+    fn contains(&self, i: usize) -> bool;
+
+    /// Tests if `self` and `other` have at least one bit set in common.
+    fn intersects(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_bit_set_for_uint(
+    ($($t: ty, $num_groups: expr);*) => {$(
+        impl CollisionGroupsBitSet for $t {
+            const NUM_GROUPS: usize = $num_groups;
+
+            #[inline]
+            fn empty() -> Self {
+                0
+            }
+
+            #[inline]
+            fn full() -> Self {
+                <$t>::max_value() >> (<$t>::max_value().count_ones() as usize - $num_groups)
+            }
+
+            #[inline]
+            fn set(&mut self, i: usize, value: bool) {
+                if value {
+                    *self |= 1 << i
+                } else {
+                    *self &= !(1 << i)
+                }
+            }
+
+            #[inline]
+            fn contains(&self, i: usize) -> bool {
+                *self & (1 << i) != 0
+            }
+
+            #[inline]
+            fn intersects(&self, other: &Self) -> bool {
+                *self & *other != 0
+            }
+        }
+    )*}
+);
+
+impl_bit_set_for_uint!(u32, 30; u64, 64; u128, 128);
+
+macro_rules! impl_bit_set_for_array(
+    ($($len: expr),*) => {$(
+        impl CollisionGroupsBitSet for [u64; $len] {
+            const NUM_GROUPS: usize = 64 * $len;
+
+            #[inline]
+            fn empty() -> Self {
+                [0; $len]
+            }
+
+            #[inline]
+            fn full() -> Self {
+                [u64::max_value(); $len]
+            }
+
+            #[inline]
+            fn set(&mut self, i: usize, value: bool) {
+                self[i / 64].set(i % 64, value)
+            }
+
+            #[inline]
+            fn contains(&self, i: usize) -> bool {
+                self[i / 64].contains(i % 64)
+            }
+
+            #[inline]
+            fn intersects(&self, other: &Self) -> bool {
+                self.iter().zip(other.iter()).any(|(a, b)| a & b != 0)
+            }
+        }
+    )*}
+);
+
+impl_bit_set_for_array!(2, 4, 8, 16);
 
 /// Groups of collision used to filter which object interact with which other one.
 ///
@@ -38,12 +133,24 @@ const NO_GROUP: u32 = 0;
 ///    * A and B will **not** interact because B is part of the group 1 which is blacklisted by A.
 ///    * Finally, B and C will **not** interact either because, even if C whitelists the group 3
 ///    (which B is part of), B does not whitelists the groups 6 nor 9 (which B is part of).
+///
+/// ### Number of groups
+/// The group masks are stored on the bit set `B`. By default, this is a `u32` limiting the number
+/// of groups to 30. Other bit sets implementing `CollisionGroupsBitSet`, e.g., `u128` or
+/// `[u64; 4]` allow up to 128 and 256 groups respectively. Such collision groups are created with
+/// `CollisionGroups::<B>::default()` and can be used by a `CollisionWorld<N, T, B>`:
+///
+/// ```.ignore
+/// let mut world = CollisionWorld::<f32, (), [u64; 4]>::new(0.02);
+/// let groups = CollisionGroups::<[u64; 4]>::default().with_membership(&[200]);
+/// ```
 #[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Copy)]
-pub struct CollisionGroups {
-    membership: u32,
-    whitelist: u32,
-    blacklist: u32,
+pub struct CollisionGroups<B: CollisionGroupsBitSet = u32> {
+    membership: B,
+    whitelist: B,
+    blacklist: B,
+    self_interaction: bool,
 }
 
 impl CollisionGroups {
@@ -51,13 +158,19 @@ impl CollisionGroups {
     /// self-interaction.
     #[inline]
     pub fn new() -> CollisionGroups {
-        CollisionGroups {
-            membership: ALL_GROUPS,
-            whitelist: ALL_GROUPS,
-            blacklist: NO_GROUP,
-        }
+        CollisionGroups::default()
+    }
+
+    /// The maximum allowed group identifier.
+    ///
+    /// Use `max_group_id_for_bit_set` for other bit sets.
+    #[inline]
+    pub fn max_group_id() -> usize {
+        Self::max_group_id_for_bit_set()
     }
+}
 
+impl<B: CollisionGroupsBitSet> CollisionGroups<B> {
     /// Returns a copy of this object, updated with a new set of membership groups.
     ///
     /// # Examples
@@ -70,8 +183,8 @@ impl CollisionGroups {
     /// assert!(groups.is_member_of(GROUP_B));
     /// ```
     #[inline]
-    pub fn with_membership(mut self, groups: &[usize]) -> CollisionGroups {
-        CollisionGroups::set_mask(&mut self.membership, groups);
+    pub fn with_membership(mut self, groups: &[usize]) -> Self {
+        Self::set_mask(&mut self.membership, groups);
         self
     }
 
@@ -87,8 +200,8 @@ impl CollisionGroups {
     /// assert!(group_a.is_group_whitelisted(GROUP_B));
     /// ```
     #[inline]
-    pub fn with_whitelist(mut self, groups: &[usize]) -> CollisionGroups {
-        CollisionGroups::set_mask(&mut self.whitelist, groups);
+    pub fn with_whitelist(mut self, groups: &[usize]) -> Self {
+        Self::set_mask(&mut self.whitelist, groups);
         self
     }
 
@@ -104,130 +217,132 @@ impl CollisionGroups {
     /// assert!(group_a.is_group_blacklisted(GROUP_B));
     /// ```
     #[inline]
-    pub fn with_blacklist(mut self, groups: &[usize]) -> CollisionGroups {
-        CollisionGroups::set_mask(&mut self.blacklist, groups);
+    pub fn with_blacklist(mut self, groups: &[usize]) -> Self {
+        Self::set_mask(&mut self.blacklist, groups);
         self
     }
 
-    /// The maximum allowed group identifier.
+    /// The maximum allowed group identifier with the bit set `B`.
+    ///
+    /// This is the same as `max_group_id` for the default `u32` bit set.
     #[inline]
-    pub fn max_group_id() -> usize {
-        29
+    pub fn max_group_id_for_bit_set() -> usize {
+        B::NUM_GROUPS - 1
     }
 
     #[inline]
-    fn modify_mask(mask: &mut u32, group_id: usize, add: bool) {
+    fn modify_mask(mask: &mut B, group_id: usize, add: bool) {
         assert!(
-            group_id < 30,
-            "There are at most 30 groups indexed from 0 to 29 (included)."
+            group_id < B::NUM_GROUPS,
+            "There are at most {} groups indexed from 0 to {} (included).",
+            B::NUM_GROUPS,
+            B::NUM_GROUPS - 1
         );
 
-        if add {
-            *mask = *mask | (1 << group_id)
-        } else {
-            *mask = *mask & !(1 << group_id)
-        }
+        mask.set(group_id, add)
     }
 
     #[inline]
-    fn set_mask(mask: &mut u32, groups: &[usize]) {
-        *mask = 0;
+    fn set_mask(mask: &mut B, groups: &[usize]) {
+        *mask = B::empty();
         for g in groups.iter() {
-            CollisionGroups::modify_mask(mask, *g, true);
+            Self::modify_mask(mask, *g, true);
         }
     }
 
     /// Adds or removes this entity from the given group.
     #[inline]
     pub fn modify_membership(&mut self, group_id: usize, add: bool) {
-        CollisionGroups::modify_mask(&mut self.membership, group_id, add);
+        Self::modify_mask(&mut self.membership, group_id, add);
     }
 
     /// Adds or removes the given group from this entity whitelist.
     #[inline]
     pub fn modify_whitelist(&mut self, group_id: usize, add: bool) {
-        CollisionGroups::modify_mask(&mut self.whitelist, group_id, add);
+        Self::modify_mask(&mut self.whitelist, group_id, add);
     }
 
     /// Adds or removes this entity from the given group.
     #[inline]
     pub fn modify_blacklist(&mut self, group_id: usize, add: bool) {
-        CollisionGroups::modify_mask(&mut self.blacklist, group_id, add);
+        Self::modify_mask(&mut self.blacklist, group_id, add);
     }
 
     /// Make this object member of the given groups only.
     #[inline]
     pub fn set_membership(&mut self, groups: &[usize]) {
-        CollisionGroups::set_mask(&mut self.membership, groups);
+        Self::set_mask(&mut self.membership, groups);
     }
 
     /// Whitelists the given groups only (others will be un-whitelisted).
     #[inline]
     pub fn set_whitelist(&mut self, groups: &[usize]) {
-        CollisionGroups::set_mask(&mut self.whitelist, groups);
+        Self::set_mask(&mut self.whitelist, groups);
     }
 
     /// Blacklists the given groups only (others will be un-blacklisted).
     #[inline]
     pub fn set_blacklist(&mut self, groups: &[usize]) {
-        CollisionGroups::set_mask(&mut self.blacklist, groups);
+        Self::set_mask(&mut self.blacklist, groups);
     }
 
     /// Copies the membership of another collision groups.
     #[inline]
-    pub fn copy_membership(&mut self, other: &CollisionGroups) {
+    pub fn copy_membership(&mut self, other: &Self) {
         self.membership = other.membership
     }
 
     /// Copies the whitelist of another collision groups.
     #[inline]
-    pub fn copy_whitelist(&mut self, other: &CollisionGroups) {
+    pub fn copy_whitelist(&mut self, other: &Self) {
         self.whitelist = other.whitelist
     }
 
     /// Copies the blacklist of another collision groups.
     #[inline]
-    pub fn copy_blacklist(&mut self, other: &CollisionGroups) {
+    pub fn copy_blacklist(&mut self, other: &Self) {
         self.blacklist = other.blacklist
     }
 
     /// Allows the object to interact with itself.
     #[inline]
     pub fn enable_self_interaction(&mut self) {
-        self.whitelist = self.whitelist | SELF_COLLISION;
+        self.self_interaction = true;
     }
 
     /// Prevents the object from interacting with itself.
     #[inline]
     pub fn disable_self_interaction(&mut self) {
-        self.whitelist = self.whitelist & !SELF_COLLISION;
+        self.self_interaction = false;
     }
 
     #[inline]
-    fn is_inside_mask(mask: u32, group_id: usize) -> bool {
+    fn is_inside_mask(mask: &B, group_id: usize) -> bool {
         assert!(
-            group_id < 30,
-            "There are at most 30 groups indexed from 0 to 29 (included)."
+            group_id < B::NUM_GROUPS,
+            "There are at most {} groups indexed from 0 to {} (included).",
+            B::NUM_GROUPS,
+            B::NUM_GROUPS - 1
         );
-        mask & (1 << group_id) != 0
+        mask.contains(group_id)
     }
 
     /// Tests if this entity is part of the given group.
     #[inline]
     pub fn is_member_of(&self, group_id: usize) -> bool {
-        CollisionGroups::is_inside_mask(self.membership, group_id)
+        Self::is_inside_mask(&self.membership, group_id)
     }
 
     /// Tests if the given group is whitelisted.
     #[inline]
     pub fn is_group_whitelisted(&self, group_id: usize) -> bool {
-        CollisionGroups::is_inside_mask(self.whitelist, group_id)
+        Self::is_inside_mask(&self.whitelist, group_id)
     }
 
     /// Tests if the given group is blacklisted.
     #[inline]
     pub fn is_group_blacklisted(&self, group_id: usize) -> bool {
-        CollisionGroups::is_inside_mask(self.blacklist, group_id)
+        Self::is_inside_mask(&self.blacklist, group_id)
     }
 
     /// Tests whether interactions with a given group is possible.
@@ -235,24 +350,23 @@ impl CollisionGroups {
     /// Collision is possible if `group_id` is whitelisted but not blacklisted.
     #[inline]
     pub fn can_interact_with(&self, group_id: usize) -> bool {
-        !CollisionGroups::is_inside_mask(self.blacklist, group_id)
-            && CollisionGroups::is_inside_mask(self.whitelist, group_id)
+        !Self::is_inside_mask(&self.blacklist, group_id)
+            && Self::is_inside_mask(&self.whitelist, group_id)
     }
 
     /// Tests whether two collision groups have at least one group in common.
     #[inline]
-    pub fn can_interact_with_groups(&self, other: &CollisionGroups) -> bool {
-        // FIXME: is there a more bitwise-y way of doing this?
-        self.membership & other.blacklist == 0
-            && other.membership & self.blacklist == 0
-            && self.membership & other.whitelist != 0
-            && other.membership & self.whitelist != 0
+    pub fn can_interact_with_groups(&self, other: &Self) -> bool {
+        !self.membership.intersects(&other.blacklist)
+            && !other.membership.intersects(&self.blacklist)
+            && self.membership.intersects(&other.whitelist)
+            && other.membership.intersects(&self.whitelist)
     }
 
     /// Tests whether self-interaction is enabled.
     #[inline]
     pub fn can_interact_with_self(&self) -> bool {
-        self.whitelist & SELF_COLLISION != 0
+        self.self_interaction
     }
 }
 
@@ -267,8 +381,15 @@ impl CollisionGroupsPairFilter {
     }
 }
 
-impl<N: RealField, T> BroadPhasePairFilter<N, T> for CollisionGroupsPairFilter {
-    fn is_pair_valid(&self, co1: &CollisionObject<N, T>, co2: &CollisionObject<N, T>) -> bool {
+impl<N: RealField, T, B: CollisionGroupsBitSet> BroadPhasePairFilter<N, T, B>
+    for CollisionGroupsPairFilter
+{
+    fn is_pair_valid(
+        &self,
+        co1: &CollisionObject<N, T, B>,
+        co2: &CollisionObject<N, T, B>,
+    ) -> bool
+    {
         if co1.handle() == co2.handle() {
             co1.collision_groups().can_interact_with_self()
         } else {
@@ -278,9 +399,16 @@ impl<N: RealField, T> BroadPhasePairFilter<N, T> for CollisionGroupsPairFilter {
     }
 }
 
-impl Default for CollisionGroups {
+impl<B: CollisionGroupsBitSet> Default for CollisionGroups<B> {
+    /// Creates a new `CollisionGroups` that enables interactions with everything except
+    /// self-interaction.
     #[inline]
     fn default() -> Self {
-        CollisionGroups::new()
+        CollisionGroups {
+            membership: B::full(),
+            whitelist: B::full(),
+            blacklist: B::empty(),
+            self_interaction: false,
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,10 @@
 use alga::general::RealField;
+use crate::bounding_volume::{self, BoundingVolume, AABB};
 use crate::math::Isometry;
 use crate::pipeline::broad_phase::ProxyHandle;
 use crate::pipeline::narrow_phase::InteractionGraphIndex;
-use crate::pipeline::world::CollisionGroups;
-use crate::query::ContactPrediction;
+use crate::pipeline::world::{CollisionGroups, CollisionGroupsBitSet};
+use crate::query::{ContactPrediction, ContactPreprocessor};
 use crate::shape::ShapeHandle;
 use slab::{Iter, Slab};
 use std::ops::{Index, IndexMut};
@@ -81,21 +82,25 @@ impl<N: RealField> GeometricQueryType<N> {
 
 #[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
 /// A stand-alone object that has a position and a shape.
-pub struct CollisionObject<N: RealField, T> {
+pub struct CollisionObject<N: RealField, T, B: CollisionGroupsBitSet = u32> {
     handle: CollisionObjectHandle,
     proxy_handle: ProxyHandle,
     graph_index: InteractionGraphIndex,
     position: Isometry<N>,
     shape: ShapeHandle<N>,
-    collision_groups: CollisionGroups,
+    collision_groups: CollisionGroups<B>,
     query_type: GeometricQueryType<N>,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    contact_preprocessor: Option<Box<dyn ContactPreprocessor<N> + Send + Sync>>,
+    // The position at the beginning of the motion, if CCD is enabled.
+    previous_position: Option<Isometry<N>>,
     data: T,
     // XXX: could this be replaced by an enum (or bitfield)
     // indicating what has been modified?
     pub(crate) timestamp: usize,
 }
 
-impl<N: RealField, T> CollisionObject<N, T> {
+impl<N: RealField, T, B: CollisionGroupsBitSet> CollisionObject<N, T, B> {
     /// Creates a new collision object.
     pub fn new(
         handle: CollisionObjectHandle,
@@ -103,10 +108,10 @@ impl<N: RealField, T> CollisionObject<N, T> {
         graph_index: InteractionGraphIndex,
         position: Isometry<N>,
         shape: ShapeHandle<N>,
-        groups: CollisionGroups,
+        groups: CollisionGroups<B>,
         query_type: GeometricQueryType<N>,
         data: T,
-    ) -> CollisionObject<N, T>
+    ) -> CollisionObject<N, T, B>
     {
         CollisionObject {
             handle,
@@ -117,6 +122,8 @@ impl<N: RealField, T> CollisionObject<N, T> {
             collision_groups: groups,
             data,
             query_type,
+            contact_preprocessor: None,
+            previous_position: None,
             timestamp: 0,
         }
     }
@@ -170,6 +177,53 @@ impl<N: RealField, T> CollisionObject<N, T> {
         self.position = pos
     }
 
+    /// Whether continuous collision detection is enabled for this collision object.
+    #[inline]
+    pub fn is_ccd_enabled(&self) -> bool {
+        self.previous_position.is_some()
+    }
+
+    /// The position of this collision object at the beginning of its motion.
+    ///
+    /// This is the same as `self.position()` if continuous collision detection is disabled.
+    #[inline]
+    pub fn previous_position(&self) -> &Isometry<N> {
+        self.previous_position.as_ref().unwrap_or(&self.position)
+    }
+
+    /// Enables or disables continuous collision detection for this collision object.
+    /// Use `CollisionWorld::set_ccd_enabled` to use this method.
+    #[inline]
+    pub(crate) fn set_ccd_enabled(&mut self, enabled: bool) {
+        self.previous_position = if enabled { Some(self.position) } else { None };
+    }
+
+    /// Sets the position of this collision object at the beginning of its motion.
+    ///
+    /// Does nothing if continuous collision detection is disabled.
+    #[inline]
+    pub(crate) fn set_previous_position(&mut self, pos: Isometry<N>) {
+        if let Some(prev) = &mut self.previous_position {
+            *prev = pos
+        }
+    }
+
+    /// The AABB of this collision object, enlarged by its query limit and swept from its previous
+    /// to its current position if continuous collision detection is enabled.
+    ///
+    /// This is the union of the AABBs at both positions. It encloses the whole motion if it
+    /// is a translation, but not necessarily the intermediate orientations of a rotation.
+    pub fn swept_aabb(&self) -> AABB<N> {
+        let mut aabb = bounding_volume::aabb(self.shape.as_ref(), &self.position);
+
+        if let Some(prev) = &self.previous_position {
+            aabb.merge(&bounding_volume::aabb(self.shape.as_ref(), prev));
+        }
+
+        aabb.loosen(self.query_type.query_limit());
+        aabb
+    }
+
     /// Deforms the underlying shape if possible.
     ///
     /// Panics if the shape is not deformable.
@@ -196,12 +250,12 @@ impl<N: RealField, T> CollisionObject<N, T> {
 
     /// The collision groups of the collision object.
     #[inline]
-    pub fn collision_groups(&self) -> &CollisionGroups {
+    pub fn collision_groups(&self) -> &CollisionGroups<B> {
         &self.collision_groups
     }
 
     #[inline]
-    pub(crate) fn set_collision_groups(&mut self, groups: CollisionGroups) {
+    pub(crate) fn set_collision_groups(&mut self, groups: CollisionGroups<B>) {
         self.collision_groups = groups
     }
 
@@ -218,6 +272,26 @@ impl<N: RealField, T> CollisionObject<N, T> {
         self.query_type = query_type;
     }
 
+    /// The contact preprocessor applied to each contact involving this collision object.
+    #[inline]
+    pub fn contact_preprocessor(&self) -> Option<&dyn ContactPreprocessor<N>> {
+        match &self.contact_preprocessor {
+            Some(p) => Some(&**p),
+            None => None,
+        }
+    }
+
+    /// Sets the contact preprocessor applied to each contact involving this collision object.
+    /// Use `CollisionWorld::set_contact_preprocessor` to use this method.
+    #[inline]
+    pub(crate) fn set_contact_preprocessor(
+        &mut self,
+        preprocessor: Option<Box<dyn ContactPreprocessor<N> + Send + Sync>>,
+    )
+    {
+        self.contact_preprocessor = preprocessor;
+    }
+
     /// Reference to the user-defined data associated to this object.
     #[inline]
     pub fn data(&self) -> &T {
@@ -250,27 +324,27 @@ impl CollisionObjectHandle {
 }
 
 /// A set of collision objects that can be indexed by collision object handles.
-pub struct CollisionObjectSlab<N: RealField, T> {
-    objects: Slab<CollisionObject<N, T>>,
+pub struct CollisionObjectSlab<N: RealField, T, B: CollisionGroupsBitSet = u32> {
+    objects: Slab<CollisionObject<N, T, B>>,
 }
 
 #[cfg(feature = "serde-serialize")]
-impl<N: RealField, T> serde::Serialize for CollisionObjectSlab<N, T> {
+impl<N: RealField, T, B: CollisionGroupsBitSet> serde::Serialize for CollisionObjectSlab<N, T, B> {
     fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
         unimplemented!("TODO: look into slab and fix this")
     }
 }
 
 #[cfg(feature = "serde-serialize")]
-impl<'de, N: RealField, T> serde::Deserialize<'de> for CollisionObjectSlab<N, T> {
-    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<CollisionObjectSlab<N, T>, D::Error> {
+impl<'de, N: RealField, T, B: CollisionGroupsBitSet> serde::Deserialize<'de> for CollisionObjectSlab<N, T, B> {
+    fn deserialize<D: serde::Deserializer<'de>>(_deserializer: D) -> Result<CollisionObjectSlab<N, T, B>, D::Error> {
         unimplemented!("TODO: look into slab and fix this")
     }
 }
 
-impl<N: RealField, T> CollisionObjectSlab<N, T> {
+impl<N: RealField, T, B: CollisionGroupsBitSet> CollisionObjectSlab<N, T, B> {
     /// Creates a new empty collecton of collision objects.
-    pub fn new() -> CollisionObjectSlab<N, T> {
+    pub fn new() -> CollisionObjectSlab<N, T, B> {
         CollisionObjectSlab {
             objects: Slab::new(),
         }
@@ -278,7 +352,7 @@ impl<N: RealField, T> CollisionObjectSlab<N, T> {
 
     /// Inserts a new collision object into this collection and returns the corresponding handle.
     #[inline]
-    pub fn insert(&mut self, co: CollisionObject<N, T>) -> CollisionObjectHandle {
+    pub fn insert(&mut self, co: CollisionObject<N, T, B>) -> CollisionObjectHandle {
         CollisionObjectHandle(self.objects.insert(co))
     }
 
@@ -286,19 +360,19 @@ impl<N: RealField, T> CollisionObjectSlab<N, T> {
     ///
     /// The removed collision object structure is returned.
     #[inline]
-    pub fn remove(&mut self, handle: CollisionObjectHandle) -> CollisionObject<N, T> {
+    pub fn remove(&mut self, handle: CollisionObjectHandle) -> CollisionObject<N, T, B> {
         self.objects.remove(handle.0)
     }
 
     /// If it exists, retrieves a reference to the collision object identified by the given handle.
     #[inline]
-    pub fn get(&self, handle: CollisionObjectHandle) -> Option<&CollisionObject<N, T>> {
+    pub fn get(&self, handle: CollisionObjectHandle) -> Option<&CollisionObject<N, T, B>> {
         self.objects.get(handle.0)
     }
 
     /// If it exists, retrieves a mutable reference to the collision object identified by the given handle.
     #[inline]
-    pub fn get_mut(&mut self, handle: CollisionObjectHandle) -> Option<&mut CollisionObject<N, T>> {
+    pub fn get_mut(&mut self, handle: CollisionObjectHandle) -> Option<&mut CollisionObject<N, T, B>> {
         self.objects.get_mut(handle.0)
     }
 
@@ -310,7 +384,7 @@ impl<N: RealField, T> CollisionObjectSlab<N, T> {
 
     /// Retrieves an iterator yielding references to each collision object.
     #[inline]
-    pub fn iter(&self) -> CollisionObjects<N, T> {
+    pub fn iter(&self) -> CollisionObjects<N, T, B> {
         CollisionObjects {
             iter: self.objects.iter(),
         }
@@ -323,8 +397,8 @@ impl<N: RealField, T> CollisionObjectSlab<N, T> {
     }
 }
 
-impl<N: RealField, T> Index<CollisionObjectHandle> for CollisionObjectSlab<N, T> {
-    type Output = CollisionObject<N, T>;
+impl<N: RealField, T, B: CollisionGroupsBitSet> Index<CollisionObjectHandle> for CollisionObjectSlab<N, T, B> {
+    type Output = CollisionObject<N, T, B>;
 
     #[inline]
     fn index(&self, handle: CollisionObjectHandle) -> &Self::Output {
@@ -332,7 +406,7 @@ impl<N: RealField, T> Index<CollisionObjectHandle> for CollisionObjectSlab<N, T>
     }
 }
 
-impl<N: RealField, T> IndexMut<CollisionObjectHandle> for CollisionObjectSlab<N, T> {
+impl<N: RealField, T, B: CollisionGroupsBitSet> IndexMut<CollisionObjectHandle> for CollisionObjectSlab<N, T, B> {
     #[inline]
     fn index_mut(&mut self, handle: CollisionObjectHandle) -> &mut Self::Output {
         &mut self.objects[handle.0]
@@ -340,12 +414,12 @@ impl<N: RealField, T> IndexMut<CollisionObjectHandle> for CollisionObjectSlab<N,
 }
 
 /// An iterator yielding references to collision objects.
-pub struct CollisionObjects<'a, N: 'a + RealField, T: 'a> {
-    iter: Iter<'a, CollisionObject<N, T>>,
+pub struct CollisionObjects<'a, N: 'a + RealField, T: 'a, B: 'a + CollisionGroupsBitSet = u32> {
+    iter: Iter<'a, CollisionObject<N, T, B>>,
 }
 
-impl<'a, N: 'a + RealField, T: 'a> Iterator for CollisionObjects<'a, N, T> {
-    type Item = &'a CollisionObject<N, T>;
+impl<'a, N: 'a + RealField, T: 'a, B: 'a + CollisionGroupsBitSet> Iterator for CollisionObjects<'a, N, T, B> {
+    type Item = &'a CollisionObject<N, T, B>;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
@@ -1,20 +1,25 @@
-use crate::bounding_volume::{self, BoundingVolume, AABB};
-use crate::math::{Isometry, Point};
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Translation, Vector};
 use na::RealField;
 use crate::pipeline::broad_phase::{
     BroadPhase, BroadPhasePairFilter, BroadPhasePairFilters, DBVTBroadPhase, ProxyHandle,
     BroadPhaseInterferenceHandler
 };
-use crate::pipeline::events::{ContactEvent, ContactEvents, ProximityEvents};
+use crate::pipeline::events::{
+    ContactEvent, ContactEvents, DetailedContactEvents, PartProximityEvents, ProximityEvents,
+    TOIEvents,
+};
 use crate::pipeline::narrow_phase::{
     DefaultContactDispatcher, NarrowPhase, DefaultProximityDispatcher,
     InteractionGraphIndex, Interaction, ContactAlgorithm, ProximityAlgorithm,
+    ContactManifoldModifier, ContactManifoldModifiers, PartProximities,
 };
 use crate::pipeline::world::{
-    CollisionGroups, CollisionGroupsPairFilter, CollisionObject, CollisionObjectHandle,
+    CollisionEventListener, CollisionEventListeners, CollisionGroups, CollisionGroupsBitSet,
+    CollisionGroupsPairFilter, CollisionObject, CollisionObjectHandle,
     CollisionObjectSlab, CollisionObjects, GeometricQueryType,
 };
-use crate::query::{PointQuery, Ray, RayCast, RayIntersection, ContactManifold};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection, ContactManifold, ContactPreprocessor};
 use crate::shape::ShapeHandle;
 use std::vec::IntoIter;
 
@@ -37,33 +42,49 @@ impl<'de, N: RealField> serde::Deserialize<'de> for BroadPhaseObject<N> {
 
 #[cfg_attr(feature = "serde-serialize", derive(serde::Serialize, serde::Deserialize))]
 /// A world that handles collision objects.
-pub struct CollisionWorld<N: RealField, T> {
-    objects: CollisionObjectSlab<N, T>,
+pub struct CollisionWorld<N: RealField, T, B: CollisionGroupsBitSet = u32> {
+    objects: CollisionObjectSlab<N, T, B>,
     broad_phase: BroadPhaseObject<N>,
     narrow_phase: NarrowPhase<N>,
     contact_events: ContactEvents,
+    detailed_contact_events: DetailedContactEvents<N>,
     proximity_events: ProximityEvents,
-    pair_filters: BroadPhasePairFilters<N, T>,
+    part_proximity_events: PartProximityEvents,
+    toi_events: TOIEvents<N>,
+    pair_filters: BroadPhasePairFilters<N, T, B>,
+    contact_modifiers: ContactManifoldModifiers<N, T, B>,
+    event_listeners: CollisionEventListeners<N, T, B>,
+    // Broad phase pairs added (`true`) or removed (`false`) since the last event dispatch.
+    broad_phase_pair_events: Vec<(CollisionObjectHandle, CollisionObjectHandle, bool)>,
     timestamp: usize, // FIXME: allow modification of the other properties too.
 }
 
-struct CollisionWorldInterferenceHandler<'a, N: RealField, T: 'a> {
+struct CollisionWorldInterferenceHandler<'a, N: RealField, T: 'a, B: CollisionGroupsBitSet> {
     narrow_phase: &'a mut NarrowPhase<N>,
     contact_events: &'a mut ContactEvents,
+    detailed_contact_events: &'a mut DetailedContactEvents<N>,
     proximity_events: &'a mut ProximityEvents,
-    objects: &'a CollisionObjectSlab<N, T>,
-    pair_filters: &'a BroadPhasePairFilters<N, T>,
+    part_proximity_events: &'a mut PartProximityEvents,
+    objects: &'a CollisionObjectSlab<N, T, B>,
+    pair_filters: &'a BroadPhasePairFilters<N, T, B>,
+    pair_events: Option<&'a mut Vec<(CollisionObjectHandle, CollisionObjectHandle, bool)>>,
 }
 
-impl <'a, N: RealField, T> BroadPhaseInterferenceHandler<CollisionObjectHandle> for CollisionWorldInterferenceHandler<'a, N, T> {
+impl <'a, N: RealField, T, B: CollisionGroupsBitSet> BroadPhaseInterferenceHandler<CollisionObjectHandle> for CollisionWorldInterferenceHandler<'a, N, T, B> {
     fn is_interference_allowed(&mut self, b1: &CollisionObjectHandle, b2: &CollisionObjectHandle) -> bool {
         CollisionWorld::filter_collision(&self.pair_filters, &self.objects, *b1, *b2)
     }
 
     fn interference_started(&mut self, b1: &CollisionObjectHandle, b2: &CollisionObjectHandle) {
+        if let Some(pair_events) = &mut self.pair_events {
+            pair_events.push((*b1, *b2, true));
+        }
+
         self.narrow_phase.handle_interaction(
             &mut self.contact_events,
+            &mut self.detailed_contact_events,
             &mut self.proximity_events,
+            &mut self.part_proximity_events,
             &self.objects,
             *b1, *b2,
             true
@@ -71,9 +92,15 @@ impl <'a, N: RealField, T> BroadPhaseInterferenceHandler<CollisionObjectHandle>
     }
 
     fn interference_stopped(&mut self, b1: &CollisionObjectHandle, b2: &CollisionObjectHandle) {
+        if let Some(pair_events) = &mut self.pair_events {
+            pair_events.push((*b1, *b2, false));
+        }
+
         self.narrow_phase.handle_interaction(
             &mut self.contact_events,
+            &mut self.detailed_contact_events,
             &mut self.proximity_events,
+            &mut self.part_proximity_events,
             &self.objects,
             *b1, *b2,
             false
@@ -81,10 +108,10 @@ impl <'a, N: RealField, T> BroadPhaseInterferenceHandler<CollisionObjectHandle>
     }
 }
 
-impl<N: RealField, T> CollisionWorld<N, T> {
+impl<N: RealField, T, B: CollisionGroupsBitSet> CollisionWorld<N, T, B> {
     /// Creates a new collision world.
     // FIXME: use default values for `margin` and allow its modification by the user ?
-    pub fn new(margin: N) -> CollisionWorld<N, T> {
+    pub fn new(margin: N) -> CollisionWorld<N, T, B> {
         let objects = CollisionObjectSlab::new();
         let coll_dispatcher = Box::new(DefaultContactDispatcher::new());
         let prox_dispatcher = Box::new(DefaultProximityDispatcher::new());
@@ -95,11 +122,17 @@ impl<N: RealField, T> CollisionWorld<N, T> {
 
         CollisionWorld {
             contact_events: ContactEvents::new(),
+            detailed_contact_events: DetailedContactEvents::new(),
+            part_proximity_events: PartProximityEvents::new(),
+            toi_events: TOIEvents::new(),
             proximity_events: ProximityEvents::new(),
             objects,
             broad_phase,
             narrow_phase,
             pair_filters: BroadPhasePairFilters::new(),
+            contact_modifiers: ContactManifoldModifiers::new(),
+            event_listeners: CollisionEventListeners::new(),
+            broad_phase_pair_events: Vec::new(),
             timestamp: 0,
         }
     }
@@ -109,10 +142,10 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         &mut self,
         position: Isometry<N>,
         shape: ShapeHandle<N>,
-        collision_groups: CollisionGroups,
+        collision_groups: CollisionGroups<B>,
         query_type: GeometricQueryType<N>,
         data: T,
-    ) -> &mut CollisionObject<N, T>
+    ) -> &mut CollisionObject<N, T, B>
     {
         let mut co = CollisionObject::new(
             CollisionObjectHandle::invalid(),
@@ -129,9 +162,7 @@ impl<N: RealField, T> CollisionWorld<N, T> {
 
         // Add objects.
         let co = &mut self.objects[handle];
-        let mut aabb = bounding_volume::aabb(co.shape().as_ref(), co.position());
-        aabb.loosen(co.query_type().query_limit());
-        let proxy_handle = self.broad_phase.create_proxy(aabb, handle);
+        let proxy_handle = self.broad_phase.create_proxy(co.swept_aabb(), handle);
         let graph_index = self.narrow_phase.handle_collision_object_added(handle);
 
         co.set_handle(handle);
@@ -146,16 +177,94 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     /// 1. Clears the event pools.
     /// 2. Executes the broad phase first.
     /// 3. Executes the narrow phase.
+    /// 4. Calls the registered collision event listeners.
     pub fn update(&mut self) {
         self.clear_events();
         self.perform_broad_phase();
         self.perform_narrow_phase();
+        self.dispatch_events();
+    }
+
+    /// Registers a listener called at the end of each update for each collision event.
+    ///
+    /// If a listener named `name` already exists, it is replaced by `listener`.
+    pub fn register_event_listener<L>(&mut self, name: &str, listener: L)
+    where L: CollisionEventListener<N, T, B> + 'static {
+        self.event_listeners
+            .register_event_listener(name, Box::new(listener))
+    }
+
+    /// Removes the collision event listener named `name`.
+    ///
+    /// Returns the listener if it was found.
+    pub fn unregister_event_listener(&mut self, name: &str) -> Option<Box<dyn CollisionEventListener<N, T, B>>> {
+        self.event_listeners.unregister_event_listener(name)
+    }
+
+    /// Calls the registered collision event listeners for all the events generated since the last
+    /// call to `self.clear_events()`.
+    ///
+    /// This is automatically called by `self.update()`.
+    pub fn dispatch_events(&mut self) {
+        let objects = &self.objects;
+        let pair_events = &mut self.broad_phase_pair_events;
+
+        if self.event_listeners.is_empty() {
+            pair_events.clear();
+            return;
+        }
+
+        for listener in self.event_listeners.iter_mut() {
+            for &(h1, h2, added) in pair_events.iter() {
+                if let (Some(co1), Some(co2)) = (objects.get(h1), objects.get(h2)) {
+                    if added {
+                        listener.on_broad_phase_pair_added(co1, co2)
+                    } else {
+                        listener.on_broad_phase_pair_removed(co1, co2)
+                    }
+                }
+            }
+
+            for event in self.contact_events.iter() {
+                match *event {
+                    ContactEvent::Started(h1, h2) => {
+                        let (co1, co2) = (&objects[h1], &objects[h2]);
+                        let pair = self.narrow_phase.interaction_graph().contact_pair(
+                            co1.graph_index(),
+                            co2.graph_index(),
+                            false,
+                        );
+
+                        if let Some((_, _, _, manifold)) = pair {
+                            listener.on_contact_started(co1, co2, manifold)
+                        }
+                    }
+                    ContactEvent::Stopped(h1, h2) => {
+                        listener.on_contact_stopped(&objects[h1], &objects[h2])
+                    }
+                }
+            }
+
+            for event in self.proximity_events.iter() {
+                listener.on_proximity_changed(
+                    &objects[event.collider1],
+                    &objects[event.collider2],
+                    event.prev_status,
+                    event.new_status,
+                )
+            }
+        }
+
+        pair_events.clear();
     }
 
     /// Empty the contact and proximity event pools.
     pub fn clear_events(&mut self) {
         self.contact_events.clear();
+        self.detailed_contact_events.clear();
         self.proximity_events.clear();
+        self.part_proximity_events.clear();
+        self.toi_events.clear();
     }
 
     /// Removed the specified set of collision objects from the world.
@@ -195,7 +304,15 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             ContactEvent::Started(co1, co2) | ContactEvent::Stopped(co1, co2) => {
                 objects.contains(co1) && objects.contains(co2)
             }
-        })
+        });
+        self.detailed_contact_events.retain(|e| {
+            let (co1, co2) = e.collision_objects();
+            objects.contains(co1) && objects.contains(co2)
+        });
+        self.part_proximity_events
+            .retain(|e| objects.contains(e.collider) && objects.contains(e.other_collider));
+        self.toi_events
+            .retain(|e| objects.contains(e.collider1) && objects.contains(e.collider2));
     }
 
     /// Sets the position the collision object attached to the specified object.
@@ -204,12 +321,61 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             .objects
             .get_mut(handle)
             .expect("Set position: collision object not found.");
-        co.set_position(pos.clone());
+        co.set_position(pos);
+        co.timestamp = self.timestamp;
+        self.broad_phase
+            .deferred_set_bounding_volume(co.proxy_handle(), co.swept_aabb());
+    }
+
+    /// Enables or disables continuous collision detection (CCD) for the given collision object.
+    ///
+    /// If CCD is enabled, the collision object is assumed to move along a straight line from its
+    /// previous position to its current one during each update. The previous position is
+    /// automatically set to the position the object had at the end of the last update, or
+    /// explicitly with `self.set_previous_position` or `self.set_ccd_velocity`. The broad phase
+    /// will then use the AABB swept by the object, and the narrow phase will generate
+    /// `TOIEvent`s whenever it hits another object during this motion.
+    ///
+    /// Only the translational part of the motion is swept: times of impact are computed as if
+    /// the object kept the orientation of its previous position during the whole motion, so
+    /// collisions caused by its rotation may be missed.
+    pub fn set_ccd_enabled(&mut self, handle: CollisionObjectHandle, enabled: bool) {
+        let co = self
+            .objects
+            .get_mut(handle)
+            .expect("Set CCD enabled: collision object not found.");
+        co.set_ccd_enabled(enabled);
+        co.timestamp = self.timestamp;
+        self.broad_phase
+            .deferred_set_bounding_volume(co.proxy_handle(), co.swept_aabb());
+    }
+
+    /// Sets the position the given collision object had at the beginning of its motion.
+    ///
+    /// This has no effect if continuous collision detection is disabled for this object.
+    pub fn set_previous_position(&mut self, handle: CollisionObjectHandle, pos: Isometry<N>) {
+        let co = self
+            .objects
+            .get_mut(handle)
+            .expect("Set previous position: collision object not found.");
+        co.set_previous_position(pos);
         co.timestamp = self.timestamp;
-        let mut aabb = bounding_volume::aabb(co.shape().as_ref(), &pos);
-        aabb.loosen(co.query_type().query_limit());
         self.broad_phase
-            .deferred_set_bounding_volume(co.proxy_handle(), aabb);
+            .deferred_set_bounding_volume(co.proxy_handle(), co.swept_aabb());
+    }
+
+    /// Sets the previous position of the given collision object such that it reaches its current
+    /// position after moving with the linear velocity `linvel` during `dt`.
+    ///
+    /// This has no effect if continuous collision detection is disabled for this object.
+    pub fn set_ccd_velocity(&mut self, handle: CollisionObjectHandle, linvel: &Vector<N>, dt: N) {
+        let pos = self
+            .objects
+            .get(handle)
+            .expect("Set CCD velocity: collision object not found.")
+            .position();
+        let prev = Translation::from(-linvel * dt) * pos;
+        self.set_previous_position(handle, prev)
     }
 
     /// Sets the `GeometricQueryType` of the collision object.
@@ -228,12 +394,7 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     pub fn set_shape(&mut self, handle: CollisionObjectHandle, shape: ShapeHandle<N>) {
         if let Some(co) = self.objects.get_mut(handle) {
             co.set_shape(shape);
-            
-            let mut aabb = bounding_volume::aabb(co.shape().as_ref(), co.position());
-
-            aabb.loosen(co.query_type().query_limit());
-
-            self.broad_phase.deferred_set_bounding_volume(co.proxy_handle(), aabb);
+            self.broad_phase.deferred_set_bounding_volume(co.proxy_handle(), co.swept_aabb());
             self.broad_phase.deferred_recompute_all_proximities_with(co.proxy_handle());
         }
     }
@@ -251,10 +412,8 @@ impl<N: RealField, T> CollisionWorld<N, T> {
             .expect("Set deformations: collision object not found.");
         co.set_deformations(coords);
         co.timestamp = self.timestamp;
-        let mut aabb = bounding_volume::aabb(co.shape().as_ref(), co.position());
-        aabb.loosen(co.query_type().query_limit());
         self.broad_phase
-            .deferred_set_bounding_volume(co.proxy_handle(), aabb);
+            .deferred_set_bounding_volume(co.proxy_handle(), co.swept_aabb());
     }
 
     /// Adds a filter that tells if a potential collision pair should be ignored or not.
@@ -264,7 +423,7 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     /// a non-trivial overhead during the next update as it will force re-detection of all
     /// collision pairs.
     pub fn register_broad_phase_pair_filter<F>(&mut self, name: &str, filter: F)
-    where F: BroadPhasePairFilter<N, T> {
+    where F: BroadPhasePairFilter<N, T, B> + 'static {
         self.pair_filters
             .register_collision_filter(name, Box::new(filter));
         self.broad_phase.deferred_recompute_all_proximities();
@@ -277,14 +436,76 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         }
     }
 
+    /// Sets the contact preprocessor applied to each contact involving the given collision object.
+    ///
+    /// The preprocessor is called on each contact before it is added to a contact manifold and
+    /// may modify it or reject it by returning `false`.
+    pub fn set_contact_preprocessor(
+        &mut self,
+        handle: CollisionObjectHandle,
+        preprocessor: Option<Box<dyn ContactPreprocessor<N> + Send + Sync>>,
+    )
+    {
+        let co = self
+            .objects
+            .get_mut(handle)
+            .expect("Set contact preprocessor: collision object not found.");
+        co.set_contact_preprocessor(preprocessor);
+        co.timestamp = self.timestamp;
+    }
+
+    /// Adds a modifier called on each contact manifold right after it has been generated.
+    ///
+    /// If a modifier named `name` already exists, it is replaced by `modifier`. All the contact
+    /// manifolds will be re-generated during the next update.
+    pub fn register_contact_manifold_modifier<M>(&mut self, name: &str, modifier: M)
+    where M: ContactManifoldModifier<N, T, B> + 'static {
+        self.contact_modifiers
+            .register_contact_manifold_modifier(name, Box::new(modifier));
+        self.touch_all_collision_objects();
+    }
+
+    /// Removes the contact manifold modifier named `name`.
+    pub fn unregister_contact_manifold_modifier(&mut self, name: &str) {
+        if self.contact_modifiers.unregister_contact_manifold_modifier(name) {
+            self.touch_all_collision_objects();
+        }
+    }
+
+    /// Sets the maximum number of contacts kept in each contact manifold.
+    ///
+    /// Manifolds with more contacts are reduced to the deepest contact and the contacts
+    /// maximizing the area of the contact polygon. `None` disables this reduction. All the
+    /// contact manifolds will be re-generated during the next update.
+    pub fn set_max_contacts_per_manifold(&mut self, max_contacts: Option<usize>) {
+        self.narrow_phase.set_max_contacts_per_manifold(max_contacts);
+        self.touch_all_collision_objects();
+    }
+
+    // Forces the re-generation of all the contact manifolds during the next update.
+    fn touch_all_collision_objects(&mut self) {
+        let timestamp = self.timestamp;
+
+        for handle in self.objects.iter().map(|co| co.handle()).collect::<Vec<_>>() {
+            self.objects[handle].timestamp = timestamp;
+        }
+    }
+
     /// Executes the broad phase of the collision detection pipeline.
     pub fn perform_broad_phase(&mut self) {
         self.broad_phase.update(&mut CollisionWorldInterferenceHandler {
             narrow_phase: &mut self.narrow_phase,
             contact_events: &mut self.contact_events,
+            detailed_contact_events: &mut self.detailed_contact_events,
             proximity_events: &mut self.proximity_events,
+            part_proximity_events: &mut self.part_proximity_events,
             pair_filters: &self.pair_filters,
             objects: &self.objects,
+            pair_events: if self.event_listeners.is_empty() {
+                None
+            } else {
+                Some(&mut self.broad_phase_pair_events)
+            },
         });
     }
 
@@ -292,16 +513,40 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     pub fn perform_narrow_phase(&mut self) {
         self.narrow_phase.update(
             &self.objects,
+            &self.contact_modifiers,
             &mut self.contact_events,
+            &mut self.detailed_contact_events,
             &mut self.proximity_events,
+            &mut self.part_proximity_events,
             self.timestamp,
         );
+        self.narrow_phase
+            .compute_times_of_impact(&self.objects, &mut self.toi_events);
+
+        // The next motion of the objects with CCD enabled starts at their current positions.
+        // Their broad phase proxies must then shrink back to their current AABB, otherwise
+        // they would keep the swept AABB until their next motion.
+        let ccd_objects: Vec<_> = self
+            .objects
+            .iter()
+            .filter(|co| co.is_ccd_enabled() && co.previous_position() != co.position())
+            .map(|co| co.handle())
+            .collect();
+
+        for handle in ccd_objects {
+            let co = &mut self.objects[handle];
+            let pos = *co.position();
+            co.set_previous_position(pos);
+            self.broad_phase
+                .deferred_reset_bounding_volume(co.proxy_handle(), co.swept_aabb());
+        }
+
         self.timestamp = self.timestamp + 1;
     }
 
     /// Iterates through all collision objects.
     #[inline]
-    pub fn collision_objects(&self) -> CollisionObjects<N, T> {
+    pub fn collision_objects(&self) -> CollisionObjects<N, T, B> {
         self.objects.iter()
     }
 
@@ -310,7 +555,7 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     pub fn collision_object(
         &self,
         handle: CollisionObjectHandle,
-    ) -> Option<&CollisionObject<N, T>>
+    ) -> Option<&CollisionObject<N, T, B>>
     {
         self.objects.get(handle)
     }
@@ -320,14 +565,14 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     pub fn collision_object_mut(
         &mut self,
         handle: CollisionObjectHandle,
-    ) -> Option<&mut CollisionObject<N, T>>
+    ) -> Option<&mut CollisionObject<N, T, B>>
     {
         self.objects.get_mut(handle)
     }
 
     /// Sets the collision groups of the given collision object.
     #[inline]
-    pub fn set_collision_groups(&mut self, handle: CollisionObjectHandle, groups: CollisionGroups) {
+    pub fn set_collision_groups(&mut self, handle: CollisionObjectHandle, groups: CollisionGroups<B>) {
         if let Some(co) = self.objects.get_mut(handle) {
             co.set_collision_groups(groups);
             self.broad_phase
@@ -340,8 +585,8 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     pub fn interferences_with_ray<'a, 'b>(
         &'a self,
         ray: &'b Ray<N>,
-        groups: &'b CollisionGroups,
-    ) -> InterferencesWithRay<'a, 'b, N, T>
+        groups: &'b CollisionGroups<B>,
+    ) -> InterferencesWithRay<'a, 'b, N, T, B>
     {
         // FIXME: avoid allocation.
         let mut handles = Vec::new();
@@ -360,8 +605,8 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     pub fn interferences_with_point<'a, 'b>(
         &'a self,
         point: &'b Point<N>,
-        groups: &'b CollisionGroups,
-    ) -> InterferencesWithPoint<'a, 'b, N, T>
+        groups: &'b CollisionGroups<B>,
+    ) -> InterferencesWithPoint<'a, 'b, N, T, B>
     {
         // FIXME: avoid allocation.
         let mut handles = Vec::new();
@@ -381,8 +626,8 @@ impl<N: RealField, T> CollisionWorld<N, T> {
     pub fn interferences_with_aabb<'a, 'b>(
         &'a self,
         aabb: &'b AABB<N>,
-        groups: &'b CollisionGroups,
-    ) -> InterferencesWithAABB<'a, 'b, N, T>
+        groups: &'b CollisionGroups<B>,
+    ) -> InterferencesWithAABB<'a, 'b, N, T, B>
     {
         // FIXME: avoid allocation.
         let mut handles = Vec::new();
@@ -495,6 +740,32 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         self.narrow_phase.interaction_graph().proximity_pair(id1, id2, effective_only)
     }
 
+    /// The proximity status of each part of the collision object `handle` with the collision
+    /// object `other`.
+    ///
+    /// Returns `None` if those objects are not a proximity pair or if the proximity detector does
+    /// not keep track of the parts of `handle`. This is supported for heightfields and triangle
+    /// meshes once the narrow phase uses a `PartwiseProximityDispatcher`:
+    ///
+    /// ```ignore
+    /// world.set_narrow_phase(NarrowPhase::new(
+    ///     Box::new(DefaultContactDispatcher::new()),
+    ///     Box::new(PartwiseProximityDispatcher::new()),
+    /// ));
+    /// ```
+    pub fn part_proximities(&self, handle: CollisionObjectHandle, other: CollisionObjectHandle)
+        -> Option<&PartProximities> {
+        let (handle1, handle2, detector) = self.proximity_pair(handle, other, false)?;
+        let parts = detector.part_proximities()?;
+        let owner = if parts.parts_of_second_shape() { handle2 } else { handle1 };
+
+        if owner == handle {
+            Some(parts)
+        } else {
+            None
+        }
+    }
+
     /// All the interaction pairs involving the specified collision object.
     ///
     /// Refer to the official [user guide](https://nphysics.org/interaction_handling_and_sensors/#interaction-iterators)
@@ -588,11 +859,45 @@ impl<N: RealField, T> CollisionWorld<N, T> {
         &self.proximity_events
     }
 
+    /// The per-part proximity events pool.
+    ///
+    /// Those events are only generated for the collision objects with shapes keeping track of the
+    /// proximity of each of their parts, e.g., heightfields and triangle meshes when the narrow
+    /// phase uses a `PartwiseProximityDispatcher`.
+    pub fn part_proximity_events(&self) -> &PartProximityEvents {
+        &self.part_proximity_events
+    }
+
+    /// The time of impact events pool.
+    ///
+    /// Those events are only generated for collision objects with continuous collision detection
+    /// enabled.
+    pub fn toi_events(&self) -> &TOIEvents<N> {
+        &self.toi_events
+    }
+
+    /// The detailed contact events pool.
+    ///
+    /// This is always empty unless detailed contact events have been enabled with
+    /// `self.set_detailed_contact_events_enabled(true)`.
+    pub fn detailed_contact_events(&self) -> &DetailedContactEvents<N> {
+        &self.detailed_contact_events
+    }
+
+    /// Enables or disables the generation of detailed contact events.
+    ///
+    /// In addition to the plain contact events, each update will then generate one detailed
+    /// contact event for each pair of collision objects that started, remained, or stopped being
+    /// in contact.
+    pub fn set_detailed_contact_events_enabled(&mut self, enabled: bool) {
+        self.narrow_phase.set_detailed_contact_events_enabled(enabled)
+    }
+
     // Filters by group and by the user-provided callback.
     #[inline]
     fn filter_collision(
-        filters: &BroadPhasePairFilters<N, T>,
-        objects: &CollisionObjectSlab<N, T>,
+        filters: &BroadPhasePairFilters<N, T, B>,
+        objects: &CollisionObjectSlab<N, T, B>,
         handle1: CollisionObjectHandle,
         handle2: CollisionObjectHandle,
     ) -> bool
@@ -606,15 +911,15 @@ impl<N: RealField, T> CollisionWorld<N, T> {
 }
 
 /// Iterator through all the objects on the world that intersect a specific ray.
-pub struct InterferencesWithRay<'a, 'b, N: 'a + RealField, T: 'a> {
+pub struct InterferencesWithRay<'a, 'b, N: 'a + RealField, T: 'a, B: 'a + CollisionGroupsBitSet = u32> {
     ray: &'b Ray<N>,
-    objects: &'a CollisionObjectSlab<N, T>,
-    groups: &'b CollisionGroups,
+    objects: &'a CollisionObjectSlab<N, T, B>,
+    groups: &'b CollisionGroups<B>,
     handles: IntoIter<&'a CollisionObjectHandle>,
 }
 
-impl<'a, 'b, N: RealField, T> Iterator for InterferencesWithRay<'a, 'b, N, T> {
-    type Item = (&'a CollisionObject<N, T>, RayIntersection<N>);
+impl<'a, 'b, N: RealField, T, B: CollisionGroupsBitSet> Iterator for InterferencesWithRay<'a, 'b, N, T, B> {
+    type Item = (&'a CollisionObject<N, T, B>, RayIntersection<N>);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
@@ -637,15 +942,15 @@ impl<'a, 'b, N: RealField, T> Iterator for InterferencesWithRay<'a, 'b, N, T> {
 }
 
 /// Iterator through all the objects on the world that intersect a specific point.
-pub struct InterferencesWithPoint<'a, 'b, N: 'a + RealField, T: 'a> {
+pub struct InterferencesWithPoint<'a, 'b, N: 'a + RealField, T: 'a, B: 'a + CollisionGroupsBitSet = u32> {
     point: &'b Point<N>,
-    objects: &'a CollisionObjectSlab<N, T>,
-    groups: &'b CollisionGroups,
+    objects: &'a CollisionObjectSlab<N, T, B>,
+    groups: &'b CollisionGroups<B>,
     handles: IntoIter<&'a CollisionObjectHandle>,
 }
 
-impl<'a, 'b, N: RealField, T> Iterator for InterferencesWithPoint<'a, 'b, N, T> {
-    type Item = &'a CollisionObject<N, T>;
+impl<'a, 'b, N: RealField, T, B: CollisionGroupsBitSet> Iterator for InterferencesWithPoint<'a, 'b, N, T, B> {
+    type Item = &'a CollisionObject<N, T, B>;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
@@ -664,14 +969,14 @@ impl<'a, 'b, N: RealField, T> Iterator for InterferencesWithPoint<'a, 'b, N, T>
 }
 
 /// Iterator through all the objects on the world which bounding volume intersects a specific AABB.
-pub struct InterferencesWithAABB<'a, 'b, N: 'a + RealField, T: 'a> {
-    objects: &'a CollisionObjectSlab<N, T>,
-    groups: &'b CollisionGroups,
+pub struct InterferencesWithAABB<'a, 'b, N: 'a + RealField, T: 'a, B: 'a + CollisionGroupsBitSet = u32> {
+    objects: &'a CollisionObjectSlab<N, T, B>,
+    groups: &'b CollisionGroups<B>,
     handles: IntoIter<&'a CollisionObjectHandle>,
 }
 
-impl<'a, 'b, N: RealField, T> Iterator for InterferencesWithAABB<'a, 'b, N, T> {
-    type Item = &'a CollisionObject<N, T>;
+impl<'a, 'b, N: RealField, T, B: CollisionGroupsBitSet> Iterator for InterferencesWithAABB<'a, 'b, N, T, B> {
+    type Item = &'a CollisionObject<N, T, B>;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
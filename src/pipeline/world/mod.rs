@@ -1,6 +1,7 @@
 //! High level API to detect collisions in large, complex scenes.
 
-pub use self::collision_groups::{CollisionGroups, CollisionGroupsPairFilter};
+pub use self::collision_event_listener::{CollisionEventListener, CollisionEventListeners};
+pub use self::collision_groups::{CollisionGroups, CollisionGroupsBitSet, CollisionGroupsPairFilter};
 pub use self::collision_object::{
     CollisionObject, CollisionObjectHandle, CollisionObjectSlab, CollisionObjects,
     GeometricQueryType,
@@ -10,6 +11,7 @@ pub use self::collision_world::{
     InterferencesWithRay,
 };
 
+mod collision_event_listener;
 mod collision_groups;
 mod collision_object;
 mod collision_world;
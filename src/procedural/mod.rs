@@ -21,15 +21,17 @@ pub use self::quad::{quad, quad_with_vertices, unit_quad};
 pub use self::sphere::{circle, unit_circle};
 #[cfg(feature = "dim3")]
 pub use self::sphere::{sphere, unit_hemisphere, unit_sphere};
-#[cfg(feature = "dim3")]
 pub use self::trimesh::{IndexBuffer, TriMesh};
+#[cfg(feature = "dim3")]
+pub use self::trimesh_repair::TriMeshValidation;
 
 #[cfg(feature = "dim3")]
 pub mod path;
 #[cfg(feature = "dim2")]
 mod polyline;
-#[cfg(feature = "dim3")]
 mod trimesh;
+#[cfg(feature = "dim3")]
+mod trimesh_repair;
 pub mod utils;
 
 mod bezier;
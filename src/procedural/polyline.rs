@@ -1,6 +1,6 @@
 use alga::linear::{Rotation, Translation};
 use crate::math::{Isometry, Point, Vector};
-use na::{self, RealField};
+use na::{self, Point2, RealField};
 
 /// Geometric description of a polyline.
 #[derive(Clone)]
@@ -10,6 +10,8 @@ pub struct Polyline<N: RealField> {
     coords: Vec<Point<N>>,
     /// Coordinates of the polyline normals.
     normals: Option<Vec<Vector<N>>>,
+    /// Indices of the segments of the polyline.
+    indices: Option<Vec<Point2<u32>>>,
 }
 
 impl<N: RealField> Polyline<N> {
@@ -25,8 +27,29 @@ impl<N: RealField> Polyline<N> {
         Polyline {
             coords: coords,
             normals: normals,
+            indices: None,
         }
     }
+
+    /// Creates a new polyline with explicit segments.
+    ///
+    /// Each element of `indices` identifies the two vertices of one segment. This allows the
+    /// polyline to be open, or to be made of several disjoint parts.
+    pub fn with_indices(
+        coords: Vec<Point<N>>,
+        normals: Option<Vec<Vector<N>>>,
+        indices: Vec<Point2<u32>>,
+    ) -> Polyline<N>
+    {
+        assert!(
+            indices.iter().all(|i| (i.x as usize) < coords.len() && (i.y as usize) < coords.len()),
+            "Polyline segment indices out of bounds."
+        );
+
+        let mut res = Self::new(coords, normals);
+        res.indices = Some(indices);
+        res
+    }
 }
 
 impl<N: RealField> Polyline<N> {
@@ -41,6 +64,55 @@ impl<N: RealField> Polyline<N> {
         &self.coords[..]
     }
 
+    /// The explicit segments of this polyline, if any.
+    #[inline]
+    pub fn indices(&self) -> Option<&[Point2<u32>]> {
+        self.indices.as_ref().map(|idx| &idx[..])
+    }
+
+    /// The segments of this polyline.
+    ///
+    /// If this polyline has no explicit segments, its vertices are assumed to form a closed
+    /// loop, except if there are only two of them in which case they form a single segment.
+    pub fn segment_indices(&self) -> Vec<Point2<u32>> {
+        if let Some(idx) = &self.indices {
+            return idx.clone();
+        }
+
+        let n = self.coords.len() as u32;
+
+        match n {
+            0 | 1 => Vec::new(),
+            2 => vec![Point2::new(0, 1)],
+            _ => (0..n).map(|i| Point2::new(i, (i + 1) % n)).collect(),
+        }
+    }
+
+    /// Appends the vertices and segments of `other` to this polyline.
+    ///
+    /// The result always has explicit segments. Its normals are kept only if both polylines
+    /// have normals.
+    pub fn append(&mut self, other: &Polyline<N>) {
+        let offset = self.coords.len() as u32;
+        let mut indices = self.segment_indices();
+        indices.extend(
+            other
+                .segment_indices()
+                .into_iter()
+                .map(|i| i + na::Vector2::repeat(offset)),
+        );
+
+        self.normals = match (self.normals.take(), &other.normals) {
+            (Some(mut ns), Some(other_ns)) => {
+                ns.extend_from_slice(other_ns);
+                Some(ns)
+            }
+            _ => None,
+        };
+        self.coords.extend_from_slice(&other.coords);
+        self.indices = Some(indices);
+    }
+
     /// The mutable coordinates of this polyline vertices.
     #[inline]
     pub fn coords_mut(&mut self) -> &mut [Point<N>] {
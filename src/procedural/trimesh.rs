@@ -1,3 +1,4 @@
+#[cfg(feature = "dim3")]
 use super::utils;
 use alga::linear::Translation;
 use crate::math::{Isometry, Point, Vector};
@@ -147,9 +148,56 @@ impl<N: RealField> TriMesh<N> {
     }
 }
 
+impl<N: RealField> TriMesh<N> {
+    /// Appends the vertices and triangles of `other` to this mesh.
+    ///
+    /// Both meshes are converted to an unified index buffer. The normals and texture
+    /// coordinates of the result are kept only if both meshes have them.
+    pub fn append(&mut self, other: &TriMesh<N>) {
+        let mut other = other.clone();
+        other.unify_index_buffer();
+
+        if self.coords.is_empty() {
+            *self = other;
+            return;
+        }
+
+        self.unify_index_buffer();
+
+        let offset = self.coords.len() as u32;
+
+        self.normals = match (self.normals.take(), other.normals) {
+            (Some(mut ns), Some(other_ns)) => {
+                ns.extend(other_ns);
+                Some(ns)
+            }
+            _ => None,
+        };
+        self.uvs = match (self.uvs.take(), other.uvs) {
+            (Some(mut uvs), Some(other_uvs)) => {
+                uvs.extend(other_uvs);
+                Some(uvs)
+            }
+            _ => None,
+        };
+        self.coords.extend(other.coords);
+
+        if let IndexBuffer::Unified(ref mut idx) = self.indices {
+            idx.extend(
+                other
+                    .indices
+                    .unwrap_unified()
+                    .into_iter()
+                    .map(|i| i + na::Vector3::repeat(offset)),
+            )
+        }
+    }
+}
+
 impl<N: RealField> TriMesh<N> {
     /// Recomputes the mesh normals using its vertex coordinates and adjascency informations
     /// infered from the index buffer.
+    #[cfg(feature = "dim3")]
     #[inline]
     pub fn recompute_normals(&mut self) {
         let mut new_normals = Vec::new();
@@ -352,6 +400,7 @@ impl<N: RealField> TriMesh<N> {
     /// Forces the mesh to use a different index for the vertices, normals and uvs.
     ///
     /// If `recover_topology` is true, this will merge exactly identical vertices together.
+    #[cfg(feature = "dim3")]
     pub fn split_index_buffer(&mut self, recover_topology: bool) {
         let new_indices = match self.indices {
             IndexBuffer::Unified(ref ids) => {
@@ -0,0 +1,799 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+
+use super::{IndexBuffer, TriMesh};
+use crate::bounding_volume::{self, BoundingVolume, AABB};
+use crate::math::{Isometry, Point, Vector};
+use crate::query::proximity_internal::support_map_against_support_map;
+use crate::query::Proximity;
+use crate::shape::Triangle;
+use crate::transformation;
+use crate::utils::DeterministicState;
+use alga::linear::FiniteDimInnerSpace;
+use na::{self, Id, Point2, Point3, RealField};
+
+/// The defects found by `TriMesh::validate`.
+///
+/// Edges are given as pairs of vertex indices, with the smallest index first, and
+/// triangles as indices in the index buffer of the mesh.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TriMeshValidation {
+    /// Triangles with two identical vertices, or with a height smaller than the tolerance.
+    pub degenerate_triangles: Vec<usize>,
+    /// Edges shared by only one triangle.
+    pub boundary_edges: Vec<Point2<u32>>,
+    /// Edges shared by more than two triangles.
+    pub non_manifold_edges: Vec<Point2<u32>>,
+    /// Vertices whose incident triangles are not connected through their edges, e.g., the
+    /// common vertex of two cones touching at their apex.
+    pub non_manifold_vertices: Vec<u32>,
+    /// Edges shared by two triangles traversing it in the same direction.
+    pub inconsistent_edges: Vec<Point2<u32>>,
+    /// Pairs of triangles without any common vertex that intersect each other.
+    pub self_intersections: Vec<(usize, usize)>,
+}
+
+impl TriMeshValidation {
+    /// Whether every edge is shared by exactly two triangles.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.boundary_edges.is_empty() && self.non_manifold_edges.is_empty()
+    }
+
+    /// Whether every edge is shared by at most two triangles and the neighborhood of every
+    /// vertex is a single fan of triangles.
+    #[inline]
+    pub fn is_manifold(&self) -> bool {
+        self.non_manifold_edges.is_empty() && self.non_manifold_vertices.is_empty()
+    }
+
+    /// Whether adjacent triangles have compatible windings.
+    #[inline]
+    pub fn is_consistently_oriented(&self) -> bool {
+        self.inconsistent_edges.is_empty()
+    }
+
+    /// Whether no defect at all was found, i.e., the mesh is the closed, oriented boundary
+    /// of a solid.
+    pub fn is_valid(&self) -> bool {
+        self.degenerate_triangles.is_empty()
+            && self.is_closed()
+            && self.is_manifold()
+            && self.is_consistently_oriented()
+            && self.self_intersections.is_empty()
+    }
+}
+
+impl<N: RealField> TriMesh<N> {
+    /// Checks this mesh for defects.
+    ///
+    /// A triangle is considered degenerate if its height relative to its longest edge is
+    /// smaller than or equal to `tolerance`. Self-intersections are only searched between
+    /// non-degenerate triangles without any common vertex.
+    pub fn validate(&self, tolerance: N) -> TriMeshValidation {
+        let tris = self.coord_triangles();
+        let mut res = TriMeshValidation::default();
+
+        res.degenerate_triangles = (0..tris.len())
+            .filter(|i| self.is_degenerate(&tris[*i], tolerance))
+            .collect();
+
+        for (edge, incident) in edge_map(&tris) {
+            let edge = Point2::new(edge.0, edge.1);
+
+            match incident.len() {
+                1 => res.boundary_edges.push(edge),
+                2 => {
+                    if incident[0].1 == incident[1].1 {
+                        res.inconsistent_edges.push(edge)
+                    }
+                }
+                _ => res.non_manifold_edges.push(edge),
+            }
+        }
+
+        res.boundary_edges.sort_by_key(|e| (e.x, e.y));
+        res.non_manifold_edges.sort_by_key(|e| (e.x, e.y));
+        res.inconsistent_edges.sort_by_key(|e| (e.x, e.y));
+        res.non_manifold_vertices = non_manifold_vertices(self.coords.len(), &tris);
+        res.self_intersections = self.self_intersections(&tris, &res.degenerate_triangles);
+
+        res
+    }
+
+    /// Merges the vertices closer than `tolerance` to each other.
+    ///
+    /// Each vertex is replaced by the first vertex of the mesh within `tolerance` of it, if
+    /// any. The normal and texture coordinates of the kept vertex are used if the index buffer
+    /// is unified. Vertices that are no longer referenced are removed. Returns the number of
+    /// merged vertices.
+    pub fn weld_vertices(&mut self, tolerance: N) -> usize {
+        let cell_size = if tolerance > N::zero() { tolerance } else { N::one() };
+        let cell = |pt: &Point<N>| -> [i64; 3] {
+            let mut res = [0; 3];
+
+            for k in 0..3 {
+                res[k] = unsafe { na::convert_unchecked::<N, f64>((pt[k] / cell_size).floor()) as i64 };
+            }
+
+            res
+        };
+        let mut grid: HashMap<[i64; 3], Vec<u32>, _> = HashMap::with_hasher(DeterministicState::new());
+        let mut remap = Vec::with_capacity(self.coords.len());
+        let mut nmerged = 0;
+
+        for (i, pt) in self.coords.iter().enumerate() {
+            let c = cell(pt);
+            let mut target = None;
+
+            'search: for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        if let Some(ids) = grid.get(&[c[0] + dx, c[1] + dy, c[2] + dz]) {
+                            for id in ids {
+                                if na::distance(pt, &self.coords[*id as usize]) <= tolerance {
+                                    target = Some(*id);
+                                    break 'search;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            match target {
+                Some(id) => {
+                    nmerged += 1;
+                    remap.push(id)
+                }
+                None => {
+                    grid.entry(c).or_insert_with(Vec::new).push(i as u32);
+                    remap.push(i as u32)
+                }
+            }
+        }
+
+        if nmerged != 0 {
+            let unified = self.is_unified();
+            let mut tris = self.corner_triangles();
+
+            for t in &mut tris {
+                for corner in t.iter_mut() {
+                    corner.x = remap[corner.x as usize];
+
+                    if unified {
+                        corner.y = corner.x;
+                        corner.z = corner.x;
+                    }
+                }
+            }
+
+            self.set_corner_triangles(tris);
+            self.remove_unused_vertices();
+        }
+
+        nmerged
+    }
+
+    /// Removes the degenerate triangles of this mesh.
+    ///
+    /// A triangle is degenerate if two of its vertices are identical or if its height
+    /// relative to its longest edge is smaller than or equal to `tolerance`. Vertices that are
+    /// no longer referenced are removed. Returns the number of removed triangles.
+    pub fn remove_degenerate_triangles(&mut self, tolerance: N) -> usize {
+        let coord_tris = self.coord_triangles();
+        let mut tris = self.corner_triangles();
+        let len = tris.len();
+        let mut i = 0;
+
+        tris.retain(|_| {
+            i += 1;
+            !self.is_degenerate(&coord_tris[i - 1], tolerance)
+        });
+
+        let nremoved = len - tris.len();
+
+        if nremoved != 0 {
+            self.set_corner_triangles(tris);
+            self.remove_unused_vertices();
+        }
+
+        nremoved
+    }
+
+    /// Flips triangles so that adjacent triangles have compatible windings.
+    ///
+    /// The orientation of the first triangle of each connected component is propagated to the
+    /// rest of the component through its manifold edges. Closed components are then flipped
+    /// as a whole if needed so that their triangles are counter-clockwise when seen from the
+    /// outside. Returns the number of flipped triangles.
+    pub fn orient_consistently(&mut self) -> usize {
+        let coord_tris = self.coord_triangles();
+        let edges = edge_map(&coord_tris);
+        let mut flipped = vec![false; coord_tris.len()];
+        let mut visited = vec![false; coord_tris.len()];
+        let mut nflipped = 0;
+
+        for start in 0..coord_tris.len() {
+            if visited[start] {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut closed = true;
+            let mut queue = VecDeque::new();
+            visited[start] = true;
+            queue.push_back(start);
+
+            while let Some(t) = queue.pop_front() {
+                component.push(t);
+
+                for k in 0..3 {
+                    let (a, b) = (coord_tris[t][k], coord_tris[t][(k + 1) % 3]);
+                    let incident = match edges.get(&(a.min(b), a.max(b))) {
+                        Some(incident) => incident,
+                        None => continue,
+                    };
+
+                    if incident.len() != 2 {
+                        closed = false;
+                        continue;
+                    }
+
+                    let (t_forward, (u, u_forward)) = if incident[0].0 == t {
+                        (incident[0].1, incident[1])
+                    } else {
+                        (incident[1].1, incident[0])
+                    };
+
+                    if !visited[u] {
+                        visited[u] = true;
+                        // The neighbor must traverse the edge in the opposite direction.
+                        flipped[u] = u_forward == (t_forward != flipped[t]);
+                        queue.push_back(u);
+                    }
+                }
+            }
+
+            if closed {
+                let mut volume = N::zero();
+
+                for t in &component {
+                    let [a, b, c] = self.triangle_points(&coord_tris[*t]);
+                    let det = a.coords.dot(&b.coords.cross(&c.coords));
+                    volume += if flipped[*t] { -det } else { det };
+                }
+
+                if volume < N::zero() {
+                    for t in &component {
+                        flipped[*t] = !flipped[*t];
+                    }
+                }
+            }
+        }
+
+        let mut tris = self.corner_triangles();
+
+        for (t, flip) in tris.iter_mut().zip(flipped.iter()) {
+            if *flip {
+                t.swap(1, 2);
+                nflipped += 1;
+            }
+        }
+
+        self.set_corner_triangles(tris);
+        nflipped
+    }
+
+    /// Closes the holes of this mesh bounded by at most `max_edges` edges.
+    ///
+    /// The holes are the loops of boundary edges. Each of them is projected on the plane
+    /// orthogonal to its average normal and filled with the constrained Delaunay triangulation
+    /// of the projection, with windings compatible with the adjacent triangles. Loops passing
+    /// through a vertex with several incident boundary loops, and loops whose projection is
+    /// not a simple polygon, are ignored. Returns the number of filled holes.
+    pub fn fill_holes(&mut self, max_edges: usize) -> usize {
+        let coord_tris = self.coord_triangles();
+        let mut tris = self.corner_triangles();
+        let mut corner_of = HashMap::with_hasher(DeterministicState::new());
+        // The next vertex of each hole boundary, the reverse of the boundary edges.
+        let mut next = HashMap::with_hasher(DeterministicState::new());
+        let mut ambiguous = Vec::new();
+
+        for (t, corners) in coord_tris.iter().zip(tris.iter()) {
+            for k in 0..3 {
+                let _ = corner_of.insert(t[k], corners[k]);
+            }
+        }
+
+        for ((lo, hi), incident) in edge_map(&coord_tris) {
+            if incident.len() == 1 {
+                let (a, b) = if incident[0].1 { (lo, hi) } else { (hi, lo) };
+
+                if next.insert(b, a).is_some() {
+                    ambiguous.push(b)
+                }
+            }
+        }
+
+        let mut visited: HashMap<u32, (), _> = HashMap::with_hasher(DeterministicState::new());
+        let mut starts: Vec<u32> = next.keys().cloned().collect();
+        let mut nfilled = 0;
+        starts.sort();
+
+        for start in starts {
+            if visited.contains_key(&start) {
+                continue;
+            }
+
+            let mut hole = vec![start];
+            let mut curr = start;
+            let _ = visited.insert(start, ());
+
+            while let Some(n) = next.get(&curr) {
+                if *n == start {
+                    break;
+                }
+
+                if visited.insert(*n, ()).is_some() {
+                    hole.clear();
+                    break;
+                }
+
+                hole.push(*n);
+                curr = *n;
+            }
+
+            let is_loop = next.get(&curr) == Some(&start);
+
+            if is_loop
+                && hole.len() >= 3
+                && hole.len() <= max_edges
+                && !hole.iter().any(|v| ambiguous.contains(v))
+            {
+                if let Some(fill) = self.triangulate_hole(&hole) {
+                    for t in fill {
+                        tris.push([
+                            corner_of[&hole[t[0]]],
+                            corner_of[&hole[t[1]]],
+                            corner_of[&hole[t[2]]],
+                        ])
+                    }
+
+                    nfilled += 1;
+                }
+            }
+        }
+
+        if nfilled != 0 {
+            self.set_corner_triangles(tris);
+        }
+
+        nfilled
+    }
+
+    /// Splits the boundary edges passing through the vertex of another boundary edge.
+    ///
+    /// A T-junction is a vertex closer than `tolerance` to the interior of a boundary edge of
+    /// an adjacent triangle, which leaves a crack between both sides. The triangle of this edge
+    /// is split at each such vertex, so that both sides of the crack share the same edges.
+    /// Returns the number of edge splits.
+    pub fn split_t_junctions(&mut self, tolerance: N) -> usize {
+        let mut nsplits = 0;
+
+        // Each pass splits at most one edge of each triangle.
+        loop {
+            let coord_tris = self.coord_triangles();
+            let mut tris = self.corner_triangles();
+            let mut corner_of = HashMap::with_hasher(DeterministicState::new());
+            let edges = edge_map(&coord_tris);
+            // The boundary edges, as a triangle and the index of the edge in this triangle.
+            let mut boundary = Vec::new();
+
+            for (i, (t, corners)) in coord_tris.iter().zip(tris.iter()).enumerate() {
+                for k in 0..3 {
+                    let _ = corner_of.insert(t[k], corners[k]);
+
+                    if t[k] != t[(k + 1) % 3] && edges[&edge_key(t, k)].len() == 1 {
+                        boundary.push((i, k))
+                    }
+                }
+            }
+
+            // The boundary vertices sorted along the x axis.
+            let mut vertices: Vec<u32> = boundary
+                .iter()
+                .flat_map(|(t, k)| vec![coord_tris[*t][*k], coord_tris[*t][(k + 1) % 3]])
+                .collect();
+            vertices.sort();
+            vertices.dedup();
+            vertices.sort_by(|a, b| {
+                self.coords[*a as usize].x
+                    .partial_cmp(&self.coords[*b as usize].x)
+                    .unwrap_or(Ordering::Equal)
+            });
+
+            let mut split = vec![false; tris.len()];
+            let mut new_tris = Vec::new();
+
+            for (t, k) in boundary {
+                if split[t] {
+                    continue;
+                }
+
+                let tri = coord_tris[t];
+                let (a, b, c) = (tri[k], tri[(k + 1) % 3], tri[(k + 2) % 3]);
+                let (pa, pb) = (self.coords[a as usize], self.coords[b as usize]);
+                let ab = pb - pa;
+                let sq_len = ab.norm_squared();
+
+                if sq_len.is_zero() {
+                    continue;
+                }
+
+                let min_x = pa.x.min(pb.x) - tolerance;
+                let max_x = pa.x.max(pb.x) + tolerance;
+                let first = vertices
+                    .iter()
+                    .position(|v| self.coords[*v as usize].x >= min_x)
+                    .unwrap_or(vertices.len());
+                let mut on_edge: Vec<(N, u32)> = vertices[first..]
+                    .iter()
+                    .take_while(|v| self.coords[**v as usize].x <= max_x)
+                    .filter(|v| **v != a && **v != b && **v != c)
+                    .filter_map(|v| {
+                        let pt = self.coords[*v as usize];
+                        let s = (pt - pa).dot(&ab) / sq_len;
+                        let on_interior = s > N::zero()
+                            && s < N::one()
+                            && na::distance(&pt, &(pa + ab * s)) <= tolerance;
+
+                        if on_interior {
+                            Some((s, *v))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+
+                if on_edge.is_empty() {
+                    continue;
+                }
+
+                on_edge.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(Ordering::Equal));
+
+                let corner_c = tris[t][(k + 2) % 3];
+                let mut prev = tris[t][k];
+
+                for (_, v) in &on_edge {
+                    new_tris.push([prev, corner_of[v], corner_c]);
+                    prev = corner_of[v];
+                }
+
+                new_tris.push([prev, tris[t][(k + 1) % 3], corner_c]);
+                split[t] = true;
+                nsplits += on_edge.len();
+            }
+
+            if new_tris.is_empty() {
+                break;
+            }
+
+            let mut i = 0;
+            tris.retain(|_| {
+                i += 1;
+                !split[i - 1]
+            });
+            tris.extend(new_tris);
+            self.set_corner_triangles(tris);
+        }
+
+        nsplits
+    }
+
+    /// Applies all the repair operations to this mesh.
+    ///
+    /// This welds the vertices closer than `tolerance`, removes the degenerate triangles,
+    /// splits the T-junctions, orients the triangles consistently, and fills the holes bounded
+    /// by at most `max_hole_edges` edges, in that order.
+    pub fn repair(&mut self, tolerance: N, max_hole_edges: usize) {
+        let _ = self.weld_vertices(tolerance);
+        let _ = self.remove_degenerate_triangles(tolerance);
+        let _ = self.split_t_junctions(tolerance);
+        let _ = self.orient_consistently();
+
+        if self.fill_holes(max_hole_edges) != 0 {
+            let _ = self.orient_consistently();
+        }
+    }
+
+    fn is_unified(&self) -> bool {
+        match self.indices {
+            IndexBuffer::Unified(_) => true,
+            IndexBuffer::Split(_) => false,
+        }
+    }
+
+    fn coord_triangles(&self) -> Vec<[u32; 3]> {
+        self.flat_indices()
+            .chunks(3)
+            .map(|t| [t[0], t[1], t[2]])
+            .collect()
+    }
+
+    // The triangles as split indices, whatever the kind of the index buffer.
+    fn corner_triangles(&self) -> Vec<[Point3<u32>; 3]> {
+        match &self.indices {
+            IndexBuffer::Unified(idx) => idx
+                .iter()
+                .map(|t| [Point3::new(t.x, t.x, t.x), Point3::new(t.y, t.y, t.y), Point3::new(t.z, t.z, t.z)])
+                .collect(),
+            IndexBuffer::Split(idx) => idx.iter().map(|t| [t.x, t.y, t.z]).collect(),
+        }
+    }
+
+    fn set_corner_triangles(&mut self, tris: Vec<[Point3<u32>; 3]>) {
+        self.indices = if self.is_unified() {
+            IndexBuffer::Unified(
+                tris.iter()
+                    .map(|t| Point3::new(t[0].x, t[1].x, t[2].x))
+                    .collect(),
+            )
+        } else {
+            IndexBuffer::Split(
+                tris.iter()
+                    .map(|t| Point3::new(t[0], t[1], t[2]))
+                    .collect(),
+            )
+        };
+    }
+
+    // Removes the vertices not referenced by any triangle, together with their normal and
+    // texture coordinates if the index buffer is unified.
+    fn remove_unused_vertices(&mut self) {
+        let unified = self.is_unified();
+        let mut tris = self.corner_triangles();
+        let mut remap = vec![None; self.coords.len()];
+        let mut nused = 0;
+
+        for t in &mut tris {
+            for corner in t.iter_mut() {
+                let id = remap[corner.x as usize].unwrap_or_else(|| {
+                    nused += 1;
+                    nused - 1
+                });
+                remap[corner.x as usize] = Some(id);
+                corner.x = id;
+
+                if unified {
+                    corner.y = id;
+                    corner.z = id;
+                }
+            }
+        }
+
+        let compact = |len: usize| {
+            let mut order = vec![0; nused as usize];
+
+            for i in 0..len {
+                if let Some(id) = remap[i] {
+                    order[id as usize] = i;
+                }
+            }
+
+            order
+        };
+        let order = compact(self.coords.len());
+
+        self.coords = order.iter().map(|i| self.coords[*i]).collect();
+
+        if unified {
+            if let Some(normals) = &mut self.normals {
+                *normals = order.iter().map(|i| normals[*i]).collect();
+            }
+
+            if let Some(uvs) = &mut self.uvs {
+                *uvs = order.iter().map(|i| uvs[*i]).collect();
+            }
+        }
+
+        self.set_corner_triangles(tris);
+    }
+
+    // Triangulates a loop of vertices, given as indices of its vertices in the loop. The
+    // triangles traverse the edges of the loop in the same direction as the loop.
+    fn triangulate_hole(&self, hole: &[u32]) -> Option<Vec<[usize; 3]>> {
+        let origin = self.coords[hole[0] as usize];
+        let mut normal = Vector::<N>::zeros();
+
+        for k in 0..hole.len() {
+            let a = self.coords[hole[k] as usize] - origin;
+            let b = self.coords[hole[(k + 1) % hole.len()] as usize] - origin;
+            normal += a.cross(&b);
+        }
+
+        if normal.norm_squared().is_zero() {
+            return None;
+        }
+
+        let mut basis = [Vector::zeros(); 2];
+        let mut k = 0;
+
+        Vector::orthonormal_subspace_basis(&[normal.normalize()], |b| {
+            basis[k] = *b;
+            k += 1;
+            true
+        });
+
+        // Make the projection of the loop counter-clockwise.
+        if basis[0].cross(&basis[1]).dot(&normal) < N::zero() {
+            basis.swap(0, 1);
+        }
+
+        let projected = hole
+            .iter()
+            .map(|v| {
+                let dpt = self.coords[*v as usize] - origin;
+                Point2::new(dpt.dot(&basis[0]), dpt.dot(&basis[1]))
+            })
+            .collect();
+        let (vertices, triangles) = transformation::triangulate_loops(vec![projected]).ok()?;
+
+        // Vertices merged by the projection cannot be mapped back to the loop.
+        if vertices.len() != hole.len() {
+            return None;
+        }
+
+        Some(
+            triangles
+                .iter()
+                .map(|t| [t.x as usize, t.y as usize, t.z as usize])
+                .collect(),
+        )
+    }
+
+    fn triangle_points(&self, t: &[u32; 3]) -> [Point<N>; 3] {
+        [
+            self.coords[t[0] as usize],
+            self.coords[t[1] as usize],
+            self.coords[t[2] as usize],
+        ]
+    }
+
+    fn is_degenerate(&self, t: &[u32; 3], tolerance: N) -> bool {
+        if t[0] == t[1] || t[1] == t[2] || t[2] == t[0] {
+            return true;
+        }
+
+        let [a, b, c] = self.triangle_points(t);
+        let longest = na::distance(&a, &b)
+            .max(na::distance(&b, &c))
+            .max(na::distance(&c, &a));
+        let twice_area = (b - a).cross(&(c - a)).norm();
+
+        longest == N::zero() || twice_area / longest <= tolerance
+    }
+
+    fn self_intersections(&self, tris: &[[u32; 3]], degenerate: &[usize]) -> Vec<(usize, usize)> {
+        let mut aabbs: Vec<(AABB<N>, usize)> = (0..tris.len())
+            .filter(|i| degenerate.binary_search(i).is_err())
+            .map(|i| {
+                let pts = self.triangle_points(&tris[i]);
+                (bounding_volume::point_cloud_aabb(&Id::new(), &pts[..]), i)
+            })
+            .collect();
+        let mut res = Vec::new();
+
+        // Sweep along the x axis.
+        aabbs.sort_by(|a, b| {
+            a.0.mins().x
+                .partial_cmp(&b.0.mins().x)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        for (k, (aabb1, i1)) in aabbs.iter().enumerate() {
+            for (aabb2, i2) in &aabbs[k + 1..] {
+                if aabb2.mins().x > aabb1.maxs().x {
+                    break;
+                }
+
+                let (t1, t2) = (&tris[*i1], &tris[*i2]);
+
+                if !aabb1.intersects(aabb2) || t1.iter().any(|v| t2.contains(v)) {
+                    continue;
+                }
+
+                let [a1, b1, c1] = self.triangle_points(t1);
+                let [a2, b2, c2] = self.triangle_points(t2);
+                let tri1 = Triangle::new(a1, b1, c1);
+                let tri2 = Triangle::new(a2, b2, c2);
+                let id = Isometry::identity();
+
+                if support_map_against_support_map(&id, &tri1, &id, &tri2, N::zero())
+                    == Proximity::Intersecting
+                {
+                    res.push((*i1.min(i2), *i1.max(i2)))
+                }
+            }
+        }
+
+        res.sort();
+        res
+    }
+}
+
+fn edge_key(t: &[u32; 3], k: usize) -> (u32, u32) {
+    let (a, b) = (t[k], t[(k + 1) % 3]);
+    (a.min(b), a.max(b))
+}
+
+// Maps each undirected edge to its incident triangles, with a flag indicating if the triangle
+// traverses it from its smallest to its largest vertex index.
+fn edge_map(tris: &[[u32; 3]]) -> HashMap<(u32, u32), Vec<(usize, bool)>, DeterministicState> {
+    let mut map: HashMap<(u32, u32), Vec<(usize, bool)>, _> =
+        HashMap::with_hasher(DeterministicState::new());
+
+    for (i, t) in tris.iter().enumerate() {
+        for k in 0..3 {
+            let (a, b) = (t[k], t[(k + 1) % 3]);
+
+            if a != b {
+                map.entry(edge_key(t, k)).or_insert_with(Vec::new).push((i, a < b))
+            }
+        }
+    }
+
+    map
+}
+
+fn non_manifold_vertices(nvertices: usize, tris: &[[u32; 3]]) -> Vec<u32> {
+    // For each vertex, the opposite edges of its incident triangles.
+    let mut fans = vec![Vec::new(); nvertices];
+
+    for t in tris {
+        if t[0] != t[1] && t[1] != t[2] && t[2] != t[0] {
+            for k in 0..3 {
+                fans[t[k] as usize].push((t[(k + 1) % 3], t[(k + 2) % 3]))
+            }
+        }
+    }
+
+    let mut res = Vec::new();
+
+    for (v, fan) in fans.iter().enumerate() {
+        if fan.len() < 2 {
+            continue;
+        }
+
+        // Count the connected components of the graph formed by the opposite edges.
+        let mut parent: HashMap<u32, u32, _> = HashMap::with_hasher(DeterministicState::new());
+
+        fn find(parent: &mut HashMap<u32, u32, DeterministicState>, v: u32) -> u32 {
+            let p = *parent.entry(v).or_insert(v);
+
+            if p == v {
+                v
+            } else {
+                let root = find(parent, p);
+                let _ = parent.insert(v, root);
+                root
+            }
+        }
+
+        for (a, b) in fan {
+            let (ra, rb) = (find(&mut parent, *a), find(&mut parent, *b));
+            let _ = parent.insert(ra, rb);
+        }
+
+        let keys: Vec<u32> = parent.keys().cloned().collect();
+        let mut roots: Vec<u32> = keys.into_iter().map(|k| find(&mut parent, k)).collect();
+        roots.sort();
+        roots.dedup();
+
+        if roots.len() > 1 {
+            res.push(v as u32)
+        }
+    }
+
+    res
+}
@@ -2,6 +2,7 @@
 
 pub use self::ball_against_ball::ball_against_ball;
 pub use self::closest_points::ClosestPoints;
+pub use self::composite_shape_against_composite_shape::composite_shape_against_composite_shape;
 pub use self::composite_shape_against_shape::{
     composite_shape_against_shape, shape_against_composite_shape,
 };
@@ -17,6 +18,7 @@ pub use self::support_map_against_support_map::support_map_against_support_map_w
 
 mod ball_against_ball;
 mod closest_points;
+mod composite_shape_against_composite_shape;
 mod composite_shape_against_shape;
 mod line_against_line;
 mod plane_against_support_map;
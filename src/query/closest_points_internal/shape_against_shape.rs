@@ -30,6 +30,8 @@ pub fn shape_against_shape<N: RealField>(
         closest_points_internal::support_map_against_plane(m1, s1, m2, p2, max_dist)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         closest_points_internal::support_map_against_support_map(m1, s1, m2, s2, max_dist)
+    } else if let (Some(c1), Some(c2)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        closest_points_internal::composite_shape_against_composite_shape(m1, c1, m2, c2, max_dist)
     } else if let Some(c1) = g1.as_composite_shape() {
         closest_points_internal::composite_shape_against_shape(m1, c1, m2, g2, max_dist)
     } else if let Some(c2) = g2.as_composite_shape() {
@@ -0,0 +1,105 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, DIM};
+use na::RealField;
+use crate::partitioning::{
+    BestFirstBVVisitStatus, BestFirstDataVisitStatus, SimultaneousBestFirstVisitor,
+};
+use crate::query::contacts_internal;
+use crate::query::Contact;
+use crate::shape::CompositeShape;
+
+/// Deepest contact between two composite shapes.
+///
+/// This performs a best-first traversal of the bounding volume test tree formed by the
+/// acceleration structures of both shapes, using the signed distance between two AABBs as a
+/// lower bound of the opposite of the penetration depth of the parts they contain.
+pub fn composite_shape_against_composite_shape<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    prediction: N,
+) -> Option<Contact<N>>
+where
+    N: RealField,
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    let mut visitor = CompositeShapeAgainstCompositeShapeContactVisitor {
+        ls_m2: m1.inverse() * m2,
+        prediction: prediction,
+        m1: m1,
+        g1: g1,
+        m2: m2,
+        g2: g2,
+    };
+
+    g1.bvh().best_first_search_bvtt(g2.bvh(), &mut visitor)
+}
+
+struct CompositeShapeAgainstCompositeShapeContactVisitor<
+    'a,
+    N: 'a + RealField,
+    G1: ?Sized + 'a,
+    G2: ?Sized + 'a,
+> {
+    ls_m2: Isometry<N>,
+    prediction: N,
+
+    m1: &'a Isometry<N>,
+    g1: &'a G1,
+    m2: &'a Isometry<N>,
+    g2: &'a G2,
+}
+
+impl<'a, N, G1: ?Sized, G2: ?Sized> SimultaneousBestFirstVisitor<N, usize, AABB<N>>
+    for CompositeShapeAgainstCompositeShapeContactVisitor<'a, N, G1, G2>
+where
+    N: RealField,
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    type Result = Contact<N>;
+
+    fn visit_bv(&mut self, bv1: &AABB<N>, bv2: &AABB<N>) -> BestFirstBVVisitStatus<N> {
+        let ls_bv2 = bv2.transform_by(&self.ls_m2);
+
+        // The gaps between the two AABBs along each axis. If one of them is positive,
+        // the AABBs are disjoint. Otherwise, the AABB penetration depth is given by the
+        // smallest overlap. In both cases, this gives a lower bound of the opposite of the
+        // penetration depth of any pair of shapes bounded by those AABBs.
+        let gaps = (bv1.mins() - ls_bv2.maxs()).zip_map(&(ls_bv2.mins() - bv1.maxs()), |a, b| a.max(b));
+        let mut max_gap = gaps[0];
+
+        for i in 1..DIM {
+            max_gap = max_gap.max(gaps[i]);
+        }
+
+        let signed_distance = if max_gap > N::zero() {
+            gaps.map(|gap| gap.max(N::zero())).norm()
+        } else {
+            max_gap
+        };
+
+        if signed_distance > self.prediction {
+            BestFirstBVVisitStatus::Stop
+        } else {
+            BestFirstBVVisitStatus::ContinueWithCost(signed_distance)
+        }
+    }
+
+    fn visit_data(&mut self, b1: &usize, b2: &usize) -> BestFirstDataVisitStatus<N, Contact<N>> {
+        let mut res = BestFirstDataVisitStatus::Continue;
+        let (m2, g2, prediction) = (self.m2, self.g2, self.prediction);
+
+        self.g1.map_part_at(*b1, self.m1, &mut |m1, g1| {
+            g2.map_part_at(*b2, m2, &mut |m2, g2| {
+                if let Some(c) = contacts_internal::contact_internal(m1, g1, m2, g2, prediction) {
+                    res = BestFirstDataVisitStatus::ContinueWithResult(-c.depth, c)
+                }
+            })
+        });
+
+        res
+    }
+}
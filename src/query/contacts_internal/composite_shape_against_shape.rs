@@ -2,7 +2,6 @@ use crate::bounding_volume::BoundingVolume;
 use crate::math::Isometry;
 use na::{self, RealField};
 use crate::query::contacts_internal;
-use crate::query::visitors::BoundingVolumeInterferencesCollector;
 use crate::query::Contact;
 use crate::shape::{CompositeShape, Shape};
 
@@ -23,10 +22,7 @@ where
 
     let mut interferences = Vec::new();
 
-    {
-        let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut interferences);
-        g1.bvh().visit(&mut visitor);
-    }
+    g1.parts_intersecting_local_aabb(&ls_aabb2, &mut interferences);
 
     let mut res = None::<Contact<N>>;
 
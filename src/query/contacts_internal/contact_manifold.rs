@@ -1,4 +1,8 @@
 use crate::math::Point;
+#[cfg(feature = "dim3")]
+use crate::math::Vector;
+#[cfg(feature = "dim3")]
+use alga::linear::FiniteDimInnerSpace;
 use na::{self, RealField};
 use crate::query::{Contact, ContactKinematic, TrackedContact};
 use crate::shape::FeatureId;
@@ -24,6 +28,24 @@ enum ContactCache<N: RealField> {
     DistanceBased(Vec<(Point<N>, usize)>, N),
 }
 
+#[derive(Clone, Debug)]
+struct ManifoldContact<N: RealField> {
+    contact: TrackedContact<N>,
+    // Equal to the manifold persistence if this contact was generated during the current update.
+    timestamp: usize,
+    // Set to `false` by `retain` and `reduce`. Disabled contacts are not part of the manifold but
+    // are still cached until the next update.
+    enabled: bool,
+    // Whether this contact was part of the manifold at the end of the previous update.
+    was_enabled: bool,
+}
+
+impl<N: RealField> ManifoldContact<N> {
+    fn is_active(&self, persistence: usize) -> bool {
+        self.timestamp == persistence && self.enabled
+    }
+}
+
 /// A contact manifold.
 ///
 /// A contact manifold is a set of contacts between two shapes.
@@ -35,7 +57,7 @@ pub struct ContactManifold<N: RealField> {
     ncontacts: usize,
     persistence: usize,
     deepest: usize,
-    contacts: Slab<(TrackedContact<N>, usize)>,
+    contacts: Slab<ManifoldContact<N>>,
     cache: ContactCache<N>,
 }
 
@@ -63,15 +85,21 @@ impl<N: RealField> ContactManifold<N> {
         let persistence = self.persistence;
         self.contacts
             .iter()
-            .filter_map(move |(_, c)| if c.1 == persistence { Some(&c.0) } else { None })
+            .filter_map(move |(_, c)| {
+                if c.is_active(persistence) {
+                    Some(&c.contact)
+                } else {
+                    None
+                }
+            })
     }
 
     /// Mutable reference to all the contact tracked by this manifold.
     pub fn contacts_mut(&mut self) -> impl Iterator<Item = &mut TrackedContact<N>> {
         let persistence = self.persistence;
         self.contacts.iter_mut().filter_map(move |(_, c)| {
-            if c.1 == persistence {
-                Some(&mut c.0)
+            if c.is_active(persistence) {
+                Some(&mut c.contact)
             } else {
                 None
             }
@@ -81,16 +109,126 @@ impl<N: RealField> ContactManifold<N> {
     /// The contact of this manifold with the deepest penetration depth.
     pub fn deepest_contact(&self) -> Option<&TrackedContact<N>> {
         if self.len() != 0 {
-            Some(&self.contacts[self.deepest].0)
+            Some(&self.contacts[self.deepest].contact)
         } else {
             None
         }
     }
 
+    /// Disables every contact for which `f` returns `false`.
+    ///
+    /// The disabled contacts are no longer part of this manifold but their identifiers are still
+    /// cached until the next update, i.e., a contact generated at the same location (or on the
+    /// same features) during the next update will be given the same identifier.
+    pub fn retain(&mut self, mut f: impl FnMut(&mut TrackedContact<N>) -> bool) {
+        let persistence = self.persistence;
+        let mut ncontacts = 0;
+        let mut deepest = None;
+
+        for (i, c) in self.contacts.iter_mut() {
+            if c.is_active(persistence) {
+                if f(&mut c.contact) {
+                    ncontacts += 1;
+
+                    match deepest {
+                        Some((_, depth)) if depth >= c.contact.contact.depth => {}
+                        _ => deepest = Some((i, c.contact.contact.depth)),
+                    }
+                } else {
+                    c.enabled = false;
+                }
+            }
+        }
+
+        self.ncontacts = ncontacts;
+        self.deepest = deepest.map(|d| d.0).unwrap_or(0);
+    }
+
+    /// Disables contacts until at most `max_contacts` remain in this manifold.
+    ///
+    /// The deepest contact is always kept. The other ones are selected greedily so that the
+    /// polygon formed by the retained contacts has the largest area, ties (e.g. in 2D where this
+    /// polygon is degenerate) being broken by keeping the contacts farthest from the ones
+    /// already selected. As with `retain`, the identifiers of the disabled contacts remain cached
+    /// until the next update.
+    ///
+    /// In order to keep the same contacts from one update to the next, the score of the contacts
+    /// that were part of this manifold at the end of the previous update is increased by 20%.
+    pub fn reduce(&mut self, max_contacts: usize) {
+        if self.ncontacts <= max_contacts {
+            return;
+        }
+
+        if max_contacts == 0 {
+            self.retain(|_| false);
+            return;
+        }
+
+        let persistence = self.persistence;
+        let hysteresis: N = na::convert(1.2);
+        let mut candidates: Vec<(usize, Point<N>, bool)> = self
+            .contacts
+            .iter()
+            .filter(|(i, c)| c.is_active(persistence) && *i != self.deepest)
+            .map(|(i, c)| (i, c.contact.contact.world1, c.was_enabled))
+            .collect();
+        let deepest = &self.contacts[self.deepest].contact.contact;
+        let mut selected = vec![(self.deepest, deepest.world1, true)];
+
+        #[cfg(feature = "dim3")]
+        let basis = {
+            let mut basis = [Vector::zeros(), Vector::zeros()];
+            let mut k = 0;
+            Vector::orthonormal_subspace_basis(&[deepest.normal.into_inner()], |b| {
+                basis[k] = *b;
+                k += 1;
+                true
+            });
+            basis
+        };
+
+        while selected.len() < max_contacts && !candidates.is_empty() {
+            let mut best = 0;
+            let mut best_score = (-N::one(), -N::one());
+
+            for (i, candidate) in candidates.iter().enumerate() {
+                #[cfg(feature = "dim2")]
+                let mut area = N::zero();
+                #[cfg(feature = "dim3")]
+                let mut area = projected_hull_area(
+                    selected.iter().chain(Some(candidate)).map(|c| &c.1),
+                    &basis,
+                );
+                let mut dist = selected
+                    .iter()
+                    .map(|s| na::distance_squared(&s.1, &candidate.1))
+                    .fold(N::max_value(), |a, b| a.min(b));
+
+                if candidate.2 {
+                    area *= hysteresis;
+                    dist *= hysteresis;
+                }
+
+                if area > best_score.0 || (area == best_score.0 && dist > best_score.1) {
+                    best = i;
+                    best_score = (area, dist);
+                }
+            }
+
+            selected.push(candidates.swap_remove(best));
+        }
+
+        for (i, _, _) in candidates {
+            self.contacts[i].enabled = false;
+        }
+
+        self.ncontacts = selected.len();
+    }
+
     /// Empty the manifold as well as its cache.
     pub fn clear(&mut self, gen: &mut IdAllocator) {
         for c in &self.contacts {
-            gen.free((c.1).0.id)
+            gen.free(c.1.contact.id)
         }
 
         match &mut self.cache {
@@ -140,22 +278,25 @@ impl<N: RealField> ContactManifold<N> {
         match &mut self.cache {
             ContactCache::DistanceBased(cache, _) => {
                 let ctcts = &self.contacts;
-                cache.retain(|c| ctcts[c.1].1 != 0);
+                cache.retain(|c| ctcts[c.1].timestamp != 0);
             }
             ContactCache::FeatureBased(cache) => {
                 let ctcts = &self.contacts;
-                cache.retain(|_k, v| ctcts[*v].1 != 0);
+                cache.retain(|_k, v| ctcts[*v].timestamp != 0);
             }
         }
 
+        let persistence = self.persistence;
         self.deepest = 0;
         self.ncontacts = 0;
         self.contacts.retain(|_i, c| {
-            if c.1 == 0 {
-                gen.free(c.0.id);
+            if c.timestamp == 0 {
+                gen.free(c.contact.id);
                 false
             } else {
-                c.1 -= 1;
+                c.was_enabled = c.is_active(persistence);
+                c.timestamp -= 1;
+                c.enabled = true;
                 true
             }
         });
@@ -191,7 +332,7 @@ impl<N: RealField> ContactManifold<N> {
         }
 
         let is_deepest = self.ncontacts == 0
-            || contact.depth > self.contacts[self.deepest].0.contact.depth;
+            || contact.depth > self.contacts[self.deepest].contact.contact.depth;
 
         match &mut self.cache {
             ContactCache::DistanceBased(cache, threshold) => {
@@ -208,7 +349,12 @@ impl<N: RealField> ContactManifold<N> {
 
                 if closest == cache.len() {
                     let tracked = TrackedContact::new(contact, kinematic, gen.alloc());
-                    let i = self.contacts.insert((tracked, self.persistence));
+                    let i = self.contacts.insert(ManifoldContact {
+                        contact: tracked,
+                        timestamp: self.persistence,
+                        enabled: true,
+                        was_enabled: false,
+                    });
                     cache.push((tracking_pt, i));
                     self.ncontacts += 1;
 
@@ -225,18 +371,19 @@ impl<N: RealField> ContactManifold<N> {
 
                     let c = &mut self.contacts[contact_i];
 
-                    if c.1 == self.persistence {
-                        if contact.depth <= c.0.contact.depth {
+                    if c.is_active(self.persistence) {
+                        if contact.depth <= c.contact.contact.depth {
                             // Keep the contact already in cache because it is deeper.
                             return true;
                         }
                     } else {
                         self.ncontacts += 1;
-                        c.1 = self.persistence;
+                        c.timestamp = self.persistence;
+                        c.enabled = true;
                     }
 
-                    c.0.contact = contact;
-                    c.0.kinematic = kinematic;
+                    c.contact.contact = contact;
+                    c.contact.kinematic = kinematic;
                     cache[closest].0 = tracking_pt;
 
                     true
@@ -246,7 +393,12 @@ impl<N: RealField> ContactManifold<N> {
                 match cache.entry((kinematic.feature1(), kinematic.feature2())) {
                     Entry::Vacant(e) => {
                         let tracked = TrackedContact::new(contact, kinematic, gen.alloc());
-                        let i = self.contacts.insert((tracked, self.persistence));
+                        let i = self.contacts.insert(ManifoldContact {
+                            contact: tracked,
+                            timestamp: self.persistence,
+                            enabled: true,
+                            was_enabled: false,
+                        });
                         let _ = e.insert(i);
                         self.ncontacts += 1;
 
@@ -263,18 +415,19 @@ impl<N: RealField> ContactManifold<N> {
 
                         let c = &mut self.contacts[*e.get()];
 
-                        if c.1 == self.persistence {
-                            if contact.depth <= c.0.contact.depth {
+                        if c.is_active(self.persistence) {
+                            if contact.depth <= c.contact.contact.depth {
                                 // Keep the contact already in cache because it is deeper.
                                 return true;
                             }
                         } else {
                             self.ncontacts += 1;
-                            c.1 = self.persistence;
+                            c.timestamp = self.persistence;
+                            c.enabled = true;
                         }
 
-                        c.0.contact = contact;
-                        c.0.kinematic = kinematic;
+                        c.contact.contact = contact;
+                        c.contact.kinematic = kinematic;
 
                         true
                     }
@@ -283,3 +436,62 @@ impl<N: RealField> ContactManifold<N> {
         }
     }
 }
+
+// Area of the convex hull of the given points projected on the plane spanned by `basis`.
+#[cfg(feature = "dim3")]
+fn projected_hull_area<'a, N: RealField>(
+    points: impl Iterator<Item = &'a Point<N>>,
+    basis: &[Vector<N>; 2],
+) -> N
+{
+    let mut pts: Vec<(N, N)> = points
+        .map(|pt| (pt.coords.dot(&basis[0]), pt.coords.dot(&basis[1])))
+        .collect();
+
+    if pts.len() < 3 {
+        return N::zero();
+    }
+
+    pts.sort_by(|a, b| {
+        (a.0, a.1)
+            .partial_cmp(&(b.0, b.1))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let cross = |o: &(N, N), a: &(N, N), b: &(N, N)| {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+
+    // Andrew's monotone chain.
+    let mut hull: Vec<(N, N)> = Vec::with_capacity(pts.len() * 2);
+
+    for pass in 0..2 {
+        let start = hull.len();
+
+        for pt in pts.iter() {
+            while hull.len() >= start + 2
+                && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], pt) <= N::zero()
+            {
+                let _ = hull.pop();
+            }
+
+            hull.push(*pt);
+        }
+
+        let _ = hull.pop();
+
+        if pass == 0 {
+            pts.reverse();
+        }
+    }
+
+    let mut area = N::zero();
+
+    for i in 0..hull.len() {
+        let a = &hull[i];
+        let b = &hull[(i + 1) % hull.len()];
+        area += a.0 * b.1 - a.1 * b.0;
+    }
+
+    area.abs() * na::convert(0.5)
+}
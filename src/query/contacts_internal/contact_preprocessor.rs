@@ -1,5 +1,7 @@
-use na::RealField;
+use na::{RealField, Unit};
+use crate::math::{Isometry, Vector};
 use crate::query::{Contact, ContactKinematic};
+use crate::utils::IsometryOps;
 
 
 /// Pre-process a contact before it is added to a contact manifold.
@@ -35,4 +37,44 @@ impl<'a, 'b, N, A, B> ContactPreprocessor<N> for (Option<&'a A>, &'b B)
             self.1.process_contact(c, kinematic, is_first)
         }
     }
-}
\ No newline at end of file
+}
+
+/// A contact preprocessor discarding the contacts incompatible with a one-way part of a shape.
+///
+/// A contact is kept only if the outward contact normal of the part deviates from the part normal
+/// by at most the given angular tolerance. In particular, this discards all the contacts with
+/// objects located behind the part.
+pub(crate) struct OneWayContactPreprocessor<'a, N: RealField> {
+    pos: &'a Isometry<N>,
+    normal: Unit<Vector<N>>,
+    cos_tolerance: N,
+}
+
+impl<'a, N: RealField> OneWayContactPreprocessor<'a, N> {
+    /// Initializes a preprocessor for a part with the local-space normal `normal` of a shape
+    /// positioned at `pos`.
+    pub fn new(pos: &'a Isometry<N>, normal: Unit<Vector<N>>, tolerance: N) -> Self {
+        OneWayContactPreprocessor {
+            pos,
+            normal,
+            cos_tolerance: tolerance.cos(),
+        }
+    }
+}
+
+impl<'a, N: RealField> ContactPreprocessor<N> for OneWayContactPreprocessor<'a, N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        _: &mut ContactKinematic<N>,
+        is_first: bool)
+        -> bool {
+        let local_normal = self.pos.inverse_transform_unit_vector(&c.normal);
+
+        if is_first {
+            local_normal.dot(&self.normal) >= self.cos_tolerance
+        } else {
+            -local_normal.dot(&self.normal) >= self.cos_tolerance
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Implementation details of the `contact` and `contacts` functions.
 
 pub use self::ball_against_ball::ball_against_ball;
+pub use self::composite_shape_against_composite_shape::composite_shape_against_composite_shape;
 pub use self::composite_shape_against_shape::{
     composite_shape_against_shape, shape_against_composite_shape,
 };
@@ -11,16 +12,28 @@ pub use self::contact_kinematic::{
 pub use self::contact_manifold::{ContactManifold, ContactTrackingMode};
 pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
 pub use self::shape_against_shape::shape_against_shape as contact_internal;
+#[cfg(feature = "dim3")]
+pub use self::signed_distance_field_against_shape::{
+    shape_against_signed_distance_field, signed_distance_field_against_shape,
+};
+#[cfg(feature = "dim3")]
+pub(crate) use self::signed_distance_field_against_shape::{
+    can_sample_shape, signed_distance_field_contacts,
+};
 pub use self::support_map_against_support_map::support_map_against_support_map;
 pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
 pub use self::contact_preprocessor::ContactPreprocessor;
+pub(crate) use self::contact_preprocessor::OneWayContactPreprocessor;
 
 mod ball_against_ball;
+mod composite_shape_against_composite_shape;
 mod composite_shape_against_shape;
 mod contact;
 mod contact_kinematic;
 mod contact_manifold;
 mod plane_against_support_map;
 mod shape_against_shape;
+#[cfg(feature = "dim3")]
+mod signed_distance_field_against_shape;
 mod support_map_against_support_map;
 mod contact_preprocessor;
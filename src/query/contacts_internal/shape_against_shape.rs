@@ -4,6 +4,8 @@ use crate::math::{Isometry, Point};
 use crate::query::contacts_internal;
 use crate::query::contacts_internal::Contact;
 use crate::shape::{Ball, Plane, Shape};
+#[cfg(feature = "dim3")]
+use crate::shape::SignedDistanceField;
 
 /// Computes one contact point between two shapes.
 ///
@@ -16,6 +18,19 @@ pub fn shape_against_shape<N: RealField>(
     prediction: N,
 ) -> Option<Contact<N>>
 {
+    #[cfg(feature = "dim3")]
+    {
+        if let Some(s1) = g1.as_shape::<SignedDistanceField<N>>() {
+            if contacts_internal::can_sample_shape(g2) {
+                return contacts_internal::signed_distance_field_against_shape(m1, s1, m2, g2, prediction);
+            }
+        } else if let Some(s2) = g2.as_shape::<SignedDistanceField<N>>() {
+            if contacts_internal::can_sample_shape(g1) {
+                return contacts_internal::shape_against_signed_distance_field(m1, g1, m2, s2, prediction);
+            }
+        }
+    }
+
     if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<N>>(), g2.as_shape::<Ball<N>>()) {
         let p1 = Point::from(m1.translation.vector);
         let p2 = Point::from(m2.translation.vector);
@@ -27,6 +42,8 @@ pub fn shape_against_shape<N: RealField>(
         contacts_internal::support_map_against_plane(m1, s1, m2, p2, prediction)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         contacts_internal::support_map_against_support_map(m1, s1, m2, s2, prediction)
+    } else if let (Some(c1), Some(c2)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        contacts_internal::composite_shape_against_composite_shape(m1, c1, m2, c2, prediction)
     } else if let Some(c1) = g1.as_composite_shape() {
         contacts_internal::composite_shape_against_shape(m1, c1, m2, g2, prediction)
     } else if let Some(c2) = g2.as_composite_shape() {
@@ -0,0 +1,173 @@
+use na::{self, RealField};
+
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Isometry, Point, Vector};
+use crate::query::Contact;
+use crate::shape::{
+    Ball, Capsule, Compound, ConvexHull, Cuboid, HeightField, Plane, Polyline, Segment, Shape,
+    SignedDistanceField, TriMesh, Triangle, Voxels,
+};
+
+/// Computes the deepest contact point between a signed distance field and a shape.
+///
+/// The shape is approximated by a set of sample points: the vertices of polyhedral shapes,
+/// meshes and heightfields, the corners of voxels, the centers of balls, the ends of capsule
+/// segments, or support points in a fixed set of directions for other convex shapes. Returns
+/// `None` if all the samples are farther than `prediction` from the surface of the signed
+/// distance field. Planes and other signed distance fields cannot be sampled so they never
+/// produce any contact.
+pub fn signed_distance_field_against_shape<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &SignedDistanceField<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+) -> Option<Contact<N>>
+{
+    let mut deepest: Option<Contact<N>> = None;
+
+    signed_distance_field_contacts(m1, g1, m2, g2, prediction, &mut |_, _, c| {
+        if deepest.as_ref().map(|d| c.depth > d.depth).unwrap_or(true) {
+            deepest = Some(c)
+        }
+    });
+
+    deepest
+}
+
+/// Computes the deepest contact point between a shape and a signed distance field.
+pub fn shape_against_signed_distance_field<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &SignedDistanceField<N>,
+    prediction: N,
+) -> Option<Contact<N>>
+{
+    let mut res = signed_distance_field_against_shape(m2, g2, m1, g1, prediction);
+
+    if let Some(c) = &mut res {
+        c.flip()
+    }
+
+    res
+}
+
+/// Calls `f` with the index, radius, and contact of each sample point of `g2` closer than
+/// `prediction` to the surface of `g1`.
+pub(crate) fn signed_distance_field_contacts<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &SignedDistanceField<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    prediction: N,
+    f: &mut dyn FnMut(usize, N, Contact<N>),
+)
+{
+    let ls_aabb2 = g2.aabb(&(m1.inverse() * m2));
+
+    if !g1.aabb().loosened(prediction).intersects(&ls_aabb2) {
+        return;
+    }
+
+    let mut samples = Vec::new();
+    sample_shape(m2, g2, &mut samples);
+
+    for (i, (pt, radius)) in samples.into_iter().enumerate() {
+        let ls_pt = m1.inverse_transform_point(&pt);
+        let dist = g1.distance_at_local_point(&ls_pt);
+        let depth = radius - dist;
+
+        if depth >= -prediction {
+            if let Some(normal) = g1.normal_at_local_point(&ls_pt) {
+                let normal = m1 * normal;
+                let world1 = pt - *normal * dist;
+                let world2 = pt - *normal * radius;
+                f(i, radius, Contact::new(world1, world2, normal, depth))
+            }
+        }
+    }
+}
+
+/// Whether `shape` can be approximated by sample points for computing contacts with a signed
+/// distance field.
+pub(crate) fn can_sample_shape<N: RealField>(shape: &dyn Shape<N>) -> bool {
+    if shape.is_shape::<Plane<N>>() || shape.is_shape::<SignedDistanceField<N>>() {
+        false
+    } else if let Some(c) = shape.as_shape::<Compound<N>>() {
+        c.shapes().iter().all(|(_, part)| can_sample_shape(&**part))
+    } else {
+        shape.is_shape::<HeightField<N>>()
+            || shape.is_shape::<Voxels<N>>()
+            || shape.is_shape::<TriMesh<N>>()
+            || shape.is_shape::<Polyline<N>>()
+            || shape.is_support_map()
+    }
+}
+
+/// Collects the world-space sample points of `shape` with their radius.
+fn sample_shape<N: RealField>(m: &Isometry<N>, shape: &dyn Shape<N>, out: &mut Vec<(Point<N>, N)>) {
+    if let Some(b) = shape.as_shape::<Ball<N>>() {
+        out.push((Point::from(m.translation.vector), b.radius()))
+    } else if let Some(c) = shape.as_shape::<Capsule<N>>() {
+        let seg = c.segment();
+        out.push((m * seg.a(), c.radius()));
+        out.push((m * seg.b(), c.radius()));
+    } else if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+        let he = c.half_extents();
+
+        for i in 0..8 {
+            let sign = |bit: usize| if i & bit == 0 { -N::one() } else { N::one() };
+            let corner = Vector::new(he.x * sign(1), he.y * sign(2), he.z * sign(4));
+            out.push((m * Point::from(corner), N::zero()))
+        }
+    } else if let Some(t) = shape.as_shape::<Triangle<N>>() {
+        out.extend([t.a(), t.b(), t.c()].iter().map(|pt| (m * *pt, N::zero())))
+    } else if let Some(s) = shape.as_shape::<Segment<N>>() {
+        out.extend([s.a(), s.b()].iter().map(|pt| (m * *pt, N::zero())))
+    } else if let Some(c) = shape.as_shape::<ConvexHull<N>>() {
+        out.extend(c.points().iter().map(|pt| (m * pt, N::zero())))
+    } else if let Some(t) = shape.as_shape::<TriMesh<N>>() {
+        out.extend(t.points().iter().map(|pt| (m * pt, N::zero())))
+    } else if let Some(p) = shape.as_shape::<Polyline<N>>() {
+        out.extend(p.points().iter().map(|pt| (m * pt, N::zero())))
+    } else if let Some(h) = shape.as_shape::<HeightField<N>>() {
+        for tri in h.triangles() {
+            out.extend([tri.a(), tri.b(), tri.c()].iter().map(|pt| (m * *pt, N::zero())))
+        }
+    } else if let Some(v) = shape.as_shape::<Voxels<N>>() {
+        for i in 0..v.cells().len() {
+            let aabb = v.voxel_aabb(i);
+            let (mins, maxs) = (aabb.mins(), aabb.maxs());
+
+            for k in 0..8 {
+                let corner = Point::new(
+                    if k & 1 == 0 { mins.x } else { maxs.x },
+                    if k & 2 == 0 { mins.y } else { maxs.y },
+                    if k & 4 == 0 { mins.z } else { maxs.z },
+                );
+                out.push((m * corner, N::zero()))
+            }
+        }
+    } else if let Some(c) = shape.as_shape::<Compound<N>>() {
+        for (part_pos, part) in c.shapes() {
+            sample_shape(&(m * part_pos), &**part, out)
+        }
+    } else if let Some(s) = shape.as_support_map() {
+        // Support points toward the vertices, edge midpoints, and face centers of a cube.
+        for i in -1i32..=1 {
+            for j in -1i32..=1 {
+                for k in -1i32..=1 {
+                    if i != 0 || j != 0 || k != 0 {
+                        let dir = Vector::new(
+                            na::convert(i as f64),
+                            na::convert(j as f64),
+                            na::convert(k as f64),
+                        );
+                        out.push((s.support_point(m, &dir), N::zero()))
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use na::RealField;
+
+use crate::math::{Isometry, Translation, Vector};
+use crate::query::{contacts_internal, Contact};
+use crate::shape::{HeightField, Shape};
+
+/// The result of a depenetration query.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Depenetration<N: RealField> {
+    /// The translation to apply to the second shape in order to separate it from the first one.
+    pub translation: Vector<N>,
+    /// Whether the shapes are actually separated once `translation` is applied.
+    ///
+    /// This is `false` if the maximum number of iterations has been reached first, e.g., when the
+    /// second shape is stuck between two parts of the first shape too close to each other.
+    pub converged: bool,
+}
+
+/// Computes the translation to apply to `g2` in order to resolve its penetration with `g1`.
+///
+/// Contrary to `query::contact` which only returns the deepest contact between one part of a
+/// composite shape (`TriMesh`, `Polyline`, `Compound`) or heightfield and the other shape, this
+/// iteratively resolves the contacts with all the overlapping parts of `g1` (resp. `g2` if only
+/// the latter is composite) until they are all separated or `max_iter` iterations have been
+/// performed. Each iteration moves the second shape out of the part it penetrates the most at
+/// its current position.
+pub fn depenetration<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    max_iter: usize,
+) -> Depenetration<N>
+{
+    let g2_has_parts = g2.is_composite_shape() || g2.is_shape::<HeightField<N>>();
+    let g1_has_parts = g1.is_composite_shape() || g1.is_shape::<HeightField<N>>();
+
+    if g2_has_parts && !g1_has_parts {
+        // Push `g1` out of the parts of `g2` instead.
+        let mut res = depenetrate_from_parts(m2, g2, m1, g1, max_iter);
+        res.translation = -res.translation;
+        res
+    } else {
+        depenetrate_from_parts(m1, g1, m2, g2, max_iter)
+    }
+}
+
+fn depenetrate_from_parts<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+    max_iter: usize,
+) -> Depenetration<N>
+{
+    let tolerance = N::default_epsilon().sqrt();
+    let mut translation = Vector::zeros();
+    let mut parts = Vec::new();
+
+    for _ in 0..max_iter {
+        let curr_m2 = Translation::from(translation) * m2;
+        let mut deepest: Option<Contact<N>> = None;
+
+        {
+            let mut keep_deepest = |m: &Isometry<N>, part: &dyn Shape<N>| {
+                if let Some(c) = contacts_internal::contact_internal(m, part, &curr_m2, g2, N::zero()) {
+                    if c.depth > tolerance && deepest.as_ref().map(|d| c.depth > d.depth).unwrap_or(true) {
+                        deepest = Some(c)
+                    }
+                }
+            };
+
+            let ls_aabb2 = g2.aabb(&(m1.inverse() * curr_m2));
+
+            if let Some(cs) = g1.as_composite_shape() {
+                parts.clear();
+
+                cs.parts_intersecting_local_aabb(&ls_aabb2, &mut parts);
+
+                for i in parts.iter() {
+                    cs.map_part_at(*i, m1, &mut keep_deepest);
+                }
+            } else if let Some(hf) = g1.as_shape::<HeightField<N>>() {
+                hf.map_elements_in_local_aabb(&ls_aabb2, &mut |_, elt, _| keep_deepest(m1, elt));
+            } else {
+                keep_deepest(m1, g1)
+            }
+        }
+
+        match deepest {
+            Some(c) => translation += *c.normal * c.depth,
+            None => {
+                return Depenetration {
+                    translation,
+                    converged: true,
+                }
+            }
+        }
+    }
+
+    Depenetration {
+        translation,
+        converged: false,
+    }
+}
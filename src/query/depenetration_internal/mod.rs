@@ -0,0 +1,5 @@
+//! Implementation details of the `depenetration` function.
+
+pub use self::depenetration::{depenetration, Depenetration};
+
+mod depenetration;
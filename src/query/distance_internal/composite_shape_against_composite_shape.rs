@@ -0,0 +1,93 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point};
+use na::RealField;
+use crate::partitioning::{
+    BestFirstBVVisitStatus, BestFirstDataVisitStatus, SimultaneousBestFirstVisitor,
+};
+use crate::query::distance_internal;
+use crate::query::PointQuery;
+use crate::shape::CompositeShape;
+
+/// Smallest distance between two composite shapes.
+///
+/// This performs a best-first traversal of the bounding volume test tree formed by the
+/// acceleration structures of both shapes.
+pub fn composite_shape_against_composite_shape<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> N
+where
+    N: RealField,
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    let mut visitor = CompositeShapeAgainstCompositeShapeDistanceVisitor {
+        ls_m2: m1.inverse() * m2,
+        m1: m1,
+        g1: g1,
+        m2: m2,
+        g2: g2,
+    };
+
+    g1.bvh()
+        .best_first_search_bvtt(g2.bvh(), &mut visitor)
+        .expect("The composite shapes must not be empty.")
+}
+
+struct CompositeShapeAgainstCompositeShapeDistanceVisitor<'a, N, G1: ?Sized + 'a, G2: ?Sized + 'a>
+where N: 'a + RealField
+{
+    ls_m2: Isometry<N>,
+
+    m1: &'a Isometry<N>,
+    g1: &'a G1,
+    m2: &'a Isometry<N>,
+    g2: &'a G2,
+}
+
+impl<'a, N, G1: ?Sized, G2: ?Sized> SimultaneousBestFirstVisitor<N, usize, AABB<N>>
+    for CompositeShapeAgainstCompositeShapeDistanceVisitor<'a, N, G1, G2>
+where
+    N: RealField,
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    type Result = N;
+
+    fn visit_bv(&mut self, bv1: &AABB<N>, bv2: &AABB<N>) -> BestFirstBVVisitStatus<N> {
+        // Compute the minkowski sum of the two AABBs, in the local-space of `g1`.
+        let ls_bv2 = bv2.transform_by(&self.ls_m2);
+        let msum = AABB::new(
+            *bv1.mins() + (-ls_bv2.maxs().coords),
+            *bv1.maxs() + (-ls_bv2.mins().coords),
+        );
+
+        // Compute the distance to the origin.
+        BestFirstBVVisitStatus::ContinueWithCost(msum.distance_to_point(
+            &Isometry::identity(),
+            &Point::origin(),
+            true,
+        ))
+    }
+
+    fn visit_data(&mut self, b1: &usize, b2: &usize) -> BestFirstDataVisitStatus<N, N> {
+        let mut res = BestFirstDataVisitStatus::Continue;
+        let (m2, g2) = (self.m2, self.g2);
+
+        self.g1.map_part_at(*b1, self.m1, &mut |m1, g1| {
+            g2.map_part_at(*b2, m2, &mut |m2, g2| {
+                let distance = distance_internal::distance(m1, g1, m2, g2);
+
+                res = if distance.is_zero() {
+                    BestFirstDataVisitStatus::ExitEarlyWithResult(distance)
+                } else {
+                    BestFirstDataVisitStatus::ContinueWithResult(distance, distance)
+                }
+            })
+        });
+
+        res
+    }
+}
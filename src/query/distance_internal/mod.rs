@@ -1,6 +1,7 @@
 //! Implementation details of the `distance` function.
 
 pub use self::ball_against_ball::ball_against_ball;
+pub use self::composite_shape_against_composite_shape::composite_shape_against_composite_shape;
 pub use self::composite_shape_against_shape::{
     composite_shape_against_shape, shape_against_composite_shape,
 };
@@ -10,6 +11,7 @@ pub use self::support_map_against_support_map::support_map_against_support_map;
 pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
 
 mod ball_against_ball;
+mod composite_shape_against_composite_shape;
 mod composite_shape_against_shape;
 mod plane_against_support_map;
 mod shape_against_shape;
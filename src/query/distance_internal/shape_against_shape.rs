@@ -24,6 +24,8 @@ pub fn shape_against_shape<N: RealField>(
         distance_internal::support_map_against_plane(m1, s1, m2, p2)
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         distance_internal::support_map_against_support_map(m1, s1, m2, s2)
+    } else if let (Some(c1), Some(c2)) = (g1.as_composite_shape(), g2.as_composite_shape()) {
+        distance_internal::composite_shape_against_composite_shape(m1, c1, m2, c2)
     } else if let Some(c1) = g1.as_composite_shape() {
         distance_internal::composite_shape_against_shape(m1, c1, m2, g2)
     } else if let Some(c2) = g2.as_composite_shape() {
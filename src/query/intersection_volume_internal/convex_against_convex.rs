@@ -0,0 +1,40 @@
+use crate::math::Isometry;
+use crate::query::IntersectionVolume;
+use crate::shape::Shape;
+use crate::utils::ConvexPolytope;
+use na::RealField;
+
+/// Computes the volume, or area in 2D, and centroid of the intersection of two convex shapes.
+///
+/// Cuboids, convex hulls, and convex polygons are handled exactly. Other convex shapes, e.g.,
+/// balls and capsules, are approximated by the convex hull of some of their support points.
+/// Returns `None` if the shapes do not overlap.
+///
+/// # Panics
+///
+/// Panics if one of the shapes does not implement the `SupportMap` trait.
+pub fn convex_against_convex<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> Option<IntersectionVolume<N>>
+{
+    // Work in the local frame of the first shape for better accuracy.
+    let poly1 = polytope(&Isometry::identity(), g1);
+    let poly2 = polytope(&(m1.inverse() * m2), g2);
+    let props = poly1.intersection(&poly2).mass_properties();
+
+    if props.volume > N::zero() {
+        Some(IntersectionVolume {
+            volume: props.volume,
+            centroid: m1 * props.local_com,
+        })
+    } else {
+        None
+    }
+}
+
+fn polytope<N: RealField>(m: &Isometry<N>, g: &dyn Shape<N>) -> ConvexPolytope<N> {
+    ConvexPolytope::from_shape(m, g).expect("The intersection volume is only supported by convex shapes.")
+}
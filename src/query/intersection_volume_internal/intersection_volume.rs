@@ -0,0 +1,12 @@
+use crate::math::Point;
+use na::RealField;
+
+/// The volume, or area in 2D, of the intersection of two shapes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntersectionVolume<N: RealField> {
+    /// The volume of the intersection, or its area in 2D.
+    pub volume: N,
+    /// The centroid of the intersection, in world-space.
+    pub centroid: Point<N>,
+}
@@ -0,0 +1,11 @@
+//! Implementation details of the `intersection_volume` function.
+
+pub use self::convex_against_convex::convex_against_convex;
+pub use self::intersection_volume::IntersectionVolume;
+pub use self::plane_against_convex::{convex_against_plane, plane_against_convex};
+pub use self::shape_against_shape::shape_against_shape as intersection_volume_internal;
+
+mod convex_against_convex;
+mod intersection_volume;
+mod plane_against_convex;
+mod shape_against_shape;
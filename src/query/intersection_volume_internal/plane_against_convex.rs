@@ -0,0 +1,52 @@
+use crate::math::Isometry;
+use crate::query::IntersectionVolume;
+use crate::shape::{Plane, Shape};
+use crate::utils::ConvexPolytope;
+use na::RealField;
+
+/// Computes the volume, or area in 2D, and centroid of the part of a convex shape below a plane.
+///
+/// The part below the plane is the part on the opposite side of its normal, e.g., the
+/// submerged part of a floating shape if the plane is the surface of the fluid. Curved
+/// shapes are approximated the same way as by `convex_against_convex`. Returns `None` if the
+/// shape is entirely above the plane.
+///
+/// # Panics
+///
+/// Panics if the shape does not implement the `SupportMap` trait.
+pub fn plane_against_convex<N: RealField>(
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+) -> Option<IntersectionVolume<N>>
+{
+    let mut poly = ConvexPolytope::from_shape(&Isometry::identity(), other)
+        .expect("The intersection volume is only supported by convex shapes.");
+
+    poly.clip_by_plane(&(mother.inverse() * mplane), plane);
+
+    let props = poly.mass_properties();
+
+    if props.volume > N::zero() {
+        Some(IntersectionVolume {
+            volume: props.volume,
+            centroid: mother * props.local_com,
+        })
+    } else {
+        None
+    }
+}
+
+/// Computes the volume, or area in 2D, and centroid of the part of a convex shape below a plane.
+///
+/// See `plane_against_convex` for details.
+pub fn convex_against_plane<N: RealField>(
+    mother: &Isometry<N>,
+    other: &dyn Shape<N>,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> Option<IntersectionVolume<N>>
+{
+    plane_against_convex(mplane, plane, mother, other)
+}
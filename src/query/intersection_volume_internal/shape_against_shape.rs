@@ -0,0 +1,31 @@
+use na::RealField;
+
+use crate::math::Isometry;
+use crate::query::{intersection_volume_internal, IntersectionVolume};
+use crate::shape::{Plane, Shape};
+
+/// Computes the volume, or area in 2D, and centroid of the intersection of two shapes.
+///
+/// If one of the shapes is a plane, this computes the part of the other shape below that
+/// plane. Returns `None` if the shapes do not overlap.
+///
+/// # Panics
+///
+/// Panics if one of the shapes is neither a plane nor a convex shape.
+pub fn shape_against_shape<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> Option<IntersectionVolume<N>>
+{
+    if let (Some(p1), true) = (g1.as_shape::<Plane<N>>(), g2.is_support_map()) {
+        intersection_volume_internal::plane_against_convex(m1, p1, m2, g2)
+    } else if let (true, Some(p2)) = (g1.is_support_map(), g2.as_shape::<Plane<N>>()) {
+        intersection_volume_internal::convex_against_plane(m1, g1, m2, p2)
+    } else if g1.is_support_map() && g2.is_support_map() {
+        intersection_volume_internal::convex_against_convex(m1, g1, m2, g2)
+    } else {
+        panic!("No algorithm known to compute the intersection volume between the given pair of shapes.")
+    }
+}
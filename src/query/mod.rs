@@ -12,8 +12,14 @@ pub use self::contacts_internal::{
     LocalShapeApproximation, NeighborhoodGeometry, TrackedContact, ContactPreprocessor
 };
 #[doc(inline)]
+pub use self::depenetration_internal::{depenetration, Depenetration};
+#[doc(inline)]
 pub use self::distance_internal::distance;
 #[doc(inline)]
+pub use self::intersection_volume_internal::intersection_volume_internal as intersection_volume;
+#[doc(inline)]
+pub use self::intersection_volume_internal::IntersectionVolume;
+#[doc(inline)]
 pub use self::point_internal::{PointProjection, PointQuery, PointQueryWithLocation};
 #[doc(inline)]
 pub use self::proximity_internal::proximity_internal as proximity;
@@ -27,7 +33,9 @@ pub use self::time_of_impact_internal::time_of_impact;
 pub mod algorithms;
 pub mod closest_points_internal;
 pub mod contacts_internal;
+pub mod depenetration_internal;
 pub mod distance_internal;
+pub mod intersection_volume_internal;
 pub mod point_internal;
 pub mod proximity_internal;
 pub mod ray_internal;
@@ -15,10 +15,13 @@ mod point_polyline;
 pub mod point_query;
 mod point_segment;
 mod point_shape;
+#[cfg(feature = "dim3")]
+mod point_signed_distance_field;
 mod point_support_map;
 #[cfg(feature = "dim3")]
 mod point_tetrahedron;
 mod point_triangle;
 #[cfg(feature = "dim3")]
 mod point_trimesh;
+mod point_voxels;
 mod point_heightfield;
@@ -0,0 +1,45 @@
+use crate::math::{Isometry, Point};
+use na::RealField;
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, SignedDistanceField};
+
+impl<N: RealField> PointQuery<N> for SignedDistanceField<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        let ls_pt = m.inverse_transform_point(point);
+        let (proj, dist) = self.project_local_point_on_surface(&ls_pt);
+        let is_inside = dist <= N::zero();
+
+        if is_inside && solid {
+            PointProjection::new(true, *point)
+        } else {
+            PointProjection::new(is_inside, m * proj)
+        }
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> N {
+        let dist = self.distance_at_local_point(&m.inverse_transform_point(point));
+
+        if solid && dist < N::zero() {
+            N::zero()
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId)
+    {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, point: &Point<N>) -> bool {
+        self.distance_at_local_point(&m.inverse_transform_point(point)) <= N::zero()
+    }
+}
@@ -7,7 +7,7 @@ use crate::query::{PointProjection, PointQuery};
 use crate::shape::ConvexPolygon;
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, ConvexHull, Cylinder};
-use crate::shape::{ConstantOrigin, ConvexPolyhedron, FeatureId, SupportMap};
+use crate::shape::{ConstantOrigin, ConvexPolyhedron, FeatureId, MinkowskiSum, SupportMap, SweptShape};
 
 /// Projects a point on a shape using the GJK algorithm.
 pub fn support_map_point_projection<N, G>(
@@ -86,6 +86,40 @@ impl<N: RealField> PointQuery<N> for Cone<N> {
     }
 }
 
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> PointQuery<N> for MinkowskiSum<A, B> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        support_map_point_projection(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId)
+    {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
+
+impl<N: RealField, S: SupportMap<N>> PointQuery<N> for SweptShape<N, S> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        support_map_point_projection(m, self, &mut VoronoiSimplex::new(), point, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId)
+    {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField> PointQuery<N> for ConvexHull<N> {
     #[inline]
@@ -29,6 +29,11 @@ impl<N: RealField> PointQuery<N> for TriMesh<N> {
     #[inline]
     fn contains_point(&self, m: &Isometry<N>, point: &Point<N>) -> bool {
         let ls_pt = m.inverse_transform_point(point);
+
+        if self.is_solid() {
+            return self.encloses_local_point(&ls_pt);
+        }
+
         let mut visitor = CompositePointContainmentTest {
             shape: self,
             point: &ls_pt,
@@ -49,7 +54,7 @@ impl<N: RealField> PointQueryWithLocation<N> for TriMesh<N> {
         &self,
         m: &Isometry<N>,
         point: &Point<N>,
-        _: bool,
+        solid: bool,
     ) -> (PointProjection<N>, Self::Location)
     {
         let ls_pt = m.inverse_transform_point(point);
@@ -61,6 +66,14 @@ impl<N: RealField> PointQueryWithLocation<N> for TriMesh<N> {
         let (mut proj, extra_info) = self.bvh().best_first_search(&mut visitor).unwrap();
         proj.point = m * proj.point;
 
+        if self.is_solid() {
+            proj.is_inside = self.encloses_local_point(&ls_pt);
+
+            if proj.is_inside && solid {
+                proj.point = *point;
+            }
+        }
+
         (proj, extra_info)
     }
 }
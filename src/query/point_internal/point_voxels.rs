@@ -0,0 +1,95 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, DIM};
+use na::{self, RealField};
+use crate::partitioning::{BestFirstBVVisitStatus, BestFirstDataVisitStatus, BestFirstVisitor, BVH};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Voxels};
+
+impl<N: RealField> PointQuery<N> for Voxels<N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, point: &Point<N>, solid: bool) -> PointProjection<N> {
+        let ls_pt = m.inverse_transform_point(point);
+        let is_inside = self.contains_local_point(&ls_pt);
+
+        if is_inside && solid {
+            return PointProjection::new(true, *point);
+        }
+
+        // The closest point of the boundary lies on a face shared by an occupied and an empty voxel.
+        let mut visitor = VoxelsPointProjVisitor {
+            voxels: self,
+            point: &ls_pt,
+        };
+
+        match self.bvt().best_first_search(&mut visitor) {
+            Some(proj) => PointProjection::new(is_inside, m * proj),
+            // There is no voxel at all.
+            None => PointProjection::new(false, *point),
+        }
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        point: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId)
+    {
+        (self.project_point(m, point, false), FeatureId::Unknown)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &Isometry<N>, point: &Point<N>) -> bool {
+        self.contains_local_point(&m.inverse_transform_point(point))
+    }
+}
+
+/*
+ * Visitors
+ */
+struct VoxelsPointProjVisitor<'a, N: 'a + RealField> {
+    voxels: &'a Voxels<N>,
+    point: &'a Point<N>,
+}
+
+impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for VoxelsPointProjVisitor<'a, N> {
+    type Result = Point<N>;
+
+    #[inline]
+    fn visit_bv(&mut self, aabb: &AABB<N>) -> BestFirstBVVisitStatus<N> {
+        BestFirstBVVisitStatus::ContinueWithCost(aabb.distance_to_point(
+            &Isometry::identity(),
+            self.point,
+            true,
+        ))
+    }
+
+    #[inline]
+    fn visit_data(&mut self, b: &usize) -> BestFirstDataVisitStatus<N, Point<N>> {
+        let cell = &self.voxels.cells()[*b];
+        let aabb = self.voxels.voxel_aabb(*b);
+        let mut best = None;
+        let mut best_dist = N::max_value();
+
+        // Project on each exposed face of the voxel.
+        for d in 0..DIM {
+            for positive in [false, true].iter() {
+                if !self.voxels.is_neighbor_occupied(cell, d, *positive) {
+                    let mut proj = na::inf(&na::sup(self.point, aabb.mins()), aabb.maxs());
+                    proj[d] = if *positive { aabb.maxs()[d] } else { aabb.mins()[d] };
+                    let dist = na::distance(self.point, &proj);
+
+                    if dist < best_dist {
+                        best_dist = dist;
+                        best = Some(proj);
+                    }
+                }
+            }
+        }
+
+        match best {
+            Some(proj) => BestFirstDataVisitStatus::ContinueWithResult(best_dist, proj),
+            None => BestFirstDataVisitStatus::Continue,
+        }
+    }
+}
@@ -18,9 +18,12 @@ mod ray_cuboid;
 mod ray_plane;
 mod ray_polyline;
 mod ray_shape;
+#[cfg(feature = "dim3")]
+mod ray_signed_distance_field;
 mod ray_support_map;
 #[cfg(feature = "dim3")]
 mod ray_triangle;
 #[cfg(feature = "dim3")]
 mod ray_trimesh;
 mod ray_heightfield;
+mod ray_voxels;
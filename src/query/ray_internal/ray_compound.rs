@@ -1,7 +1,7 @@
-use crate::bounding_volume::AABB;
+use crate::bounding_volume::{SimdAABB, SIMD_WIDTH};
 use crate::math::Isometry;
 use na::RealField;
-use crate::partitioning::{BestFirstBVVisitStatus, BestFirstDataVisitStatus, BestFirstVisitor, BVH};
+use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
 use crate::query::{Ray, RayCast, RayIntersection};
 use crate::shape::Compound;
 
@@ -16,7 +16,7 @@ impl<N: RealField> RayCast<N> for Compound<N> {
             solid: solid,
         };
 
-        self.bvt().best_first_search(&mut visitor)
+        self.qbvh().best_first_search(&mut visitor)
     }
 
     fn toi_and_normal_with_ray(
@@ -34,7 +34,7 @@ impl<N: RealField> RayCast<N> for Compound<N> {
             solid: solid,
         };
 
-        self.bvt().best_first_search(&mut visitor).map(|mut res| {
+        self.qbvh().best_first_search(&mut visitor).map(|mut res| {
             res.normal = m * res.normal;
             res
         })
@@ -53,23 +53,38 @@ struct CompoundRayToiVisitor<'a, N: 'a + RealField> {
     solid: bool,
 }
 
-impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for CompoundRayToiVisitor<'a, N> {
+impl<'a, N: RealField> SimdBestFirstVisitor<N, usize> for CompoundRayToiVisitor<'a, N> {
     type Result = N;
 
     #[inline]
-    fn visit_bv(&mut self, aabb: &AABB<N>) -> BestFirstBVVisitStatus<N> {
-        match aabb.toi_with_ray(&Isometry::identity(), self.ray, self.solid) {
-            Some(toi) => BestFirstBVVisitStatus::ContinueWithCost(toi),
-            None => BestFirstBVVisitStatus::Stop,
+    fn visit(
+        &mut self,
+        best: N,
+        aabb: &SimdAABB,
+        data: [Option<&usize>; SIMD_WIDTH],
+    ) -> SimdBestFirstVisitStatus<N, N>
+    {
+        let (mut mask, mut weights) = aabb.cast_ray(self.ray, best);
+        let mut results = [None; SIMD_WIDTH];
+
+        for ii in 0..SIMD_WIDTH {
+            if let (true, Some(b)) = (mask[ii], data[ii]) {
+                let elt = &self.compound.shapes()[*b];
+
+                match elt.1.toi_with_ray(&elt.0, self.ray, self.solid) {
+                    Some(toi) => {
+                        weights[ii] = toi;
+                        results[ii] = Some(toi);
+                    }
+                    None => mask[ii] = false,
+                }
+            }
         }
-    }
 
-    #[inline]
-    fn visit_data(&mut self, b: &usize) -> BestFirstDataVisitStatus<N, N> {
-        let elt = &self.compound.shapes()[*b];
-        match elt.1.toi_with_ray(&elt.0, self.ray, self.solid) {
-            Some(toi) => BestFirstDataVisitStatus::ContinueWithResult(toi, toi),
-            None => BestFirstDataVisitStatus::Continue,
+        SimdBestFirstVisitStatus::MaybeContinue {
+            weights,
+            mask,
+            results,
         }
     }
 }
@@ -80,23 +95,38 @@ struct CompoundRayToiAndNormalVisitor<'a, N: 'a + RealField> {
     solid: bool,
 }
 
-impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for CompoundRayToiAndNormalVisitor<'a, N> {
+impl<'a, N: RealField> SimdBestFirstVisitor<N, usize> for CompoundRayToiAndNormalVisitor<'a, N> {
     type Result = RayIntersection<N>;
 
     #[inline]
-    fn visit_bv(&mut self, aabb: &AABB<N>) -> BestFirstBVVisitStatus<N> {
-        match aabb.toi_with_ray(&Isometry::identity(), self.ray, self.solid) {
-            Some(toi) => BestFirstBVVisitStatus::ContinueWithCost(toi),
-            None => BestFirstBVVisitStatus::Stop,
+    fn visit(
+        &mut self,
+        best: N,
+        aabb: &SimdAABB,
+        data: [Option<&usize>; SIMD_WIDTH],
+    ) -> SimdBestFirstVisitStatus<N, RayIntersection<N>>
+    {
+        let (mut mask, mut weights) = aabb.cast_ray(self.ray, best);
+        let mut results = [None; SIMD_WIDTH];
+
+        for ii in 0..SIMD_WIDTH {
+            if let (true, Some(b)) = (mask[ii], data[ii]) {
+                let elt = &self.compound.shapes()[*b];
+
+                match elt.1.toi_and_normal_with_ray(&elt.0, self.ray, self.solid) {
+                    Some(inter) => {
+                        weights[ii] = inter.toi;
+                        results[ii] = Some(inter);
+                    }
+                    None => mask[ii] = false,
+                }
+            }
         }
-    }
 
-    #[inline]
-    fn visit_data(&mut self, b: &usize) -> BestFirstDataVisitStatus<N, RayIntersection<N>> {
-        let elt = &self.compound.shapes()[*b];
-        match elt.1.toi_and_normal_with_ray(&elt.0, self.ray, self.solid) {
-            Some(inter) => BestFirstDataVisitStatus::ContinueWithResult(inter.toi, inter),
-            None => BestFirstDataVisitStatus::Continue,
+        SimdBestFirstVisitStatus::MaybeContinue {
+            weights,
+            mask,
+            results,
         }
     }
 }
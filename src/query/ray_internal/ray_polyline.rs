@@ -67,6 +67,10 @@ impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for PolylineRayToiVis
 
     #[inline]
     fn visit_data(&mut self, b: &usize) -> BestFirstDataVisitStatus<N, N> {
+        if !self.polyline.can_ray_hit_part(*b, &self.ray.dir) {
+            return BestFirstDataVisitStatus::Continue;
+        }
+
         // FIXME: optimize this by not using Isometry identity.
         match self
             .polyline
@@ -97,6 +101,10 @@ impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for PolylineRayToiAnd
 
     #[inline]
     fn visit_data(&mut self, b: &usize) -> BestFirstDataVisitStatus<N, (usize, RayIntersection<N>)> {
+        if !self.polyline.can_ray_hit_part(*b, &self.ray.dir) {
+            return BestFirstDataVisitStatus::Continue;
+        }
+
         // FIXME: optimize this by not using the Isometry identity.
         match self.polyline.segment_at(*b).toi_and_normal_with_ray(
             &Isometry::identity(),
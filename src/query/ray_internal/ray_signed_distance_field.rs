@@ -0,0 +1,65 @@
+use crate::math::{Isometry, Vector};
+use na::{self, RealField};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, SignedDistanceField};
+
+// The maximum number of steps performed by the ray marching.
+const MAX_STEPS: usize = 512;
+
+impl<N: RealField> RayCast<N> for SignedDistanceField<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        solid: bool,
+    ) -> Option<RayIntersection<N>>
+    {
+        let ls_ray = ray.inverse_transform_by(m);
+        let dir_norm = ls_ray.dir.norm();
+
+        if dir_norm.is_zero() {
+            return None;
+        }
+
+        // The surface is entirely contained by the grid.
+        let (min_t, max_t) = self.aabb().clip_ray_parameters(&ls_ray)?;
+        let tolerance = self.cell_size().min() * na::convert(1.0e-3);
+        let mut prev_t = min_t;
+        let mut prev_dist = self.distance_at_local_point(&ls_ray.point_at(min_t));
+        let from_inside = prev_dist < N::zero();
+
+        if from_inside && solid {
+            return Some(RayIntersection::new(min_t, Vector::zeros(), FeatureId::Unknown));
+        }
+
+        // Sphere tracing: the signed distance is the length of a step that cannot cross the surface.
+        // If it does anyway because of interpolation errors, the crossing point is interpolated.
+        let mut t = min_t;
+
+        for _ in 0..MAX_STEPS {
+            let dist = self.distance_at_local_point(&ls_ray.point_at(t));
+
+            if (dist < N::zero()) != from_inside || dist.abs() <= tolerance {
+                if dist.abs() > tolerance {
+                    t = prev_t + (t - prev_t) * prev_dist / (prev_dist - dist);
+                }
+
+                let normal = self.gradient_at_local_point(&ls_ray.point_at(t));
+                let normal = if from_inside { -normal } else { normal };
+                let normal = m * normal.try_normalize(N::default_epsilon()).unwrap_or_else(Vector::zeros);
+
+                return Some(RayIntersection::new(t, normal, FeatureId::Unknown));
+            }
+
+            prev_t = t;
+            prev_dist = dist;
+            t += dist.abs().max(tolerance) / dir_norm;
+
+            if t > max_t {
+                return None;
+            }
+        }
+
+        None
+    }
+}
@@ -7,7 +7,7 @@ use crate::query::{Ray, RayCast, RayIntersection};
 use crate::query;
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
-use crate::shape::{Capsule, Segment, SupportMap, FeatureId};
+use crate::shape::{Capsule, MinkowskiSum, Segment, SupportMap, SweptShape, FeatureId};
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, ConvexHull, Cylinder};
 
@@ -128,6 +128,54 @@ impl<N: RealField> RayCast<N> for Capsule<N> {
     }
 }
 
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> RayCast<N> for MinkowskiSum<A, B> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        solid: bool,
+    ) -> Option<RayIntersection<N>>
+    {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        implicit_toi_and_normal_with_ray(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
+
+impl<N: RealField, S: SupportMap<N>> RayCast<N> for SweptShape<N, S> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        solid: bool,
+    ) -> Option<RayIntersection<N>>
+    {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        implicit_toi_and_normal_with_ray(
+            &Isometry::identity(),
+            self,
+            &mut VoronoiSimplex::new(),
+            &ls_ray,
+            solid,
+        )
+        .map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField> RayCast<N> for ConvexHull<N> {
     fn toi_and_normal_with_ray(
@@ -1,9 +1,9 @@
-use crate::bounding_volume::AABB;
+use crate::bounding_volume::{SimdAABB, SIMD_WIDTH};
 use crate::math::Isometry;
 use na::{Point2, RealField, Vector3};
-use crate::partitioning::{BestFirstBVVisitStatus, BestFirstDataVisitStatus, BestFirstVisitor};
+use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
 use crate::query::{ray_internal, Ray, RayCast, RayIntersection};
-use crate::shape::{CompositeShape, TriMesh, FeatureId};
+use crate::shape::{TriMesh, FeatureId};
 
 impl<N: RealField> RayCast<N> for TriMesh<N> {
     #[inline]
@@ -15,7 +15,7 @@ impl<N: RealField> RayCast<N> for TriMesh<N> {
             ray: &ls_ray,
         };
 
-        self.bvh().best_first_search(&mut visitor)
+        self.qbvh().best_first_search(&mut visitor)
     }
 
     #[inline]
@@ -33,7 +33,7 @@ impl<N: RealField> RayCast<N> for TriMesh<N> {
             ray: &ls_ray,
         };
 
-        self.bvh().best_first_search(&mut visitor).map(|(best, mut res)| {
+        self.qbvh().best_first_search(&mut visitor).map(|(best, mut res)| {
             if let FeatureId::Face(1) = res.feature {
                 res.feature = FeatureId::Face(best + self.faces().len());
             } else {
@@ -62,7 +62,7 @@ impl<N: RealField> RayCast<N> for TriMesh<N> {
             mesh: self,
             ray: &ls_ray,
         };
-        let cast = self.bvh().best_first_search(&mut visitor);
+        let cast = self.qbvh().best_first_search(&mut visitor);
 
         match cast {
             None => None,
@@ -105,26 +105,45 @@ struct TriMeshRayToiVisitor<'a, N: 'a + RealField> {
     ray: &'a Ray<N>,
 }
 
-impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for TriMeshRayToiVisitor<'a, N> {
+impl<'a, N: RealField> SimdBestFirstVisitor<N, usize> for TriMeshRayToiVisitor<'a, N> {
     type Result = N;
 
     #[inline]
-    fn visit_bv(&mut self, aabb: &AABB<N>) -> BestFirstBVVisitStatus<N> {
-        match aabb.toi_with_ray(&Isometry::identity(), self.ray, true) {
-            Some(toi) => BestFirstBVVisitStatus::ContinueWithCost(toi),
-            None => BestFirstBVVisitStatus::Stop,
+    fn visit(
+        &mut self,
+        best: N,
+        aabb: &SimdAABB,
+        data: [Option<&usize>; SIMD_WIDTH],
+    ) -> SimdBestFirstVisitStatus<N, N>
+    {
+        let (mut mask, mut weights) = aabb.cast_ray(self.ray, best);
+        let mut results = [None; SIMD_WIDTH];
+
+        for ii in 0..SIMD_WIDTH {
+            if let (true, Some(b)) = (mask[ii], data[ii]) {
+                if !self.mesh.can_ray_hit_part(*b, &self.ray.dir) {
+                    mask[ii] = false;
+                    continue;
+                }
+
+                match self
+                    .mesh
+                    .triangle_at(*b)
+                    .toi_with_ray(&Isometry::identity(), self.ray, true)
+                {
+                    Some(toi) => {
+                        weights[ii] = toi;
+                        results[ii] = Some(toi);
+                    }
+                    None => mask[ii] = false,
+                }
+            }
         }
-    }
 
-    #[inline]
-    fn visit_data(&mut self, b: &usize) -> BestFirstDataVisitStatus<N, N> {
-        match self
-            .mesh
-            .triangle_at(*b)
-            .toi_with_ray(&Isometry::identity(), self.ray, true)
-        {
-            Some(toi) => BestFirstDataVisitStatus::ContinueWithResult(toi, toi),
-            None => BestFirstDataVisitStatus::Continue,
+        SimdBestFirstVisitStatus::MaybeContinue {
+            weights,
+            mask,
+            results,
         }
     }
 }
@@ -134,26 +153,45 @@ struct TriMeshRayToiAndNormalVisitor<'a, N: 'a + RealField> {
     ray: &'a Ray<N>,
 }
 
-impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for TriMeshRayToiAndNormalVisitor<'a, N> {
+impl<'a, N: RealField> SimdBestFirstVisitor<N, usize> for TriMeshRayToiAndNormalVisitor<'a, N> {
     type Result = (usize, RayIntersection<N>);
 
     #[inline]
-    fn visit_bv(&mut self, aabb: &AABB<N>) -> BestFirstBVVisitStatus<N> {
-        match aabb.toi_with_ray(&Isometry::identity(), self.ray, true) {
-            Some(toi) => BestFirstBVVisitStatus::ContinueWithCost(toi),
-            None => BestFirstBVVisitStatus::Stop,
+    fn visit(
+        &mut self,
+        best: N,
+        aabb: &SimdAABB,
+        data: [Option<&usize>; SIMD_WIDTH],
+    ) -> SimdBestFirstVisitStatus<N, (usize, RayIntersection<N>)>
+    {
+        let (mut mask, mut weights) = aabb.cast_ray(self.ray, best);
+        let mut results = [None; SIMD_WIDTH];
+
+        for ii in 0..SIMD_WIDTH {
+            if let (true, Some(b)) = (mask[ii], data[ii]) {
+                if !self.mesh.can_ray_hit_part(*b, &self.ray.dir) {
+                    mask[ii] = false;
+                    continue;
+                }
+
+                match self.mesh.triangle_at(*b).toi_and_normal_with_ray(
+                    &Isometry::identity(),
+                    self.ray,
+                    true,
+                ) {
+                    Some(inter) => {
+                        weights[ii] = inter.toi;
+                        results[ii] = Some((*b, inter));
+                    }
+                    None => mask[ii] = false,
+                }
+            }
         }
-    }
 
-    #[inline]
-    fn visit_data(&mut self, b: &usize) -> BestFirstDataVisitStatus<N, (usize, RayIntersection<N>)> {
-        match self.mesh.triangle_at(*b).toi_and_normal_with_ray(
-            &Isometry::identity(),
-            self.ray,
-            true,
-        ) {
-            Some(inter) => BestFirstDataVisitStatus::ContinueWithResult(inter.toi, (*b, inter)),
-            None => BestFirstDataVisitStatus::Continue,
+        SimdBestFirstVisitStatus::MaybeContinue {
+            weights,
+            mask,
+            results,
         }
     }
 }
@@ -163,37 +201,50 @@ struct TriMeshRayToiAndNormalAndUVsVisitor<'a, N: 'a + RealField> {
     ray: &'a Ray<N>,
 }
 
-impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>>
+impl<'a, N: RealField> SimdBestFirstVisitor<N, usize>
     for TriMeshRayToiAndNormalAndUVsVisitor<'a, N>
 {
     type Result = (usize, RayIntersection<N>, Vector3<N>);
 
     #[inline]
-    fn visit_bv(&mut self, aabb: &AABB<N>) -> BestFirstBVVisitStatus<N> {
-        match aabb.toi_with_ray(&Isometry::identity(), self.ray, true) {
-            Some(toi) => BestFirstBVVisitStatus::ContinueWithCost(toi),
-            None => BestFirstBVVisitStatus::Stop,
-        }
-    }
-
-    #[inline]
-    fn visit_data(
+    fn visit(
         &mut self,
-        i: &usize,
-    ) -> BestFirstDataVisitStatus<N, (usize, RayIntersection<N>, Vector3<N>)>
+        best: N,
+        aabb: &SimdAABB,
+        data: [Option<&usize>; SIMD_WIDTH],
+    ) -> SimdBestFirstVisitStatus<N, (usize, RayIntersection<N>, Vector3<N>)>
     {
+        let (mut mask, mut weights) = aabb.cast_ray(self.ray, best);
+        let mut results = [None; SIMD_WIDTH];
         let vs = self.mesh.points();
-        let idx = self.mesh.faces()[*i].indices;
-
-        let a = &vs[idx[0]];
-        let b = &vs[idx[1]];
-        let c = &vs[idx[2]];
 
-        match ray_internal::triangle_ray_intersection(a, b, c, self.ray) {
-            Some(inter) => {
-                BestFirstDataVisitStatus::ContinueWithResult(inter.0.toi, (*i, inter.0, inter.1))
+        for ii in 0..SIMD_WIDTH {
+            if let (true, Some(i)) = (mask[ii], data[ii]) {
+                if !self.mesh.can_ray_hit_part(*i, &self.ray.dir) {
+                    mask[ii] = false;
+                    continue;
+                }
+
+                let idx = self.mesh.faces()[*i].indices;
+
+                let a = &vs[idx[0]];
+                let b = &vs[idx[1]];
+                let c = &vs[idx[2]];
+
+                match ray_internal::triangle_ray_intersection(a, b, c, self.ray) {
+                    Some(inter) => {
+                        weights[ii] = inter.0.toi;
+                        results[ii] = Some((*i, inter.0, inter.1));
+                    }
+                    None => mask[ii] = false,
+                }
             }
-            None => BestFirstDataVisitStatus::Continue,
+        }
+
+        SimdBestFirstVisitStatus::MaybeContinue {
+            weights,
+            mask,
+            results,
         }
     }
 }
@@ -0,0 +1,97 @@
+use crate::math::{Isometry, Point, Translation, Vector, DIM};
+use na::{self, RealField};
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Voxels};
+
+impl<N: RealField> RayCast<N> for Voxels<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        solid: bool,
+    ) -> Option<RayIntersection<N>>
+    {
+        // This also excludes the grids with zero cells along some axis.
+        if self.cells().is_empty() {
+            return None;
+        }
+
+        let ls_ray = ray.inverse_transform_by(m);
+        let (min_t, max_t) = self.grid_aabb().clip_ray_parameters(&ls_ray)?;
+        let start = ls_ray.point_at(min_t);
+
+        // Traverse the cells crossed by the ray with a grid DDA.
+        let mut cell = Point::origin();
+        let mut step = [0isize; DIM];
+        let mut next_t = Vector::repeat(N::max_value());
+        let mut delta_t = Vector::repeat(N::max_value());
+
+        for d in 0..DIM {
+            let max_cell = self.dims()[d] - 1;
+            let size = self.voxel_size()[d];
+            let i = (start[d] / size).floor().max(N::zero());
+            cell[d] = unsafe { (na::convert_unchecked::<N, f64>(i) as usize).min(max_cell) };
+            let cell_min = na::convert::<f64, N>(cell[d] as f64) * size;
+
+            if ls_ray.dir[d] > N::zero() {
+                step[d] = 1;
+                next_t[d] = min_t + (cell_min + size - start[d]) / ls_ray.dir[d];
+                delta_t[d] = size / ls_ray.dir[d];
+            } else if ls_ray.dir[d] < N::zero() {
+                step[d] = -1;
+                next_t[d] = min_t + (cell_min - start[d]) / ls_ray.dir[d];
+                delta_t[d] = -size / ls_ray.dir[d];
+            }
+        }
+
+        // If the ray starts inside of the voxels and `solid` is `false`, we look for the first empty cell instead.
+        let exit_mode = !solid && min_t.is_zero() && self.is_voxel_occupied(&cell);
+
+        loop {
+            if !exit_mode {
+                if let Some(part) = self.part_at_cell(&cell) {
+                    let pos = Translation::from(self.voxel_center(&self.cells()[part]).coords);
+                    let inter = self
+                        .voxel_shape()
+                        .toi_and_normal_with_ray(&Isometry::from_parts(pos, na::one()), &ls_ray, solid);
+
+                    if let Some(mut inter) = inter {
+                        inter.normal = m * inter.normal;
+                        inter.feature = FeatureId::Unknown;
+                        return Some(inter);
+                    }
+                }
+            }
+
+            // Move to the next cell.
+            let d = next_t.imin();
+            let toi = next_t[d];
+
+            if toi > max_t {
+                return None;
+            }
+
+            let leaving_grid = (step[d] < 0 && cell[d] == 0) || (step[d] > 0 && cell[d] == self.dims()[d] - 1);
+
+            if !leaving_grid {
+                if step[d] > 0 {
+                    cell[d] += 1;
+                } else {
+                    cell[d] -= 1;
+                }
+            }
+
+            if exit_mode && (leaving_grid || !self.is_voxel_occupied(&cell)) {
+                let mut normal = Vector::zeros();
+                normal[d] = if step[d] > 0 { -N::one() } else { N::one() };
+                return Some(RayIntersection::new(toi, m * normal, FeatureId::Unknown));
+            }
+
+            if leaving_grid {
+                return None;
+            }
+
+            next_t[d] += delta_t[d];
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use crate::bounding_volume::BoundingVolume;
+use crate::bounding_volume::{BoundingVolume, SimdAABB, AABB, SIMD_WIDTH};
 use na::RealField;
-use crate::partitioning::{VisitStatus, Visitor};
+use crate::partitioning::{SimdVisitStatus, SimdVisitor, VisitStatus, Visitor};
 use std::marker::PhantomData;
 
 /// Spatial partitioning data structure visitor collecting interferences with a given bounding volume.
@@ -51,3 +51,24 @@ where
         }
     }
 }
+
+impl<'a, N, T> SimdVisitor<T> for BoundingVolumeInterferencesCollector<'a, N, T, AABB<N>>
+where
+    N: RealField,
+    T: Clone,
+{
+    #[inline]
+    fn visit(&mut self, bv: &SimdAABB, data: [Option<&T>; SIMD_WIDTH]) -> SimdVisitStatus {
+        let mask = bv.intersects_aabb(self.bv);
+
+        for ii in 0..SIMD_WIDTH {
+            if mask[ii] {
+                if let Some(t) = data[ii] {
+                    self.collector.push(t.clone())
+                }
+            }
+        }
+
+        SimdVisitStatus::MaybeContinue(mask)
+    }
+}
@@ -1,6 +1,7 @@
+use crate::bounding_volume::{SimdAABB, SIMD_WIDTH};
 use crate::math::{Isometry, Point};
 use na::RealField;
-use crate::partitioning::{VisitStatus, Visitor};
+use crate::partitioning::{SimdVisitStatus, SimdVisitor, VisitStatus, Visitor};
 use crate::query::PointQuery;
 
 // FIXME: add a point cost fn.
@@ -46,3 +47,24 @@ where
         }
     }
 }
+
+impl<'a, N, T> SimdVisitor<T> for PointInterferencesCollector<'a, N, T>
+where
+    N: RealField,
+    T: Clone,
+{
+    #[inline]
+    fn visit(&mut self, bv: &SimdAABB, data: [Option<&T>; SIMD_WIDTH]) -> SimdVisitStatus {
+        let mask = bv.contains_point(self.point);
+
+        for ii in 0..SIMD_WIDTH {
+            if mask[ii] {
+                if let Some(t) = data[ii] {
+                    self.collector.push(t.clone())
+                }
+            }
+        }
+
+        SimdVisitStatus::MaybeContinue(mask)
+    }
+}
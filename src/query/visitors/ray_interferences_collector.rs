@@ -1,6 +1,7 @@
+use crate::bounding_volume::{SimdAABB, SIMD_WIDTH};
 use crate::math::Isometry;
 use na::RealField;
-use crate::partitioning::{VisitStatus, Visitor};
+use crate::partitioning::{SimdVisitStatus, SimdVisitor, VisitStatus, Visitor};
 use crate::query::{Ray, RayCast};
 
 /// Bounding Volume Tree visitor collecting interferences with a given ray.
@@ -41,3 +42,24 @@ where
         }
     }
 }
+
+impl<'a, N, T> SimdVisitor<T> for RayInterferencesCollector<'a, N, T>
+where
+    N: RealField,
+    T: Clone,
+{
+    #[inline]
+    fn visit(&mut self, bv: &SimdAABB, data: [Option<&T>; SIMD_WIDTH]) -> SimdVisitStatus {
+        let (mask, _) = bv.cast_ray(self.ray, N::max_value());
+
+        for ii in 0..SIMD_WIDTH {
+            if mask[ii] {
+                if let Some(t) = data[ii] {
+                    self.collector.push(t.clone())
+                }
+            }
+        }
+
+        SimdVisitStatus::MaybeContinue(mask)
+    }
+}
@@ -1,9 +1,9 @@
 use crate::bounding_volume::AABB;
 use crate::math::Isometry;
 use na::RealField;
-use crate::partitioning::BVHImpl;
+use crate::partitioning::{BVHImpl, QBVH};
 use crate::shape::Shape;
-use crate::query::{ContactPreprocessor, ContactPrediction};
+use crate::query::{visitors::BoundingVolumeInterferencesCollector, ContactPreprocessor, ContactPrediction};
 
 /// Trait implemented by shapes composed of multiple simpler shapes.
 ///
@@ -39,4 +39,34 @@ pub trait CompositeShape<N: RealField> {
 
     /// Gets the acceleration structure of the concave shape.
     fn bvh(&self) -> BVHImpl<N, usize, AABB<N>>;
+
+    /// Gets the flattened four-wide acceleration structure of the concave shape, if it has one.
+    ///
+    /// When available, it is used instead of `self.bvh()` to collect the sub-shapes intersecting
+    /// a bounding volume.
+    #[inline]
+    fn qbvh(&self) -> Option<&QBVH<usize>> {
+        None
+    }
+
+    /// Collects the indices of the parts whose AABB intersects the given local-space AABB.
+    ///
+    /// By default, this traverses `self.qbvh()` if available, or `self.bvh()` otherwise.
+    fn parts_intersecting_local_aabb(&self, aabb: &AABB<N>, out: &mut Vec<usize>) {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(aabb, out);
+
+        match self.qbvh() {
+            Some(qbvh) => qbvh.visit(&mut visitor),
+            None => self.bvh().visit(&mut visitor),
+        }
+    }
+
+    /// Tests if the part identified by `i` only interacts with objects located in front of it
+    /// and if the center of the given local-space AABB lies behind it.
+    ///
+    /// Proximity detectors ignore the parts for which this returns `true`.
+    #[inline]
+    fn is_aabb_behind_part(&self, _i: usize, _aabb: &AABB<N>) -> bool {
+        false
+    }
 }
@@ -6,7 +6,7 @@ use std::mem;
 use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::Isometry;
 use na::{self, RealField};
-use crate::partitioning::{BVHImpl, BVT};
+use crate::partitioning::{BVHImpl, BVT, QBVH};
 use crate::shape::{CompositeShape, Shape, ShapeHandle, FeatureId};
 use crate::query::{ContactPrediction, ContactPreprocessor, Contact, ContactKinematic};
 
@@ -19,6 +19,7 @@ use crate::query::{ContactPrediction, ContactPreprocessor, Contact, ContactKinem
 pub struct Compound<N: RealField> {
     shapes: Vec<(Isometry<N>, ShapeHandle<N>)>,
     bvt: BVT<usize, AABB<N>>,
+    qbvh: QBVH<usize>,
     bvs: Vec<AABB<N>>,
     nbits: usize
 }
@@ -43,10 +44,12 @@ impl<N: RealField> Compound<N> {
 
         let nbits = mem::size_of::<usize>() * 8 - leaves.len().leading_zeros() as usize;
         let bvt = BVT::new_balanced(leaves);
+        let qbvh = QBVH::from_bvh(&bvt);
 
         Compound {
             shapes: shapes,
             bvt: bvt,
+            qbvh,
             bvs: bvs,
             nbits,
         }
@@ -66,6 +69,15 @@ impl<N: RealField> Compound<N> {
         &self.bvt
     }
 
+    /// The flattened four-wide optimization structure used by this compound shape.
+    ///
+    /// It is built from `self.bvt()` and kept alongside it: ray casts and the collection of the
+    /// parts intersecting an AABB go through the QBVH, while the other queries traverse the BVT.
+    #[inline]
+    pub fn qbvh(&self) -> &QBVH<usize> {
+        &self.qbvh
+    }
+
     /// The AABB of this compound in its local-space.
     #[inline]
     pub fn aabb(&self) -> &AABB<N> {
@@ -141,6 +153,11 @@ impl<N: RealField> CompositeShape<N> for Compound<N> {
     fn bvh(&self) -> BVHImpl<N, usize, AABB<N>> {
         BVHImpl::BVT(&self.bvt)
     }
+
+    #[inline]
+    fn qbvh(&self) -> Option<&QBVH<usize>> {
+        Some(&self.qbvh)
+    }
 }
 
 
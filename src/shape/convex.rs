@@ -350,6 +350,14 @@ impl<N: RealField> ConvexHull<N> {
         &self.points[..]
     }
 
+    /// The indices of the vertices of each face of this convex polyhedron, in order around the face.
+    pub(crate) fn face_vertex_indices<'a>(&'a self) -> impl Iterator<Item = &'a [usize]> + 'a {
+        self.faces.iter().map(move |face| {
+            let first = face.first_vertex_or_edge;
+            &self.vertices_adj_to_face[first..first + face.num_vertices_or_edges]
+        })
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     pub fn tangent_cone_contains_dir(
         &self,
@@ -2,6 +2,7 @@ use std::iter;
 use na::{DVector, RealField, Point2};
 
 use crate::bounding_volume::AABB;
+use crate::partitioning::{BVT, QBVH};
 use crate::query::{ContactPreprocessor, Contact, ContactKinematic};
 use crate::shape::Segment;
 use crate::math::Vector;
@@ -15,6 +16,8 @@ pub struct HeightField<N: RealField> {
     scale: Vector<N>,
     removed: Vec<bool>,
     aabb: AABB<N>,
+    qbvh: QBVH<usize>,
+    one_way: Option<N>,
 }
 
 impl<N: RealField> HeightField<N> {
@@ -30,11 +33,86 @@ impl<N: RealField> HeightField<N> {
             Point2::new(hscale.x, max * scale.y)
         );
 
+        let qbvh = Self::cells_qbvh(&heights, &scale);
+
         HeightField {
-            heights, scale, aabb, removed: Vec::new()
+            heights, scale, aabb, removed: Vec::new(), qbvh, one_way: None
+        }
+    }
+
+    // A QBVH which leaves are the indices of the segments of the heightfield.
+    fn cells_qbvh(heights: &DVector<N>, scale: &Vector<N>) -> QBVH<usize> {
+        let _0_5: N = na::convert(0.5);
+        let seg_length = N::one() / na::convert(heights.len() as f64 - 1.0);
+        let leaves = (0..heights.len() - 1)
+            .map(|i| {
+                let x0 = -_0_5 + seg_length * na::convert(i as f64);
+                let y0 = heights[i] * scale.y;
+                let y1 = heights[i + 1] * scale.y;
+                let aabb = AABB::new(
+                    Point2::new(x0 * scale.x, y0.min(y1)),
+                    Point2::new((x0 + seg_length) * scale.x, y0.max(y1)),
+                );
+
+                (i, aabb)
+            })
+            .collect();
+
+        QBVH::from_bvh(&BVT::new_balanced(leaves))
+    }
+
+    /// The angular tolerance of the one-way collisions with this heightfield, if enabled.
+    ///
+    /// By default, one-way collisions are disabled.
+    pub fn one_way(&self) -> Option<N> {
+        self.one_way
+    }
+
+    /// Enables one-way collisions with this heightfield, or disables them if `tolerance` is `None`.
+    ///
+    /// When enabled, the segments of this heightfield only interact with objects located above
+    /// them. Contacts are discarded if their normal deviates from the upward normal of the segment
+    /// they are on by more than the angle `tolerance` (in radians), and rays only hit the segments
+    /// from above.
+    pub fn set_one_way(&mut self, tolerance: Option<N>) {
+        self.one_way = tolerance
+    }
+
+    /// Tests if a ray with the local-space direction `dir` may hit the given segment of this
+    /// heightfield.
+    ///
+    /// This is always the case, unless one-way collisions are enabled and `dir` points upward.
+    pub(crate) fn can_ray_hit_segment(&self, segment: &Segment<N>, dir: &Vector<N>) -> bool {
+        match (self.one_way, segment.normal()) {
+            (Some(_), Some(n)) => {
+                let n = if n.y < N::zero() { -n } else { n };
+                n.dot(dir) < N::zero()
+            }
+            _ => true,
         }
     }
 
+    /// Tests if the center of the given `aabb` is located behind the given segment of this heightfield.
+    ///
+    /// This is always `false`, unless one-way collisions are enabled.
+    pub(crate) fn is_aabb_behind_element(&self, segment: &Segment<N>, aabb: &AABB<N>) -> bool {
+        match (self.one_way, segment.normal()) {
+            (Some(_), Some(n)) => {
+                let n = if n.y < N::zero() { -n } else { n };
+                (aabb.center() - segment.a()).dot(&n) < N::zero()
+            }
+            _ => false,
+        }
+    }
+
+    /// The flattened four-wide acceleration structure over the segments of this heightfield.
+    ///
+    /// Its leaf data are the indices of the segments, including the removed ones.
+    #[inline]
+    pub fn qbvh(&self) -> &QBVH<usize> {
+        &self.qbvh
+    }
+
     /// The number of cells of this heightfield.
     pub fn num_cells(&self) -> usize {
         self.heights.len() - 1
@@ -1,6 +1,7 @@
 use na::{DMatrix, RealField, Point3};
 
 use crate::bounding_volume::AABB;
+use crate::partitioning::{BVT, QBVH};
 use crate::query::{ContactPreprocessor, Contact, ContactKinematic};
 use crate::shape::{Triangle, FeatureId};
 use crate::math::Vector;
@@ -30,6 +31,8 @@ pub struct HeightField<N: RealField> {
     aabb: AABB<N>,
     num_triangles: usize,
     status: DMatrix<HeightFieldCellStatus>,
+    qbvh: QBVH<usize>,
+    one_way: Option<N>,
 }
 
 impl<N: RealField> HeightField<N> {
@@ -45,12 +48,99 @@ impl<N: RealField> HeightField<N> {
         );
         let num_triangles = (heights.nrows() - 1) * (heights.ncols() - 1) * 2;
         let status = DMatrix::repeat(heights.nrows() - 1, heights.ncols() - 1, HeightFieldCellStatus::default());
+        let qbvh = Self::cells_qbvh(&heights, &scale);
 
         HeightField {
-            heights, scale, aabb, num_triangles, status
+            heights, scale, aabb, num_triangles, status, qbvh, one_way: None
         }
     }
 
+    // A QBVH which leaves are the cells of the heightfield, identified by `i + j * nrows`.
+    fn cells_qbvh(heights: &DMatrix<N>, scale: &Vector<N>) -> QBVH<usize> {
+        let _0_5: N = na::convert(0.5);
+        let nrows = heights.nrows() - 1;
+        let ncols = heights.ncols() - 1;
+        let cell_width = N::one() / na::convert(ncols as f64);
+        let cell_height = N::one() / na::convert(nrows as f64);
+        let mut leaves = Vec::with_capacity(nrows * ncols);
+
+        for j in 0..ncols {
+            for i in 0..nrows {
+                let x0 = -_0_5 + cell_width * na::convert(j as f64);
+                let z0 = -_0_5 + cell_height * na::convert(i as f64);
+                let ys = [
+                    heights[(i, j)] * scale.y,
+                    heights[(i + 1, j)] * scale.y,
+                    heights[(i, j + 1)] * scale.y,
+                    heights[(i + 1, j + 1)] * scale.y,
+                ];
+                let ymin = ys.iter().fold(ys[0], |a, b| a.min(*b));
+                let ymax = ys.iter().fold(ys[0], |a, b| a.max(*b));
+                let aabb = AABB::new(
+                    Point3::new(x0 * scale.x, ymin, z0 * scale.z),
+                    Point3::new((x0 + cell_width) * scale.x, ymax, (z0 + cell_height) * scale.z),
+                );
+
+                leaves.push((i + j * nrows, aabb));
+            }
+        }
+
+        QBVH::from_bvh(&BVT::new_balanced(leaves))
+    }
+
+    /// The angular tolerance of the one-way collisions with this heightfield, if enabled.
+    ///
+    /// By default, one-way collisions are disabled.
+    pub fn one_way(&self) -> Option<N> {
+        self.one_way
+    }
+
+    /// Enables one-way collisions with this heightfield, or disables them if `tolerance` is `None`.
+    ///
+    /// When enabled, the triangles of this heightfield only interact with objects located above
+    /// them. Contacts are discarded if their normal deviates from the upward normal of the triangle
+    /// they are on by more than the angle `tolerance` (in radians), and rays only hit the triangles
+    /// from above.
+    pub fn set_one_way(&mut self, tolerance: Option<N>) {
+        self.one_way = tolerance
+    }
+
+    /// Tests if a ray with the local-space direction `dir` may hit the given triangle of this
+    /// heightfield.
+    ///
+    /// This is always the case, unless one-way collisions are enabled and `dir` points upward.
+    pub(crate) fn can_ray_hit_triangle(&self, triangle: &Triangle<N>, dir: &Vector<N>) -> bool {
+        match (self.one_way, triangle.normal()) {
+            (Some(_), Some(n)) => {
+                let n = if n.y < N::zero() { -n } else { n };
+                n.dot(dir) < N::zero()
+            }
+            _ => true,
+        }
+    }
+
+    /// Tests if the center of the given `aabb` is located behind the given triangle of this heightfield.
+    ///
+    /// This is always `false`, unless one-way collisions are enabled.
+    pub(crate) fn is_aabb_behind_element(&self, triangle: &Triangle<N>, aabb: &AABB<N>) -> bool {
+        match (self.one_way, triangle.normal()) {
+            (Some(_), Some(n)) => {
+                let n = if n.y < N::zero() { -n } else { n };
+                (aabb.center() - triangle.a()).dot(&n) < N::zero()
+            }
+            _ => false,
+        }
+    }
+
+    /// The flattened four-wide acceleration structure over the cells of this heightfield.
+    ///
+    /// The leaf data `i + j * self.nrows()` identifies the cell `(i, j)`. The bounding volume of
+    /// a cell encloses both its triangles, even if they have been removed.
+    #[inline]
+    pub fn qbvh(&self) -> &QBVH<usize> {
+        &self.qbvh
+    }
+
     /// The number of rows of this heightfield.
     pub fn nrows(&self) -> usize {
         self.heights.nrows() - 1
@@ -0,0 +1,56 @@
+//! Support mapping based Minkowski sum of two shapes.
+
+use na::{RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::SupportMap;
+
+/// The Minkowski sum of two convex shapes expressed in the same local frame.
+///
+/// This is the set of all the sums `a + b` of a point `a` of the first shape and a point `b` of
+/// the second one. For example, the Minkowski sum of a cuboid and a ball is a cuboid with rounded
+/// edges and corners.
+///
+/// The collision pipeline generates at most one contact between a Minkowski sum and another
+/// shape, so it does not rest stably on one of its flat sides. Use the `ConvexHull` of its
+/// triangle mesh (or polyline in 2D) if several contacts are needed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct MinkowskiSum<A, B> {
+    shape1: A,
+    shape2: B,
+}
+
+impl<A, B> MinkowskiSum<A, B> {
+    /// Creates the Minkowski sum of two shapes.
+    pub fn new(shape1: A, shape2: B) -> Self {
+        MinkowskiSum { shape1, shape2 }
+    }
+
+    /// The first shape involved in this Minkowski sum.
+    #[inline]
+    pub fn shape1(&self) -> &A {
+        &self.shape1
+    }
+
+    /// The second shape involved in this Minkowski sum.
+    #[inline]
+    pub fn shape2(&self) -> &B {
+        &self.shape2
+    }
+}
+
+impl<N: RealField, A: SupportMap<N>, B: SupportMap<N>> SupportMap<N> for MinkowskiSum<A, B> {
+    #[inline]
+    fn support_point(&self, m: &Isometry<N>, dir: &Vector<N>) -> Point<N> {
+        // Both support points include the translation of `m`.
+        self.shape1.support_point(m, dir) + self.shape2.support_point(m, dir).coords
+            - m.translation.vector
+    }
+
+    #[inline]
+    fn support_point_toward(&self, m: &Isometry<N>, dir: &Unit<Vector<N>>) -> Point<N> {
+        self.shape1.support_point_toward(m, dir) + self.shape2.support_point_toward(m, dir).coords
+            - m.translation.vector
+    }
+}
@@ -23,16 +23,21 @@ pub use self::cylinder::Cylinder;
 pub use self::deformable_shape::{DeformableShape, DeformationsType};
 //#[cfg(feature = "dim3")]
 //pub use self::deformable_trimesh::DeformableTriMesh;
+pub use self::minkowski_sum::MinkowskiSum;
 pub use self::plane::Plane;
 pub use self::polyline::Polyline;
 pub use self::segment::{Segment, SegmentPointLocation};
+#[cfg(feature = "dim3")]
+pub use self::signed_distance_field::SignedDistanceField;
 #[doc(inline)]
 pub use self::shape::{Shape, ShapeHandle};
 #[doc(inline)]
 pub use self::support_map::SupportMap;
+pub use self::swept_shape::SweptShape;
 #[cfg(feature = "dim3")]
 pub use self::tetrahedron::{Tetrahedron, TetrahedronPointLocation};
 pub use self::triangle::{Triangle, TrianglePointLocation};
+pub use self::voxels::Voxels;
 #[cfg(feature = "dim3")]
 pub use self::trimesh::{TriMesh, TriMeshFace};
 #[cfg(feature = "dim3")]
@@ -61,17 +66,22 @@ mod cuboid;
 #[cfg(feature = "dim3")]
 mod cylinder;
 mod deformable_shape;
+mod minkowski_sum;
 mod plane;
 mod polyline;
 mod segment;
+#[cfg(feature = "dim3")]
+mod signed_distance_field;
 #[doc(hidden)]
 pub mod shape;
 mod shape_impl;
 #[doc(hidden)]
 pub mod support_map;
+mod swept_shape;
 #[cfg(feature = "dim3")]
 mod tetrahedron;
 mod triangle;
+mod voxels;
 #[cfg(feature = "dim3")]
 mod trimesh;
 #[cfg(feature = "dim3")]
@@ -4,6 +4,7 @@ use crate::bounding_volume::{self, BoundingVolume, AABB};
 use crate::math::{Isometry, Point, Vector, DIM};
 use na::{self, Id, Point2, RealField, Unit};
 use crate::partitioning::{BVHImpl, BVT};
+use crate::query::contacts_internal::OneWayContactPreprocessor;
 use crate::query::{LocalShapeApproximation, NeighborhoodGeometry, ContactPreprocessor, ContactPrediction, Contact, ContactKinematic};
 use crate::shape::{
     CompositeShape, DeformableShape, DeformationsType, FeatureId, Segment, Shape,
@@ -50,6 +51,7 @@ pub struct Polyline<N: RealField> {
     adj_vertex_list: Vec<usize>,
     deformations: DeformationInfos<N>,
     oriented: bool, // FIXME: useless in 3D
+    one_way: Option<N>, // FIXME: useless in 3D
 }
 
 impl<N: RealField> Polyline<N> {
@@ -113,6 +115,7 @@ impl<N: RealField> Polyline<N> {
             adj_edge_list,
             adj_vertex_list,
             oriented: false,
+            one_way: None,
         }
     }
 
@@ -247,6 +250,48 @@ impl<N: RealField> Polyline<N> {
         self.oriented = oriented
     }
 
+    /// The angular tolerance of the one-way collisions with this polyline, if enabled.
+    ///
+    /// By default, one-way collisions are disabled.
+    #[inline]
+    pub fn one_way(&self) -> Option<N> {
+        self.one_way
+    }
+
+    /// Enables one-way collisions with this polyline, or disables them if `tolerance` is `None`.
+    ///
+    /// When enabled, each segment only interacts with objects located in front of it, i.e., on
+    /// the side its counterclockwise normal points to. Contacts with a segment are discarded if
+    /// their normal deviates from the segment normal by more than the angle `tolerance` (in
+    /// radians). Objects located behind a segment are ignored by proximity queries and rays only
+    /// hit the segments from the front. This has no effect in 3D.
+    #[inline]
+    pub fn set_one_way(&mut self, tolerance: Option<N>) {
+        self.one_way = tolerance
+    }
+
+    /// Tests if a ray with the local-space direction `dir` may hit the `i`-th segment.
+    ///
+    /// This is always the case, unless one-way collisions are enabled and `dir` points to the
+    /// same side as the segment normal.
+    #[inline]
+    pub(crate) fn can_ray_hit_part(&self, i: usize, dir: &Vector<N>) -> bool {
+        match self.one_way_normal(i) {
+            Some(n) => n.dot(dir) < N::zero(),
+            None => true,
+        }
+    }
+
+    /// The normal of the `i`-th segment if one-way collisions are enabled.
+    #[inline]
+    pub(crate) fn one_way_normal(&self, i: usize) -> Option<Unit<Vector<N>>> {
+        if self.one_way.is_some() {
+            self.edges[i].normal
+        } else {
+            None
+        }
+    }
+
     /// Face containing feature.
     #[inline]
     pub fn edge_containing_feature(&self, id: FeatureId) -> usize {
@@ -529,6 +574,17 @@ impl<N: RealField> CompositeShape<N> for Polyline<N> {
     fn bvh(&self) -> BVHImpl<N, usize, AABB<N>> {
         BVHImpl::BVT(&self.bvt)
     }
+
+    #[inline]
+    fn is_aabb_behind_part(&self, i: usize, aabb: &AABB<N>) -> bool {
+        match self.one_way_normal(i) {
+            Some(n) => {
+                let pt = &self.points[self.edges[i].indices.x];
+                (aabb.center() - pt).dot(&n) < N::zero()
+            }
+            None => false,
+        }
+    }
 }
 
 impl<N: RealField> DeformableShape<N> for Polyline<N> {
@@ -679,7 +735,7 @@ impl<'a, N: RealField> PolylineContactProcessor<'a, N> {
 impl<'a, N: RealField> ContactPreprocessor<N> for PolylineContactProcessor<'a, N> {
     fn process_contact(
         &self,
-        _c: &mut Contact<N>,
+        c: &mut Contact<N>,
         kinematic: &mut ContactKinematic<N>,
         is_first: bool)
         -> bool {
@@ -711,6 +767,12 @@ impl<'a, N: RealField> ContactPreprocessor<N> for PolylineContactProcessor<'a, N
             kinematic.set_feature2(actual_feature);
         }
 
+        if let (Some(tolerance), Some(normal)) = (self.polyline.one_way(), self.polyline.one_way_normal(self.edge_id)) {
+            if !OneWayContactPreprocessor::new(self.pos, normal, tolerance).process_contact(c, kinematic, is_first) {
+                return false;
+            }
+        }
+
 /*
         // TODO: Test the validity of the LMD.
         if c.depth > N::zero() {
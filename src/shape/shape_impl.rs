@@ -6,10 +6,10 @@ use crate::query::{PointQuery, RayCast};
 use crate::shape::ConvexPolygon;
 use crate::shape::{
     Ball, CompositeShape, Compound, ConvexPolyhedron, Cuboid, FeatureId, Plane, Polyline, Segment,
-    Capsule, Shape, SupportMap, DeformableShape, HeightField
+    Capsule, Shape, SupportMap, DeformableShape, HeightField, Voxels, MinkowskiSum, SweptShape
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{ConvexHull, TriMesh, Triangle};
+use crate::shape::{ConvexHull, SignedDistanceField, TriMesh, Triangle};
 use crate::utils::IsometryOps;
 
 macro_rules! impl_as_convex_polyhedron (
@@ -164,6 +164,51 @@ impl<N: RealField> Shape<N> for Capsule<N> {
     }
 }
 
+impl<N, A, B> Shape<N> for MinkowskiSum<A, B>
+where
+    N: RealField,
+    A: SupportMap<N> + Clone + Send + Sync + 'static,
+    B: SupportMap<N> + Clone + Send + Sync + 'static,
+{
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    // This shape has no polyhedral feature with a known tangent cone. Its contacts are
+    // approximated by its tangent plane instead, which does not rely on this test.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool
+    {
+        false
+    }
+}
+
+impl<N, S> Shape<N> for SweptShape<N, S>
+where
+    N: RealField,
+    S: SupportMap<N> + Clone + Send + Sync + 'static,
+{
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    // This shape has no polyhedral feature with a known tangent cone. Its contacts are
+    // approximated by its tangent plane instead, which does not rely on this test.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool
+    {
+        false
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField> Shape<N> for ConvexHull<N> {
     impl_shape_common!();
@@ -274,6 +319,39 @@ impl<N: RealField> Shape<N> for HeightField<N> {
     }
 }
 
+#[cfg(feature = "dim3")]
+impl<N: RealField> Shape<N> for SignedDistanceField<N> {
+    impl_shape_common!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _fid: FeatureId,
+        _m: &Isometry<N>,
+        _deformations: Option<&[N]>,
+        _dir: &Unit<Vector<N>>,
+    ) -> bool
+    {
+        false
+    }
+}
+
+impl<N: RealField> Shape<N> for Voxels<N> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _feature: FeatureId,
+        _m: &Isometry<N>,
+        _deformations: Option<&[N]>,
+        _dir: &Unit<Vector<N>>,
+    ) -> bool
+    {
+        // FIXME
+        false
+    }
+}
+
 impl<N: RealField> Shape<N> for Plane<N> {
     impl_shape_common!();
 
@@ -0,0 +1,213 @@
+use na::{self, RealField, Unit, Vector3};
+
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::Shape;
+
+/// A signed distance field sampled on a regular 3D grid.
+///
+/// The distance between a point and the surface of the shape is obtained by trilinear
+/// interpolation of the values at the grid samples. This distance is negative for points
+/// inside of the shape. Outside of the grid, the distance to the grid is added to the value
+/// at the closest point of the grid.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct SignedDistanceField<N: RealField> {
+    aabb: AABB<N>,
+    nsamples: Vector3<usize>,
+    cell_size: Vector<N>,
+    // Indexed by `i + j * nsamples.x + k * nsamples.x * nsamples.y`.
+    values: Vec<N>,
+}
+
+impl<N: RealField> SignedDistanceField<N> {
+    /// Creates a new signed distance field from the given samples.
+    ///
+    /// The samples are located on a regular grid with `nsamples` samples along each axis, the
+    /// first and last samples being located at the corners `aabb.mins()` and `aabb.maxs()`.
+    /// The value of the sample `(i, j, k)` is `values[i + j * nsamples.x + k * nsamples.x * nsamples.y]`.
+    pub fn new(aabb: AABB<N>, nsamples: Vector3<usize>, values: Vec<N>) -> Self {
+        assert!(
+            nsamples.iter().all(|n| *n > 1),
+            "A signed distance field must have at least 2 samples along each axis."
+        );
+        assert_eq!(
+            values.len(),
+            nsamples.x * nsamples.y * nsamples.z,
+            "The number of values does not match the number of samples."
+        );
+
+        assert!(
+            aabb.extents().iter().all(|e| *e > N::zero()),
+            "A signed distance field must have a positive extent along each axis."
+        );
+
+        let ncells = nsamples.map(|n| na::convert::<f64, N>((n - 1) as f64));
+        let cell_size = aabb.extents().component_div(&ncells);
+
+        SignedDistanceField {
+            aabb,
+            nsamples,
+            cell_size,
+            values,
+        }
+    }
+
+    /// Computes the signed distance field of `shape` by sampling it on a regular grid.
+    ///
+    /// The grid covers the local AABB of `shape` enlarged by `margin`, with samples spaced
+    /// by at most `cell_size` along each axis. The distances are computed with
+    /// `PointQuery::distance_to_point`, so `shape` must support point queries and classify
+    /// points as inside of it correctly. For a `TriMesh`, this means it must be closed and
+    /// configured as solid with `TriMesh::set_solid`.
+    ///
+    /// The grid is thickened to `cell_size` along the axes where the enlarged AABB is flatter
+    /// than that. Panics if the AABB of `shape` is too large to be sampled, e.g., for a `Plane`.
+    pub fn from_shape(shape: &dyn Shape<N>, cell_size: N, margin: N) -> Self {
+        assert!(cell_size > N::zero(), "The cell size must be positive.");
+        let pq = shape
+            .as_point_query()
+            .expect("The shape must support point queries.");
+        let aabb = shape.local_aabb();
+        let mut mins = aabb.mins() - Vector::repeat(margin);
+        let mut extents = aabb.extents() + Vector::repeat(margin * na::convert(2.0));
+
+        for d in 0..3 {
+            if extents[d] < cell_size {
+                mins[d] -= (cell_size - extents[d]) * na::convert(0.5);
+                extents[d] = cell_size;
+            }
+        }
+
+        let nsamples = extents.map(|e| unsafe { na::convert_unchecked::<N, f64>((e / cell_size).ceil()) + 1.0 });
+        let total = nsamples.x * nsamples.y * nsamples.z;
+        assert!(
+            total.is_finite() && total < isize::max_value() as f64,
+            "The AABB of the shape is too large to be sampled with the given cell size."
+        );
+        let nsamples = nsamples.map(|n| n as usize);
+        let ncells = nsamples.map(|n| na::convert::<f64, N>((n - 1) as f64));
+        let cell_size = extents.component_div(&ncells);
+        let mut values = Vec::with_capacity(nsamples.x * nsamples.y * nsamples.z);
+
+        for k in 0..nsamples.z {
+            for j in 0..nsamples.y {
+                for i in 0..nsamples.x {
+                    let idx = Vector3::new(i, j, k).map(|e| na::convert::<f64, N>(e as f64));
+                    let pt = mins + idx.component_mul(&cell_size);
+                    values.push(pq.distance_to_point(&Isometry::identity(), &pt, false))
+                }
+            }
+        }
+
+        let aabb = AABB::new(mins, mins + extents);
+        SignedDistanceField::new(aabb, nsamples, values)
+    }
+
+    /// The region covered by the samples of this signed distance field.
+    #[inline]
+    pub fn aabb(&self) -> &AABB<N> {
+        &self.aabb
+    }
+
+    /// The number of samples along each axis.
+    #[inline]
+    pub fn nsamples(&self) -> &Vector3<usize> {
+        &self.nsamples
+    }
+
+    /// The distance between two consecutive samples along each axis.
+    #[inline]
+    pub fn cell_size(&self) -> &Vector<N> {
+        &self.cell_size
+    }
+
+    /// The values of all the samples.
+    #[inline]
+    pub fn values(&self) -> &[N] {
+        &self.values[..]
+    }
+
+    /// The value of the sample `(i, j, k)`.
+    #[inline]
+    pub fn value_at(&self, i: usize, j: usize, k: usize) -> N {
+        self.values[i + j * self.nsamples.x + k * self.nsamples.x * self.nsamples.y]
+    }
+
+    /// The signed distance between the local-space point `pt` and the surface of this shape.
+    pub fn distance_at_local_point(&self, pt: &Point<N>) -> N {
+        let clamped = na::inf(&na::sup(pt, self.aabb.mins()), self.aabb.maxs());
+        let rel = (clamped - self.aabb.mins()).component_div(&self.cell_size);
+        let mut cell = [0; 3];
+        let mut t = Vector3::zeros();
+
+        for d in 0..3 {
+            let max = self.nsamples[d] - 2;
+            let fl = unsafe { na::convert_unchecked::<N, f64>(rel[d].floor()) };
+            cell[d] = if fl < 0.0 { 0 } else { (fl as usize).min(max) };
+            t[d] = na::clamp(rel[d] - na::convert(cell[d] as f64), N::zero(), N::one());
+        }
+
+        let (i, j, k) = (cell[0], cell[1], cell[2]);
+        let lerp = |a: N, b: N, t: N| a + (b - a) * t;
+        let c00 = lerp(self.value_at(i, j, k), self.value_at(i + 1, j, k), t.x);
+        let c10 = lerp(self.value_at(i, j + 1, k), self.value_at(i + 1, j + 1, k), t.x);
+        let c01 = lerp(self.value_at(i, j, k + 1), self.value_at(i + 1, j, k + 1), t.x);
+        let c11 = lerp(self.value_at(i, j + 1, k + 1), self.value_at(i + 1, j + 1, k + 1), t.x);
+        let value = lerp(lerp(c00, c10, t.y), lerp(c01, c11, t.y), t.z);
+
+        value + na::distance(pt, &clamped)
+    }
+
+    /// The gradient of the signed distance at the local-space point `pt`.
+    ///
+    /// This is computed by central differences, with a step equal to half the smallest cell size.
+    pub fn gradient_at_local_point(&self, pt: &Point<N>) -> Vector<N> {
+        let h = self.cell_size.min() * na::convert(0.5);
+        let mut gradient = Vector::zeros();
+
+        for d in 0..3 {
+            let mut shift = Vector::zeros();
+            shift[d] = h;
+            gradient[d] = (self.distance_at_local_point(&(pt + shift))
+                - self.distance_at_local_point(&(pt - shift)))
+                / (h + h);
+        }
+
+        gradient
+    }
+
+    /// The outward normal of the isosurface of the signed distance passing through the local-space point `pt`.
+    ///
+    /// Returns `None` if the gradient at `pt` is zero.
+    #[inline]
+    pub fn normal_at_local_point(&self, pt: &Point<N>) -> Option<Unit<Vector<N>>> {
+        Unit::try_new(self.gradient_at_local_point(pt), N::default_epsilon())
+    }
+
+    /// Projects the local-space point `pt` on the zero isosurface of this signed distance field.
+    ///
+    /// The point is moved along the normal by its signed distance, a few times in a row to
+    /// account for interpolation errors. Returns the projected point and the signed distance at `pt`.
+    pub(crate) fn project_local_point_on_surface(&self, pt: &Point<N>) -> (Point<N>, N) {
+        let dist = self.distance_at_local_point(pt);
+        let tolerance = self.cell_size.min() * na::convert(1.0e-3);
+        let mut proj = *pt;
+        let mut curr = dist;
+
+        for _ in 0..4 {
+            if curr.abs() <= tolerance {
+                break;
+            }
+
+            match self.normal_at_local_point(&proj) {
+                Some(n) => proj -= *n * curr,
+                None => break,
+            }
+
+            curr = self.distance_at_local_point(&proj);
+        }
+
+        (proj, dist)
+    }
+}
@@ -0,0 +1,66 @@
+//! Support mapping based shape swept by a linear motion.
+
+use na::{RealField, Unit};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::SupportMap;
+
+/// The volume swept by a convex shape translated along a vector.
+///
+/// This is the convex hull of the shape at its initial and final positions, i.e., the Minkowski
+/// sum of the shape and of the segment joining the origin to the translation.
+///
+/// The collision pipeline generates at most one contact between a swept shape and another
+/// shape, so it does not rest stably on one of its flat sides. Use the `ConvexHull` of its
+/// triangle mesh (or polyline in 2D) if several contacts are needed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct SweptShape<N: RealField, S> {
+    shape: S,
+    translation: Vector<N>,
+}
+
+impl<N: RealField, S> SweptShape<N, S> {
+    /// Creates the shape swept by `shape` translated along `translation`, in its local frame.
+    pub fn new(shape: S, translation: Vector<N>) -> Self {
+        SweptShape { shape, translation }
+    }
+
+    /// The shape being swept.
+    #[inline]
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// The translation of the swept shape, in its local frame.
+    #[inline]
+    pub fn translation(&self) -> &Vector<N> {
+        &self.translation
+    }
+}
+
+impl<N: RealField, S: SupportMap<N>> SupportMap<N> for SweptShape<N, S> {
+    #[inline]
+    fn support_point(&self, m: &Isometry<N>, dir: &Vector<N>) -> Point<N> {
+        let pt = self.shape.support_point(m, dir);
+        let shift = m * self.translation;
+
+        if shift.dot(dir) > N::zero() {
+            pt + shift
+        } else {
+            pt
+        }
+    }
+
+    #[inline]
+    fn support_point_toward(&self, m: &Isometry<N>, dir: &Unit<Vector<N>>) -> Point<N> {
+        let pt = self.shape.support_point_toward(m, dir);
+        let shift = m * self.translation;
+
+        if shift.dot(dir) > N::zero() {
+            pt + shift
+        } else {
+            pt
+        }
+    }
+}
@@ -3,9 +3,10 @@
 use crate::bounding_volume::{self, BoundingVolume, AABB};
 use crate::math::{Isometry, Point, Vector, DIM};
 use na::{self, Id, Point2, Point3, RealField, Unit};
-use crate::partitioning::{BVHImpl, BVT};
+use crate::partitioning::{BVHImpl, BVTNodeId, BVH, BVT, QBVH};
 use crate::procedural;
-use crate::query::{LocalShapeApproximation, NeighborhoodGeometry, ContactPrediction, ContactPreprocessor, Contact, ContactKinematic};
+use crate::query::contacts_internal::OneWayContactPreprocessor;
+use crate::query::{LocalShapeApproximation, NeighborhoodGeometry, ContactPrediction, ContactPreprocessor, Contact, ContactKinematic, PointQuery, PointQueryWithLocation};
 use crate::shape::{
     CompositeShape, DeformableShape, DeformationsType, FeatureId, Segment, Shape, Triangle,
 };
@@ -50,6 +51,7 @@ pub struct TriMeshFace<N: RealField> {
     /// Indices of the edges of this face.
     pub edges: Point3<usize>,
     bvt_leaf: usize,
+    qbvh_leaf: usize,
     /// The normal of this face if it is not degenerate.
     pub normal: Option<Unit<Vector<N>>>,
     /// Outward edge normals on the face's plane.
@@ -76,11 +78,53 @@ pub struct TriMeshVertex {
     pub adj_vertices: Range<usize>,
 }
 
+/// The local shape of a triangle mesh around one of its edges, as seen from the side its first
+/// adjacent face normal points to.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum EdgeConvexity {
+    /// The two adjacent faces form a ridge.
+    Convex,
+    /// The two adjacent faces form a valley.
+    Concave,
+    /// The two adjacent faces are coplanar.
+    Flat,
+    /// The edge has only one adjacent face, or one of its adjacent faces is degenerate.
+    Boundary,
+}
+
+impl EdgeConvexity {
+    /// The convexity of the same edge, as seen from the other side of the mesh.
+    fn flipped(self) -> Self {
+        match self {
+            EdgeConvexity::Convex => EdgeConvexity::Concave,
+            EdgeConvexity::Concave => EdgeConvexity::Convex,
+            other => other,
+        }
+    }
+}
+
+// First-order approximation of the contribution of the faces below an internal node of the BVT
+// to the generalized winding number, accurate for points far enough from `center`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+struct WindingCluster<N: RealField> {
+    // The average of the face centroids, weighted by the face areas.
+    center: Point<N>,
+    // The sum of the face normals, weighted by the face areas.
+    area_normal: Vector<N>,
+    // An upper bound of the distance between `center` and the vertices of the faces.
+    radius: N,
+}
+
 /// A 3d triangle mesh.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone)]
 pub struct TriMesh<N: RealField> {
     bvt: BVT<usize, AABB<N>>,
+    qbvh: QBVH<usize>,
+    // Indexed by the internal nodes of `bvt`.
+    winding_clusters: Vec<WindingCluster<N>>,
     uvs: Option<Vec<Point2<N>>>,
     points: Vec<Point<N>>,
     vertices: Vec<TriMeshVertex>,
@@ -90,6 +134,13 @@ pub struct TriMesh<N: RealField> {
     adj_vertex_list: Vec<usize>,
     deformations: DeformationInfos<N>,
     oriented: bool,
+    one_way: Option<N>,
+    solid: bool,
+    flat_tolerance: N,
+    edge_convexities: Vec<EdgeConvexity>,
+    // For each vertex, whether it touches a convex or boundary edge as seen from the front and
+    // from the back of the mesh. Contacts on other vertices are always internal.
+    active_vertices: Vec<(bool, bool)>,
 }
 
 impl<N: RealField> TriMesh<N> {
@@ -133,6 +184,7 @@ impl<N: RealField> TriMesh<N> {
                     indices: *is,
                     edges: Point3::origin(), // Will be set later.
                     bvt_leaf: 0,             // Will be set later.
+                    qbvh_leaf: 0,            // Will be set later.
                     normal,
                     side_normals,
                 })
@@ -140,12 +192,17 @@ impl<N: RealField> TriMesh<N> {
         }
 
         let bvt = BVT::new_balanced(leaves);
+        let qbvh = QBVH::from_bvh(&bvt);
 
-        // Set face.bvt_leaf
+        // Set face.bvt_leaf and face.qbvh_leaf
         for (i, leaf) in bvt.leaves().iter().enumerate() {
             faces[*leaf.data()].bvt_leaf = i;
         }
 
+        for (i, fid) in qbvh.data().iter().enumerate() {
+            faces[*fid].qbvh_leaf = i;
+        }
+
         // Set face.edges
         for (i, e) in edges.iter().enumerate() {
             let fid1 = e.adj_faces.0.face_id;
@@ -166,6 +223,11 @@ impl<N: RealField> TriMesh<N> {
             }
         }
 
+        let flat_tolerance = na::convert(1.0e-3);
+        let edge_convexities = Self::edge_convexities(&points, &faces, &edges, flat_tolerance);
+        let active_vertices = Self::active_vertices(points.len(), &edges, &edge_convexities);
+        let winding_clusters = Self::winding_clusters(&bvt, &points, &faces);
+
         let deformations = DeformationInfos {
             margin: na::convert(0.1), // FIXME: find a better way to define the margin.
             curr_timestamp: 0,
@@ -176,6 +238,8 @@ impl<N: RealField> TriMesh<N> {
 
         TriMesh {
             bvt,
+            qbvh,
+            winding_clusters,
             points,
             uvs,
             deformations,
@@ -185,7 +249,100 @@ impl<N: RealField> TriMesh<N> {
             adj_face_list,
             adj_vertex_list,
             oriented: false,
+            one_way: None,
+            solid: false,
+            flat_tolerance,
+            edge_convexities,
+            active_vertices,
+        }
+    }
+
+    fn edge_convexities(
+        points: &[Point<N>],
+        faces: &[TriMeshFace<N>],
+        edges: &[TriMeshEdge],
+        flat_tolerance: N,
+    ) -> Vec<EdgeConvexity>
+    {
+        let cos_flat_tolerance = flat_tolerance.cos();
+
+        edges
+            .iter()
+            .map(|e| Self::edge_convexity(points, faces, e, cos_flat_tolerance))
+            .collect()
+    }
+
+    fn edge_convexity(
+        points: &[Point<N>],
+        faces: &[TriMeshFace<N>],
+        e: &TriMeshEdge,
+        cos_flat_tolerance: N,
+    ) -> EdgeConvexity
+    {
+        let adj1 = &e.adj_faces.0;
+        let adj2 = &e.adj_faces.1;
+
+        if adj1.face_id == adj2.face_id {
+            return EdgeConvexity::Boundary;
+        }
+
+        match (faces[adj1.face_id].normal, faces[adj2.face_id].normal) {
+            (Some(n1), Some(n2)) => {
+                // The vertex of the second face that is not on the edge.
+                let opposite = faces[adj2.face_id].indices[(adj2.edge_id + 2) % 3];
+                let height = (points[opposite] - points[e.indices.x]).dot(&n1);
+
+                // Compares the angle between the normals of the two faces.
+                if n1.dot(&n2) >= cos_flat_tolerance {
+                    EdgeConvexity::Flat
+                } else if height < N::zero() {
+                    EdgeConvexity::Convex
+                } else {
+                    EdgeConvexity::Concave
+                }
+            }
+            _ => EdgeConvexity::Boundary,
+        }
+    }
+
+    fn active_vertices(
+        nvertices: usize,
+        edges: &[TriMeshEdge],
+        convexities: &[EdgeConvexity],
+    ) -> Vec<(bool, bool)>
+    {
+        let mut active: Vec<(bool, bool)> = iter::repeat((false, false)).take(nvertices).collect();
+
+        for (e, convexity) in edges.iter().zip(convexities.iter()) {
+            let front = *convexity == EdgeConvexity::Convex || *convexity == EdgeConvexity::Boundary;
+            let back = *convexity == EdgeConvexity::Concave || *convexity == EdgeConvexity::Boundary;
+
+            for i in [e.indices.x, e.indices.y].iter() {
+                active[*i].0 |= front;
+                active[*i].1 |= back;
+            }
+        }
+
+        active
+    }
+
+    // Recomputes the activity of the given vertex from the convexity of its adjacent edges.
+    fn update_active_vertex(&mut self, i: usize) {
+        let mut active = (false, false);
+
+        for fid in &self.adj_face_list[self.vertices[i].adj_faces.clone()] {
+            let face = &self.faces[*fid];
+
+            for k in 0..3 {
+                if face.indices[k] == i || face.indices[(k + 1) % 3] == i {
+                    let convexity = self.edge_convexities[face.edges[k]];
+                    active.0 |= convexity == EdgeConvexity::Convex || convexity == EdgeConvexity::Boundary;
+                    active.1 |= convexity == EdgeConvexity::Concave || convexity == EdgeConvexity::Boundary;
+                }
+            }
         }
+
+        self.active_vertices[i] = active;
     }
 
     fn edges_list(indices: &[Point3<usize>]) -> Vec<TriMeshEdge> {
@@ -357,6 +514,8 @@ impl<N: RealField> TriMesh<N> {
         for pt in &mut self.points {
             *pt = transform * *pt
         }
+
+        self.winding_clusters = Self::winding_clusters(&self.bvt, &self.points, &self.faces);
     }
 
     /// Applies a non-uniform scale to this triangle mesh.
@@ -364,6 +523,8 @@ impl<N: RealField> TriMesh<N> {
         for pt in &mut self.points {
             pt.coords.component_mul_assign(scale)
         }
+
+        self.winding_clusters = Self::winding_clusters(&self.bvt, &self.points, &self.faces);
     }
 
     /// Whether this trimesh is considered is oriented or not.
@@ -382,6 +543,265 @@ impl<N: RealField> TriMesh<N> {
         self.oriented = oriented
     }
 
+    /// The angular tolerance of the one-way collisions with this trimesh, if enabled.
+    ///
+    /// By default, one-way collisions are disabled.
+    #[inline]
+    pub fn one_way(&self) -> Option<N> {
+        self.one_way
+    }
+
+    /// Enables one-way collisions with this trimesh, or disables them if `tolerance` is `None`.
+    ///
+    /// When enabled, each face only interacts with objects located in front of it, i.e., on the
+    /// side its normal points to. Contacts with a face are discarded if their normal deviates
+    /// from the face normal by more than the angle `tolerance` (in radians). Objects located
+    /// behind a face are ignored by proximity queries and rays only hit the faces from the front.
+    #[inline]
+    pub fn set_one_way(&mut self, tolerance: Option<N>) {
+        self.one_way = tolerance
+    }
+
+    /// The angle (in radians) between the normals of two adjacent faces below which they are
+    /// considered coplanar.
+    ///
+    /// Contacts with the edges between coplanar faces are replaced by contacts with the faces.
+    /// Defaults to `1.0e-3`.
+    #[inline]
+    pub fn flat_tolerance(&self) -> N {
+        self.flat_tolerance
+    }
+
+    /// Sets the angle (in radians) between the normals of two adjacent faces below which they are
+    /// considered coplanar.
+    pub fn set_flat_tolerance(&mut self, tolerance: N) {
+        self.flat_tolerance = tolerance;
+        self.edge_convexities = Self::edge_convexities(&self.points, &self.faces, &self.edges, tolerance);
+        self.active_vertices = Self::active_vertices(self.points.len(), &self.edges, &self.edge_convexities);
+    }
+
+    /// Whether this trimesh is treated as a solid volume by point queries.
+    ///
+    /// By default a trimesh is a surface, i.e., only the points on its triangles are inside of it.
+    #[inline]
+    pub fn is_solid(&self) -> bool {
+        self.solid
+    }
+
+    /// Sets whether this trimesh is treated as a solid volume by point queries.
+    ///
+    /// If `solid` is `true`, `project_point` and `contains_point` consider the points enclosed
+    /// by the mesh as inside of it. This requires the mesh to be closed, with its faces in
+    /// counterclockwise order when seen from the outside. Small holes or cracks are tolerated
+    /// since the inside/outside classification relies on the generalized winding number. Its
+    /// evaluation traverses the BVT and approximates the faces far from the point by clusters, so
+    /// its cost grows logarithmically with the number of faces, instead of linearly.
+    #[inline]
+    pub fn set_solid(&mut self, solid: bool) {
+        self.solid = solid
+    }
+
+    /// Returns `true` if each edge of this trimesh is shared by exactly two faces.
+    pub fn is_closed(&self) -> bool {
+        let mut num_adj_faces = HashMap::with_hasher(DeterministicState::new());
+
+        for face in &self.faces {
+            let idx = face.indices;
+
+            for (a, b) in [(idx.x, idx.y), (idx.y, idx.z), (idx.z, idx.x)].iter() {
+                let key = if a < b { (*a, *b) } else { (*b, *a) };
+                *num_adj_faces.entry(key).or_insert(0) += 1;
+            }
+        }
+
+        num_adj_faces.values().all(|n| *n == 2)
+    }
+
+    /// The generalized winding number of this trimesh around the local-space point `pt`.
+    ///
+    /// This is the sum of the signed solid angles of all the faces seen from `pt`, divided by
+    /// `4 * pi`. For a closed mesh with faces in counterclockwise order when seen from the
+    /// outside, this is 1 for points inside of the mesh, and 0 for points outside. Points are
+    /// usually classified as inside if their winding number is greater than `0.5`.
+    ///
+    /// This is exact and visits every face. Use `self.encloses_local_point(pt)` to classify a
+    /// point with a cost logarithmic wrt. the number of faces.
+    pub fn winding_number(&self, pt: &Point<N>) -> N {
+        let mut total = N::zero();
+
+        for i in 0..self.faces.len() {
+            total += self.face_winding_number(i, pt);
+        }
+
+        total
+    }
+
+    // The contribution of the `i`-th face to the winding number around `pt`.
+    fn face_winding_number(&self, i: usize, pt: &Point<N>) -> N {
+        let idx = self.faces[i].indices;
+        let a = self.points[idx.x] - pt;
+        let b = self.points[idx.y] - pt;
+        let c = self.points[idx.z] - pt;
+        let (na, nb, nc) = (a.norm(), b.norm(), c.norm());
+
+        // Van Oosterom and Strackee formula for the solid angle of a triangle.
+        let num = a.dot(&b.cross(&c));
+        let den = na * nb * nc + a.dot(&b) * nc + a.dot(&c) * nb + b.dot(&c) * na;
+
+        // The atan2 is half the solid angle.
+        num.atan2(den) / N::two_pi()
+    }
+
+    // Approximates the winding number around `pt`, replacing the clusters of faces located far
+    // from `pt` by the first-order term of their multipole expansion.
+    fn approximate_winding_number(&self, pt: &Point<N>) -> N {
+        // A cluster is approximated if `pt` is farther than `beta` times its radius.
+        let beta: N = na::convert(2.0);
+        let four_pi = N::two_pi() * na::convert(2.0);
+        let mut total = N::zero();
+        let mut stack: Vec<_> = self.bvt.root().into_iter().collect();
+
+        while let Some(node) = stack.pop() {
+            match node {
+                BVTNodeId::Leaf(i) => total += self.face_winding_number(*self.bvt.leaf(i).data(), pt),
+                BVTNodeId::Internal(i) => {
+                    let cluster = &self.winding_clusters[i];
+                    let dpt = cluster.center - pt;
+                    let dist = dpt.norm();
+
+                    if dist > beta * cluster.radius {
+                        total += dpt.dot(&cluster.area_normal) / (four_pi * dist * dist * dist);
+                    } else {
+                        stack.push(self.bvt.child(0, node));
+                        stack.push(self.bvt.child(1, node));
+                    }
+                }
+            }
+        }
+
+        total
+    }
+
+    fn winding_clusters(bvt: &BVT<usize, AABB<N>>, points: &[Point<N>], faces: &[TriMeshFace<N>]) -> Vec<WindingCluster<N>> {
+        let empty = WindingCluster {
+            center: Point::origin(),
+            area_normal: Vector::zeros(),
+            radius: N::zero(),
+        };
+        // A binary tree with n leaves has n - 1 internal nodes.
+        let mut clusters = vec![empty; faces.len().saturating_sub(1)];
+
+        if let Some(root) = bvt.root() {
+            let _ = Self::build_winding_cluster(bvt, root, points, faces, &mut clusters);
+        }
+
+        clusters
+    }
+
+    // Returns the cluster of the faces below `node`, and their total area.
+    fn build_winding_cluster(
+        bvt: &BVT<usize, AABB<N>>,
+        node: BVTNodeId,
+        points: &[Point<N>],
+        faces: &[TriMeshFace<N>],
+        clusters: &mut [WindingCluster<N>],
+    ) -> (WindingCluster<N>, N)
+    {
+        match node {
+            BVTNodeId::Leaf(i) => {
+                let idx = faces[*bvt.leaf(i).data()].indices;
+                let (a, b, c) = (points[idx.x], points[idx.y], points[idx.z]);
+                let area_normal = (b - a).cross(&(c - a)) * na::convert::<_, N>(0.5);
+                let center = Point::from((a.coords + b.coords + c.coords) / na::convert::<_, N>(3.0));
+                let radius = na::distance(&center, &a)
+                    .max(na::distance(&center, &b))
+                    .max(na::distance(&center, &c));
+                let area = area_normal.norm();
+
+                (WindingCluster { center, area_normal, radius }, area)
+            }
+            BVTNodeId::Internal(i) => {
+                let (left, left_area) = Self::build_winding_cluster(bvt, bvt.child(0, node), points, faces, clusters);
+                let (right, right_area) = Self::build_winding_cluster(bvt, bvt.child(1, node), points, faces, clusters);
+                let area = left_area + right_area;
+                let center = if area > N::zero() {
+                    Point::from((left.center.coords * left_area + right.center.coords * right_area) / area)
+                } else {
+                    na::center(&left.center, &right.center)
+                };
+                let radius = (na::distance(&center, &left.center) + left.radius)
+                    .max(na::distance(&center, &right.center) + right.radius);
+                let cluster = WindingCluster {
+                    center,
+                    area_normal: left.area_normal + right.area_normal,
+                    radius,
+                };
+
+                clusters[i] = cluster.clone();
+                (cluster, area)
+            }
+        }
+    }
+
+    /// Tests if the local-space point `pt` is enclosed by this trimesh.
+    ///
+    /// This assumes the mesh is closed, with its faces in counterclockwise order when seen from
+    /// the outside, independently from `self.is_solid()`. The winding number around `pt` is
+    /// approximated by grouping the faces far from `pt`, so the cost of this test is logarithmic
+    /// wrt. the number of faces.
+    #[inline]
+    pub fn encloses_local_point(&self, pt: &Point<N>) -> bool {
+        // Outside of the AABB, all the faces lie on one side of a plane passing through `pt`
+        // so the absolute value of the winding number cannot exceed 0.5.
+        self.aabb().contains_point(&Isometry::identity(), pt)
+            && self.approximate_winding_number(pt) > na::convert(0.5)
+    }
+
+    /// The signed distance between `pt` and this trimesh transformed by `m`.
+    ///
+    /// This is the distance between `pt` and the closest triangle of the mesh, negated if the
+    /// point is enclosed by the mesh. This assumes the mesh is closed, with its faces in
+    /// counterclockwise order when seen from the outside, independently from `self.is_solid()`.
+    pub fn signed_distance_to_point(&self, m: &Isometry<N>, pt: &Point<N>) -> N {
+        let ls_pt = m.inverse_transform_point(pt);
+        let (proj, _) = self.project_point_with_location(&Isometry::identity(), &ls_pt, false);
+        let dist = na::distance(&ls_pt, &proj.point);
+        // The projection already computed the winding number if the mesh is solid.
+        let inside = if self.is_solid() {
+            proj.is_inside
+        } else {
+            self.encloses_local_point(&ls_pt)
+        };
+
+        if inside {
+            -dist
+        } else {
+            dist
+        }
+    }
+
+    /// Tests if a ray with the local-space direction `dir` may hit the `i`-th face.
+    ///
+    /// This is always the case, unless one-way collisions are enabled and `dir` points to the
+    /// same side as the face normal.
+    #[inline]
+    pub(crate) fn can_ray_hit_part(&self, i: usize, dir: &Vector<N>) -> bool {
+        match self.one_way_normal(i) {
+            Some(n) => n.dot(dir) < N::zero(),
+            None => true,
+        }
+    }
+
+    /// The normal of the `i`-th face if one-way collisions are enabled.
+    #[inline]
+    pub(crate) fn one_way_normal(&self, i: usize) -> Option<Unit<Vector<N>>> {
+        if self.one_way.is_some() {
+            self.faces[i].normal
+        } else {
+            None
+        }
+    }
+
     /// Face containing feature.
     #[inline]
     pub fn face_containing_feature(&self, id: FeatureId) -> usize {
@@ -431,6 +851,15 @@ impl<N: RealField> TriMesh<N> {
         &self.bvt
     }
 
+    /// The flattened four-wide optimization structure used by this triangle mesh.
+    ///
+    /// It is built from `self.bvt()` and kept alongside it: ray casts and the collection of the
+    /// parts intersecting an AABB go through the QBVH, while the other queries traverse the BVT.
+    #[inline]
+    pub fn qbvh(&self) -> &QBVH<usize> {
+        &self.qbvh
+    }
+
     /// Tests that the given `dir` is on the tangent cone of the `i`th vertex
     /// of this mesh.
     pub fn vertex_tangent_cone_contains_dir(
@@ -726,6 +1155,22 @@ impl<N: RealField> CompositeShape<N> for TriMesh<N> {
     fn bvh(&self) -> BVHImpl<N, usize, AABB<N>> {
         BVHImpl::BVT(&self.bvt)
     }
+
+    #[inline]
+    fn qbvh(&self) -> Option<&QBVH<usize>> {
+        Some(&self.qbvh)
+    }
+
+    #[inline]
+    fn is_aabb_behind_part(&self, i: usize, aabb: &AABB<N>) -> bool {
+        match self.one_way_normal(i) {
+            Some(n) => {
+                let pt = &self.points[self.faces[i].indices.x];
+                (aabb.center() - pt).dot(&n) < N::zero()
+            }
+            None => false,
+        }
+    }
 }
 
 impl<N: RealField> DeformableShape<N> for TriMesh<N> {
@@ -740,6 +1185,18 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
         let is_first_init = self.init_deformation_infos();
         self.deformations.curr_timestamp += 1;
 
+        // Find the faces adjacent to the vertices that moved since the last call.
+        let mut moved_faces = Vec::new();
+
+        for (i, vtx) in self.vertices.iter().enumerate() {
+            if is_first_init || coords[i * DIM..(i + 1) * DIM] != self.points[i].coords.as_slice()[..] {
+                moved_faces.extend_from_slice(&self.adj_face_list[vtx.adj_faces.clone()]);
+            }
+        }
+
+        moved_faces.sort();
+        moved_faces.dedup();
+
         // There is a bit of unsafe code in order to perform a memcopy for
         // efficiency reasons when the mapping between degrees of freedom
         // is trivial.
@@ -768,7 +1225,8 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
         }
 
         // Update normals.
-        for f in &mut self.faces {
+        for fid in &moved_faces {
+            let f = &mut self.faces[*fid];
             let ab = self.points[f.indices.y] - self.points[f.indices.x];
             let ac = self.points[f.indices.z] - self.points[f.indices.x];
 
@@ -786,6 +1244,24 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
             }
         }
 
+        // The convexity of an edge only depends on its two adjacent faces.
+        let cos_flat_tolerance = self.flat_tolerance.cos();
+
+        for fid in &moved_faces {
+            for k in 0..3 {
+                let eid = self.faces[*fid].edges[k];
+                self.edge_convexities[eid] =
+                    Self::edge_convexity(&self.points, &self.faces, &self.edges[eid], cos_flat_tolerance);
+            }
+        }
+
+        for fid in &moved_faces {
+            for k in 0..3 {
+                let vid = self.faces[*fid].indices[k];
+                self.update_active_vertex(vid);
+            }
+        }
+
         // Apply the bounding volumes changes.
         for tri_id in self.deformations.tri_to_update.drain(..) {
             if self.deformations.timestamps[tri_id] != self.deformations.curr_timestamp {
@@ -796,6 +1272,7 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
                     &[self.points[idx.x], self.points[idx.y], self.points[idx.z]],
                 );
                 new_bv.loosen(self.deformations.margin);
+                self.qbvh.set_leaf_aabb(self.faces[tri_id].qbvh_leaf, &new_bv);
                 self.bvt
                     .set_leaf_bounding_volume(self.faces[tri_id].bvt_leaf, new_bv, false);
                 self.deformations.timestamps[tri_id] = self.deformations.curr_timestamp;
@@ -803,7 +1280,9 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
         }
 
         // FIXME: measure efficiency with a non-zero margin.
-        self.bvt.refit(N::zero())
+        self.bvt.refit(N::zero());
+        self.qbvh.refit_modified_leaves();
+        self.winding_clusters = Self::winding_clusters(&self.bvt, &self.points, &self.faces);
     }
 
     fn update_local_approximation(
@@ -894,6 +1373,91 @@ impl<'a, N: RealField> TriMeshContactProcessor<'a, N> {
             mesh, pos, face_id, prediction
         }
     }
+
+    // The feature ID and outward normal of the face `i` on the side of the mesh `dir` points to.
+    fn face_on_side(&self, i: usize, dir: &Unit<Vector<N>>) -> Option<(usize, Unit<Vector<N>>)> {
+        let normal = self.mesh.faces[i].normal?;
+
+        if normal.dot(dir) >= N::zero() {
+            Some((i, normal))
+        } else {
+            Some((i + self.mesh.faces.len(), -normal))
+        }
+    }
+
+    /// Computes the face normal that should replace the local-space outward normal `dir` of
+    /// the mesh at `feature`, if `dir` is not a valid normal of the mesh at this feature.
+    ///
+    /// Returns the face feature ID and normal to use instead, or `None` if `dir` is valid.
+    /// This removes the contacts with edges and vertices that lie inside of a flat or concave
+    /// region of the mesh, which would otherwise make objects bump against triangle boundaries.
+    fn internal_feature_correction(
+        &self,
+        feature: FeatureId,
+        dir: &Unit<Vector<N>>,
+        sin_ang_tol: N,
+    ) -> Option<(usize, Unit<Vector<N>>)>
+    {
+        let mesh = self.mesh;
+
+        match feature {
+            FeatureId::Edge(i) => {
+                let e = &mesh.edges[i];
+                let convexity = mesh.edge_convexities[i];
+
+                if convexity == EdgeConvexity::Boundary {
+                    return None;
+                }
+
+                let (adj1, adj2) = (&e.adj_faces.0, &e.adj_faces.1);
+                let (f1, f2) = (&mesh.faces[adj1.face_id], &mesh.faces[adj2.face_id]);
+                let n1 = f1.normal?;
+                let mut n2 = f2.normal?;
+
+                // Make both normals point to the same side of the mesh, even if
+                // the two faces do not have the same winding.
+                if f1.indices[adj1.edge_id] != f2.indices[(adj2.edge_id + 1) % 3] {
+                    n2 = -n2;
+                }
+
+                let (n1, n2, convexity) = if dir.dot(&(n1.into_inner() + n2.into_inner())) >= N::zero() {
+                    (n1, n2, convexity)
+                } else {
+                    (-n1, -n2, convexity.flipped())
+                };
+
+                if convexity == EdgeConvexity::Convex {
+                    let side1 = f1.side_normals.as_ref()?[adj1.edge_id];
+                    let side2 = f2.side_normals.as_ref()?[adj2.edge_id];
+
+                    if side1.dot(dir) >= -sin_ang_tol && side2.dot(dir) >= -sin_ang_tol {
+                        None
+                    } else if n1.dot(dir) >= n2.dot(dir) {
+                        Some((self.face_on_side(adj1.face_id, &n1)?.0, n1))
+                    } else {
+                        Some((self.face_on_side(adj2.face_id, &n2)?.0, n2))
+                    }
+                } else {
+                    self.face_on_side(self.face_id, dir)
+                }
+            }
+            FeatureId::Vertex(i) => {
+                let (face, normal) = self.face_on_side(self.face_id, dir)?;
+                let active = if face < mesh.faces.len() {
+                    mesh.active_vertices[i].0
+                } else {
+                    mesh.active_vertices[i].1
+                };
+
+                if !active || !mesh.vertex_tangent_cone_polar_contains_dir(i, dir, sin_ang_tol) {
+                    Some((face, normal))
+                } else {
+                    None
+                }
+            }
+            FeatureId::Face(_) | FeatureId::Unknown => None,
+        }
+    }
 }
 
 impl<'a, N: RealField> ContactPreprocessor<N> for TriMeshContactProcessor<'a, N> {
@@ -911,7 +1475,7 @@ impl<'a, N: RealField> ContactPreprocessor<N> for TriMeshContactProcessor<'a, N>
         };
 
         let face = &self.mesh.faces()[self.face_id];
-        let actual_feature = match feature {
+        let mut actual_feature = match feature {
             FeatureId::Vertex(i) => FeatureId::Vertex(face.indices[i]),
             FeatureId::Edge(i) => FeatureId::Edge(face.edges[i]),
             FeatureId::Face(i) => {
@@ -930,6 +1494,42 @@ impl<'a, N: RealField> ContactPreprocessor<N> for TriMeshContactProcessor<'a, N>
             kinematic.set_feature2(actual_feature);
         }
 
+        if let (Some(tolerance), Some(normal)) = (self.mesh.one_way(), self.mesh.one_way_normal(self.face_id)) {
+            if !OneWayContactPreprocessor::new(self.pos, normal, tolerance).process_contact(c, kinematic, is_first) {
+                return false;
+            }
+        }
+
+        // Replace the normals of contacts with internal edges and vertices by face normals.
+        let (sin_ang_tol, cos_ang_tol) = if is_first {
+            (self.prediction.sin_angular1(), self.prediction.cos_angular1())
+        } else {
+            (self.prediction.sin_angular2(), self.prediction.cos_angular2())
+        };
+        let outward_normal = if is_first { c.normal } else { -c.normal };
+        let local_dir = self.pos.inverse_transform_unit_vector(&outward_normal);
+
+        if let Some((face, normal)) = self.internal_feature_correction(actual_feature, &local_dir, sin_ang_tol) {
+            let world_normal = self.pos * normal;
+            c.normal = if is_first { world_normal } else { -world_normal };
+            // The contact points stay on their respective shapes so this depth underestimates the
+            // actual penetration along the new normal. The contacts with the face itself are exact.
+            c.depth = (c.world1 - c.world2).dot(&c.normal);
+
+            if c.depth < -self.prediction.linear() {
+                return false;
+            }
+
+            actual_feature = FeatureId::Face(face);
+            let approx = if is_first {
+                kinematic.approx1_mut()
+            } else {
+                kinematic.approx2_mut()
+            };
+            approx.feature = actual_feature;
+            approx.geometry = NeighborhoodGeometry::Plane(normal);
+        }
+
         // Test the validity of the LMD.
         if c.depth > N::zero() {
             true
@@ -937,10 +1537,10 @@ impl<'a, N: RealField> ContactPreprocessor<N> for TriMeshContactProcessor<'a, N>
             let local_dir = self.pos.inverse_transform_unit_vector(&c.normal);
 
             if is_first {
-                self.mesh.tangent_cone_polar_contains_dir(actual_feature, &local_dir, self.prediction.sin_angular1(), self.prediction.cos_angular1())
+                self.mesh.tangent_cone_polar_contains_dir(actual_feature, &local_dir, sin_ang_tol, cos_ang_tol)
             } else {
-                self.mesh.tangent_cone_polar_contains_dir(actual_feature, &-local_dir, self.prediction.sin_angular2(), self.prediction.cos_angular2())
+                self.mesh.tangent_cone_polar_contains_dir(actual_feature, &-local_dir, sin_ang_tol, cos_ang_tol)
             }
         }
     }
-}
\ No newline at end of file
+}
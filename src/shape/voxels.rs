@@ -0,0 +1,424 @@
+use std::mem::size_of;
+
+use na::{self, RealField, Unit};
+
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Translation, Vector, DIM};
+use crate::partitioning::{BVHImpl, BVT};
+use crate::query::{Contact, ContactKinematic, ContactPrediction, ContactPreprocessor, NeighborhoodGeometry};
+use crate::shape::{CompositeShape, Cuboid, FeatureId, Shape};
+
+/// A grid of voxels, each occupied voxel being a cuboid.
+///
+/// The voxel with grid coordinates `cell` occupies the local-space region between
+/// `cell * voxel_size` and `(cell + 1) * voxel_size` (component-wise). Contacts with the
+/// faces and edges shared by two adjacent occupied voxels are suppressed, so that objects
+/// sliding on the voxels do not get stuck on internal edges.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub struct Voxels<N: RealField> {
+    voxel_size: Vector<N>,
+    dims: Vector<usize>,
+    // The part index of each cell of the grid, or `usize::max_value()` if the cell is empty.
+    grid: Vec<usize>,
+    cells: Vec<Point<usize>>,
+    cuboid: Cuboid<N>,
+    bvt: BVT<usize, AABB<N>>,
+    aabb: AABB<N>,
+    nbits: usize,
+}
+
+impl<N: RealField> Voxels<N> {
+    /// Creates a new grid of voxels from a dense occupancy grid.
+    ///
+    /// The grid has `dims` voxels along each axis. The voxel with grid coordinates `cell` is
+    /// occupied if `occupied[cell.x + cell.y * dims.x + cell.z * dims.x * dims.y]` is `true`.
+    pub fn new(voxel_size: Vector<N>, dims: Vector<usize>, occupied: &[bool]) -> Self {
+        assert_eq!(
+            occupied.len(),
+            dims.iter().product::<usize>(),
+            "The occupancy grid size does not match the grid dimensions."
+        );
+
+        let mut cells = Vec::new();
+
+        for (id, occupied) in occupied.iter().enumerate() {
+            if *occupied {
+                let mut cell = Point::origin();
+                let mut rem = id;
+
+                for d in 0..DIM {
+                    cell[d] = rem % dims[d];
+                    rem /= dims[d];
+                }
+
+                cells.push(cell)
+            }
+        }
+
+        Self::from_cells(voxel_size, dims, cells)
+    }
+
+    /// Creates a new grid of voxels with `dims` voxels along each axis, where only the voxels
+    /// with the grid coordinates listed in `cells` are occupied.
+    pub fn from_cells(voxel_size: Vector<N>, dims: Vector<usize>, mut cells: Vec<Point<usize>>) -> Self {
+        assert!(
+            voxel_size.iter().all(|s| *s > N::zero()),
+            "The voxel size must be positive."
+        );
+        assert!(
+            cells.iter().all(|c| (0..DIM).all(|d| c[d] < dims[d])),
+            "Voxel grid coordinates out of bounds."
+        );
+
+        let mut grid = vec![usize::max_value(); dims.iter().product()];
+        let half_size = voxel_size * na::convert::<f64, N>(0.5);
+        let mut leaves = Vec::with_capacity(cells.len());
+
+        cells.retain(|cell| {
+            let id = Self::linear_index(&dims, cell);
+
+            if grid[id] == usize::max_value() {
+                grid[id] = leaves.len();
+                leaves.push((leaves.len(), Self::cell_aabb(&voxel_size, cell)));
+                true
+            } else {
+                false
+            }
+        });
+
+        let aabb = leaves
+            .iter()
+            .fold(None, |acc: Option<AABB<N>>, leaf| match acc {
+                Some(aabb) => Some(AABB::new(
+                    na::inf(aabb.mins(), leaf.1.mins()),
+                    na::sup(aabb.maxs(), leaf.1.maxs()),
+                )),
+                None => Some(leaf.1.clone()),
+            })
+            .unwrap_or_else(|| AABB::new(Point::origin(), Point::origin()));
+        let nbits = size_of::<usize>() * 8 - leaves.len().leading_zeros() as usize;
+        let bvt = BVT::new_balanced(leaves);
+
+        Voxels {
+            voxel_size,
+            dims,
+            grid,
+            cells,
+            cuboid: Cuboid::new(half_size),
+            bvt,
+            aabb,
+            nbits,
+        }
+    }
+
+    fn linear_index(dims: &Vector<usize>, cell: &Point<usize>) -> usize {
+        let mut id = 0;
+
+        for d in (0..DIM).rev() {
+            id = id * dims[d] + cell[d];
+        }
+
+        id
+    }
+
+    fn cell_aabb(voxel_size: &Vector<N>, cell: &Point<usize>) -> AABB<N> {
+        let mins = Point::from(cell.coords.map(|e| na::convert::<f64, N>(e as f64)).component_mul(voxel_size));
+        AABB::new(mins, mins + voxel_size)
+    }
+
+    /// The size of each voxel.
+    #[inline]
+    pub fn voxel_size(&self) -> &Vector<N> {
+        &self.voxel_size
+    }
+
+    /// The number of voxels of the grid along each axis.
+    #[inline]
+    pub fn dims(&self) -> &Vector<usize> {
+        &self.dims
+    }
+
+    /// The grid coordinates of the occupied voxels.
+    ///
+    /// The `i`-th occupied voxel is the `i`-th part of this composite shape.
+    #[inline]
+    pub fn cells(&self) -> &[Point<usize>] {
+        &self.cells[..]
+    }
+
+    /// The AABB of the occupied voxels.
+    #[inline]
+    pub fn aabb(&self) -> &AABB<N> {
+        &self.aabb
+    }
+
+    /// The AABB of the whole grid, including the empty voxels.
+    pub fn grid_aabb(&self) -> AABB<N> {
+        let dims = self.dims.map(|e| na::convert::<f64, N>(e as f64));
+        AABB::new(Point::origin(), Point::from(dims.component_mul(&self.voxel_size)))
+    }
+
+    /// The bounding volume tree of the occupied voxels.
+    ///
+    /// This is the only tree of this shape, returned by `CompositeShape::bvh`. The collection of
+    /// the parts intersecting an AABB and ray casts read the occupancy grid instead.
+    #[inline]
+    pub fn bvt(&self) -> &BVT<usize, AABB<N>> {
+        &self.bvt
+    }
+
+    /// The cuboid shape of each voxel, centered at the origin.
+    #[inline]
+    pub fn voxel_shape(&self) -> &Cuboid<N> {
+        &self.cuboid
+    }
+
+    /// The part index of the voxel with the given grid coordinates, if it is occupied.
+    #[inline]
+    pub fn part_at_cell(&self, cell: &Point<usize>) -> Option<usize> {
+        if (0..DIM).all(|d| cell[d] < self.dims[d]) {
+            let part = self.grid[Self::linear_index(&self.dims, cell)];
+
+            if part != usize::max_value() {
+                return Some(part);
+            }
+        }
+
+        None
+    }
+
+    /// Tests if the voxel with the given grid coordinates is occupied.
+    #[inline]
+    pub fn is_voxel_occupied(&self, cell: &Point<usize>) -> bool {
+        self.part_at_cell(cell).is_some()
+    }
+
+    /// Tests if the neighbor of the given voxel along the axis `axis` is occupied.
+    ///
+    /// The neighbor along the positive direction of the axis is tested if `positive` is `true`.
+    pub fn is_neighbor_occupied(&self, cell: &Point<usize>, axis: usize, positive: bool) -> bool {
+        let mut neighbor = *cell;
+
+        if positive {
+            neighbor[axis] += 1;
+        } else if cell[axis] == 0 {
+            return false;
+        } else {
+            neighbor[axis] -= 1;
+        }
+
+        self.is_voxel_occupied(&neighbor)
+    }
+
+    /// Tests if the local-space point `pt` lies inside of an occupied voxel.
+    pub fn contains_local_point(&self, pt: &Point<N>) -> bool {
+        self.cell_at_point(pt)
+            .map(|cell| self.is_voxel_occupied(&cell))
+            .unwrap_or(false)
+    }
+
+    /// The grid coordinates of the voxel containing the local-space point `pt`, if it lies inside of the grid.
+    pub fn cell_at_point(&self, pt: &Point<N>) -> Option<Point<usize>> {
+        let mut cell = Point::origin();
+
+        for d in 0..DIM {
+            let i = (pt[d] / self.voxel_size[d]).floor();
+
+            if i < N::zero() || i >= na::convert(self.dims[d] as f64) {
+                return None;
+            }
+
+            cell[d] = unsafe { na::convert_unchecked::<N, f64>(i) as usize };
+        }
+
+        Some(cell)
+    }
+
+    /// The local-space center of the voxel with the given grid coordinates.
+    pub fn voxel_center(&self, cell: &Point<usize>) -> Point<N> {
+        let half: N = na::convert(0.5);
+        let coords = cell.coords.map(|e| na::convert::<f64, N>(e as f64) + half);
+        Point::from(coords.component_mul(&self.voxel_size))
+    }
+
+    /// The local-space AABB of the `i`-th occupied voxel.
+    #[inline]
+    pub fn voxel_aabb(&self, i: usize) -> AABB<N> {
+        Self::cell_aabb(&self.voxel_size, &self.cells[i])
+    }
+
+    /// Collects the part indices of the occupied voxels intersecting the given local-space AABB.
+    ///
+    /// This reads the occupancy grid directly instead of traversing a bounding volume hierarchy.
+    pub fn parts_intersecting_local_aabb(&self, aabb: &AABB<N>, out: &mut Vec<usize>) {
+        let mut mins = Point::origin();
+        let mut maxs = Point::origin();
+
+        for d in 0..DIM {
+            let max: N = na::convert(self.dims[d] as f64);
+            let lo = (aabb.mins()[d] / self.voxel_size[d]).floor();
+            let hi = (aabb.maxs()[d] / self.voxel_size[d]).floor();
+
+            if hi < N::zero() || lo >= max {
+                return;
+            }
+
+            mins[d] = unsafe { na::convert_unchecked::<N, f64>(lo.max(N::zero())) as usize };
+            maxs[d] = unsafe { na::convert_unchecked::<N, f64>(hi.min(max - N::one())) as usize };
+        }
+
+        let mut cell = mins;
+
+        loop {
+            if let Some(part) = self.part_at_cell(&cell) {
+                out.push(part)
+            }
+
+            // Move to the next cell of the range.
+            let mut d = 0;
+
+            loop {
+                if cell[d] < maxs[d] {
+                    cell[d] += 1;
+                    break;
+                }
+
+                cell[d] = mins[d];
+                d += 1;
+
+                if d == DIM {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl<N: RealField> CompositeShape<N> for Voxels<N> {
+    #[inline]
+    fn nparts(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, m: &Isometry<N>, f: &mut dyn FnMut(&Isometry<N>, &dyn Shape<N>)) {
+        let pos = m * Translation::from(self.voxel_center(&self.cells[i]).coords);
+        f(&pos, &self.cuboid)
+    }
+
+    fn map_part_and_preprocessor_at(
+        &self,
+        i: usize,
+        m: &Isometry<N>,
+        _prediction: &ContactPrediction<N>,
+        f: &mut dyn FnMut(&Isometry<N>, &dyn Shape<N>, &dyn ContactPreprocessor<N>),
+    )
+    {
+        let part_pos = Isometry::from_parts(Translation::from(self.voxel_center(&self.cells[i]).coords), na::one());
+        let pos = m * part_pos;
+        let proc = VoxelContactPreprocessor {
+            voxels: self,
+            pos: *m,
+            part_pos,
+            part_id: i,
+        };
+
+        f(&pos, &self.cuboid, &proc)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<N> {
+        self.voxel_aabb(i)
+    }
+
+    #[inline]
+    fn bvh(&self) -> BVHImpl<N, usize, AABB<N>> {
+        BVHImpl::BVT(&self.bvt)
+    }
+
+    #[inline]
+    fn parts_intersecting_local_aabb(&self, aabb: &AABB<N>, out: &mut Vec<usize>) {
+        Voxels::parts_intersecting_local_aabb(self, aabb, out)
+    }
+}
+
+/// Contact preprocessor removing the contributions of the faces shared by adjacent voxels.
+struct VoxelContactPreprocessor<'a, N: RealField> {
+    voxels: &'a Voxels<N>,
+    pos: Isometry<N>,
+    part_pos: Isometry<N>,
+    part_id: usize,
+}
+
+impl<'a, N: RealField> ContactPreprocessor<N> for VoxelContactPreprocessor<'a, N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool)
+        -> bool {
+        // Fix the feature ID.
+        let feature = if is_first {
+            kinematic.feature1()
+        } else {
+            kinematic.feature2()
+        };
+
+        let actual_feature = match feature {
+            FeatureId::Vertex(i) => FeatureId::Vertex((i << self.voxels.nbits) | self.part_id),
+            #[cfg(feature = "dim3")]
+            FeatureId::Edge(i) => FeatureId::Edge((i << self.voxels.nbits) | self.part_id),
+            FeatureId::Face(i) => FeatureId::Face((i << self.voxels.nbits) | self.part_id),
+            FeatureId::Unknown => return false,
+        };
+
+        // The contact kinematics must be expressed on the local frame of
+        // the voxels instead of the cuboid.
+        if is_first {
+            kinematic.set_feature1(actual_feature);
+            kinematic.transform1(&self.part_pos);
+        } else {
+            kinematic.set_feature2(actual_feature);
+            kinematic.transform2(&self.part_pos);
+        }
+
+        // Remove the normal components pointing toward occupied neighbors. A contact with
+        // a face shared by two voxels is discarded, and a contact with an edge shared by
+        // two voxels is replaced by a contact with their exposed faces.
+        let cell = &self.voxels.cells[self.part_id];
+        let out_normal = if is_first { c.normal } else { -c.normal };
+        let mut local_normal = self.pos.inverse_transform_vector(&out_normal);
+        let mut corrected = false;
+
+        for d in 0..DIM {
+            if !local_normal[d].is_zero()
+                && self.voxels.is_neighbor_occupied(cell, d, local_normal[d] > N::zero())
+            {
+                local_normal[d] = N::zero();
+                corrected = true;
+            }
+        }
+
+        if corrected {
+            let local_normal = match Unit::try_new(local_normal, N::default_epsilon()) {
+                Some(n) => n,
+                None => return false,
+            };
+            let out_normal = self.pos * local_normal;
+
+            c.normal = if is_first { out_normal } else { -out_normal };
+            c.depth = (c.world1 - c.world2).dot(&c.normal);
+
+            if is_first {
+                let pt = kinematic.local1();
+                kinematic.set_approx1(actual_feature, pt, NeighborhoodGeometry::Plane(local_normal));
+            } else {
+                let pt = kinematic.local2();
+                kinematic.set_approx2(actual_feature, pt, NeighborhoodGeometry::Plane(local_normal));
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,137 @@
+use crate::math::Isometry;
+use crate::shape::{Plane, Shape};
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+#[cfg(feature = "dim3")]
+use crate::shape::ConvexHull;
+use crate::utils::ConvexPolytope;
+use na::RealField;
+
+/// Computes the intersection of two convex shapes.
+///
+/// The result is expressed in the local frame of the first shape. Cuboids, convex hulls, and
+/// convex polygons are handled exactly. Other convex shapes, e.g., balls and capsules, are
+/// approximated by the convex hull of some of their support points. Returns `None` if the
+/// shapes do not overlap, or if one of them has no area, e.g., a segment.
+///
+/// # Panics
+///
+/// Panics if one of the shapes does not implement the `SupportMap` trait.
+#[cfg(feature = "dim2")]
+pub fn convex_intersection<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> Option<ConvexPolygon<N>>
+{
+    to_convex_polygon(intersection_polytope(m1, g1, m2, g2))
+}
+
+/// Computes the intersection of two convex shapes.
+///
+/// The result is expressed in the local frame of the first shape. Cuboids and convex hulls
+/// are handled exactly. Other convex shapes, e.g., balls and capsules, are approximated by
+/// the convex hull of some of their support points. Returns `None` if the shapes do not
+/// overlap, or if one of them has no volume, e.g., a triangle.
+///
+/// # Panics
+///
+/// Panics if one of the shapes does not implement the `SupportMap` trait.
+#[cfg(feature = "dim3")]
+pub fn convex_intersection<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> Option<ConvexHull<N>>
+{
+    to_convex_hull(intersection_polytope(m1, g1, m2, g2))
+}
+
+/// Computes the part of a convex shape below a plane, i.e., on the opposite side of its normal.
+///
+/// The result is expressed in the local frame of the shape. Curved shapes are approximated
+/// the same way as by `convex_intersection`. Returns `None` if the shape is entirely above
+/// the plane.
+///
+/// # Panics
+///
+/// Panics if the shape does not implement the `SupportMap` trait.
+#[cfg(feature = "dim2")]
+pub fn clip_convex_by_half_space<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> Option<ConvexPolygon<N>>
+{
+    to_convex_polygon(clipped_polytope(m, shape, mplane, plane))
+}
+
+/// Computes the part of a convex shape below a plane, i.e., on the opposite side of its normal.
+///
+/// The result is expressed in the local frame of the shape. Curved shapes are approximated
+/// the same way as by `convex_intersection`. Returns `None` if the shape is entirely above
+/// the plane.
+///
+/// # Panics
+///
+/// Panics if the shape does not implement the `SupportMap` trait.
+#[cfg(feature = "dim3")]
+pub fn clip_convex_by_half_space<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> Option<ConvexHull<N>>
+{
+    to_convex_hull(clipped_polytope(m, shape, mplane, plane))
+}
+
+fn polytope<N: RealField>(m: &Isometry<N>, g: &dyn Shape<N>) -> ConvexPolytope<N> {
+    ConvexPolytope::from_shape(m, g).expect("Clipping is only supported by convex shapes.")
+}
+
+fn intersection_polytope<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> ConvexPolytope<N>
+{
+    let poly1 = polytope(&Isometry::identity(), g1);
+    let poly2 = polytope(&(m1.inverse() * m2), g2);
+
+    poly1.intersection(&poly2)
+}
+
+fn clipped_polytope<N: RealField>(
+    m: &Isometry<N>,
+    shape: &dyn Shape<N>,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> ConvexPolytope<N>
+{
+    let mut poly = polytope(&Isometry::identity(), shape);
+    poly.clip_by_plane(&(m.inverse() * mplane), plane);
+    poly
+}
+
+#[cfg(feature = "dim2")]
+fn to_convex_polygon<N: RealField>(poly: ConvexPolytope<N>) -> Option<ConvexPolygon<N>> {
+    if poly.is_empty() {
+        None
+    } else {
+        ConvexPolygon::try_from_points(&poly.vertices)
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn to_convex_hull<N: RealField>(poly: ConvexPolytope<N>) -> Option<ConvexHull<N>> {
+    if poly.is_empty() {
+        None
+    } else {
+        ConvexHull::try_from_points(&poly.vertices)
+    }
+}
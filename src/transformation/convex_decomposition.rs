@@ -0,0 +1,157 @@
+//! Convex decomposition of 2D polygons.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::alga::general::RealField;
+use crate::math::Point;
+use crate::shape::{ConvexPolygon, Polyline};
+use crate::transformation::triangulate::orient;
+use crate::transformation::{self, TriangulationError};
+use crate::utils::DeterministicState;
+use na;
+
+/// Decomposes a polygon with holes into convex polygons.
+///
+/// The polygon is described as for `triangulate_polygon`: `outer` is its outer boundary and
+/// each element of `holes` bounds a hole inside of it. The polygon is first triangulated, then
+/// adjacent pieces are merged across their common edge as long as the result is convex and
+/// has at most `max_vertices` vertices (Hertel-Mehlhorn algorithm). Without a vertex limit,
+/// the number of resulting pieces is at most four times the minimum possible.
+///
+/// The resulting pieces can be used directly to build a `Compound` of `ConvexPolygon`s.
+/// Returns an error if the polygon cannot be triangulated.
+pub fn convex_decomposition<N: RealField>(
+    outer: &[Point<N>],
+    holes: &[Vec<Point<N>>],
+    max_vertices: usize,
+) -> Result<Vec<ConvexPolygon<N>>, TriangulationError<N>>
+{
+    assert!(
+        max_vertices >= 3,
+        "A convex decomposition requires at least 3 vertices per piece."
+    );
+
+    let mesh = transformation::triangulate_polygon(outer, holes)?;
+    Ok(merge_triangles(&mesh.coords, mesh.flat_indices(), max_vertices))
+}
+
+/// Decomposes the region enclosed by a polyline into convex polygons.
+///
+/// The polyline must be made of closed loops, as for `triangulate_polyline`. See
+/// `convex_decomposition` for details on the decomposition itself.
+pub fn convex_decomposition_polyline<N: RealField>(
+    polyline: &Polyline<N>,
+    max_vertices: usize,
+) -> Result<Vec<ConvexPolygon<N>>, TriangulationError<N>>
+{
+    assert!(
+        max_vertices >= 3,
+        "A convex decomposition requires at least 3 vertices per piece."
+    );
+
+    let mesh = transformation::triangulate_polyline(polyline)?;
+    Ok(merge_triangles(&mesh.coords, mesh.flat_indices(), max_vertices))
+}
+
+fn merge_triangles<N: RealField>(
+    pts: &[Point<N>],
+    indices: Vec<u32>,
+    max_vertices: usize,
+) -> Vec<ConvexPolygon<N>>
+{
+    let mut pieces: Vec<Option<Vec<usize>>> = indices
+        .chunks(3)
+        .map(|t| Some(t.iter().map(|i| *i as usize).collect()))
+        .collect();
+    // Maps each directed edge of a counter-clockwise piece to this piece.
+    let mut owners = HashMap::with_hasher(DeterministicState::new());
+    let mut diagonals = Vec::new();
+
+    for (i, piece) in pieces.iter().enumerate() {
+        let piece = piece.as_ref().unwrap();
+
+        for k in 0..3 {
+            let (a, b) = (piece[k], piece[(k + 1) % 3]);
+            let _ = owners.insert((a, b), i);
+
+            if owners.contains_key(&(b, a)) {
+                diagonals.push((a, b))
+            }
+        }
+    }
+
+    // Removing long diagonals first tends to give fewer, better-shaped pieces.
+    diagonals.sort_by(|d1, d2| {
+        let l1 = na::distance_squared(&pts[d1.0], &pts[d1.1]);
+        let l2 = na::distance_squared(&pts[d2.0], &pts[d2.1]);
+        l2.partial_cmp(&l1).unwrap_or(Ordering::Equal)
+    });
+
+    for (a, b) in diagonals {
+        let i1 = owners[&(a, b)];
+        let i2 = owners[&(b, a)];
+        let merged = {
+            let p1 = pieces[i1].as_ref().unwrap();
+            let p2 = pieces[i2].as_ref().unwrap();
+            // `p1` traversed from `b` to `a`, followed by `p2` traversed from `a` to `b`,
+            // without repeating the ends of the diagonal.
+            let s1 = p1.iter().position(|i| *i == b).unwrap();
+            let s2 = p2.iter().position(|i| *i == a).unwrap();
+            let mut merged: Vec<usize> = (0..p1.len()).map(|k| p1[(s1 + k) % p1.len()]).collect();
+            merged.extend((1..p2.len() - 1).map(|k| p2[(s2 + k) % p2.len()]));
+            merged
+        };
+
+        // Only the corners at `a` and `b` may have become reflex. Pieces sharing several
+        // collinear edges would give a loop visiting some vertices twice.
+        let n = merged.len();
+        let ia = merged.iter().position(|i| *i == a).unwrap();
+
+        if (1..n).any(|k| merged[k..].contains(&merged[k - 1]))
+            || !is_convex_corner(pts, &merged, 0)
+            || !is_convex_corner(pts, &merged, ia)
+            || (0..n).filter(|k| !is_flat_corner(pts, &merged, *k)).count() > max_vertices
+        {
+            continue;
+        }
+
+        let p2 = pieces[i2].take().unwrap();
+
+        for k in 0..p2.len() {
+            let _ = owners.insert((p2[k], p2[(k + 1) % p2.len()]), i1);
+        }
+
+        let _ = owners.remove(&(a, b));
+        let _ = owners.remove(&(b, a));
+        pieces[i1] = Some(merged);
+    }
+
+    pieces
+        .into_iter()
+        .filter_map(|piece| {
+            let piece = piece?;
+            ConvexPolygon::try_new(piece.into_iter().map(|i| pts[i]).collect())
+        })
+        .collect()
+}
+
+fn corner<N: RealField>(pts: &[Point<N>], piece: &[usize], k: usize) -> (N, N) {
+    let n = piece.len();
+    let prev = &pts[piece[(k + n - 1) % n]];
+    let curr = &pts[piece[k]];
+    let next = &pts[piece[(k + 1) % n]];
+    let scale = na::distance(prev, curr) * na::distance(curr, next);
+
+    (orient(prev, curr, next), scale * N::default_epsilon().sqrt())
+}
+
+fn is_convex_corner<N: RealField>(pts: &[Point<N>], piece: &[usize], k: usize) -> bool {
+    let (area, eps) = corner(pts, piece, k);
+    area >= -eps
+}
+
+fn is_flat_corner<N: RealField>(pts: &[Point<N>], piece: &[usize], k: usize) -> bool {
+    let (area, eps) = corner(pts, piece, k);
+    area.abs() <= eps
+}
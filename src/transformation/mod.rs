@@ -1,5 +1,8 @@
 //! Transformation, simplification and decomposition of meshes.
 
+pub use self::convex_clip::{clip_convex_by_half_space, convex_intersection};
+#[cfg(feature = "dim2")]
+pub use self::convex_decomposition::{convex_decomposition, convex_decomposition_polyline};
 #[cfg(feature = "dim3")]
 pub(crate) use self::convex_hull2::convex_hull2_idx;
 #[cfg(feature = "dim2")]
@@ -8,12 +11,24 @@ pub use self::convex_hull2::{convex_hull2 as convex_hull, convex_hull2_idx as co
 pub use self::convex_hull3::convex_hull3 as convex_hull;
 #[cfg(feature = "dim3")]
 pub use self::hacd::hacd;
+#[cfg(feature = "dim3")]
+pub use self::simplify::simplify;
+#[cfg(feature = "dim3")]
+pub use self::slice_trimesh::{slice_trimesh, trimesh_cross_section};
 #[cfg(feature = "dim2")]
-pub use self::to_polyline::ToPolyline;
+pub use self::to_polyline::{shape_to_polyline, ToPolyline};
+#[cfg(feature = "dim3")]
+pub use self::to_trimesh::{shape_to_trimesh, ToTriMesh};
 #[cfg(feature = "dim3")]
-pub use self::to_trimesh::ToTriMesh;
-// pub use self::triangulate::triangulate;
+pub(crate) use self::triangulate::triangulate_loops;
+#[cfg(feature = "dim2")]
+pub use self::triangulate::{
+    triangulate, triangulate_polygon, triangulate_polyline, TriangulationError,
+};
 
+mod convex_clip;
+#[cfg(feature = "dim2")]
+mod convex_decomposition;
 mod convex_hull2;
 #[cfg(feature = "dim3")]
 mod convex_hull3;
@@ -21,8 +36,12 @@ mod convex_hull3;
 pub mod convex_hull_utils; // Internal implementation details.
 #[cfg(feature = "dim3")]
 mod hacd;
+#[cfg(feature = "dim3")]
+mod simplify;
+#[cfg(feature = "dim3")]
+mod slice_trimesh;
 #[cfg(feature = "dim2")]
 mod to_polyline;
 #[cfg(feature = "dim3")]
 mod to_trimesh;
-// mod triangulate;
+mod triangulate;
@@ -0,0 +1,440 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::mem;
+
+use crate::math::{Point, Vector};
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::utils::DeterministicState;
+use na::{self, Matrix3, Point3, RealField};
+
+/// Simplifies a triangle mesh by collapsing edges with the quadric error metric.
+///
+/// Edges are collapsed one after the other, starting with the one that moves the surface the
+/// least, until the mesh has at most `max_triangles` triangles or the error of the next
+/// collapse exceeds `max_error`. The error of a collapse is the sum of the squared distances
+/// between the new vertex and the planes of the original triangles merged into it.
+///
+/// Vertices on the boundary of the mesh, on an edge shared by more than two triangles, or
+/// whose incident triangles form several fans, e.g., the common apex of two cones, are never
+/// moved so the boundaries and non-manifold features are preserved exactly. Collapses that
+/// would flip a triangle are rejected. If `preserve_topology` is `true`, collapses that would
+/// change the topology of the mesh, e.g., closing a hole or pinching a handle, are rejected
+/// too.
+///
+/// The resulting mesh has an unified index buffer. Its normals are recomputed if `mesh` has
+/// normals, and its texture coordinates are discarded.
+pub fn simplify<N: RealField>(
+    mesh: &TriMesh<N>,
+    max_triangles: usize,
+    max_error: N,
+    preserve_topology: bool,
+) -> TriMesh<N>
+{
+    let mut simplifier = Simplifier::new(mesh);
+    simplifier.run(max_triangles, max_error, preserve_topology);
+
+    let mut res = simplifier.into_trimesh();
+
+    if mesh.has_normals() {
+        res.recompute_normals()
+    }
+
+    res
+}
+
+// A quadric `x^T a x + 2 b^T x + c` measuring the sum of squared distances to a set of planes.
+#[derive(Copy, Clone)]
+struct Quadric<N: RealField> {
+    a: Matrix3<N>,
+    b: Vector<N>,
+    c: N,
+}
+
+impl<N: RealField> Quadric<N> {
+    fn zero() -> Self {
+        Quadric {
+            a: Matrix3::zeros(),
+            b: Vector::zeros(),
+            c: N::zero(),
+        }
+    }
+
+    // The quadric of the plane with unit normal `n` passing through `pt`.
+    fn plane(n: &Vector<N>, pt: &Point<N>) -> Self {
+        let d = -n.dot(&pt.coords);
+
+        Quadric {
+            a: n * n.transpose(),
+            b: n * d,
+            c: d * d,
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.a += other.a;
+        self.b += other.b;
+        self.c += other.c;
+    }
+
+    fn error(&self, pt: &Point<N>) -> N {
+        let x = pt.coords;
+        (x.dot(&(self.a * x)) + self.b.dot(&x) * na::convert(2.0) + self.c).max(N::zero())
+    }
+
+    // The point minimizing this quadric, if it is unique.
+    fn minimizer(&self) -> Option<Point<N>> {
+        let scale = self.a.norm();
+
+        if scale == N::zero() || self.a.determinant().abs() <= scale * scale * scale * na::convert(1.0e-8) {
+            return None;
+        }
+
+        self.a.try_inverse().map(|inv| Point::from(-(inv * self.b)))
+    }
+}
+
+struct Collapse<N: RealField> {
+    cost: N,
+    // The collapsed edge, `v` being merged into `u`.
+    u: usize,
+    v: usize,
+    target: Point<N>,
+    // The versions of `u` and `v` when this collapse was computed.
+    versions: (usize, usize),
+}
+
+impl<N: RealField> PartialEq for Collapse<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N: RealField> Eq for Collapse<N> {}
+
+impl<N: RealField> PartialOrd for Collapse<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: RealField> Ord for Collapse<N> {
+    // Reversed so that the binary heap yields the cheapest collapse first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Greater
+        } else if self.cost > other.cost {
+            Ordering::Less
+        } else {
+            (other.u, other.v).cmp(&(self.u, self.v))
+        }
+    }
+}
+
+struct Simplifier<N: RealField> {
+    coords: Vec<Point<N>>,
+    triangles: Vec<Option<[usize; 3]>>,
+    // The triangles incident to each vertex, including the removed ones.
+    incident: Vec<Vec<usize>>,
+    quadrics: Vec<Quadric<N>>,
+    locked: Vec<bool>,
+    versions: Vec<usize>,
+    ntriangles: usize,
+}
+
+impl<N: RealField> Simplifier<N> {
+    fn new(mesh: &TriMesh<N>) -> Self {
+        let coords = mesh.coords.clone();
+        let mut triangles = Vec::new();
+        let mut incident = vec![Vec::new(); coords.len()];
+        let mut quadrics = vec![Quadric::zero(); coords.len()];
+        let mut edges = HashMap::with_hasher(DeterministicState::new());
+
+        for t in mesh.flat_indices().chunks(3) {
+            let t = [t[0] as usize, t[1] as usize, t[2] as usize];
+
+            if t[0] == t[1] || t[1] == t[2] || t[2] == t[0] {
+                continue;
+            }
+
+            let (a, b, c) = (&coords[t[0]], &coords[t[1]], &coords[t[2]]);
+
+            if let Some(n) = (b - a).cross(&(c - a)).try_normalize(N::zero()) {
+                let q = Quadric::plane(&n, a);
+
+                for i in t.iter() {
+                    quadrics[*i].add(&q)
+                }
+            }
+
+            for k in 0..3 {
+                incident[t[k]].push(triangles.len());
+                let (a, b) = (t[k], t[(k + 1) % 3]);
+                *edges.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+            }
+
+            triangles.push(Some(t));
+        }
+
+        let mut locked = vec![false; coords.len()];
+
+        for ((a, b), count) in edges {
+            if count != 2 {
+                locked[a] = true;
+                locked[b] = true;
+            }
+        }
+
+        for (v, tris) in incident.iter().enumerate() {
+            if !locked[v] && !is_single_fan(&triangles, tris, v) {
+                locked[v] = true;
+            }
+        }
+
+        Simplifier {
+            ntriangles: triangles.len(),
+            versions: vec![0; coords.len()],
+            coords,
+            triangles,
+            incident,
+            quadrics,
+            locked,
+        }
+    }
+
+    fn neighbors(&self, v: usize) -> HashSet<usize, DeterministicState> {
+        let mut res = HashSet::with_hasher(DeterministicState::new());
+
+        for t in &self.incident[v] {
+            if let Some(t) = &self.triangles[*t] {
+                res.extend(t.iter().cloned().filter(|i| *i != v))
+            }
+        }
+
+        res
+    }
+
+    fn collapse_for(&self, u: usize, v: usize) -> Option<Collapse<N>> {
+        let (u, v) = match (self.locked[u], self.locked[v]) {
+            (true, true) => return None,
+            (false, true) => (v, u),
+            _ => (u, v),
+        };
+        let mut q = self.quadrics[u];
+        q.add(&self.quadrics[v]);
+
+        let target = if self.locked[u] {
+            self.coords[u]
+        } else {
+            let (pu, pv) = (self.coords[u], self.coords[v]);
+            let mid = na::center(&pu, &pv);
+            let mut candidates = vec![pu, pv, mid];
+            candidates.extend(q.minimizer());
+
+            candidates
+                .into_iter()
+                .min_by(|a, b| q.error(a).partial_cmp(&q.error(b)).unwrap_or(Ordering::Equal))
+                .unwrap()
+        };
+
+        Some(Collapse {
+            cost: q.error(&target),
+            u,
+            v,
+            target,
+            versions: (self.versions[u], self.versions[v]),
+        })
+    }
+
+    fn is_valid(&self, c: &Collapse<N>, preserve_topology: bool) -> bool {
+        if preserve_topology {
+            // The link condition: the common neighbors of `u` and `v` must be the opposite
+            // vertices of the triangles sharing the edge.
+            let nu = self.neighbors(c.u);
+            let nv = self.neighbors(c.v);
+            let mut opposite = HashSet::with_hasher(DeterministicState::new());
+
+            for t in &self.incident[c.u] {
+                if let Some(t) = &self.triangles[*t] {
+                    if t.contains(&c.v) {
+                        opposite.extend(t.iter().cloned().filter(|i| *i != c.u && *i != c.v))
+                    }
+                }
+            }
+
+            if nu.intersection(&nv).any(|i| !opposite.contains(i)) {
+                return false;
+            }
+        }
+
+        // Reject the collapse if it flips or degenerates any remaining triangle.
+        for w in [c.u, c.v].iter() {
+            for t in &self.incident[*w] {
+                let t = match &self.triangles[*t] {
+                    Some(t) if !(t.contains(&c.u) && t.contains(&c.v)) => t,
+                    _ => continue,
+                };
+
+                let pts = [self.coords[t[0]], self.coords[t[1]], self.coords[t[2]]];
+                let mut moved = pts;
+
+                for k in 0..3 {
+                    if t[k] == *w {
+                        moved[k] = c.target
+                    }
+                }
+
+                let before = (pts[1] - pts[0]).cross(&(pts[2] - pts[0]));
+                let after = (moved[1] - moved[0]).cross(&(moved[2] - moved[0]));
+
+                if after.dot(&before) <= N::zero()
+                    || after.norm_squared() <= before.norm_squared() * na::convert(1.0e-6)
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn apply(&mut self, c: &Collapse<N>) {
+        let incident_v = mem::replace(&mut self.incident[c.v], Vec::new());
+
+        for t in incident_v {
+            let mut removed = false;
+
+            if let Some(tri) = &mut self.triangles[t] {
+                if tri.contains(&c.u) {
+                    removed = true;
+                } else {
+                    for i in tri.iter_mut() {
+                        if *i == c.v {
+                            *i = c.u
+                        }
+                    }
+
+                    self.incident[c.u].push(t);
+                }
+            }
+
+            if removed {
+                self.triangles[t] = None;
+                self.ntriangles -= 1;
+            }
+        }
+
+        // Without the link condition, the collapse may create duplicate triangles.
+        let mut seen = HashSet::with_hasher(DeterministicState::new());
+
+        for t in self.incident[c.u].clone() {
+            if let Some(tri) = self.triangles[t] {
+                let mut key = tri;
+                key.sort();
+
+                if !seen.insert(key) {
+                    self.triangles[t] = None;
+                    self.ntriangles -= 1;
+                }
+            }
+        }
+
+        let triangles = &self.triangles;
+        self.incident[c.u].retain(|t| triangles[*t].is_some());
+
+        let qv = self.quadrics[c.v];
+        self.quadrics[c.u].add(&qv);
+        self.coords[c.u] = c.target;
+        self.versions[c.u] += 1;
+        self.versions[c.v] += 1;
+    }
+
+    fn run(&mut self, max_triangles: usize, max_error: N, preserve_topology: bool) {
+        let mut heap = BinaryHeap::new();
+
+        for t in self.triangles.iter().flatten() {
+            for k in 0..3 {
+                let (a, b) = (t[k], t[(k + 1) % 3]);
+
+                // Each interior edge is seen twice, only keep it once. Boundary edges are
+                // ignored anyway since both their vertices are locked.
+                if a < b {
+                    heap.extend(self.collapse_for(a, b));
+                }
+            }
+        }
+
+        while self.ntriangles > max_triangles {
+            let c = match heap.pop() {
+                Some(c) => c,
+                None => break,
+            };
+
+            if c.versions != (self.versions[c.u], self.versions[c.v]) {
+                continue;
+            }
+
+            if c.cost > max_error {
+                break;
+            }
+
+            if !self.is_valid(&c, preserve_topology) {
+                continue;
+            }
+
+            self.apply(&c);
+
+            for n in self.neighbors(c.u) {
+                heap.extend(self.collapse_for(c.u, n));
+            }
+        }
+    }
+
+    fn into_trimesh(self) -> TriMesh<N> {
+        let mut remap = vec![u32::max_value(); self.coords.len()];
+        let mut coords = Vec::new();
+        let mut indices = Vec::new();
+
+        for t in self.triangles.iter().flatten() {
+            let mut ids = [0; 3];
+
+            for k in 0..3 {
+                if remap[t[k]] == u32::max_value() {
+                    remap[t[k]] = coords.len() as u32;
+                    coords.push(self.coords[t[k]]);
+                }
+
+                ids[k] = remap[t[k]];
+            }
+
+            indices.push(Point3::new(ids[0], ids[1], ids[2]));
+        }
+
+        TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(indices)))
+    }
+}
+
+// Tests if the triangles incident to `v` are connected through their edges incident to `v`.
+fn is_single_fan(triangles: &[Option<[usize; 3]>], incident: &[usize], v: usize) -> bool {
+    if incident.is_empty() {
+        return true;
+    }
+
+    let mut visited = vec![false; incident.len()];
+    let mut stack = vec![0];
+    visited[0] = true;
+
+    while let Some(i) = stack.pop() {
+        let ti = triangles[incident[i]].unwrap();
+
+        for j in 0..incident.len() {
+            let tj = triangles[incident[j]].unwrap();
+
+            if !visited[j] && ti.iter().any(|a| *a != v && tj.contains(a)) {
+                visited[j] = true;
+                stack.push(j);
+            }
+        }
+    }
+
+    visited.iter().all(|b| *b)
+}
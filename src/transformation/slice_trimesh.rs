@@ -0,0 +1,167 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::math::{Isometry, Point};
+use crate::shape::{Plane, Polyline, TriMesh};
+use crate::utils::{DeterministicState, HashablePartialEq, SortedPair};
+use na::{Point2, RealField};
+
+/// Computes the polylines where a triangle mesh intersects a plane.
+///
+/// The plane position `mplane` and the resulting polylines are expressed in the local frame
+/// of the mesh. Each connected component of the intersection yields one polyline. Components
+/// that form closed loops, e.g., all of them if the mesh is closed, yield closed polylines.
+pub fn slice_trimesh<N: RealField>(
+    mesh: &TriMesh<N>,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> Vec<Polyline<N>>
+{
+    slice_chains(mesh, mplane, plane)
+        .into_iter()
+        .map(|(points, closed)| {
+            let nsegments = if closed { points.len() } else { points.len() - 1 };
+            let indices = (0..nsegments)
+                .map(|i| Point2::new(i, (i + 1) % points.len()))
+                .collect();
+
+            Polyline::new(points, Some(indices))
+        })
+        .collect()
+}
+
+/// Computes the cross-section polygons of a closed triangle mesh by a plane.
+///
+/// The plane position `mplane` and the resulting polygons are expressed in the local frame of
+/// the mesh. Each polygon is a closed loop of points with the last point implicitly connected
+/// to the first one. If the mesh triangles are oriented counter-clockwise when seen from the
+/// outside, the outer boundaries of the cross-section are counter-clockwise when seen from the
+/// side the plane normal points to, and the boundaries of its holes are clockwise. Open
+/// components of the intersection, which exist only if the mesh is not closed, are ignored.
+pub fn trimesh_cross_section<N: RealField>(
+    mesh: &TriMesh<N>,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> Vec<Vec<Point<N>>>
+{
+    slice_chains(mesh, mplane, plane)
+        .into_iter()
+        .filter(|(points, closed)| *closed && points.len() >= 3)
+        .map(|(points, _)| points)
+        .collect()
+}
+
+// The chains of points where the mesh crosses the plane, and whether they are closed.
+fn slice_chains<N: RealField>(
+    mesh: &TriMesh<N>,
+    mplane: &Isometry<N>,
+    plane: &Plane<N>,
+) -> Vec<(Vec<Point<N>>, bool)>
+{
+    let normal = mplane * plane.normal();
+    let center = Point::from(mplane.translation.vector);
+    let pts = mesh.points();
+    // Vertices duplicated at texture or normal seams must be identified for the crossings of
+    // their edges to be linked.
+    let mut vtx_to_id = HashMap::with_hasher(DeterministicState::new());
+    let ids: Vec<usize> = pts
+        .iter()
+        .enumerate()
+        .map(|(i, pt)| *vtx_to_id.entry(unsafe { HashablePartialEq::new(*pt) }).or_insert(i))
+        .collect();
+    // Vertices exactly on the plane are considered above it so that each edge is either
+    // crossed once or not at all.
+    let dists: Vec<N> = pts.iter().map(|pt| normal.dot(&(pt - center))).collect();
+    let above = |i: usize| dists[i] >= N::zero();
+
+    let crossing = |a: usize, b: usize| {
+        let t = dists[a] / (dists[a] - dists[b]);
+        pts[a] + (pts[b] - pts[a]) * t
+    };
+
+    // Map each crossed edge to the crossed edge following it along the intersection.
+    let mut next = HashMap::with_hasher(DeterministicState::new());
+    let mut has_prev = HashSet::with_hasher(DeterministicState::new());
+    let mut points = HashMap::with_hasher(DeterministicState::new());
+
+    for face in mesh.faces() {
+        let face_normal = match face.normal {
+            Some(n) => n,
+            None => continue,
+        };
+        let idx = face.indices;
+        let mut crossed = Vec::new();
+
+        for k in 0..3 {
+            let (a, b) = (ids[idx[k]], ids[idx[(k + 1) % 3]]);
+
+            if above(a) != above(b) {
+                let key = SortedPair::new(a, b);
+                let _ = points.entry(key).or_insert_with(|| crossing(a, b));
+                crossed.push(key);
+            }
+        }
+
+        if crossed.len() != 2 {
+            continue;
+        }
+
+        // Orient the segment so that the inside of the mesh is on its left when seen from the
+        // side the plane normal points to.
+        let dir = points[&crossed[1]] - points[&crossed[0]];
+        let (start, end) = if normal.cross(&face_normal).dot(&dir) >= N::zero() {
+            (crossed[0], crossed[1])
+        } else {
+            (crossed[1], crossed[0])
+        };
+
+        let _ = next.insert(start, end);
+        let _ = has_prev.insert(end);
+    }
+
+    let mut starts: Vec<_> = next.keys().filter(|k| !has_prev.contains(*k)).cloned().collect();
+    starts.sort();
+    let mut loop_starts: Vec<_> = next.keys().cloned().collect();
+    loop_starts.sort();
+
+    let mut visited = HashSet::with_hasher(DeterministicState::new());
+    let mut res = Vec::new();
+
+    // Open chains first, then closed loops.
+    for (start, closed) in starts
+        .into_iter()
+        .map(|s| (s, false))
+        .chain(loop_starts.into_iter().map(|s| (s, true)))
+    {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let mut chain: Vec<Point<N>> = Vec::new();
+        let mut curr = Some(start);
+
+        while let Some(key) = curr {
+            if !visited.insert(key) {
+                break;
+            }
+
+            let pt = points[&key];
+
+            // Vertices on the plane are reached through several edges.
+            if chain.last() != Some(&pt) {
+                chain.push(pt)
+            }
+
+            curr = next.get(&key).cloned();
+        }
+
+        if closed && chain.len() > 1 && chain.first() == chain.last() {
+            let _ = chain.pop();
+        }
+
+        if chain.len() >= 2 {
+            res.push((chain, closed))
+        }
+    }
+
+    res
+}
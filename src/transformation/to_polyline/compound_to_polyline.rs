@@ -0,0 +1,25 @@
+use super::{shape_to_polyline, ToPolyline};
+use alga::general::RealField;
+use crate::procedural::Polyline;
+use crate::shape::Compound;
+
+impl<N: RealField> ToPolyline<N> for Compound<N> {
+    /// The discretization parameters given to `shape_to_polyline` for each part.
+    type DiscretizationParameter = (u32, N);
+
+    /// Merges the polylines of all the parts of this compound, expressed in its local space.
+    ///
+    /// The parts that cannot be converted to a polyline are ignored.
+    fn to_polyline(&self, (nsubdiv, plane_half_extent): (u32, N)) -> Polyline<N> {
+        let mut res = Polyline::with_indices(Vec::new(), None, Vec::new());
+
+        for (pos, part) in self.shapes() {
+            if let Some(mut polyline) = shape_to_polyline(&**part, nsubdiv, plane_half_extent) {
+                polyline.transform_by(pos);
+                res.append(&polyline);
+            }
+        }
+
+        res
+    }
+}
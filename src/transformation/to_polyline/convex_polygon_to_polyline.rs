@@ -0,0 +1,12 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use crate::procedural::Polyline;
+use crate::shape::ConvexPolygon;
+
+impl<N: RealField> ToPolyline<N> for ConvexPolygon<N> {
+    type DiscretizationParameter = ();
+
+    fn to_polyline(&self, _: ()) -> Polyline<N> {
+        Polyline::new(self.points().to_vec(), None)
+    }
+}
@@ -0,0 +1,23 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use na::Point2;
+use crate::procedural::Polyline;
+use crate::shape::HeightField;
+
+impl<N: RealField> ToPolyline<N> for HeightField<N> {
+    type DiscretizationParameter = ();
+
+    fn to_polyline(&self, _: ()) -> Polyline<N> {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for seg in self.segments() {
+            let i = vertices.len() as u32;
+            vertices.push(*seg.a());
+            vertices.push(*seg.b());
+            indices.push(Point2::new(i, i + 1));
+        }
+
+        Polyline::with_indices(vertices, None, indices)
+    }
+}
@@ -0,0 +1,25 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use crate::procedural::Polyline;
+use crate::shape::MinkowskiSum;
+use crate::transformation;
+
+impl<N, A, B> ToPolyline<N> for MinkowskiSum<A, B>
+where
+    N: RealField,
+    A: ToPolyline<N>,
+    B: ToPolyline<N>,
+{
+    type DiscretizationParameter = (A::DiscretizationParameter, B::DiscretizationParameter);
+
+    fn to_polyline(&self, (i1, i2): Self::DiscretizationParameter) -> Polyline<N> {
+        let pts1 = self.shape1().to_polyline(i1).unwrap().0;
+        let pts2 = self.shape2().to_polyline(i2).unwrap().0;
+        let sums: Vec<_> = pts1
+            .iter()
+            .flat_map(|a| pts2.iter().map(move |b| a + b.coords))
+            .collect();
+
+        transformation::convex_hull(&sums)
+    }
+}
@@ -1,3 +1,4 @@
+pub use self::shape_to_polyline::shape_to_polyline;
 pub use self::to_polyline::ToPolyline;
 
 #[doc(hidden)]
@@ -5,6 +6,15 @@ pub mod to_polyline;
 
 mod ball_to_polyline;
 mod capsule_to_polyline;
+mod compound_to_polyline;
+mod convex_polygon_to_polyline;
 mod cuboid_to_polyline;
+mod heightfield_to_polyline;
+mod minkowski_sum_to_polyline;
+mod plane_to_polyline;
+mod polyline_to_polyline;
 mod segment_to_polyline;
+mod shape_to_polyline;
+mod swept_shape_to_polyline;
 mod triangle_to_polyline;
+mod voxels_to_polyline;
@@ -0,0 +1,20 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use na::{Point2, Vector2};
+use crate::procedural::Polyline;
+use crate::shape::Plane;
+
+impl<N: RealField> ToPolyline<N> for Plane<N> {
+    /// The half-length of the segment used to represent the plane.
+    type DiscretizationParameter = N;
+
+    fn to_polyline(&self, half_extent: N) -> Polyline<N> {
+        let normal = self.normal();
+        let tangent = Vector2::new(-normal.y, normal.x) * half_extent;
+
+        Polyline::new(
+            vec![Point2::origin() - tangent, Point2::origin() + tangent],
+            Some(vec![normal.into_inner(); 2]),
+        )
+    }
+}
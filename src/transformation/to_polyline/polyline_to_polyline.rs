@@ -0,0 +1,19 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use na;
+use crate::procedural::Polyline;
+use crate::shape;
+
+impl<N: RealField> ToPolyline<N> for shape::Polyline<N> {
+    type DiscretizationParameter = ();
+
+    fn to_polyline(&self, _: ()) -> Polyline<N> {
+        let indices = self
+            .edges()
+            .iter()
+            .map(|e| na::convert(e.indices))
+            .collect();
+
+        Polyline::with_indices(self.points().to_vec(), None, indices)
+    }
+}
@@ -0,0 +1,43 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use crate::procedural::Polyline;
+use crate::shape::{
+    Ball, Capsule, Compound, ConvexPolygon, Cuboid, HeightField, Plane, Segment, Shape, Voxels,
+};
+use crate::shape;
+
+/// Builds a polyline approximating a shape of any type known by ncollide.
+///
+/// The shape is discretized with `nsubdiv` subdivisions for its curved parts, and planes are
+/// represented by segments of length `2 * plane_half_extent`. Returns `None` for user-defined
+/// shapes.
+pub fn shape_to_polyline<N: RealField>(
+    shape: &dyn Shape<N>,
+    nsubdiv: u32,
+    plane_half_extent: N,
+) -> Option<Polyline<N>>
+{
+    if let Some(s) = shape.as_shape::<Ball<N>>() {
+        Some(s.to_polyline(nsubdiv))
+    } else if let Some(s) = shape.as_shape::<Cuboid<N>>() {
+        Some(s.to_polyline(()))
+    } else if let Some(s) = shape.as_shape::<Capsule<N>>() {
+        Some(s.to_polyline(nsubdiv))
+    } else if let Some(s) = shape.as_shape::<Segment<N>>() {
+        Some(s.to_polyline(()))
+    } else if let Some(s) = shape.as_shape::<ConvexPolygon<N>>() {
+        Some(s.to_polyline(()))
+    } else if let Some(s) = shape.as_shape::<shape::Polyline<N>>() {
+        Some(s.to_polyline(()))
+    } else if let Some(s) = shape.as_shape::<HeightField<N>>() {
+        Some(s.to_polyline(()))
+    } else if let Some(s) = shape.as_shape::<Voxels<N>>() {
+        Some(s.to_polyline(()))
+    } else if let Some(s) = shape.as_shape::<Plane<N>>() {
+        Some(s.to_polyline(plane_half_extent))
+    } else if let Some(s) = shape.as_shape::<Compound<N>>() {
+        Some(s.to_polyline((nsubdiv, plane_half_extent)))
+    } else {
+        None
+    }
+}
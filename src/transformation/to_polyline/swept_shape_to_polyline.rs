@@ -0,0 +1,18 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use crate::procedural::Polyline;
+use crate::shape::SweptShape;
+use crate::transformation;
+
+impl<N: RealField, S: ToPolyline<N>> ToPolyline<N> for SweptShape<N, S> {
+    type DiscretizationParameter = S::DiscretizationParameter;
+
+    fn to_polyline(&self, i: S::DiscretizationParameter) -> Polyline<N> {
+        let mut pts = self.shape().to_polyline(i).unwrap().0;
+        let shift = *self.translation();
+        let translated: Vec<_> = pts.iter().map(|pt| pt + shift).collect();
+        pts.extend(translated);
+
+        transformation::convex_hull(&pts)
+    }
+}
@@ -0,0 +1,41 @@
+use super::ToPolyline;
+use alga::general::RealField;
+use na::{Point2, Vector2};
+use crate::procedural::Polyline;
+use crate::shape::Voxels;
+
+impl<N: RealField> ToPolyline<N> for Voxels<N> {
+    type DiscretizationParameter = ();
+
+    /// Builds a polyline made of the sides of the voxels that are not shared with another voxel.
+    fn to_polyline(&self, _: ()) -> Polyline<N> {
+        let half = self.voxel_size() * na::convert::<f64, N>(0.5);
+        let mut coords = Vec::new();
+        let mut indices = Vec::new();
+
+        for cell in self.cells() {
+            let center = self.voxel_center(cell);
+
+            for axis in 0..2 {
+                for positive in [false, true].iter().cloned() {
+                    if self.is_neighbor_occupied(cell, axis, positive) {
+                        continue;
+                    }
+
+                    let mut normal = Vector2::zeros();
+                    normal[axis] = if positive { N::one() } else { -N::one() };
+                    // Counter-clockwise around the voxel.
+                    let tangent = Vector2::new(-normal.y * half.x, normal.x * half.y);
+                    let side_center = center + normal.component_mul(&half);
+
+                    let i = coords.len() as u32;
+                    coords.push(side_center - tangent);
+                    coords.push(side_center + tangent);
+                    indices.push(Point2::new(i, i + 1));
+                }
+            }
+        }
+
+        Polyline::with_indices(coords, None, indices)
+    }
+}
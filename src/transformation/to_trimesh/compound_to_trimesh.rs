@@ -0,0 +1,25 @@
+use super::{shape_to_trimesh, ToTriMesh};
+use alga::general::RealField;
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::shape::Compound;
+
+impl<N: RealField> ToTriMesh<N> for Compound<N> {
+    /// The discretization parameters given to `shape_to_trimesh` for each part.
+    type DiscretizationParameter = (u32, N);
+
+    /// Merges the meshes of all the parts of this compound, expressed in its local space.
+    ///
+    /// The parts that cannot be converted to a triangle mesh are ignored.
+    fn to_trimesh(&self, (nsubdiv, plane_half_extent): (u32, N)) -> TriMesh<N> {
+        let mut res = TriMesh::new(Vec::new(), None, None, Some(IndexBuffer::Unified(Vec::new())));
+
+        for (pos, part) in self.shapes() {
+            if let Some(mut mesh) = shape_to_trimesh(&**part, nsubdiv, plane_half_extent) {
+                mesh.transform_by(pos);
+                res.append(&mesh);
+            }
+        }
+
+        res
+    }
+}
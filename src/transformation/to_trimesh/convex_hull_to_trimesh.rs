@@ -0,0 +1,27 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use na::Point3;
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::shape::ConvexHull;
+
+impl<N: RealField> ToTriMesh<N> for ConvexHull<N> {
+    type DiscretizationParameter = ();
+
+    fn to_trimesh(&self, _: ()) -> TriMesh<N> {
+        let mut indices = Vec::new();
+
+        // Each face is convex so it can be triangulated as a fan.
+        for face in self.face_vertex_indices() {
+            for i in 1..face.len() - 1 {
+                indices.push(Point3::new(face[0] as u32, face[i] as u32, face[i + 1] as u32));
+            }
+        }
+
+        TriMesh::new(
+            self.points().to_vec(),
+            None,
+            None,
+            Some(IndexBuffer::Unified(indices)),
+        )
+    }
+}
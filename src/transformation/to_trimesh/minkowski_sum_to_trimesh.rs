@@ -0,0 +1,25 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use crate::procedural::TriMesh;
+use crate::shape::MinkowskiSum;
+use crate::transformation;
+
+impl<N, A, B> ToTriMesh<N> for MinkowskiSum<A, B>
+where
+    N: RealField,
+    A: ToTriMesh<N>,
+    B: ToTriMesh<N>,
+{
+    type DiscretizationParameter = (A::DiscretizationParameter, B::DiscretizationParameter);
+
+    fn to_trimesh(&self, (i1, i2): Self::DiscretizationParameter) -> TriMesh<N> {
+        let pts1 = self.shape1().to_trimesh(i1).coords;
+        let pts2 = self.shape2().to_trimesh(i2).coords;
+        let sums: Vec<_> = pts1
+            .iter()
+            .flat_map(|a| pts2.iter().map(move |b| a + b.coords))
+            .collect();
+
+        transformation::convex_hull(&sums)
+    }
+}
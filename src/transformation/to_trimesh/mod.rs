@@ -1,12 +1,22 @@
+pub use self::shape_to_trimesh::shape_to_trimesh;
 pub use self::to_trimesh::ToTriMesh;
 
 mod ball_to_trimesh;
 mod capsule_to_trimesh;
+mod compound_to_trimesh;
 mod cone_to_trimesh;
+mod convex_hull_to_trimesh;
 mod cuboid_to_trimesh;
 mod cylinder_to_trimesh;
+mod minkowski_sum_to_trimesh;
+mod plane_to_trimesh;
+mod shape_to_trimesh;
+mod signed_distance_field_to_trimesh;
+mod swept_shape_to_trimesh;
 #[doc(hidden)]
 pub mod to_trimesh;
 // mod mesh_to_trimesh;
 mod triangle_to_trimesh;
-mod heightfield_to_trimesh;
\ No newline at end of file
+mod trimesh_to_trimesh;
+mod heightfield_to_trimesh;
+mod voxels_to_trimesh;
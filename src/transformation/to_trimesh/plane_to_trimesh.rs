@@ -0,0 +1,40 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use alga::linear::FiniteDimInnerSpace;
+use na::Point3;
+use crate::math::Vector;
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::shape::Plane;
+
+impl<N: RealField> ToTriMesh<N> for Plane<N> {
+    /// The half-length of the sides of the square used to represent the plane.
+    type DiscretizationParameter = N;
+
+    fn to_trimesh(&self, half_extent: N) -> TriMesh<N> {
+        let normal = self.normal();
+        let mut basis = [Vector::zeros(); 2];
+        let mut k = 0;
+
+        Vector::orthonormal_subspace_basis(&[normal.into_inner()], |b| {
+            basis[k] = *b * half_extent;
+            k += 1;
+            true
+        });
+
+        // Make the triangles counter-clockwise when seen from the side the normal points to.
+        if basis[0].cross(&basis[1]).dot(normal) < N::zero() {
+            basis.swap(0, 1);
+        }
+
+        let coords = vec![
+            Point3::origin() - basis[0] - basis[1],
+            Point3::origin() + basis[0] - basis[1],
+            Point3::origin() + basis[0] + basis[1],
+            Point3::origin() - basis[0] + basis[1],
+        ];
+        let normals = vec![normal.into_inner(); 4];
+        let indices = vec![Point3::new(0, 1, 2), Point3::new(0, 2, 3)];
+
+        TriMesh::new(coords, Some(normals), None, Some(IndexBuffer::Unified(indices)))
+    }
+}
@@ -0,0 +1,47 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use crate::procedural::TriMesh;
+use crate::shape::{
+    Ball, Capsule, Compound, ConvexHull, Cuboid, HeightField, Plane, Shape, SignedDistanceField,
+    Triangle, Voxels,
+};
+use crate::shape;
+
+/// Builds a triangle mesh approximating a shape of any type known by ncollide.
+///
+/// The shape is discretized with `nsubdiv` subdivisions for its curved parts, and planes are
+/// represented by squares with sides of length `2 * plane_half_extent`. Signed distance fields
+/// are discretized at the resolution of their grid. Returns `None` for segments and polylines
+/// since they do not bound any volume, as well as for user-defined shapes.
+pub fn shape_to_trimesh<N: RealField>(
+    shape: &dyn Shape<N>,
+    nsubdiv: u32,
+    plane_half_extent: N,
+) -> Option<TriMesh<N>>
+{
+    if let Some(s) = shape.as_shape::<Ball<N>>() {
+        Some(s.to_trimesh((nsubdiv, nsubdiv)))
+    } else if let Some(s) = shape.as_shape::<Cuboid<N>>() {
+        Some(s.to_trimesh(()))
+    } else if let Some(s) = shape.as_shape::<Capsule<N>>() {
+        Some(s.to_trimesh((nsubdiv, nsubdiv)))
+    } else if let Some(s) = shape.as_shape::<Triangle<N>>() {
+        Some(s.to_trimesh(()))
+    } else if let Some(s) = shape.as_shape::<ConvexHull<N>>() {
+        Some(s.to_trimesh(()))
+    } else if let Some(s) = shape.as_shape::<shape::TriMesh<N>>() {
+        Some(s.to_trimesh(()))
+    } else if let Some(s) = shape.as_shape::<HeightField<N>>() {
+        Some(s.to_trimesh(()))
+    } else if let Some(s) = shape.as_shape::<Voxels<N>>() {
+        Some(s.to_trimesh(()))
+    } else if let Some(s) = shape.as_shape::<SignedDistanceField<N>>() {
+        Some(s.to_trimesh(()))
+    } else if let Some(s) = shape.as_shape::<Plane<N>>() {
+        Some(s.to_trimesh(plane_half_extent))
+    } else if let Some(s) = shape.as_shape::<Compound<N>>() {
+        Some(s.to_trimesh((nsubdiv, plane_half_extent)))
+    } else {
+        None
+    }
+}
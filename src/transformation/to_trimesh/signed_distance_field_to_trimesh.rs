@@ -0,0 +1,126 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use na::{Point3, Vector3};
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::shape::SignedDistanceField;
+use crate::utils::DeterministicState;
+use std::collections::HashMap;
+
+// The decomposition of a grid cell into six tetrahedra sharing its diagonal from the corner 0
+// to the corner 7. The corner `i` is at the offset `(i & 1, (i >> 1) & 1, (i >> 2) & 1)`.
+// Adjacent cells are split along the same diagonals of their common face.
+const TETRAHEDRA: [[usize; 4]; 6] = [
+    [0, 1, 3, 7],
+    [0, 1, 5, 7],
+    [0, 2, 3, 7],
+    [0, 2, 6, 7],
+    [0, 4, 5, 7],
+    [0, 4, 6, 7],
+];
+
+impl<N: RealField> ToTriMesh<N> for SignedDistanceField<N> {
+    type DiscretizationParameter = ();
+
+    /// Builds a mesh of the zero level set of the signed distance field.
+    ///
+    /// The surface is extracted with marching tetrahedra on the grid of samples, so its
+    /// resolution is the cell size of the signed distance field. The mesh is closed if the
+    /// surface does not cross the boundary of the grid.
+    fn to_trimesh(&self, _: ()) -> TriMesh<N> {
+        let n = *self.nsamples();
+        let mut coords = Vec::new();
+        let mut indices = Vec::new();
+        // The vertex on the grid edge between two samples, identified by their linear indices.
+        let mut edge_vertices = HashMap::with_hasher(DeterministicState::new());
+
+        let sample_id = |s: &Vector3<usize>| s.x + s.y * n.x + s.z * n.x * n.y;
+        let sample_pt = |s: &Vector3<usize>| {
+            let idx = s.map(|e| na::convert::<f64, N>(e as f64));
+            self.aabb().mins() + idx.component_mul(self.cell_size())
+        };
+
+        for k in 0..n.z - 1 {
+            for j in 0..n.y - 1 {
+                for i in 0..n.x - 1 {
+                    let mut corners = [Vector3::zeros(); 8];
+
+                    for (c, corner) in corners.iter_mut().enumerate() {
+                        *corner = Vector3::new(i + (c & 1), j + ((c >> 1) & 1), k + ((c >> 2) & 1));
+                    }
+
+                    for tetra in TETRAHEDRA.iter() {
+                        let samples =
+                            [corners[tetra[0]], corners[tetra[1]], corners[tetra[2]], corners[tetra[3]]];
+                        let mut values = [N::zero(); 4];
+
+                        for (value, s) in values.iter_mut().zip(samples.iter()) {
+                            *value = self.value_at(s.x, s.y, s.z);
+                        }
+
+                        let (inside, outside): (Vec<usize>, Vec<usize>) =
+                            (0..4).partition(|v| values[*v] < N::zero());
+
+                        if inside.is_empty() || outside.is_empty() {
+                            continue;
+                        }
+
+                        let mut vertex_at = |a: usize, b: usize| -> u32 {
+                            let (ia, ib) = (sample_id(&samples[a]), sample_id(&samples[b]));
+                            let key = if ia < ib { (ia, ib) } else { (ib, ia) };
+
+                            *edge_vertices.entry(key).or_insert_with(|| {
+                                let t = values[a] / (values[a] - values[b]);
+                                let (pa, pb) = (sample_pt(&samples[a]), sample_pt(&samples[b]));
+                                coords.push(pa + (pb - pa) * t);
+                                coords.len() as u32 - 1
+                            })
+                        };
+
+                        let tris = match (inside.len(), outside.len()) {
+                            (1, 3) => {
+                                let a = inside[0];
+                                let (b, c, d) = (outside[0], outside[1], outside[2]);
+                                vec![[vertex_at(a, b), vertex_at(a, c), vertex_at(a, d)]]
+                            }
+                            (3, 1) => {
+                                let (a, b, c) = (inside[0], inside[1], inside[2]);
+                                let d = outside[0];
+                                vec![[vertex_at(a, d), vertex_at(b, d), vertex_at(c, d)]]
+                            }
+                            _ => {
+                                let (a, b) = (inside[0], inside[1]);
+                                let (c, d) = (outside[0], outside[1]);
+                                // The four vertices form the cycle ac, ad, bd, bc.
+                                let (ac, ad) = (vertex_at(a, c), vertex_at(a, d));
+                                let (bd, bc) = (vertex_at(b, d), vertex_at(b, c));
+                                vec![[ac, ad, bd], [ac, bd, bc]]
+                            }
+                        };
+
+                        // Orient the triangles from the inside samples toward the outside samples.
+                        let center = |vs: &[usize]| {
+                            let sum = vs
+                                .iter()
+                                .fold(Vector3::zeros(), |acc, v| acc + sample_pt(&samples[*v]).coords);
+                            sum / na::convert::<f64, N>(vs.len() as f64)
+                        };
+                        let out_dir = center(&outside) - center(&inside);
+
+                        for tri in tris {
+                            let pa = coords[tri[0] as usize];
+                            let (pb, pc) = (coords[tri[1] as usize], coords[tri[2] as usize]);
+
+                            if (pb - pa).cross(&(pc - pa)).dot(&out_dir) >= N::zero() {
+                                indices.push(Point3::new(tri[0], tri[1], tri[2]));
+                            } else {
+                                indices.push(Point3::new(tri[0], tri[2], tri[1]));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(indices)))
+    }
+}
@@ -0,0 +1,18 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use crate::procedural::TriMesh;
+use crate::shape::SweptShape;
+use crate::transformation;
+
+impl<N: RealField, S: ToTriMesh<N>> ToTriMesh<N> for SweptShape<N, S> {
+    type DiscretizationParameter = S::DiscretizationParameter;
+
+    fn to_trimesh(&self, i: S::DiscretizationParameter) -> TriMesh<N> {
+        let mut pts = self.shape().to_trimesh(i).coords;
+        let shift = *self.translation();
+        let translated: Vec<_> = pts.iter().map(|pt| pt + shift).collect();
+        pts.extend(translated);
+
+        transformation::convex_hull(&pts)
+    }
+}
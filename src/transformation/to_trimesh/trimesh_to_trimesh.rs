@@ -0,0 +1,24 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use na;
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::shape;
+
+impl<N: RealField> ToTriMesh<N> for shape::TriMesh<N> {
+    type DiscretizationParameter = ();
+
+    fn to_trimesh(&self, _: ()) -> TriMesh<N> {
+        let indices = self
+            .faces()
+            .iter()
+            .map(|f| na::convert(f.indices))
+            .collect();
+
+        TriMesh::new(
+            self.points().to_vec(),
+            None,
+            self.uvs().map(|uvs| uvs.to_vec()),
+            Some(IndexBuffer::Unified(indices)),
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use super::ToTriMesh;
+use alga::general::RealField;
+use na::{Point3, Vector3};
+use crate::procedural::{IndexBuffer, TriMesh};
+use crate::shape::Voxels;
+
+impl<N: RealField> ToTriMesh<N> for Voxels<N> {
+    type DiscretizationParameter = ();
+
+    /// Builds a mesh made of the faces of the voxels that are not shared with another voxel.
+    fn to_trimesh(&self, _: ()) -> TriMesh<N> {
+        let half = self.voxel_size() * na::convert::<f64, N>(0.5);
+        let mut coords = Vec::new();
+        let mut indices = Vec::new();
+
+        for cell in self.cells() {
+            let center = self.voxel_center(cell);
+
+            for axis in 0..3 {
+                let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+
+                for positive in [false, true].iter().cloned() {
+                    if self.is_neighbor_occupied(cell, axis, positive) {
+                        continue;
+                    }
+
+                    let mut shift = Vector3::zeros();
+                    let mut du = Vector3::zeros();
+                    let mut dv = Vector3::zeros();
+                    shift[axis] = if positive { half[axis] } else { -half[axis] };
+                    du[u] = half[u];
+                    dv[v] = half[v];
+
+                    // The axes `u`, `v`, and `axis` form a right-handed basis.
+                    if !positive {
+                        std::mem::swap(&mut du, &mut dv);
+                    }
+
+                    let base = coords.len() as u32;
+                    let face_center = center + shift;
+                    coords.push(face_center - du - dv);
+                    coords.push(face_center + du - dv);
+                    coords.push(face_center + du + dv);
+                    coords.push(face_center - du + dv);
+                    indices.push(Point3::new(base, base + 1, base + 2));
+                    indices.push(Point3::new(base, base + 2, base + 3));
+                }
+            }
+        }
+
+        TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(indices)))
+    }
+}
@@ -1,193 +1,763 @@
-//! Point cloud triangulation.
+//! Delaunay and constrained Delaunay triangulation of planar point sets and polygons.
 
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
 
 use crate::alga::general::RealField;
-use na::{self, Point3};
+use crate::bounding_volume::AABB;
 use crate::math::Point;
-use crate::utils;
-use crate::bounding_volume;
+use crate::partitioning::{BVH, BVT};
+#[cfg(feature = "dim2")]
 use crate::procedural::{IndexBuffer, TriMesh};
+use crate::query::visitors::BoundingVolumeInterferencesCollector;
+#[cfg(feature = "dim2")]
+use crate::shape::Polyline;
+use crate::utils::DeterministicState;
+use na::{self, Point2, Point3};
+
+/// Error returned when a polygon cannot be triangulated.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TriangulationError<N: RealField> {
+    /// The boundary loop with the given index has less than three distinct vertices, or all
+    /// its vertices are collinear.
+    DegenerateLoop(usize),
+    /// The boundary touches itself, or another boundary loop, at the given point.
+    SelfTouching(Point2<N>),
+    /// Two boundary edges cross each other at the given point.
+    SelfIntersecting(Point2<N>),
+    /// The polyline vertex at the given point is the end of an open chain of segments.
+    #[cfg_attr(feature = "dim3", allow(dead_code))]
+    OpenBoundary(Point2<N>),
+    /// The triangulation failed near the given point because of rounding errors.
+    RoundingError(Point2<N>),
+}
+
+impl<N: RealField> fmt::Display for TriangulationError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TriangulationError::DegenerateLoop(i) => write!(
+                f,
+                "the boundary loop {} has less than three non-collinear vertices",
+                i
+            ),
+            TriangulationError::SelfTouching(pt) => {
+                write!(f, "the boundary touches itself at ({}, {})", pt.x, pt.y)
+            }
+            TriangulationError::SelfIntersecting(pt) => {
+                write!(f, "the boundary intersects itself at ({}, {})", pt.x, pt.y)
+            }
+            TriangulationError::OpenBoundary(pt) => {
+                write!(f, "the boundary is not closed at ({}, {})", pt.x, pt.y)
+            }
+            TriangulationError::RoundingError(pt) => write!(
+                f,
+                "the triangulation failed because of rounding errors near ({}, {})",
+                pt.x, pt.y
+            ),
+        }
+    }
+}
+
+impl<N: RealField> Error for TriangulationError<N> {}
+
+/// Computes the Delaunay triangulation of a set of points.
+///
+/// The vertices of the resulting mesh are the given points, in the same order. Duplicate
+/// points are not referenced by any triangle, and the mesh has no triangle if there are less
+/// than three non-collinear points. Returns an error if rounding errors prevent the insertion
+/// of a point.
+#[cfg(feature = "dim2")]
+pub fn triangulate<N: RealField>(pts: &[Point2<N>]) -> Result<TriMesh<N>, TriangulationError<N>> {
+    let mut triangulation = Triangulation::new(pts.to_vec());
+
+    for i in 0..pts.len() {
+        triangulation.insert_point(i)?;
+    }
+
+    Ok(into_trimesh(triangulation.into_triangles(false)))
+}
 
-struct Triangle<N: RealField> {
-    idx: Point3<usize>,
-    circumcircle_center: Point<N>,
-    circumcircle_sq_radius: N,
+/// Computes the constrained Delaunay triangulation of a polygon with holes.
+///
+/// The polygon is bounded by the `outer` loop of vertices, and each element of `holes` is a
+/// loop of vertices bounding a hole inside of it. The loops may be given in any orientation,
+/// and do not need to repeat their first vertex at the end. More generally, the regions
+/// enclosed by an odd number of loops are triangulated, so holes may contain islands.
+///
+/// The vertices of the resulting mesh are those of the outer loop followed by those of each
+/// hole, without consecutive duplicates. Its triangles are counter-clockwise and every edge of
+/// the loops is an edge of the triangulation, including edges with collinear vertices.
+///
+/// Returns an error if a loop is degenerate, if the loops touch or cross each other or
+/// themselves, or if rounding errors prevent the insertion of a point or an edge.
+#[cfg(feature = "dim2")]
+pub fn triangulate_polygon<N: RealField>(
+    outer: &[Point2<N>],
+    holes: &[Vec<Point2<N>>],
+) -> Result<TriMesh<N>, TriangulationError<N>>
+{
+    let loops = Some(outer.to_vec())
+        .into_iter()
+        .chain(holes.iter().cloned())
+        .collect();
+
+    triangulate_loops(loops).map(into_trimesh)
 }
 
-impl<N: RealField> Triangle<N> {
-    pub fn new(idx: Point3<usize>, pts: &[Point<N>]) -> Triangle<N> {
-        let pa = &pts[idx.x];
-        let pb = &pts[idx.y];
-        let pc = &pts[idx.z];
+/// Computes the constrained Delaunay triangulation of the region enclosed by a polyline.
+///
+/// Every vertex of the polyline that is part of a segment must be shared by exactly two
+/// segments, so that the polyline is made of closed loops. The regions enclosed by an odd
+/// number of those loops are triangulated, as with `triangulate_polygon`. The vertices of the
+/// resulting mesh are those of the loops, in the order they are traversed.
+#[cfg(feature = "dim2")]
+pub fn triangulate_polyline<N: RealField>(
+    polyline: &Polyline<N>,
+) -> Result<TriMesh<N>, TriangulationError<N>> {
+    let points = polyline.points();
+    let mut adj = vec![Vec::new(); points.len()];
+
+    for e in polyline.edges() {
+        if e.indices.x != e.indices.y {
+            adj[e.indices.x].push(e.indices.y);
+            adj[e.indices.y].push(e.indices.x);
+        }
+    }
+
+    for (i, neighbors) in adj.iter().enumerate() {
+        match neighbors.len() {
+            0 | 2 => {}
+            1 => return Err(TriangulationError::OpenBoundary(points[i])),
+            _ => return Err(TriangulationError::SelfTouching(points[i])),
+        }
+    }
+
+    let mut visited = vec![false; points.len()];
+    let mut loops = Vec::new();
+
+    for start in 0..points.len() {
+        if visited[start] || adj[start].is_empty() {
+            continue;
+        }
+
+        let mut curr_loop = Vec::new();
+        let mut prev = start;
+        let mut curr = adj[start][0];
+        visited[start] = true;
+        curr_loop.push(points[start]);
+
+        while curr != start {
+            visited[curr] = true;
+            curr_loop.push(points[curr]);
+
+            let next = if adj[curr][0] == prev {
+                adj[curr][1]
+            } else {
+                adj[curr][0]
+            };
+            prev = curr;
+            curr = next;
+        }
+
+        loops.push(curr_loop);
+    }
 
-        let (center, radius) = utils::circumcircle(pa, pb, pc);
+    triangulate_loops(loops).map(into_trimesh)
+}
 
-        Triangle {
-            idx: idx,
-            circumcircle_center: center,
-            circumcircle_sq_radius: radius * radius,
+/// Computes the constrained Delaunay triangulation of the regions enclosed by an odd number of
+/// loops.
+///
+/// Returns the vertices of the loops without consecutive duplicates, and the counter-clockwise
+/// triangles as indices of those vertices.
+pub(crate) fn triangulate_loops<N: RealField>(
+    loops: Vec<Vec<Point2<N>>>,
+) -> Result<(Vec<Point2<N>>, Vec<Point3<u32>>), TriangulationError<N>> {
+    let loops: Vec<_> = loops.into_iter().map(remove_duplicates).collect();
+    check_loops(&loops)?;
+
+    let mut coords = Vec::new();
+    let mut constraints = Vec::new();
+
+    for l in loops {
+        let base = coords.len();
+
+        for i in 0..l.len() {
+            constraints.push((base + i, base + (i + 1) % l.len()));
         }
+
+        coords.extend(l);
+    }
+
+    let mut triangulation = Triangulation::new(coords);
+
+    for i in 0..triangulation.nvertices {
+        triangulation.insert_point(i)?;
     }
 
-    pub fn circumcircle_contains_point(&self, pt: &Point<N>) -> bool {
-        na::distance_squared(pt, &self.circumcircle_center) <= self.circumcircle_sq_radius
+    for (a, b) in constraints {
+        triangulation.insert_constraint(a, b)?;
     }
+
+    Ok(triangulation.into_triangles(true))
 }
 
-/// Incremental triangulation utility.
-pub struct Triangulator<N: RealField> {
-    vertices: Vec<Point<N>>,
-    triangles: Vec<Triangle<N>>,
-    edges: HashMap<(usize, usize), usize>,
+#[cfg(feature = "dim2")]
+fn into_trimesh<N: RealField>((coords, indices): (Vec<Point2<N>>, Vec<Point3<u32>>)) -> TriMesh<N> {
+    TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(indices)))
 }
 
-impl<N: RealField> Triangulator<P> {
-    /// Creates a new Triangulator.
-    pub fn new(supertriangle_a: Point<N>, supertriangle_b: Point<N>, supertriangle_c: Point<N>) -> Triangulator<P> {
-        let vertices = vec![supertriangle_a, supertriangle_b, supertriangle_c];
+// Removes the consecutive duplicate vertices of a loop, including a last vertex equal to the first.
+fn remove_duplicates<N: RealField>(mut vertices: Vec<Point2<N>>) -> Vec<Point2<N>> {
+    vertices.dedup();
+
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        let _ = vertices.pop();
+    }
+
+    vertices
+}
 
-        Triangulator {
-            // FIXME: why do we have to specify the type explicitely here ?
-            triangles: vec![Triangle::<P>::new(Point3::new(0, 1, 2), &vertices[..])],
-            vertices: vertices,
-            edges: HashMap::new(),
+// Checks that the loops are not degenerate and do not touch or intersect.
+fn check_loops<N: RealField>(loops: &[Vec<Point2<N>>]) -> Result<(), TriangulationError<N>> {
+    let mut edges = Vec::new();
+
+    for (i, l) in loops.iter().enumerate() {
+        if l.len() < 3 || l.iter().all(|pt| orient(&l[0], &l[1], pt).is_zero()) {
+            return Err(TriangulationError::DegenerateLoop(i));
+        }
+
+        for j in 0..l.len() {
+            edges.push((i, j, l[j], l[(j + 1) % l.len()]));
+        }
+    }
+
+    // Only the pairs of edges with intersecting AABBs need to be tested.
+    let leaves = edges
+        .iter()
+        .enumerate()
+        .map(|(k, e)| (k, segment_aabb(&e.2, &e.3)))
+        .collect();
+    let bvt = BVT::new_balanced(leaves);
+    let mut candidates = Vec::new();
+
+    for (k1, edge1) in edges.iter().enumerate() {
+        let aabb = segment_aabb(&edge1.2, &edge1.3);
+        bvt.visit(&mut BoundingVolumeInterferencesCollector::new(&aabb, &mut candidates));
+
+        for k2 in candidates.drain(..) {
+            if k2 > k1 {
+                check_edge_pair(loops, edge1, &edges[k2])?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// The AABB of a segment, embedded in the plane of the first two axes if the space has three
+// dimensions.
+fn segment_aabb<N: RealField>(a: &Point2<N>, b: &Point2<N>) -> AABB<N> {
+    let mut mins = Point::origin();
+    let mut maxs = Point::origin();
+
+    for i in 0..2 {
+        mins[i] = a[i].min(b[i]);
+        maxs[i] = a[i].max(b[i]);
+    }
+
+    AABB::new(mins, maxs)
+}
+
+// Checks that two loop edges, given as `(loop, index in the loop, first vertex, second vertex)`,
+// do not touch or intersect, except at the vertex shared by consecutive edges.
+fn check_edge_pair<N: RealField>(
+    loops: &[Vec<Point2<N>>],
+    &(loop1, j1, a1, b1): &(usize, usize, Point2<N>, Point2<N>),
+    &(loop2, j2, a2, b2): &(usize, usize, Point2<N>, Point2<N>),
+) -> Result<(), TriangulationError<N>>
+{
+    let len = loops[loop1].len();
+
+    if loop1 == loop2 && (j2 == (j1 + 1) % len || j1 == (j2 + 1) % len) {
+        // Consecutive edges only share one vertex, unless they fold back onto each other.
+        let (shared, x, y) = if j2 == (j1 + 1) % len {
+            (b1, a1, b2)
+        } else {
+            (a1, b1, a2)
+        };
+
+        if orient(&shared, &x, &y).is_zero() && (x - shared).dot(&(y - shared)) > N::zero() {
+            return Err(TriangulationError::SelfTouching(shared));
         }
+
+        return Ok(());
     }
 
-    /// Adds a point to the triangulated set.
-    pub fn add_point(&mut self, pt: Point<N>) {
-        self.remove_containing_triangles_and_register_edges(&pt);
+    let o1 = orient(&a1, &b1, &a2);
+    let o2 = orient(&a1, &b1, &b2);
+    let o3 = orient(&a2, &b2, &a1);
+    let o4 = orient(&a2, &b2, &b1);
+
+    if o1 * o2 < N::zero() && o3 * o4 < N::zero() {
+        let t = o3 / (o3 - o4);
+        return Err(TriangulationError::SelfIntersecting(a1 + (b1 - a1) * t));
+    }
 
-        let ipt = self.vertices.len();
-        self.vertices.push(pt);
+    let touching = [(o1, a2, a1, b1), (o2, b2, a1, b1), (o3, a1, a2, b2), (o4, b1, a2, b2)];
 
-        for (&(ia, ib), num) in self.edges.iter() {
-            if *num == 1 {
-                // FIXME: why do we have to specify the type explicitely here ?
-                let t = Triangle::<P>::new(Point3::new(ia, ib, ipt), &self.vertices[..]);
+    for (o, pt, a, b) in touching.iter() {
+        if o.is_zero() && (a - pt).dot(&(b - pt)) <= N::zero() {
+            return Err(TriangulationError::SelfTouching(*pt));
+        }
+    }
 
-                self.triangles.push(t)
+    Ok(())
+}
+
+// Twice the signed area of the triangle `abc`, positive if it is counter-clockwise.
+pub(super) fn orient<N: RealField>(a: &Point2<N>, b: &Point2<N>, c: &Point2<N>) -> N {
+    let ab = b - a;
+    let ac = c - a;
+    ab.x * ac.y - ab.y * ac.x
+}
+
+// Tests if `d` lies strictly inside of the circumcircle of the counter-clockwise triangle `abc`.
+fn in_circumcircle<N: RealField>(a: &Point2<N>, b: &Point2<N>, c: &Point2<N>, d: &Point2<N>) -> bool {
+    let ad = a - d;
+    let bd = b - d;
+    let cd = c - d;
+    let det = ad.norm_squared() * (bd.x * cd.y - bd.y * cd.x)
+        + bd.norm_squared() * (cd.x * ad.y - cd.y * ad.x)
+        + cd.norm_squared() * (ad.x * bd.y - ad.y * bd.x);
+
+    det > N::zero()
+}
+
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// An incremental Delaunay triangulation with constrained edges.
+///
+/// Triangles are stored as counter-clockwise vertex indices, and each directed edge is mapped
+/// to the triangle it belongs to. The last three vertices are the corners of a triangle
+/// enclosing all the others.
+struct Triangulation<N: RealField> {
+    vertices: Vec<Point2<N>>,
+    nvertices: usize,
+    triangles: Vec<Option<[usize; 3]>>,
+    edges: HashMap<(usize, usize), usize, DeterministicState>,
+    constraints: HashSet<(usize, usize), DeterministicState>,
+    // A triangle containing each vertex, if it has already been inserted.
+    vertex_triangles: Vec<usize>,
+    last_triangle: usize,
+}
+
+impl<N: RealField> Triangulation<N> {
+    fn new(mut vertices: Vec<Point2<N>>) -> Self {
+        let nvertices = vertices.len();
+        // Without any vertex, only the enclosing triangle is built around the origin.
+        let first = vertices.first().cloned().unwrap_or_else(Point2::origin);
+        let (mins, maxs) = vertices
+            .iter()
+            .fold((first, first), |(mins, maxs), pt| {
+                (na::inf(&mins, pt), na::sup(&maxs, pt))
+            });
+        let center = na::center(&mins, &maxs);
+        let mut radius = (maxs - mins).max() * na::convert(0.5);
+
+        if radius.is_zero() {
+            radius = N::one();
+        }
+
+        let (_10, _20): (N, N) = (na::convert(10.0), na::convert(20.0));
+        vertices.push(Point2::new(center.x - radius * _20, center.y - radius * _10));
+        vertices.push(Point2::new(center.x + radius * _20, center.y - radius * _10));
+        vertices.push(Point2::new(center.x, center.y + radius * _20));
+
+        let mut res = Triangulation {
+            vertices,
+            nvertices,
+            triangles: Vec::new(),
+            edges: HashMap::with_hasher(DeterministicState::new()),
+            constraints: HashSet::with_hasher(DeterministicState::new()),
+            vertex_triangles: vec![usize::max_value(); nvertices + 3],
+            last_triangle: 0,
+        };
+
+        let _ = res.add_triangle([nvertices, nvertices + 1, nvertices + 2]);
+        res
+    }
+
+    fn add_triangle(&mut self, tri: [usize; 3]) -> usize {
+        let id = self.triangles.len();
+
+        for k in 0..3 {
+            let _ = self.edges.insert((tri[k], tri[(k + 1) % 3]), id);
+            self.vertex_triangles[tri[k]] = id;
+        }
+
+        self.triangles.push(Some(tri));
+        self.last_triangle = id;
+        id
+    }
+
+    fn remove_triangle(&mut self, id: usize) {
+        if let Some(tri) = self.triangles[id].take() {
+            for k in 0..3 {
+                let _ = self.edges.remove(&(tri[k], tri[(k + 1) % 3]));
             }
         }
     }
 
-    /// Returns the result of the triangulation.
-    pub fn to_trimesh(mut self) -> TriMesh<N> {
-        let mut idx = Vec::with_capacity(self.triangles.len());
+    // The vertex of the triangle containing the directed edge `(a, b)` that is not on this edge.
+    fn apex(&self, a: usize, b: usize) -> Option<usize> {
+        let tri = self.triangles[*self.edges.get(&(a, b))?]?;
+        tri.iter().cloned().find(|v| *v != a && *v != b)
+    }
+
+    fn contains_point(&self, tri: &[usize; 3], pt: &Point2<N>) -> bool {
+        (0..3).all(|k| {
+            orient(&self.vertices[tri[k]], &self.vertices[tri[(k + 1) % 3]], pt) >= N::zero()
+        })
+    }
 
-        let _ = self.vertices.swap_remove(2);
-        let _ = self.vertices.swap_remove(1);
-        let _ = self.vertices.swap_remove(0);
+    // Finds a triangle containing `pt` by walking from the last created triangle.
+    fn locate(&self, pt: &Point2<N>) -> Result<usize, TriangulationError<N>> {
+        let mut curr = self.last_triangle;
 
-        let num_ids = self.vertices.len();
+        'walk: for _ in 0..self.triangles.len() {
+            let tri = match self.triangles[curr] {
+                Some(tri) => tri,
+                None => break,
+            };
 
-        for t in self.triangles.iter() {
-            if t.idx.x > 2 && t.idx.y > 2 && t.idx.z > 2 {
-                let mut shifted_idx = t.idx;
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
 
-                if shifted_idx.x >= num_ids {
-                    shifted_idx.x -= num_ids;
+                if orient(&self.vertices[a], &self.vertices[b], pt) < N::zero() {
+                    if let Some(next) = self.edges.get(&(b, a)) {
+                        curr = *next;
+                        continue 'walk;
+                    }
                 }
+            }
+
+            return Ok(curr);
+        }
+
+        // The walk failed because of rounding errors: fall back to an exhaustive search.
+        self.triangles
+            .iter()
+            .position(|tri| tri.map(|tri| self.contains_point(&tri, pt)).unwrap_or(false))
+            .ok_or(TriangulationError::RoundingError(*pt))
+    }
+
+    // Inserts the `i`-th vertex with the Bowyer-Watson algorithm.
+    fn insert_point(&mut self, i: usize) -> Result<(), TriangulationError<N>> {
+        let pt = self.vertices[i];
+        let first = self.locate(&pt)?;
+
+        if self.triangles[first]
+            .unwrap()
+            .iter()
+            .any(|v| self.vertices[*v] == pt)
+        {
+            // Duplicate point.
+            return Ok(());
+        }
 
-                if shifted_idx.y >= num_ids {
-                    shifted_idx.y -= num_ids;
+        // Find all the triangles with a circumcircle containing the point.
+        let mut cavity = HashSet::with_hasher(DeterministicState::new());
+        let mut stack = vec![first];
+        let _ = cavity.insert(first);
+
+        while let Some(curr) = stack.pop() {
+            let tri = self.triangles[curr].unwrap();
+
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
+
+                if let Some(&neighbor) = self.edges.get(&(b, a)) {
+                    if !cavity.contains(&neighbor) {
+                        let ntri = self.triangles[neighbor].unwrap();
+                        let (pa, pb, pc) = (
+                            &self.vertices[ntri[0]],
+                            &self.vertices[ntri[1]],
+                            &self.vertices[ntri[2]],
+                        );
+
+                        if in_circumcircle(pa, pb, pc, &pt) {
+                            let _ = cavity.insert(neighbor);
+                            stack.push(neighbor);
+                        }
+                    }
                 }
+            }
+        }
+
+        // Replace the cavity by triangles connecting its boundary to the new point.
+        let mut boundary = Vec::new();
 
-                if shifted_idx.z >= num_ids {
-                    shifted_idx.z -= num_ids;
+        for id in &cavity {
+            let tri = self.triangles[*id].unwrap();
+
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
+
+                if self.edges.get(&(b, a)).map(|n| !cavity.contains(n)).unwrap_or(true) {
+                    boundary.push((a, b));
                 }
+            }
+        }
 
-                idx.push(na::convert(shifted_idx));
+        for id in cavity {
+            self.remove_triangle(id);
+        }
+
+        for (a, b) in boundary {
+            let _ = self.add_triangle([a, b, i]);
+        }
+
+        Ok(())
+    }
+
+    // Flips the edge `(u, v)` shared by two triangles forming a strictly convex quadrilateral.
+    // Returns the new edge.
+    fn flip(&mut self, u: usize, v: usize) -> Option<(usize, usize)> {
+        let p = self.apex(u, v)?;
+        let q = self.apex(v, u)?;
+        let (pu, pv, pp, pq) = (
+            &self.vertices[u],
+            &self.vertices[v],
+            &self.vertices[p],
+            &self.vertices[q],
+        );
+
+        if orient(pu, pq, pp) <= N::zero() || orient(pv, pp, pq) <= N::zero() {
+            return None;
+        }
+
+        let t1 = self.edges[&(u, v)];
+        let t2 = self.edges[&(v, u)];
+        self.remove_triangle(t1);
+        self.remove_triangle(t2);
+        let _ = self.add_triangle([u, q, p]);
+        let _ = self.add_triangle([v, p, q]);
+
+        Some((p, q))
+    }
+
+    fn segments_cross(&self, a: usize, b: usize, u: usize, v: usize) -> bool {
+        let (pa, pb, pu, pv) = (
+            &self.vertices[a],
+            &self.vertices[b],
+            &self.vertices[u],
+            &self.vertices[v],
+        );
+
+        orient(pa, pb, pu) * orient(pa, pb, pv) < N::zero()
+            && orient(pu, pv, pa) * orient(pu, pv, pb) < N::zero()
+    }
+
+    // Collects the edges crossed by the segment `(a, b)`, by walking through the triangles
+    // it traverses starting from the vertex `a`.
+    fn crossed_edges(
+        &self,
+        a: usize,
+        b: usize,
+    ) -> Result<VecDeque<(usize, usize)>, TriangulationError<N>>
+    {
+        let (pa, pb) = (self.vertices[a], self.vertices[b]);
+        let mut tri = self
+            .triangles
+            .get(self.vertex_triangles[a])
+            .and_then(|tri| *tri)
+            .ok_or(TriangulationError::RoundingError(pa))?;
+        let mut crossing = VecDeque::new();
+
+        // Rotate around `a` until finding the triangle `(a, x, y)` with `(x, y)` crossed by the
+        // segment.
+        let (mut x, mut y) = (a, a);
+
+        for _ in 0..self.triangles.len() {
+            let k = tri
+                .iter()
+                .position(|v| *v == a)
+                .ok_or(TriangulationError::RoundingError(pa))?;
+            x = tri[(k + 1) % 3];
+            y = tri[(k + 2) % 3];
+            let px = self.vertices[x];
+            let ox = orient(&pa, &pb, &px);
+
+            if ox.is_zero() && (px - pa).dot(&(pb - pa)) > N::zero() {
+                // The segment passes through a neighbor of `a`.
+                return Err(TriangulationError::SelfTouching(px));
+            }
+
+            if ox < N::zero() && orient(&pa, &pb, &self.vertices[y]) > N::zero() {
+                break;
+            }
+
+            match self.edges.get(&(a, y)).and_then(|next| self.triangles[*next]) {
+                Some(next) => tri = next,
+                None => return Err(TriangulationError::RoundingError(pa)),
             }
         }
 
-        TriMesh::new(self.vertices, None, None, Some(IndexBuffer::Unified(idx)))
+        // Walk toward `b`. The vertex `x` is on the right of the segment and `y` on its left.
+        loop {
+            if !self.segments_cross(a, b, x, y) {
+                return Err(TriangulationError::RoundingError(pa));
+            }
+
+            crossing.push_back((x, y));
+
+            let q = self.apex(y, x).ok_or(TriangulationError::RoundingError(pa))?;
+
+            if q == b {
+                return Ok(crossing);
+            }
+
+            let o = orient(&pa, &pb, &self.vertices[q]);
+
+            if o > N::zero() {
+                y = q;
+            } else if o < N::zero() {
+                x = q;
+            } else {
+                // The segment passes through another vertex.
+                return Err(TriangulationError::SelfTouching(self.vertices[q]));
+            }
+        }
     }
 
-    fn remove_containing_triangles_and_register_edges(&mut self, pt: &Point<N>) {
-        self.edges.clear();
+    // Forces the segment `(a, b)` to be an edge of the triangulation by flipping the edges
+    // crossing it, then restores the Delaunay property of the other edges.
+    fn insert_constraint(&mut self, a: usize, b: usize) -> Result<(), TriangulationError<N>> {
+        let _ = self.constraints.insert(edge_key(a, b));
 
-        let mut i = 0;
+        if self.edges.contains_key(&(a, b)) || self.edges.contains_key(&(b, a)) {
+            return Ok(());
+        }
 
-        while i != self.triangles.len() {
-            // the len might change inside of the loop
-            if self.triangles[i].circumcircle_contains_point(pt) {
-                {
-                    let t = &self.triangles[i];
+        let mut crossing = self.crossed_edges(a, b)?;
+        let mut new_edges = Vec::new();
+        // Guards against infinite loops caused by rounding errors.
+        let mut max_iter = (crossing.len() + 1) * (crossing.len() + 1) * 4;
 
-                    fn s(a: usize, b: usize) -> (usize, usize) {
-                        if a > b {
-                            (b, a)
-                        } else {
-                            (a, b)
-                        }
-                    }
+        while let Some((u, v)) = crossing.pop_front() {
+            if max_iter == 0 {
+                return Err(TriangulationError::RoundingError(self.vertices[a]));
+            }
+
+            max_iter -= 1;
 
-                    let edge_keys = [
-                        s(t.idx.x, t.idx.y),
-                        s(t.idx.y, t.idx.z),
-                        s(t.idx.z, t.idx.x),
-                    ];
-
-                    for edge_key in edge_keys.iter() {
-                        match self.edges.entry(*edge_key) {
-                            Entry::Occupied(mut entry) => *entry.get_mut() += 1,
-                            Entry::Vacant(entry) => {
-                                let _ = entry.insert(1);
-                            }
-                        };
+            match self.flip(u, v) {
+                Some((p, q)) => {
+                    if self.segments_cross(a, b, p, q) {
+                        crossing.push_back((p, q))
+                    } else {
+                        new_edges.push((p, q))
                     }
                 }
+                None => crossing.push_back((u, v)),
+            }
+        }
 
-                let _ = self.triangles.swap_remove(i);
-            } else {
-                i = i + 1;
+        self.restore_delaunay(new_edges)
+    }
+
+    fn restore_delaunay(
+        &mut self,
+        mut stack: Vec<(usize, usize)>,
+    ) -> Result<(), TriangulationError<N>>
+    {
+        let mut max_iter = self.triangles.len() * 10;
+
+        while let Some((u, v)) = stack.pop() {
+            if max_iter == 0 {
+                return Err(TriangulationError::RoundingError(self.vertices[u]));
+            }
+
+            max_iter -= 1;
+
+            if self.constraints.contains(&edge_key(u, v)) {
+                continue;
+            }
+
+            if let (Some(p), Some(q)) = (self.apex(u, v), self.apex(v, u)) {
+                let (pu, pv, pp, pq) = (
+                    &self.vertices[u],
+                    &self.vertices[v],
+                    &self.vertices[p],
+                    &self.vertices[q],
+                );
+
+                if in_circumcircle(pu, pv, pp, pq) && self.flip(u, v).is_some() {
+                    stack.extend_from_slice(&[(u, q), (q, v), (v, p), (p, u)]);
+                }
             }
         }
+
+        Ok(())
     }
-}
 
-/// Triangulates a set of point (sort of) lying on the same 2d plane.
-///
-/// If the points do not lie on the same 2d plane, strange things might happends (triangle might be
-/// attached together in an unnatural way). Though, if they are only slighly perturbated on the
-/// directions orthogonal to the plane, this should be fine.
-pub fn triangulate<N: RealField>(pts: &[Point<N>]) -> TriMesh<N> {
-    //// Compute the super-triangle
-    let (center, radius) = bounding_volume::point_cloud_bounding_sphere(pts);
-    let radius = radius * na::convert(2.0);
-
-    // Compute a triangle with (center, radius) as its inscribed circle.
-    let pi = N::pi();
-    let right_shift = radius / (pi / na::convert(6.0)).tan();
-    let up_shift = (right_shift * right_shift + radius * radius).sqrt();
-
-    let mut up = na::zero::<Vector<N>>();
-    up[0] = na::one();
-
-    let mut right = na::zero::<Vector<N>>();
-    right[1] = na::one();
-
-    // Triangle:
-    //
-    //              top
-    //
-    //
-    //         bleft    bright
-    //
-    let top = center + up * up_shift;
-    // FIXME: use `-` instead of `+ (-` when the trait refor is done.
-    let bright = center + (-up * radius + right * right_shift);
-    let bleft = center + (-up * radius - right * right_shift);
-
-    //// Build the triangulator.
-    let mut triangulator = Triangulator::new(top, bright, bleft);
-
-    for pt in pts.iter() {
-        triangulator.add_point(pt.clone());
-    }
-
-    triangulator.to_trimesh()
+    // Builds the final mesh. If `even_odd` is true, only the triangles separated from the
+    // enclosing triangle by an odd number of constrained edges are kept.
+    fn into_triangles(mut self, even_odd: bool) -> (Vec<Point2<N>>, Vec<Point3<u32>>) {
+        let n = self.nvertices;
+        let mut inside = vec![None; self.triangles.len()];
+
+        if even_odd {
+            let mut stack = Vec::new();
+
+            for (i, tri) in self.triangles.iter().enumerate() {
+                if tri.map(|tri| tri.iter().any(|v| *v >= n)).unwrap_or(false) {
+                    inside[i] = Some(false);
+                    stack.push(i);
+                }
+            }
+
+            while let Some(curr) = stack.pop() {
+                let tri = self.triangles[curr].unwrap();
+
+                for k in 0..3 {
+                    let (a, b) = (tri[k], tri[(k + 1) % 3]);
+
+                    if let Some(&neighbor) = self.edges.get(&(b, a)) {
+                        if inside[neighbor].is_none() {
+                            let crosses_boundary = self.constraints.contains(&edge_key(a, b));
+                            inside[neighbor] = Some(inside[curr].unwrap() != crosses_boundary);
+                            stack.push(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+
+        let indices = self
+            .triangles
+            .iter()
+            .zip(inside.iter())
+            .filter_map(|(tri, inside)| {
+                let tri = (*tri)?;
+
+                if tri.iter().any(|v| *v >= n) || (even_odd && *inside != Some(true)) {
+                    None
+                } else {
+                    Some(Point3::new(tri[0] as u32, tri[1] as u32, tri[2] as u32))
+                }
+            })
+            .collect();
+
+        self.vertices.truncate(n);
+        (self.vertices, indices)
+    }
 }
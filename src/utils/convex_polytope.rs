@@ -0,0 +1,398 @@
+use crate::mass_properties::MassProperties;
+#[cfg(feature = "dim2")]
+use crate::mass_properties::mass_properties::polygon_mass_properties;
+#[cfg(feature = "dim3")]
+use crate::mass_properties::mass_properties::polyhedron_mass_properties;
+use crate::math::{Isometry, Point, Vector, DIM};
+#[cfg(feature = "dim3")]
+use crate::procedural;
+use crate::shape::{Cuboid, Plane, Shape};
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+#[cfg(feature = "dim3")]
+use crate::shape::ConvexHull;
+#[cfg(feature = "dim3")]
+use crate::transformation;
+#[cfg(feature = "dim3")]
+use crate::utils::{self, DeterministicState, SortedPair};
+use na::{self, RealField, Unit};
+#[cfg(feature = "dim3")]
+use std::collections::HashMap;
+
+// Number of directions sampled to approximate curved shapes by polytopes.
+#[cfg(feature = "dim2")]
+const NSAMPLES: u32 = 64;
+#[cfg(feature = "dim3")]
+const NSAMPLES: u32 = 32;
+
+// A convex polytope that can be clipped by half-spaces.
+//
+// In 2D, the vertices are given in counter-clockwise order. In 3D, each face is a list of
+// indices of vertices given in counter-clockwise order when seen from the outside.
+#[derive(Clone, Debug)]
+pub(crate) struct ConvexPolytope<N: RealField> {
+    pub vertices: Vec<Point<N>>,
+    #[cfg(feature = "dim3")]
+    pub faces: Vec<Vec<usize>>,
+}
+
+impl<N: RealField> ConvexPolytope<N> {
+    // A polytope without any vertex.
+    pub fn empty() -> Self {
+        ConvexPolytope {
+            vertices: Vec::new(),
+            #[cfg(feature = "dim3")]
+            faces: Vec::new(),
+        }
+    }
+
+    // Whether this polytope has no volume, or no area in 2D.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.len() <= DIM
+    }
+
+    // The polytope described by `shape` transformed by `m`.
+    //
+    // Curved shapes are approximated by the convex hull of some of their support points.
+    // Returns `None` if `shape` is not convex.
+    pub fn from_shape(m: &Isometry<N>, shape: &dyn Shape<N>) -> Option<Self> {
+        let mut res = if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+            Self::from_cuboid(c)
+        } else if let Some(res) = Self::from_polytope_shape(shape) {
+            res
+        } else {
+            Self::from_support_points(shape)?
+        };
+
+        res.transform_by(m);
+        Some(res)
+    }
+
+    pub fn transform_by(&mut self, m: &Isometry<N>) {
+        for pt in &mut self.vertices {
+            *pt = m * *pt
+        }
+    }
+
+    // Removes the part of this polytope on the positive side of the plane with the given
+    // normal, at the given distance from the origin.
+    pub fn clip(&mut self, normal: &Unit<Vector<N>>, offset: N) {
+        let dists: Vec<N> = self
+            .vertices
+            .iter()
+            .map(|pt| normal.dot(&pt.coords) - offset)
+            .collect();
+        let scale = dists.iter().fold(N::zero(), |max, d| max.max(d.abs()));
+        let eps = scale * N::default_epsilon().sqrt();
+
+        if dists.iter().all(|d| *d <= eps) {
+            return;
+        }
+
+        if dists.iter().all(|d| *d >= -eps) {
+            *self = Self::empty();
+            return;
+        }
+
+        self.clip_with_distances(normal, &dists, eps);
+
+        if self.is_empty() {
+            *self = Self::empty()
+        }
+    }
+
+    // Removes the part of this polytope above the given plane, expressed in the frame of the
+    // vertices of this polytope.
+    pub fn clip_by_plane(&mut self, mplane: &Isometry<N>, plane: &Plane<N>) {
+        let normal = mplane * plane.normal();
+        let center = Point::from(mplane.translation.vector);
+
+        self.clip(&normal, normal.dot(&center.coords))
+    }
+
+    // The intersection of this polytope with another one.
+    pub fn intersection(&self, other: &Self) -> Self {
+        // An empty polytope has no half-space to clip with.
+        if self.is_empty() || other.is_empty() {
+            return Self::empty();
+        }
+
+        let mut res = self.clone();
+
+        for (normal, offset) in other.half_spaces() {
+            if res.is_empty() {
+                break;
+            }
+
+            res.clip(&normal, offset)
+        }
+
+        res
+    }
+
+    // The mass properties of this polytope with a unit density, in the frame of its vertices.
+    pub fn mass_properties(&self) -> MassProperties<N> {
+        if self.is_empty() {
+            return MassProperties::zero();
+        }
+
+        self.compute_mass_properties()
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl<N: RealField> ConvexPolytope<N> {
+    fn from_cuboid(c: &Cuboid<N>) -> Self {
+        let he = c.half_extents();
+
+        ConvexPolytope {
+            vertices: vec![
+                Point::new(-he.x, -he.y),
+                Point::new(he.x, -he.y),
+                Point::new(he.x, he.y),
+                Point::new(-he.x, he.y),
+            ],
+        }
+    }
+
+    fn from_polytope_shape(shape: &dyn Shape<N>) -> Option<Self> {
+        shape
+            .as_shape::<ConvexPolygon<N>>()
+            .map(|poly| ConvexPolytope { vertices: poly.points().to_vec() })
+    }
+
+    fn from_support_points(shape: &dyn Shape<N>) -> Option<Self> {
+        let sm = shape.as_support_map()?;
+        let m = Isometry::identity();
+        let dangle = N::two_pi() / na::convert(NSAMPLES as f64);
+        let pts: Vec<_> = (0..NSAMPLES)
+            .map(|i| {
+                let angle = dangle * na::convert(i as f64);
+                sm.support_point(&m, &Vector::new(angle.cos(), angle.sin()))
+            })
+            .collect();
+
+        match ConvexPolygon::try_from_points(&pts) {
+            Some(poly) => Some(ConvexPolytope { vertices: poly.points().to_vec() }),
+            None => Some(Self::empty()),
+        }
+    }
+
+    // The half-spaces bounding this polygon, as pairs of outward normals and offsets.
+    pub fn half_spaces(&self) -> Vec<(Unit<Vector<N>>, N)> {
+        let mut res = Vec::new();
+
+        for i in 0..self.vertices.len() {
+            let a = &self.vertices[i];
+            let b = &self.vertices[(i + 1) % self.vertices.len()];
+
+            if let Some(normal) = Unit::try_new(Vector::new(b.y - a.y, a.x - b.x), N::default_epsilon()) {
+                res.push((normal, normal.dot(&a.coords)))
+            }
+        }
+
+        res
+    }
+
+    fn clip_with_distances(&mut self, _: &Unit<Vector<N>>, dists: &[N], eps: N) {
+        let mut vertices = Vec::new();
+
+        for i in 0..self.vertices.len() {
+            let j = (i + 1) % self.vertices.len();
+            let (da, db) = (dists[i], dists[j]);
+
+            if da <= eps {
+                vertices.push(self.vertices[i])
+            }
+
+            if (da < -eps && db > eps) || (da > eps && db < -eps) {
+                let t = da / (da - db);
+                vertices.push(self.vertices[i] + (self.vertices[j] - self.vertices[i]) * t)
+            }
+        }
+
+        self.vertices = vertices;
+    }
+
+    fn compute_mass_properties(&self) -> MassProperties<N> {
+        polygon_mass_properties(&self.vertices, N::one())
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> ConvexPolytope<N> {
+    fn from_cuboid(c: &Cuboid<N>) -> Self {
+        let he = c.half_extents();
+        // The i-th vertex has positive coordinates along the axes given by the bits of `i`.
+        let vertices = (0..8)
+            .map(|i| {
+                Point::new(
+                    if i & 1 == 0 { -he.x } else { he.x },
+                    if i & 2 == 0 { -he.y } else { he.y },
+                    if i & 4 == 0 { -he.z } else { he.z },
+                )
+            })
+            .collect();
+        let faces = vec![
+            vec![0, 4, 6, 2],
+            vec![1, 3, 7, 5],
+            vec![0, 1, 5, 4],
+            vec![2, 6, 7, 3],
+            vec![0, 2, 3, 1],
+            vec![4, 5, 7, 6],
+        ];
+
+        ConvexPolytope { vertices, faces }
+    }
+
+    fn from_polytope_shape(shape: &dyn Shape<N>) -> Option<Self> {
+        shape.as_shape::<ConvexHull<N>>().map(|hull| ConvexPolytope {
+            vertices: hull.points().to_vec(),
+            faces: hull.face_vertex_indices().map(|face| face.to_vec()).collect(),
+        })
+    }
+
+    fn from_support_points(shape: &dyn Shape<N>) -> Option<Self> {
+        let sm = shape.as_support_map()?;
+        let m = Isometry::identity();
+        let dirs = procedural::unit_sphere::<N>(NSAMPLES, NSAMPLES / 2, false).coords;
+        let pts: Vec<_> = dirs
+            .iter()
+            .map(|dir| sm.support_point(&m, &dir.coords))
+            .collect();
+        let hull = transformation::convex_hull(&pts);
+        let faces = hull
+            .flat_indices()
+            .chunks(3)
+            .map(|t| vec![t[0] as usize, t[1] as usize, t[2] as usize])
+            .collect();
+        let res = ConvexPolytope {
+            vertices: hull.coords,
+            faces,
+        };
+
+        // The hull of flat shapes has no volume.
+        if res.compute_mass_properties().volume > N::zero() {
+            Some(res)
+        } else {
+            Some(Self::empty())
+        }
+    }
+
+    // The half-spaces bounding this polyhedron, as pairs of outward normals and offsets.
+    pub fn half_spaces(&self) -> Vec<(Unit<Vector<N>>, N)> {
+        let mut res = Vec::new();
+
+        for face in &self.faces {
+            // Newell's method is robust to collinear consecutive vertices.
+            let mut normal = Vector::zeros();
+
+            for k in 0..face.len() {
+                let a = &self.vertices[face[k]];
+                let b = &self.vertices[face[(k + 1) % face.len()]];
+                normal += a.coords.cross(&b.coords);
+            }
+
+            if let Some(normal) = Unit::try_new(normal, N::default_epsilon()) {
+                res.push((normal, normal.dot(&self.vertices[face[0]].coords)))
+            }
+        }
+
+        res
+    }
+
+    fn clip_with_distances(&mut self, normal: &Unit<Vector<N>>, dists: &[N], eps: N) {
+        let mut vertices = self.vertices.clone();
+        let mut on_plane: Vec<bool> = dists.iter().map(|d| d.abs() <= eps).collect();
+        let mut edge_points = HashMap::with_hasher(DeterministicState::new());
+        let mut faces = Vec::new();
+
+        for face in &self.faces {
+            // Faces lying on the clipping plane are replaced by the cap below.
+            if face.iter().all(|i| on_plane[*i]) {
+                continue;
+            }
+
+            let mut clipped = Vec::new();
+
+            for k in 0..face.len() {
+                let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                let (da, db) = (dists[a], dists[b]);
+
+                if da <= eps {
+                    clipped.push(a)
+                }
+
+                if (da < -eps && db > eps) || (da > eps && db < -eps) {
+                    let id = *edge_points.entry(SortedPair::new(a, b)).or_insert_with(|| {
+                        let t = da / (da - db);
+                        vertices.push(self.vertices[a] + (self.vertices[b] - self.vertices[a]) * t);
+                        on_plane.push(true);
+                        vertices.len() - 1
+                    });
+                    clipped.push(id)
+                }
+            }
+
+            if clipped.len() >= 3 {
+                faces.push(clipped)
+            }
+        }
+
+        // Close the polyhedron with a face on the clipping plane.
+        let mut cap: Vec<usize> = Vec::new();
+
+        for face in &faces {
+            for i in face {
+                if on_plane[*i] && !cap.contains(i) {
+                    cap.push(*i)
+                }
+            }
+        }
+
+        if cap.len() >= 3 {
+            let center = utils::center(&cap.iter().map(|i| vertices[*i]).collect::<Vec<_>>());
+            let axis = if normal.x.abs() < na::convert(0.5) {
+                Vector::x()
+            } else {
+                Vector::y()
+            };
+            let u = normal.cross(&axis).normalize();
+            let v = normal.cross(&u);
+            let angle = |i: &usize| {
+                let dpt = vertices[*i] - center;
+                dpt.dot(&v).atan2(dpt.dot(&u))
+            };
+
+            cap.sort_by(|a, b| angle(a).partial_cmp(&angle(b)).unwrap_or(std::cmp::Ordering::Equal));
+            faces.push(cap);
+        }
+
+        // Remove the vertices that were clipped out.
+        let mut remap = vec![usize::max_value(); vertices.len()];
+        let mut new_vertices = Vec::new();
+
+        for face in &mut faces {
+            for i in face.iter_mut() {
+                if remap[*i] == usize::max_value() {
+                    remap[*i] = new_vertices.len();
+                    new_vertices.push(vertices[*i]);
+                }
+
+                *i = remap[*i];
+            }
+        }
+
+        self.vertices = new_vertices;
+        self.faces = faces;
+    }
+
+    fn compute_mass_properties(&self) -> MassProperties<N> {
+        let pts = &self.vertices;
+        let triangles = self.faces.iter().flat_map(|face| {
+            (1..face.len() - 1).map(move |k| [pts[face[0]], pts[face[k]], pts[face[k + 1]]])
+        });
+
+        polyhedron_mass_properties(triangles, N::one())
+    }
+}
@@ -4,6 +4,7 @@
 pub use self::as_bytes::AsBytes;
 pub use self::ccw_face_normal::ccw_face_normal;
 pub use self::center::center;
+pub(crate) use self::convex_polytope::ConvexPolytope;
 #[cfg(feature = "dim3")]
 pub use self::cleanup::remove_unused_points;
 pub use self::deterministic_state::DeterministicState;
@@ -30,6 +31,7 @@ pub use self::triangle::{
 pub mod as_bytes;
 mod ccw_face_normal;
 mod center;
+mod convex_polytope;
 #[cfg(feature = "dim3")]
 mod cleanup;
 mod deterministic_state;